@@ -0,0 +1,259 @@
+//! JSON backup of signer group state
+
+use crate::{eth::eth_address_to_checksum, Error};
+use audius::state::{SecpSignatureOffsets, SignerGroup, SignerKeyType, ValidSigner};
+use hex::FromHex;
+use serde_json::{json, Value};
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Valid signer restored from the backup
+#[derive(Debug, PartialEq)]
+pub struct SignerBackup {
+    /// Valid signer account on the exported cluster
+    pub pubkey: Pubkey,
+    /// Registered Ethereum addresses, empty for Ed25519 signers
+    pub eth_addresses: Vec<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>,
+    /// Ed25519 public key for Ed25519 signers
+    pub ed25519_pubkey: Option<Pubkey>,
+    /// Human readable label, empty if not set
+    pub label: String,
+    /// Last slot signer's signatures are valid at, if signer expires
+    pub expiry_slot: Option<u64>,
+}
+
+/// Serializes signer group and its valid signers to JSON
+pub fn signer_group_to_json(
+    signer_group: &Pubkey,
+    signer_group_data: &SignerGroup,
+    signers: &[(Pubkey, ValidSigner)],
+) -> Value {
+    json!({
+        "signer_group": signer_group.to_string(),
+        "owner": signer_group_data.owner.to_string(),
+        "member_count": signers.len(),
+        "signers": signers
+            .iter()
+            .map(|(pubkey, valid_signer)| signer_to_json(pubkey, valid_signer))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn signer_to_json(pubkey: &Pubkey, valid_signer: &ValidSigner) -> Value {
+    let eth_addresses: Vec<String> = valid_signer
+        .eth_addresses()
+        .iter()
+        .map(eth_address_to_checksum)
+        .collect();
+    let ed25519_pubkey = if valid_signer.key_type == SignerKeyType::ED25519 {
+        Some(valid_signer.ed25519_pubkey.to_string())
+    } else {
+        None
+    };
+    let expiry_slot = match u64::from(valid_signer.expires_at_slot) {
+        0 => None,
+        slot => Some(slot),
+    };
+    json!({
+        "pubkey": pubkey.to_string(),
+        // ids are assigned by the group anew on import
+        "signer_id": u32::from(valid_signer.signer_id),
+        "eth_address": eth_addresses.first(),
+        "eth_addresses": eth_addresses,
+        "ed25519_pubkey": ed25519_pubkey,
+        "version": valid_signer.version,
+        "label": valid_signer.label().unwrap_or_default(),
+        // program has no signer suspension, the field is kept for consumers
+        "is_suspended": false,
+        "expiry_slot": expiry_slot,
+    })
+}
+
+/// Parses valid signers from the JSON produced by [signer_group_to_json]
+pub fn signers_from_json(backup: &Value) -> Result<Vec<SignerBackup>, Error> {
+    let signers = backup["signers"]
+        .as_array()
+        .ok_or("Backup has no signers array")?;
+    if backup["member_count"].as_u64() != Some(signers.len() as u64) {
+        return Err("Backup member count doesn't match signers array".into());
+    }
+    signers.iter().map(signer_from_json).collect()
+}
+
+fn signer_from_json(signer: &Value) -> Result<SignerBackup, Error> {
+    let pubkey = parse_pubkey(&signer["pubkey"])?;
+
+    let ed25519_pubkey = match &signer["ed25519_pubkey"] {
+        Value::Null => None,
+        value => Some(parse_pubkey(value)?),
+    };
+
+    // backups written by other tools may carry only the primary address
+    let eth_addresses: Vec<&Value> = match (&signer["eth_addresses"], &signer["eth_address"]) {
+        (Value::Array(addresses), _) => addresses.iter().collect(),
+        (Value::Null, Value::Null) => vec![],
+        (Value::Null, address) => vec![address],
+        _ => return Err(format!("Wrong eth addresses of signer {}", pubkey).into()),
+    };
+    let eth_addresses = eth_addresses
+        .into_iter()
+        .map(|address| {
+            address
+                .as_str()
+                .and_then(|address| {
+                    let address = address.strip_prefix("0x").unwrap_or(address);
+                    <[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>::from_hex(address).ok()
+                })
+                .ok_or_else(|| format!("Wrong eth address of signer {}", pubkey).into())
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if eth_addresses.is_empty() == ed25519_pubkey.is_none() {
+        return Err(format!(
+            "Signer {} must have either eth address or Ed25519 key",
+            pubkey
+        )
+        .into());
+    }
+    if eth_addresses.len() > ValidSigner::MAX_ETH_ADDRESSES {
+        return Err(format!("Signer {} has too many eth addresses", pubkey).into());
+    }
+
+    let label = signer["label"].as_str().unwrap_or_default().to_string();
+    ValidSigner::pack_label(&label).map_err(|_| format!("Wrong label of signer {}", pubkey))?;
+
+    let expiry_slot = match &signer["expiry_slot"] {
+        Value::Null => None,
+        value => Some(
+            value
+                .as_u64()
+                .filter(|slot| *slot != 0)
+                .ok_or_else(|| format!("Wrong expiry slot of signer {}", pubkey))?,
+        ),
+    };
+
+    Ok(SignerBackup {
+        pubkey,
+        eth_addresses,
+        ed25519_pubkey,
+        label,
+        expiry_slot,
+    })
+}
+
+fn parse_pubkey(value: &Value) -> Result<Pubkey, Error> {
+    value
+        .as_str()
+        .and_then(|pubkey| Pubkey::from_str(pubkey).ok())
+        .ok_or_else(|| format!("Wrong pubkey in backup: {}", value).into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_signer_group_json_roundtrip() {
+        let signer_group = Pubkey::new_unique();
+        let signer_group_data = SignerGroup {
+            version: 1,
+            owner: Pubkey::new_unique(),
+            ..SignerGroup::default()
+        };
+        let secp_signer = ValidSigner {
+            version: 3,
+            signer_group,
+            eth_addresses: [[1; 20], [2; 20], [0; 20]],
+            eth_address_count: 2,
+            label: ValidSigner::pack_label("node-1").unwrap(),
+            expires_at_slot: 5000.into(),
+            signer_id: 1.into(),
+            ..ValidSigner::default()
+        };
+        let ed25519_signer = ValidSigner {
+            version: 3,
+            signer_group,
+            key_type: SignerKeyType::ED25519,
+            ed25519_pubkey: Pubkey::new_unique(),
+            ..ValidSigner::default()
+        };
+        let signers = vec![
+            (Pubkey::new_unique(), secp_signer),
+            (Pubkey::new_unique(), ed25519_signer),
+        ];
+
+        let backup = signer_group_to_json(&signer_group, &signer_group_data, &signers);
+        assert_eq!(backup["signer_group"], signer_group.to_string());
+        assert_eq!(backup["owner"], signer_group_data.owner.to_string());
+        assert_eq!(backup["member_count"], 2);
+        assert_eq!(
+            backup["signers"][0]["eth_address"],
+            eth_address_to_checksum(&[1u8; 20])
+        );
+        assert_eq!(backup["signers"][0]["is_suspended"], false);
+        assert_eq!(backup["signers"][0]["signer_id"], 1);
+        assert_eq!(backup["signers"][0]["expiry_slot"], 5000);
+        assert_eq!(backup["signers"][1]["eth_address"], Value::Null);
+        assert_eq!(backup["signers"][1]["expiry_slot"], Value::Null);
+
+        // backup survives the trip through the file
+        let backup: Value = serde_json::from_str(&backup.to_string()).unwrap();
+        assert_eq!(
+            signers_from_json(&backup).unwrap(),
+            vec![
+                SignerBackup {
+                    pubkey: signers[0].0,
+                    eth_addresses: vec![[1; 20], [2; 20]],
+                    ed25519_pubkey: None,
+                    label: "node-1".to_string(),
+                    expiry_slot: Some(5000),
+                },
+                SignerBackup {
+                    pubkey: signers[1].0,
+                    eth_addresses: vec![],
+                    ed25519_pubkey: Some(ed25519_signer.ed25519_pubkey),
+                    label: String::new(),
+                    expiry_slot: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_signers_from_json_errors() {
+        let signer = json!({
+            "pubkey": Pubkey::new_unique().to_string(),
+            "eth_address": hex::encode([1u8; 20]),
+        });
+        let backup = json!({ "member_count": 1, "signers": [signer.clone()] });
+        assert_eq!(
+            signers_from_json(&backup).unwrap()[0].eth_addresses,
+            vec![[1; 20]]
+        );
+
+        let backup = json!({ "member_count": 2, "signers": [signer] });
+        assert!(signers_from_json(&backup).is_err());
+
+        let backup = json!({
+            "member_count": 1,
+            "signers": [{ "pubkey": Pubkey::new_unique().to_string(), "eth_address": "0x01" }],
+        });
+        assert!(signers_from_json(&backup).is_err());
+
+        let backup = json!({
+            "member_count": 1,
+            "signers": [{ "pubkey": Pubkey::new_unique().to_string() }],
+        });
+        assert!(signers_from_json(&backup).is_err());
+
+        let backup = json!({
+            "member_count": 1,
+            "signers": [{
+                "pubkey": Pubkey::new_unique().to_string(),
+                "eth_address": hex::encode([1u8; 20]),
+                "expiry_slot": "soon",
+            }],
+        });
+        assert!(signers_from_json(&backup).is_err());
+    }
+}
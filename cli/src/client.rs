@@ -0,0 +1,320 @@
+//! Client-side helpers to read program accounts and typed client of the program
+
+use crate::Error;
+use audius::{
+    find_valid_signer_address,
+    instruction::{
+        clear_valid_signer, init_signer_group, init_valid_signer, validate_signature_raw,
+        SignatureData,
+    },
+    merkle::{self, MerkleNode},
+    state::{
+        offsets::valid_signer_group_filter, ProgramAccount, SecpSignatureOffsets, SignerGroup,
+        SignerKeyType, ValidSigner,
+    },
+    MAX_MESSAGE_LEN,
+};
+use borsh::BorshDeserialize;
+use secp256k1::SecretKey;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::RpcFilterType,
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{
+    secp256k1_instruction,
+    signature::{Keypair, Signature, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use std::collections::HashMap;
+
+/// Typed client of the Audius program, builds, signs and sends its transactions
+/// paid by the payer and reads its accounts
+pub struct AudiusClient {
+    /// RPC client of the cluster the program is deployed to
+    pub rpc: RpcClient,
+    /// Address of the program
+    pub program_id: Pubkey,
+    /// Fee payer of the transactions, also funds the created accounts
+    pub payer: Keypair,
+}
+
+impl AudiusClient {
+    /// Creates client of the program deployed at the given address
+    pub fn new(rpc: RpcClient, program_id: Pubkey, payer: Keypair) -> Self {
+        Self {
+            rpc,
+            program_id,
+            payer,
+        }
+    }
+
+    /// Creates signer group account and initializes it with the given owner
+    pub fn init_signer_group(
+        &self,
+        signer_group: &Keypair,
+        owner: &Pubkey,
+    ) -> Result<Signature, Error> {
+        let lamports = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(SignerGroup::LEN)?;
+        self.send(
+            &[
+                system_instruction::create_account(
+                    &self.payer.pubkey(),
+                    &signer_group.pubkey(),
+                    lamports,
+                    SignerGroup::LEN as u64,
+                    &self.program_id,
+                ),
+                init_signer_group(
+                    &self.program_id,
+                    &signer_group.pubkey(),
+                    owner,
+                    &self.payer.pubkey(),
+                )?,
+            ],
+            &[signer_group],
+        )
+    }
+
+    /// Registers eth address in the signer group, returns address of the created valid signer
+    pub fn init_valid_signer(
+        &self,
+        signer_group: &Pubkey,
+        owner: &dyn Signer,
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> Result<Pubkey, Error> {
+        // new signer is appended to the group's signer list
+        let signer_group_data = self.get_signer_group(signer_group)?;
+        self.send(
+            &[init_valid_signer(
+                &self.program_id,
+                signer_group,
+                &owner.pubkey(),
+                &self.payer.pubkey(),
+                list_signer(&signer_group_data.last_signer),
+                eth_address,
+            )?],
+            &[owner],
+        )?;
+        Ok(find_valid_signer_address(&self.program_id, signer_group, &eth_address).0)
+    }
+
+    /// Removes valid signer from its signer group
+    pub fn clear_valid_signer(
+        &self,
+        valid_signer: &Pubkey,
+        owner: &dyn Signer,
+    ) -> Result<Signature, Error> {
+        let valid_signer_data = self.get_valid_signer(valid_signer)?;
+        self.send(
+            &[clear_valid_signer(
+                &self.program_id,
+                valid_signer,
+                &valid_signer_data.signer_group,
+                &owner.pubkey(),
+                list_signer(&valid_signer_data.prev_signer),
+                list_signer(&valid_signer_data.next_signer),
+            )?],
+            &[owner],
+        )
+    }
+
+    /// Signs the message with valid signer's Secp256k1 key and validates the signature
+    pub fn validate_signature(
+        &self,
+        valid_signer: &Pubkey,
+        secret_key: &SecretKey,
+        message: Vec<u8>,
+    ) -> Result<Signature, Error> {
+        let valid_signer_data = self.get_valid_signer(valid_signer)?;
+        let (secp_instruction, signature_data) = sign_message(secret_key, message)?;
+        self.send(
+            &[
+                secp_instruction,
+                validate_signature_raw(
+                    &self.program_id,
+                    valid_signer,
+                    &valid_signer_data.signer_group,
+                    signature_data,
+                )?,
+            ],
+            &[],
+        )
+    }
+
+    /// Reads initialized signer group
+    pub fn get_signer_group(&self, signer_group: &Pubkey) -> Result<SignerGroup, Error> {
+        self.get_program_account(signer_group)
+    }
+
+    /// Reads initialized valid signer
+    pub fn get_valid_signer(&self, valid_signer: &Pubkey) -> Result<ValidSigner, Error> {
+        self.get_program_account(valid_signer)
+    }
+
+    /// Reads initialized account of the program stored at any of its layouts
+    fn get_program_account<T: ProgramAccount>(&self, address: &Pubkey) -> Result<T, Error> {
+        let account = self.rpc.get_account(address)?;
+        if account.owner != self.program_id {
+            return Err(format!("Account {} isn't owned by the program", address).into());
+        }
+        let (account, _) = T::deserialize_any_version(account.data.as_slice())?;
+        if !account.is_initialized() {
+            return Err(T::UNINITIALIZED_ERROR.into());
+        }
+        Ok(account)
+    }
+
+    /// Signs the transaction by the payer and the given signers, sends it and waits
+    /// for confirmation
+    fn send(
+        &self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+    ) -> Result<Signature, Error> {
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&self.payer.pubkey()));
+        let mut all_signers: Vec<&dyn Signer> = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let (recent_blockhash, _) = self.rpc.get_recent_blockhash()?;
+        transaction.try_sign(&all_signers, recent_blockhash)?;
+        Ok(self.rpc.send_and_confirm_transaction(&transaction)?)
+    }
+}
+
+/// Converts signer list link to option, zero pubkey means there is no neighbour
+pub fn list_signer(link: &Pubkey) -> Option<&Pubkey> {
+    if *link == Pubkey::default() {
+        None
+    } else {
+        Some(link)
+    }
+}
+
+/// Collects valid signers of the group following its signer list from the first signer
+pub fn traverse_signer_group(
+    rpc_client: &RpcClient,
+    signer_group: &Pubkey,
+) -> Result<Vec<(Pubkey, ValidSigner)>, Error> {
+    let signer_group_data = rpc_client.get_account_data(signer_group)?;
+    let signer_group_data = SignerGroup::deserialize_any_version(signer_group_data.as_slice())?.0;
+
+    let mut signers: Vec<(Pubkey, ValidSigner)> = vec![];
+    let mut next_signer = list_signer(&signer_group_data.first_signer).copied();
+    while let Some(valid_signer) = next_signer {
+        let valid_signer_data = rpc_client.get_account_data(&valid_signer)?;
+        let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
+
+        let prev_signer = signers
+            .last()
+            .map(|(pubkey, _)| *pubkey)
+            .unwrap_or_default();
+        if valid_signer_data.prev_signer != prev_signer {
+            return Err(format!("Signer list is broken at {}", valid_signer).into());
+        }
+
+        next_signer = list_signer(&valid_signer_data.next_signer).copied();
+        signers.push((valid_signer, valid_signer_data));
+    }
+    Ok(signers)
+}
+
+/// Fetches all initialized valid signers of the group with a single `getProgramAccounts` call.
+/// Signers are ordered by the group's signer list, signers missing from the list go last.
+pub fn fetch_group_signers(
+    rpc_client: &RpcClient,
+    signer_group: &Pubkey,
+    signer_group_data: &SignerGroup,
+) -> Result<Vec<(Pubkey, ValidSigner)>, Error> {
+    let accounts = rpc_client.get_program_accounts_with_config(
+        &audius::id(),
+        RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(ValidSigner::LEN as u64),
+                valid_signer_group_filter(signer_group),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+        },
+    )?;
+
+    let mut unordered = HashMap::new();
+    for (pubkey, account) in accounts {
+        let valid_signer = ValidSigner::deserialize_stored(account.data.as_slice())?;
+        if valid_signer.is_initialized() {
+            unordered.insert(pubkey, valid_signer);
+        }
+    }
+
+    let mut signers = vec![];
+    let mut next_signer = list_signer(&signer_group_data.first_signer).copied();
+    while let Some(pubkey) = next_signer {
+        match unordered.remove(&pubkey) {
+            Some(valid_signer) => {
+                next_signer = list_signer(&valid_signer.next_signer).copied();
+                signers.push((pubkey, valid_signer));
+            }
+            None => break,
+        }
+    }
+    let mut unlinked: Vec<_> = unordered.into_iter().collect();
+    unlinked.sort_by_key(|(pubkey, _)| *pubkey);
+    signers.extend(unlinked);
+    Ok(signers)
+}
+
+/// Sorted Merkle leaves of the eth addresses registered by the group's signers,
+/// their root is the group's members Merkle root
+pub fn members_merkle_leaves(signers: &[(Pubkey, ValidSigner)]) -> Vec<MerkleNode> {
+    let mut leaves: Vec<MerkleNode> = signers
+        .iter()
+        .filter(|(_, valid_signer)| valid_signer.key_type != SignerKeyType::ED25519)
+        .flat_map(|(_, valid_signer)| valid_signer.eth_addresses().iter().map(merkle::leaf))
+        .collect();
+    leaves.sort_unstable();
+    leaves.dedup();
+    leaves
+}
+
+/// Signs the message with Secp256k1 key, returns Secp256 program instruction verifying
+/// the signature and signature data to validate it with
+pub fn sign_message(
+    private_key: &SecretKey,
+    message: Vec<u8>,
+) -> Result<(Instruction, SignatureData), Error> {
+    if message.len() > MAX_MESSAGE_LEN {
+        return Err(format!(
+            "Message is too large: {} bytes, {} bytes max",
+            message.len(),
+            MAX_MESSAGE_LEN
+        )
+        .into());
+    }
+
+    let secp256_program_instruction =
+        secp256k1_instruction::new_secp256k1_instruction(private_key, &message);
+
+    let start = 1;
+    let end = start + SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    let offsets =
+        SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])?;
+
+    let sig_start = offsets.signature_offset as usize;
+    let sig_end = sig_start + SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+
+    let mut signature: [u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
+        [0u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+    signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
+
+    let recovery_id = secp256_program_instruction.data[sig_end];
+
+    let signature_data = SignatureData::new(signature, recovery_id, message);
+    Ok((secp256_program_instruction, signature_data))
+}
@@ -0,0 +1,98 @@
+//! Solana cluster the CLI talks to
+
+use std::fmt;
+
+/// Cluster classified by its RPC URL
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cluster {
+    /// Public mainnet-beta cluster
+    MainnetBeta,
+    /// Public testnet cluster
+    Testnet,
+    /// Public devnet cluster
+    Devnet,
+    /// Test validator running on the local machine
+    Localnet,
+    /// Any other cluster, given by its RPC URL
+    Custom(String),
+}
+
+impl Cluster {
+    /// Classifies the cluster by the host of its RPC URL, unknown hosts are custom clusters
+    pub fn from_rpc_url(rpc_url: &str) -> Self {
+        let host = rpc_url
+            .split("://")
+            .last()
+            .and_then(|address| address.split(|c| c == '/' || c == ':').next())
+            .unwrap_or_default();
+        match host {
+            "api.mainnet-beta.solana.com" => Self::MainnetBeta,
+            "api.testnet.solana.com" => Self::Testnet,
+            "api.devnet.solana.com" => Self::Devnet,
+            "localhost" | "127.0.0.1" | "0.0.0.0" => Self::Localnet,
+            _ => Self::Custom(rpc_url.to_string()),
+        }
+    }
+
+    /// Public RPC URL of the cluster, custom clusters return their own URL
+    pub fn default_rpc_url(&self) -> &str {
+        match self {
+            Self::MainnetBeta => "https://api.mainnet-beta.solana.com",
+            Self::Testnet => "https://api.testnet.solana.com",
+            Self::Devnet => "https://api.devnet.solana.com",
+            Self::Localnet => "http://127.0.0.1:8899",
+            Self::Custom(rpc_url) => rpc_url,
+        }
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MainnetBeta => write!(f, "mainnet-beta"),
+            Self::Testnet => write!(f, "testnet"),
+            Self::Devnet => write!(f, "devnet"),
+            Self::Localnet => write!(f, "localnet"),
+            Self::Custom(rpc_url) => write!(f, "custom ({})", rpc_url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cluster_from_rpc_url() {
+        for cluster in &[
+            Cluster::MainnetBeta,
+            Cluster::Testnet,
+            Cluster::Devnet,
+            Cluster::Localnet,
+        ] {
+            assert_eq!(&Cluster::from_rpc_url(cluster.default_rpc_url()), cluster);
+        }
+        assert_eq!(
+            Cluster::from_rpc_url("https://api.devnet.solana.com/"),
+            Cluster::Devnet
+        );
+        assert_eq!(
+            Cluster::from_rpc_url("http://api.mainnet-beta.solana.com:80"),
+            Cluster::MainnetBeta
+        );
+        assert_eq!(
+            Cluster::from_rpc_url("http://localhost:8899"),
+            Cluster::Localnet
+        );
+
+        let custom_url = "https://solana-api.example.com/rpc";
+        let custom = Cluster::from_rpc_url(custom_url);
+        assert_eq!(custom, Cluster::Custom(custom_url.to_string()));
+        assert_eq!(custom.default_rpc_url(), custom_url);
+        // lookalike hosts aren't mistaken for the public clusters
+        assert!(matches!(
+            Cluster::from_rpc_url("https://api.devnet.solana.com.example.com"),
+            Cluster::Custom(_)
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+//! Ethereum address formatting
+
+use audius::state::SecpSignatureOffsets;
+use sha3::{Digest, Keccak256};
+
+/// Format Ethereum address with EIP-55 checksum, letters of the hex address are
+/// capitalized where the Keccak256 hash of the lowercase address has a nibble of 8 or more
+pub fn eth_address_to_checksum(address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]) -> String {
+    let hex_address = hex::encode(address);
+    let hash = Keccak256::digest(hex_address.as_bytes());
+    let checksummed: String = hex_address
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex::FromHex;
+
+    #[test]
+    fn test_eth_address_to_checksum() {
+        // test vectors of the EIP-55 specification
+        for checksum_address in &[
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+            "0xde709f2102306220921060314715629080e2fb77",
+            "0x27b1fdb04752bbc536007a920d24acb045561c26",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let address = <[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>::from_hex(
+                checksum_address[2..].to_lowercase(),
+            )
+            .unwrap();
+            assert_eq!(&eth_address_to_checksum(&address), checksum_address);
+        }
+    }
+}
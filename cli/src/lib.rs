@@ -0,0 +1,8 @@
+//! Rust client of the Audius program, shared by the CLI
+
+pub mod client;
+pub mod cluster;
+pub mod migration;
+
+/// Error returned by the client
+pub type Error = Box<dyn std::error::Error>;
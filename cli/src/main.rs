@@ -1,45 +1,90 @@
+mod backup;
+mod eth;
+
 use audius::{
+    find_ownership_history_address, find_program_stats_address, find_revocation_registry_address,
+    find_valid_signer_address, find_valid_signer_metadata_address,
     instruction::{
-        clear_valid_signer, init_signer_group, init_valid_signer, validate_signature, SignatureData,
+        add_signer_address, clear_delegate_operator, clear_valid_signer, clone_signer_group,
+        init_program_stats, init_signer_group, init_valid_signer, init_valid_signer_ed25519,
+        init_valid_signer_metadata, init_valid_signer_with_label, migrate_valid_signer,
+        remove_signer_address, revoke_eth_address, set_delegate_operator, set_governance_realm,
+        set_lockdown_mode, set_signer_expiry, set_signer_label, update_max_signers,
+        update_merkle_root, update_valid_signer_metadata, validate_signature_raw,
+        validate_signature_with_fee, MetadataArgs, SignatureData,
+    },
+    merkle,
+    processor::Processor,
+    state::{
+        offsets::{valid_signer_eth_address_filter, valid_signer_group_filter},
+        OwnershipHistory, ProgramAccount, ProgramStats, RevocationRegistry, SecpSignatureOffsets,
+        SignerGroup, SignerKeyType, ValidSigner, ValidSignerMetadata,
+    },
+};
+use audius_cli::{
+    client::{
+        fetch_group_signers, list_signer, members_merkle_leaves, sign_message,
+        traverse_signer_group,
     },
-    state::{SecpSignatureOffsets, SignerGroup, ValidSigner},
+    cluster::Cluster,
+    migration::LayoutStatus,
+    Error,
 };
+use backup::{signer_group_to_json, signers_from_json};
 use borsh::BorshDeserialize;
 use clap::{
     crate_description, crate_name, crate_version, value_t, value_t_or_exit, App, AppSettings, Arg,
-    SubCommand,
+    ArgGroup, SubCommand,
 };
+use eth::eth_address_to_checksum;
 use hex::FromHex;
 use secp256k1::SecretKey;
+use sha3::{Digest, Keccak256};
+use solana_account_decoder::UiAccountEncoding;
 use solana_clap_utils::{
     input_parsers::pubkey_of,
-    input_validators::{is_keypair, is_pubkey, is_url},
+    input_validators::{is_keypair, is_parsable, is_pubkey, is_url},
     keypair::signer_from_path,
 };
-use solana_client::rpc_client::RpcClient;
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::RpcFilterType,
+};
 use solana_program::pubkey::Pubkey;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
+    hash::Hash,
     native_token::lamports_to_sol,
-    secp256k1_instruction,
-    signature::{Keypair, Signer},
+    nonce,
+    signature::{Keypair, Signature, Signer},
     system_instruction,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
+};
+use std::{
+    process::exit,
+    thread::sleep,
+    time::{Duration, Instant},
 };
-use std::process::exit;
 
 #[allow(dead_code)]
 struct Config {
     rpc_client: RpcClient,
+    cluster: Cluster,
     verbose: bool,
     owner: Box<dyn Signer>,
     fee_payer: Box<dyn Signer>,
     commitment_config: CommitmentConfig,
+    confirm_timeout: Duration,
+    max_retries: usize,
 }
 
-type Error = Box<dyn std::error::Error>;
 type CommandResult = Result<Option<Transaction>, Error>;
 
+/// Interval between transaction status queries while waiting for confirmation
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 fn is_hex(s: String) -> Result<(), String> {
     if hex::decode(s).is_err() {
         Err(String::from("Wrong address format"))
@@ -48,6 +93,106 @@ fn is_hex(s: String) -> Result<(), String> {
     }
 }
 
+/// Parse 20 byte Ethereum address given in hex with an optional `0x` prefix,
+/// mixed case address must match its EIP-55 checksum
+fn parse_eth_address(s: &str) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], String> {
+    let hex_address = s.strip_prefix("0x").unwrap_or(s);
+    if hex_address.len() != SecpSignatureOffsets::ETH_ADDRESS_SIZE * 2 {
+        return Err(format!(
+            "Ethereum address must be {} hex characters, got {}",
+            SecpSignatureOffsets::ETH_ADDRESS_SIZE * 2,
+            hex_address.len()
+        ));
+    }
+    let address = <[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>::from_hex(hex_address)
+        .map_err(|_| format!("Ethereum address {} isn't valid hex", s))?;
+
+    let mixed_case = hex_address.chars().any(|c| c.is_ascii_lowercase())
+        && hex_address.chars().any(|c| c.is_ascii_uppercase());
+    let checksum_address = eth_address_to_checksum(&address);
+    if mixed_case && hex_address != &checksum_address[2..] {
+        return Err(format!(
+            "Ethereum address {} has wrong EIP-55 checksum, expected {}",
+            s, checksum_address
+        ));
+    }
+    Ok(address)
+}
+
+fn is_eth_address(s: String) -> Result<(), String> {
+    parse_eth_address(&s).map(|_| ())
+}
+
+/// Parse hex encoded Secp256k1 secret key, 0x prefix is optional
+fn parse_secret_key(s: &str) -> Result<SecretKey, String> {
+    let hex_key = s.strip_prefix("0x").unwrap_or(s);
+    let decoded = <[u8; 32]>::from_hex(hex_key)
+        .map_err(|_| "Secp256k1 secret key must be 64 hex characters".to_string())?;
+    SecretKey::parse(&decoded).map_err(|_| "Secp256k1 secret key is out of range".to_string())
+}
+
+fn is_secret_key(s: String) -> Result<(), String> {
+    parse_secret_key(&s).map(|_| ())
+}
+
+/// Recover Ethereum address of the key which signed the message
+fn recover_eth_address(
+    signature: &[u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+    recovery_id: u8,
+    message: &[u8],
+) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], Error> {
+    let mut message_hash = [0u8; 32];
+    message_hash.copy_from_slice(&Keccak256::digest(message));
+    Ok(audius::crypto::recover_eth_address(
+        signature,
+        recovery_id,
+        &message_hash,
+    )?)
+}
+
+/// Check locally that the signature recovers to one of valid signer's eth addresses,
+/// so a signature the program is going to reject isn't paid for
+fn check_signature_preflight(
+    valid_signer: &ValidSigner,
+    signature_data: &SignatureData,
+) -> Result<(), Error> {
+    if valid_signer.key_type != SignerKeyType::SECP256K1 {
+        return Err("Preflight check failed: valid signer doesn't sign with Secp256k1 key".into());
+    }
+    let recovered_address = recover_eth_address(
+        &signature_data.signature,
+        signature_data.recovery_id,
+        &signature_data.message,
+    )?;
+    if !valid_signer.has_eth_address(&recovered_address) {
+        let registered_addresses: Vec<String> = valid_signer
+            .eth_addresses()
+            .iter()
+            .map(eth_address_to_checksum)
+            .collect();
+        return Err(format!(
+            "Preflight check failed: signature recovers to {}, valid signer has {}, \
+             check the secret key, recovery ID and message encoding",
+            eth_address_to_checksum(&recovered_address),
+            registered_addresses.join(", ")
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Commitment levels accepted by `--commitment`
+const COMMITMENT_LEVELS: &[&str] = &["processed", "confirmed", "finalized"];
+
+fn parse_commitment(commitment: &str) -> Result<CommitmentConfig, Error> {
+    match commitment {
+        "processed" => Ok(CommitmentConfig::processed()),
+        "confirmed" => Ok(CommitmentConfig::confirmed()),
+        "finalized" => Ok(CommitmentConfig::finalized()),
+        _ => Err(format!("Unknown commitment level: {}", commitment).into()),
+    }
+}
+
 fn check_fee_payer_balance(config: &Config, required_balance: u64) -> Result<(), Error> {
     let balance = config.rpc_client.get_balance(&config.fee_payer.pubkey())?;
     if balance < required_balance {
@@ -70,6 +215,14 @@ fn command_create_signer_group(config: &Config) -> CommandResult {
         signer_group.pubkey()
     );
 
+    // sent here as the new account keypair is needed to resubmit the transaction
+    let signature = create_signer_group(config, &signer_group)?;
+    println!("Signature: {}", signature);
+    Ok(None)
+}
+
+/// Creates and initializes signer group account owned by the configured owner
+fn create_signer_group(config: &Config, signer_group: &Keypair) -> Result<Signature, Error> {
     let signer_group_account_balance = config
         .rpc_client
         .get_minimum_balance_for_rent_exemption(SignerGroup::LEN)?;
@@ -86,23 +239,83 @@ fn command_create_signer_group(config: &Config) -> CommandResult {
                 &audius::id(),
                 &signer_group.pubkey(),
                 &config.owner.pubkey(),
-            )
-            .unwrap(),
+                &config.fee_payer.pubkey(),
+            )?,
         ],
         Some(&config.fee_payer.pubkey()),
     );
 
-    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    let (_, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
     check_fee_payer_balance(
         config,
         fee_calculator.calculate_fee(&transaction.message()) + signer_group_account_balance,
     )?;
 
-    transaction.sign(
-        &[config.fee_payer.as_ref(), &signer_group],
-        recent_blockhash,
+    let (recent_blockhash, _) = config.rpc_client.get_recent_blockhash()?;
+    let signers: [&dyn Signer; 2] = [config.fee_payer.as_ref(), signer_group];
+    transaction.sign(&signers.to_vec(), recent_blockhash);
+    send_and_confirm_transaction(config, transaction, &signers)
+}
+
+/// Genesis hash of mainnet-beta cluster
+const MAINNET_BETA_GENESIS_HASH: &str = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+/// Genesis hash of testnet cluster
+const TESTNET_GENESIS_HASH: &str = "4uhcVJyU9pJkvQyS88uRDiswHXSCkY3zQawwpjk2NsNY";
+/// Genesis hash of devnet cluster
+const DEVNET_GENESIS_HASH: &str = "EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG";
+
+/// Cluster with the given genesis hash, only test clusters can be bootstrapped
+fn bootstrap_cluster(genesis_hash: &Hash) -> Result<Cluster, Error> {
+    match genesis_hash.to_string().as_str() {
+        DEVNET_GENESIS_HASH => Ok(Cluster::Devnet),
+        TESTNET_GENESIS_HASH => Ok(Cluster::Testnet),
+        MAINNET_BETA_GENESIS_HASH => Err(refuse_mainnet_bootstrap()),
+        _ => Err(format!(
+            "Unknown cluster with genesis hash {}, bootstrap runs on devnet and testnet only",
+            genesis_hash
+        )
+        .into()),
+    }
+}
+
+fn refuse_mainnet_bootstrap() -> Error {
+    format!(
+        "Refusing to bootstrap on mainnet-beta, use --url {} instead",
+        Cluster::Devnet.default_rpc_url()
+    )
+    .into()
+}
+
+fn command_bootstrap(config: &Config, lamports: u64) -> CommandResult {
+    // don't even query the public mainnet-beta endpoint, genesis hash catches the others
+    if config.cluster == Cluster::MainnetBeta {
+        return Err(refuse_mainnet_bootstrap());
+    }
+    let cluster = bootstrap_cluster(&config.rpc_client.get_genesis_hash()?)?;
+
+    let airdrop_signature = config
+        .rpc_client
+        .request_airdrop(&config.fee_payer.pubkey(), lamports)?;
+    config
+        .rpc_client
+        .poll_for_signature_with_commitment(&airdrop_signature, config.commitment_config)?;
+
+    let signer_group = Keypair::new();
+    let signer_group_signature = create_signer_group(config, &signer_group)?;
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+            "cluster": cluster.to_string(),
+            "program_id": audius::id().to_string(),
+            "fee_payer": config.fee_payer.pubkey().to_string(),
+            "owner": config.owner.pubkey().to_string(),
+            "signer_group": signer_group.pubkey().to_string(),
+            "airdrop_signature": airdrop_signature.to_string(),
+            "signer_group_signature": signer_group_signature.to_string(),
+        }))?
     );
-    Ok(Some(transaction))
+    Ok(None)
 }
 
 fn command_create_valid_signer(
@@ -110,36 +323,28 @@ fn command_create_valid_signer(
     signer_group: &Pubkey,
     eth_address: String,
 ) -> CommandResult {
-    let valid_signer = Keypair::new();
-    println!(
-        "Creating new valid signer account {}",
-        valid_signer.pubkey()
-    );
+    let decoded_address = parse_eth_address(&eth_address)?;
 
-    let decoded_address = <[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>::from_hex(eth_address)
-        .expect("Ethereum address decoding failed");
+    let (valid_signer, _) =
+        find_valid_signer_address(&audius::id(), signer_group, &decoded_address);
+    println!("Creating new valid signer account {}", valid_signer);
+
+    // Get signer group data to append new signer to its list
+    let signer_group_data = config.rpc_client.get_account_data(signer_group)?;
+    let signer_group_data = SignerGroup::deserialize_any_version(signer_group_data.as_slice())?.0;
 
     let valid_signer_account_balance = config
         .rpc_client
         .get_minimum_balance_for_rent_exemption(ValidSigner::LEN)?;
     let mut transaction = Transaction::new_with_payer(
-        &[
-            system_instruction::create_account(
-                &config.fee_payer.pubkey(),
-                &valid_signer.pubkey(),
-                valid_signer_account_balance,
-                ValidSigner::LEN as u64,
-                &audius::id(),
-            ),
-            init_valid_signer(
-                &audius::id(),
-                &valid_signer.pubkey(),
-                signer_group,
-                &config.owner.pubkey(),
-                decoded_address,
-            )
-            .unwrap(),
-        ],
+        &[init_valid_signer(
+            &audius::id(),
+            signer_group,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            list_signer(&signer_group_data.last_signer),
+            decoded_address,
+        )?],
         Some(&config.fee_payer.pubkey()),
     );
 
@@ -150,11 +355,7 @@ fn command_create_valid_signer(
     )?;
 
     transaction.sign(
-        &[
-            config.fee_payer.as_ref(),
-            config.owner.as_ref(),
-            &valid_signer,
-        ],
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
         recent_blockhash,
     );
     Ok(Some(transaction))
@@ -163,7 +364,7 @@ fn command_create_valid_signer(
 fn command_clear_valid_signer(config: &Config, valid_signer: &Pubkey) -> CommandResult {
     // Get valid signer data
     let valid_signer_data = config.rpc_client.get_account_data(valid_signer)?;
-    let valid_signer_data = ValidSigner::try_from_slice(valid_signer_data.as_slice()).unwrap();
+    let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
 
     let mut transaction = Transaction::new_with_payer(
         &[clear_valid_signer(
@@ -171,8 +372,9 @@ fn command_clear_valid_signer(config: &Config, valid_signer: &Pubkey) -> Command
             valid_signer,
             &valid_signer_data.signer_group,
             &config.owner.pubkey(),
-        )
-        .unwrap()],
+            list_signer(&valid_signer_data.prev_signer),
+            list_signer(&valid_signer_data.next_signer),
+        )?],
         Some(&config.fee_payer.pubkey()),
     );
 
@@ -186,189 +388,1697 @@ fn command_clear_valid_signer(config: &Config, valid_signer: &Pubkey) -> Command
     Ok(Some(transaction))
 }
 
-fn command_send_message(
+fn command_migrate_valid_signer(
     config: &Config,
     valid_signer: &Pubkey,
-    secret_key: String,
-    message: String,
+    new_signer_group: &Pubkey,
 ) -> CommandResult {
-    // Get valid signer data
     let valid_signer_data = config.rpc_client.get_account_data(valid_signer)?;
-    let valid_signer_data = ValidSigner::try_from_slice(valid_signer_data.as_slice()).unwrap();
+    let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
 
-    let decoded_secret =
-        <[u8; 32]>::from_hex(secret_key).expect("Secp256k1 secret key decoding failed");
-    let private_key = SecretKey::parse(&decoded_secret).unwrap();
-    let message = message.as_bytes().to_vec();
+    // Get new signer group data to append the signer to its list
+    let new_signer_group_data = config.rpc_client.get_account_data(new_signer_group)?;
+    let new_signer_group_data =
+        SignerGroup::deserialize_any_version(new_signer_group_data.as_slice())?.0;
 
-    let secp256_program_instruction =
-        secp256k1_instruction::new_secp256k1_instruction(&private_key, &message);
+    println!(
+        "Migrating valid signer {} from signer group {} to {}",
+        valid_signer, valid_signer_data.signer_group, new_signer_group
+    );
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_valid_signer(
+            &audius::id(),
+            valid_signer,
+            &valid_signer_data.signer_group,
+            new_signer_group,
+            &config.owner.pubkey(),
+            &config.owner.pubkey(),
+            list_signer(&valid_signer_data.prev_signer),
+            list_signer(&valid_signer_data.next_signer),
+            list_signer(&new_signer_group_data.last_signer),
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
 
-    let start = 1;
-    let end = start + SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
 
-    let offsets =
-        SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])
-            .unwrap();
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
 
-    let sig_start = offsets.signature_offset as usize;
-    let sig_end = sig_start + SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+fn command_update_max_signers(
+    config: &Config,
+    signer_group: &Pubkey,
+    max_valid_signers: u32,
+) -> CommandResult {
+    let mut transaction = Transaction::new_with_payer(
+        &[update_max_signers(
+            &audius::id(),
+            signer_group,
+            &config.owner.pubkey(),
+            max_valid_signers,
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
 
-    let mut signature: [u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
-        [0u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
-    signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
 
-    let recovery_id = secp256_program_instruction.data[sig_end];
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
 
-    let signature_data = SignatureData {
-        signature,
-        recovery_id,
-        message: message.to_vec(),
+fn command_set_delegate_operator(
+    config: &Config,
+    signer_group: &Pubkey,
+    delegate_operator: Option<&Pubkey>,
+) -> CommandResult {
+    let instruction = match delegate_operator {
+        Some(delegate_operator) => set_delegate_operator(
+            &audius::id(),
+            signer_group,
+            &config.owner.pubkey(),
+            delegate_operator,
+        ),
+        None => clear_delegate_operator(&audius::id(), signer_group, &config.owner.pubkey()),
     };
+    let mut transaction =
+        Transaction::new_with_payer(&[instruction?], Some(&config.fee_payer.pubkey()));
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
+
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
 
+fn command_set_governance_realm(
+    config: &Config,
+    signer_group: &Pubkey,
+    governance_realm: &Pubkey,
+) -> CommandResult {
     let mut transaction = Transaction::new_with_payer(
-        &[
-            secp256_program_instruction,
-            validate_signature(
-                &audius::id(),
-                valid_signer,
-                &valid_signer_data.signer_group,
-                signature_data,
-            )
-            .unwrap(),
-        ],
+        &[set_governance_realm(
+            &audius::id(),
+            signer_group,
+            &config.owner.pubkey(),
+            governance_realm,
+        )?],
         Some(&config.fee_payer.pubkey()),
     );
 
     let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
     check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
 
-    transaction.sign(&[config.fee_payer.as_ref()], recent_blockhash);
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
     Ok(Some(transaction))
 }
 
-fn main() {
-    let matches = App::new(crate_name!())
-        .about(crate_description!())
-        .version(crate_version!())
-        .setting(AppSettings::SubcommandRequiredElseHelp)
-        .arg({
-            let arg = Arg::with_name("config_file")
-                .short("C")
-                .long("config")
-                .value_name("PATH")
-                .takes_value(true)
-                .global(true)
-                .help("Configuration file to use");
-            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
-                arg.default_value(&config_file)
-            } else {
-                arg
-            }
-        })
-        .arg(
-            Arg::with_name("verbose")
-                .long("verbose")
-                .short("v")
-                .takes_value(false)
-                .global(true)
-                .help("Show additional information"),
-        )
-        .arg(
-            Arg::with_name("json_rpc_url")
-                .long("url")
-                .value_name("URL")
-                .takes_value(true)
-                .validator(is_url)
-                .help("JSON RPC URL for the cluster.  Default from the configuration file."),
-        )
-        .arg(
-            Arg::with_name("owner")
-                .long("owner")
-                .value_name("KEYPAIR")
-                .validator(is_keypair)
-                .takes_value(true)
-                .help(
-                    "Specify the signer group's owner. \
-                     This may be a keypair file, the ASK keyword. \
-                     Defaults to the client keypair.",
-                ),
-        )
-        .arg(
-            Arg::with_name("fee_payer")
-                .long("fee-payer")
-                .value_name("KEYPAIR")
-                .validator(is_keypair)
-                .takes_value(true)
-                .help(
-                    "Specify the fee-payer account. \
-                     This may be a keypair file, the ASK keyword. \
-                     Defaults to the client keypair.",
-                ),
-        )
-        .subcommand(SubCommand::with_name("create-signer-group").about("Create a new signer group"))
-        .subcommand(
-            SubCommand::with_name("create-valid-signer")
-                .about("Create new valid signer and add to the signer group")
-                .arg(
-                    Arg::with_name("signer_group")
-                        .index(1)
-                        .validator(is_pubkey)
-                        .value_name("ADDRESS")
-                        .takes_value(true)
-                        .required(true)
-                        .help("Group for Valid Signer to join with."),
-                )
-                .arg(
-                    Arg::with_name("eth_address")
-                        .index(2)
-                        .validator(is_hex)
-                        .value_name("ETH_ADDRESS")
-                        .takes_value(true)
-                        .required(true)
-                        .help("Ethereum address calculated valid signer's private key (without 0x prefix)."),
-                ),
-        )
-        .subcommand(
-            SubCommand::with_name("clear-valid-signer")
-                .about("Remove valid signer from the signer group")
-                .arg(
-                    Arg::with_name("valid_signer")
-                        .index(1)
-                        .validator(is_pubkey)
-                        .value_name("ADDRESS")
-                        .takes_value(true)
-                        .required(true)
-                        .help("Account of valid signer to be removed."),
-                ),
-        )
-        .subcommand(
-            SubCommand::with_name("send-message")
-                .about("Validate signer's signature")
-                .arg(
-                    Arg::with_name("valid_signer")
-                        .index(1)
-                        .validator(is_pubkey)
-                        .value_name("ADDRESS")
-                        .takes_value(true)
-                        .required(true)
-                        .help("Account of valid signer."),
-                )
+fn command_set_lockdown_mode(config: &Config, lockdown_mode: u8) -> CommandResult {
+    let mut transaction = Transaction::new_with_payer(
+        &[set_lockdown_mode(
+            &audius::id(),
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            lockdown_mode,
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
+
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
+
+fn command_update_merkle_root(config: &Config, signer_group: &Pubkey) -> CommandResult {
+    let signer_group_data = config.rpc_client.get_account_data(signer_group)?;
+    let signer_group_data = SignerGroup::deserialize_any_version(signer_group_data.as_slice())?.0;
+    if !signer_group_data.is_initialized() {
+        return Err("Signer group is not initialized".into());
+    }
+
+    let signers = fetch_group_signers(&config.rpc_client, signer_group, &signer_group_data)?;
+    let leaves = members_merkle_leaves(&signers);
+    let members_merkle_root = merkle::root(&leaves);
+    println!(
+        "Members Merkle root of {} eth addresses: {}",
+        leaves.len(),
+        hex::encode(members_merkle_root)
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_merkle_root(
+            &audius::id(),
+            signer_group,
+            &config.owner.pubkey(),
+            members_merkle_root,
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
+
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
+
+fn command_update_signer_address(
+    config: &Config,
+    valid_signer: &Pubkey,
+    eth_address: String,
+    remove: bool,
+) -> CommandResult {
+    let decoded_address = parse_eth_address(&eth_address)?;
+
+    let valid_signer_data = config.rpc_client.get_account_data(valid_signer)?;
+    let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
+
+    let update_address = if remove {
+        remove_signer_address
+    } else {
+        add_signer_address
+    };
+    let mut transaction = Transaction::new_with_payer(
+        &[update_address(
+            &audius::id(),
+            valid_signer,
+            &valid_signer_data.signer_group,
+            &config.owner.pubkey(),
+            decoded_address,
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(config, fee_calculator.calculate_fee(&transaction.message()))?;
+
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
+
+fn command_revoke_eth_address(
+    config: &Config,
+    signer_group: &Pubkey,
+    eth_address: String,
+) -> CommandResult {
+    let decoded_address = parse_eth_address(&eth_address)?;
+
+    // registry is created and paid for with the first revocation in the group
+    let (registry_address, _) = find_revocation_registry_address(&audius::id(), signer_group);
+    let registry_balance = if config
+        .rpc_client
+        .get_account_with_commitment(&registry_address, config.commitment_config)?
+        .value
+        .is_some()
+    {
+        0
+    } else {
+        println!("Creating revocation registry {}", registry_address);
+        config
+            .rpc_client
+            .get_minimum_balance_for_rent_exemption(RevocationRegistry::LEN)?
+    };
+
+    let mut transaction = Transaction::new_with_payer(
+        &[revoke_eth_address(
+            &audius::id(),
+            signer_group,
+            &config.owner.pubkey(),
+            &config.fee_payer.pubkey(),
+            decoded_address,
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(
+        config,
+        fee_calculator.calculate_fee(&transaction.message()) + registry_balance,
+    )?;
+
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
+
+/// Supported encodings of the message given as a string
+const MESSAGE_ENCODINGS: &[&str] = &["utf8", "hex", "base58", "base64"];
+
+/// Decode message string to bytes according to its encoding
+fn decode_message(message: String, encoding: &str) -> Result<Vec<u8>, Error> {
+    match encoding {
+        "utf8" => Ok(message.into_bytes()),
+        "hex" => hex::decode(message.trim_start_matches("0x"))
+            .map_err(|e| format!("Message hex decoding failed: {}", e).into()),
+        "base58" => bs58::decode(message)
+            .into_vec()
+            .map_err(|e| format!("Message base58 decoding failed: {}", e).into()),
+        "base64" => base64::decode(message)
+            .map_err(|e| format!("Message base64 decoding failed: {}", e).into()),
+        _ => Err(format!("Unknown message encoding: {}", encoding).into()),
+    }
+}
+
+/// Read message given either as an encoded string or as a file with raw bytes
+fn read_message(
+    message: Option<String>,
+    message_file: Option<String>,
+    encoding: &str,
+) -> Result<Vec<u8>, Error> {
+    match (message, message_file) {
+        (Some(message), None) => decode_message(message, encoding),
+        (None, Some(message_file)) => Ok(std::fs::read(message_file)?),
+        _ => Err("Either message or message file must be given".into()),
+    }
+}
+
+/// Sign message and build Secp256 instruction along with signature data for it
+fn command_send_message(
+    config: &Config,
+    valid_signer: &Pubkey,
+    secret_key: String,
+    message: Vec<u8>,
+    skip_preflight_checks: bool,
+) -> CommandResult {
+    // Get valid signer data
+    let valid_signer_data = config.rpc_client.get_account_data(valid_signer)?;
+    let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
+
+    let private_key = parse_secret_key(&secret_key)?;
+    let (secp256_program_instruction, signature_data) = sign_message(&private_key, message)?;
+
+    if !skip_preflight_checks {
+        check_signature_preflight(&valid_signer_data, &signature_data)?;
+    }
+
+    // the CLI holds the whole message, so it's validated in raw form rather than by digest;
+    // group may charge a fee for each validation, it's paid by the fee payer
+    let signer_group_data = config
+        .rpc_client
+        .get_account_data(&valid_signer_data.signer_group)?;
+    let signer_group_data = SignerGroup::deserialize_any_version(signer_group_data.as_slice())?.0;
+    let validation_fee = u64::from(signer_group_data.validation_fee);
+    let validate_instruction = if validation_fee == 0 {
+        validate_signature_raw(
+            &audius::id(),
+            valid_signer,
+            &valid_signer_data.signer_group,
+            signature_data,
+        )
+    } else {
+        validate_signature_with_fee(
+            &audius::id(),
+            valid_signer,
+            &valid_signer_data.signer_group,
+            &config.fee_payer.pubkey(),
+            &signer_group_data.treasury,
+            signature_data,
+        )
+    }?;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[secp256_program_instruction, validate_instruction],
+        Some(&config.fee_payer.pubkey()),
+    );
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(
+        config,
+        fee_calculator.calculate_fee(&transaction.message()) + validation_fee,
+    )?;
+
+    transaction.sign(&[config.fee_payer.as_ref()], recent_blockhash);
+    Ok(Some(transaction))
+}
+
+/// Pack string into zero padded fixed size field
+fn pack_metadata_field<const N: usize>(value: &str) -> Result<[u8; N], Error> {
+    let bytes = value.as_bytes();
+    if bytes.len() > N {
+        return Err(format!("Value is too long: {} bytes max", N).into());
+    }
+    let mut field = [0u8; N];
+    field[..bytes.len()].copy_from_slice(bytes);
+    Ok(field)
+}
+
+/// Unpack zero padded fixed size field into string
+fn unpack_metadata_field(field: &[u8]) -> String {
+    let end = field
+        .iter()
+        .position(|b| *b == 0)
+        .unwrap_or_else(|| field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+fn command_set_valid_signer_metadata(
+    config: &Config,
+    valid_signer: &Pubkey,
+    endpoint_url: String,
+    operator_name: String,
+) -> CommandResult {
+    // Get valid signer data
+    let valid_signer_data = config.rpc_client.get_account_data(valid_signer)?;
+    let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
+
+    let metadata = MetadataArgs {
+        endpoint_url: pack_metadata_field(&endpoint_url)?,
+        operator_name: pack_metadata_field(&operator_name)?,
+    };
+
+    let (metadata_address, _) = find_valid_signer_metadata_address(&audius::id(), valid_signer);
+    let metadata_exists = config
+        .rpc_client
+        .get_account_with_commitment(&metadata_address, config.commitment_config)?
+        .value
+        .is_some();
+
+    let (instruction, required_balance) = if metadata_exists {
+        println!("Updating valid signer metadata {}", metadata_address);
+        (
+            update_valid_signer_metadata(
+                &audius::id(),
+                valid_signer,
+                &valid_signer_data.signer_group,
+                &config.owner.pubkey(),
+                metadata,
+            )?,
+            0,
+        )
+    } else {
+        println!("Creating valid signer metadata {}", metadata_address);
+        (
+            init_valid_signer_metadata(
+                &audius::id(),
+                valid_signer,
+                &valid_signer_data.signer_group,
+                &config.owner.pubkey(),
+                &config.fee_payer.pubkey(),
+                metadata,
+            )?,
+            config
+                .rpc_client
+                .get_minimum_balance_for_rent_exemption(ValidSignerMetadata::LEN)?,
+        )
+    };
+
+    let mut transaction =
+        Transaction::new_with_payer(&[instruction], Some(&config.fee_payer.pubkey()));
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(
+        config,
+        fee_calculator.calculate_fee(&transaction.message()) + required_balance,
+    )?;
+
+    transaction.sign(
+        &[config.fee_payer.as_ref(), config.owner.as_ref()],
+        recent_blockhash,
+    );
+    Ok(Some(transaction))
+}
+
+fn command_get_valid_signer_metadata(config: &Config, valid_signer: &Pubkey) -> CommandResult {
+    let (metadata_address, _) = find_valid_signer_metadata_address(&audius::id(), valid_signer);
+    let metadata_data = config.rpc_client.get_account_data(&metadata_address)?;
+    let metadata = ValidSignerMetadata::try_from_slice(metadata_data.as_slice())?;
+
+    println!("Metadata account: {}", metadata_address);
+    println!(
+        "Endpoint URL: {}",
+        unpack_metadata_field(&metadata.endpoint_url)
+    );
+    println!(
+        "Operator name: {}",
+        unpack_metadata_field(&metadata.operator_name)
+    );
+    Ok(None)
+}
+
+fn command_get_valid_signer_info(config: &Config, valid_signer: &Pubkey) -> CommandResult {
+    let valid_signer_data = config.rpc_client.get_account_data(valid_signer)?;
+    let valid_signer_data = ValidSigner::deserialize_stored(valid_signer_data.as_slice())?;
+    if !valid_signer_data.is_initialized() {
+        return Err("Valid signer is not initialized".into());
+    }
+
+    println!("Valid signer: {}", valid_signer);
+    println!("Signer group: {}", valid_signer_data.signer_group);
+    println!("Signer id: {}", u32::from(valid_signer_data.signer_id));
+    if valid_signer_data.key_type == SignerKeyType::ED25519 {
+        println!("Ed25519 key: {}", valid_signer_data.ed25519_pubkey);
+    } else {
+        for eth_address in valid_signer_data.eth_addresses() {
+            println!("Eth address: {}", eth_address_to_checksum(eth_address));
+        }
+    }
+    println!(
+        "Label: {}",
+        valid_signer_data.label().unwrap_or("<invalid label>")
+    );
+    println!(
+        "Total validations: {}",
+        u32::from(valid_signer_data.total_validations)
+    );
+    Ok(None)
+}
+
+fn command_init_program_stats(config: &Config) -> CommandResult {
+    let (stats_address, _) = find_program_stats_address(&audius::id());
+    println!("Creating program stats account {}", stats_address);
+
+    let stats_account_balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(ProgramStats::LEN)?;
+    let mut transaction = Transaction::new_with_payer(
+        &[init_program_stats(
+            &audius::id(),
+            &config.fee_payer.pubkey(),
+        )?],
+        Some(&config.fee_payer.pubkey()),
+    );
+
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(
+        config,
+        fee_calculator.calculate_fee(&transaction.message()) + stats_account_balance,
+    )?;
+
+    transaction.sign(&[config.fee_payer.as_ref()], recent_blockhash);
+    Ok(Some(transaction))
+}
+
+fn command_get_program_stats(config: &Config) -> CommandResult {
+    let (stats_address, _) = find_program_stats_address(&audius::id());
+    let stats_data = config.rpc_client.get_account_data(&stats_address)?;
+    let stats = ProgramStats::try_from_slice(stats_data.as_slice())?;
+
+    println!("Program stats account: {}", stats_address);
+    println!("Total groups: {}", stats.total_groups);
+    println!("Total signers: {}", stats.total_signers);
+    println!("Total validations: {}", stats.total_validations);
+    println!("Total clearances: {}", stats.total_clearances);
+    Ok(None)
+}
+
+fn command_get_ownership_history(config: &Config, signer_group: &Pubkey) -> CommandResult {
+    let (history_address, _) = find_ownership_history_address(&audius::id(), signer_group);
+    println!("Ownership history account: {}", history_address);
+
+    // history account is created by the first accepted ownership transfer
+    let history = match config
+        .rpc_client
+        .get_account_with_commitment(&history_address, config.commitment_config)?
+        .value
+    {
+        Some(account) => OwnershipHistory::unpack(&account.data)?,
+        None => OwnershipHistory::default(),
+    };
+    if history.transfers.is_empty() {
+        println!("Ownership was never transferred");
+        return Ok(None);
+    }
+
+    // history keeps the most recent transfer first, show them in chronological order
+    for transfer in history.transfers.iter().rev() {
+        println!(
+            "Slot {}: {} -> {}",
+            transfer.transfer_slot, transfer.previous_owner, transfer.new_owner
+        );
+    }
+    Ok(None)
+}
+
+/// Account types accepted by `estimate-rent`
+const RENT_ACCOUNT_TYPES: &[&str] = &["signer-group", "valid-signer", "nonce-account"];
+
+/// Size of the account of the given type
+fn account_type_len(account_type: &str) -> Result<usize, Error> {
+    match account_type {
+        "signer-group" => Ok(SignerGroup::LEN),
+        "valid-signer" => Ok(ValidSigner::LEN),
+        "nonce-account" => Ok(nonce::State::size()),
+        _ => Err(format!("Unknown account type: {}", account_type).into()),
+    }
+}
+
+fn command_estimate_rent(config: &Config, account_type: &str, extra_bytes: usize) -> CommandResult {
+    let account_len = account_type_len(account_type)? + extra_bytes;
+    let balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(account_len)?;
+    println!(
+        "Rent exempt balance of {} bytes: {} lamports ({} SOL)",
+        account_len,
+        balance,
+        lamports_to_sol(balance)
+    );
+    Ok(None)
+}
+
+fn command_lookup_valid_signer(
+    config: &Config,
+    signer_group: &Pubkey,
+    eth_address: String,
+) -> CommandResult {
+    let decoded_address = parse_eth_address(&eth_address)?;
+
+    // address may be registered in any of the slots, query each of them
+    let mut found = false;
+    for slot in 0..ValidSigner::MAX_ETH_ADDRESSES {
+        let accounts = config.rpc_client.get_program_accounts_with_config(
+            &audius::id(),
+            RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::DataSize(ValidSigner::LEN as u64),
+                    valid_signer_group_filter(signer_group),
+                    valid_signer_eth_address_filter(&decoded_address, slot),
+                ]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                },
+            },
+        )?;
+
+        for (pubkey, account) in accounts {
+            let valid_signer = ValidSigner::deserialize_stored(account.data.as_slice())?;
+            if valid_signer.is_initialized() && valid_signer.has_eth_address(&decoded_address) {
+                println!("Valid signer: {}", pubkey);
+                found = true;
+            }
+        }
+    }
+    if !found {
+        return Err("Valid signer not found".into());
+    }
+    Ok(None)
+}
+
+fn command_list_valid_signers(config: &Config, signer_group: &Pubkey) -> CommandResult {
+    for (pubkey, valid_signer) in traverse_signer_group(&config.rpc_client, signer_group)? {
+        let key = if valid_signer.key_type == SignerKeyType::ED25519 {
+            valid_signer.ed25519_pubkey.to_string()
+        } else {
+            valid_signer
+                .eth_addresses()
+                .iter()
+                .map(eth_address_to_checksum)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let signer_id = u32::from(valid_signer.signer_id);
+        match valid_signer.label() {
+            Ok("") => println!("Valid signer #{}: {} {}", signer_id, pubkey, key),
+            Ok(label) => println!(
+                "Valid signer #{}: {} {} \"{}\"",
+                signer_id, pubkey, key, label
+            ),
+            Err(_) => println!(
+                "Valid signer #{}: {} {} <invalid label>",
+                signer_id, pubkey, key
+            ),
+        }
+    }
+    Ok(None)
+}
+
+fn command_export_signer_group(
+    config: &Config,
+    signer_group: &Pubkey,
+    output: &str,
+) -> CommandResult {
+    let signer_group_data = config.rpc_client.get_account_data(signer_group)?;
+    let signer_group_data = SignerGroup::deserialize_any_version(signer_group_data.as_slice())?.0;
+    if !signer_group_data.is_initialized() {
+        return Err("Signer group is not initialized".into());
+    }
+
+    let signers = fetch_group_signers(&config.rpc_client, signer_group, &signer_group_data)?;
+    let backup = signer_group_to_json(signer_group, &signer_group_data, &signers);
+    std::fs::write(output, serde_json::to_string_pretty(&backup)?)?;
+    println!("Exported {} valid signers to {}", signers.len(), output);
+    Ok(None)
+}
+
+fn command_layout_status<T: ProgramAccount>(
+    config: &Config,
+    address: &Pubkey,
+    version: u8,
+    len: usize,
+) -> CommandResult {
+    let account = config.rpc_client.get_account(address)?;
+    let stored_version = T::deserialize_any_version(account.data.as_slice())?.1;
+    if stored_version == 0 {
+        return Err(format!("Account {} is not initialized", address).into());
+    }
+
+    match LayoutStatus::of(stored_version, account.data.len(), version, len) {
+        LayoutStatus::Current => {
+            println!(
+                "Account {} is stored at the current layout version {}, nothing to migrate",
+                address, stored_version
+            );
+            Ok(None)
+        }
+        LayoutStatus::Newer { version: stored } => Err(format!(
+            "Account {} is stored at layout version {}, newer than version {} known to the CLI",
+            address, stored, version
+        )
+        .into()),
+        LayoutStatus::Outdated {
+            version: stored,
+            missing_len,
+        } => {
+            let balance = config
+                .rpc_client
+                .get_minimum_balance_for_rent_exemption(len)?;
+            let rent_delta = balance.saturating_sub(account.lamports);
+            println!(
+                "Account {} is stored at layout version {} ({} bytes), current version is {} ({} bytes)",
+                address,
+                stored,
+                account.data.len(),
+                version,
+                len
+            );
+            println!(
+                "Migration grows the account by {} bytes and needs {} lamports ({} SOL) of rent",
+                missing_len,
+                rent_delta,
+                lamports_to_sol(rent_delta)
+            );
+            // Accounts can't be resized by the runtime the program is built for,
+            // so the program has no migration instruction
+            Err(
+                "Program can't migrate accounts in place, re-create the signer group \
+                 with clone-signer-group or import-signer-group instead"
+                    .into(),
+            )
+        }
+    }
+}
+
+fn command_clone_signer_group(config: &Config, source_group: &Pubkey) -> CommandResult {
+    let source_group_data = config.rpc_client.get_account_data(source_group)?;
+    let source_group_data = SignerGroup::deserialize_any_version(source_group_data.as_slice())?.0;
+    if !source_group_data.is_initialized() {
+        return Err("Signer group is not initialized".into());
+    }
+    let source_signers = fetch_group_signers(&config.rpc_client, source_group, &source_group_data)?;
+
+    let signer_group = Keypair::new();
+    println!(
+        "Creating new signer group account {}",
+        signer_group.pubkey()
+    );
+    let signer_group_account_balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(SignerGroup::LEN)?;
+    let valid_signer_account_balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(ValidSigner::LEN)?;
+    let mut instructions = vec![system_instruction::create_account(
+        &config.fee_payer.pubkey(),
+        &signer_group.pubkey(),
+        signer_group_account_balance,
+        SignerGroup::LEN as u64,
+        &audius::id(),
+    )];
+
+    // Ed25519 signers are copied to keypair accounts created here, Secp256k1 ones
+    // are derived from the new group by the program
+    let mut signer_keypairs = vec![];
+    let mut signers = vec![];
+    for (source_signer, valid_signer) in &source_signers {
+        let copy = if valid_signer.key_type == SignerKeyType::ED25519 {
+            let keypair = Keypair::new();
+            instructions.push(system_instruction::create_account(
+                &config.fee_payer.pubkey(),
+                &keypair.pubkey(),
+                valid_signer_account_balance,
+                ValidSigner::LEN as u64,
+                &audius::id(),
+            ));
+            let copy = keypair.pubkey();
+            signer_keypairs.push(keypair);
+            copy
+        } else {
+            find_valid_signer_address(
+                &audius::id(),
+                &signer_group.pubkey(),
+                &valid_signer.eth_addresses[0],
+            )
+            .0
+        };
+        signers.push((*source_signer, copy));
+    }
+    instructions.push(clone_signer_group(
+        &audius::id(),
+        source_group,
+        &signer_group.pubkey(),
+        &config.owner.pubkey(),
+        &config.fee_payer.pubkey(),
+        &signers,
+    )?);
+
+    let mut transaction =
+        Transaction::new_with_payer(&instructions, Some(&config.fee_payer.pubkey()));
+    let (recent_blockhash, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(
+        config,
+        fee_calculator.calculate_fee(&transaction.message())
+            + signer_group_account_balance
+            + valid_signer_account_balance * signers.len() as u64,
+    )?;
+
+    let mut transaction_signers: Vec<&dyn Signer> = vec![
+        config.fee_payer.as_ref(),
+        config.owner.as_ref(),
+        &signer_group,
+    ];
+    transaction_signers.extend(signer_keypairs.iter().map(|keypair| keypair as &dyn Signer));
+    transaction.sign(&transaction_signers, recent_blockhash);
+    println!(
+        "Copying {} valid signers of {}",
+        signers.len(),
+        source_group
+    );
+    Ok(Some(transaction))
+}
+
+fn command_import_signer_group(config: &Config, input: &str) -> CommandResult {
+    let backup: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(input)?)?;
+    let signers = signers_from_json(&backup)?;
+
+    let signer_group = Keypair::new();
+    println!(
+        "Creating new signer group account {}",
+        signer_group.pubkey()
+    );
+    let signer_group_account_balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(SignerGroup::LEN)?;
+    let valid_signer_account_balance = config
+        .rpc_client
+        .get_minimum_balance_for_rent_exemption(ValidSigner::LEN)?;
+    let transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::create_account(
+                &config.fee_payer.pubkey(),
+                &signer_group.pubkey(),
+                signer_group_account_balance,
+                SignerGroup::LEN as u64,
+                &audius::id(),
+            ),
+            init_signer_group(
+                &audius::id(),
+                &signer_group.pubkey(),
+                &config.owner.pubkey(),
+                &config.fee_payer.pubkey(),
+            )?,
+        ],
+        Some(&config.fee_payer.pubkey()),
+    );
+    let (_, fee_calculator) = config.rpc_client.get_recent_blockhash()?;
+    check_fee_payer_balance(
+        config,
+        fee_calculator.calculate_fee(&transaction.message())
+            + signer_group_account_balance
+            + valid_signer_account_balance * signers.len() as u64,
+    )?;
+    send_transaction(
+        config,
+        transaction,
+        &[config.fee_payer.as_ref(), &signer_group],
+    )?;
+
+    // signers are re-created one per transaction in the exported list order
+    let mut last_signer: Option<Pubkey> = None;
+    for signer in signers {
+        let mut instructions = vec![];
+        // Ed25519 signers keep keypair accounts, Secp256k1 ones are derived from eth address
+        let mut valid_signer_keypair = None;
+        let valid_signer = match signer.ed25519_pubkey {
+            Some(ed25519_pubkey) => {
+                let keypair = Keypair::new();
+                instructions.push(system_instruction::create_account(
+                    &config.fee_payer.pubkey(),
+                    &keypair.pubkey(),
+                    valid_signer_account_balance,
+                    ValidSigner::LEN as u64,
+                    &audius::id(),
+                ));
+                instructions.push(init_valid_signer_ed25519(
+                    &audius::id(),
+                    &keypair.pubkey(),
+                    &signer_group.pubkey(),
+                    &config.owner.pubkey(),
+                    last_signer.as_ref(),
+                    &ed25519_pubkey,
+                )?);
+                if !signer.label.is_empty() {
+                    instructions.push(set_signer_label(
+                        &audius::id(),
+                        &keypair.pubkey(),
+                        &signer_group.pubkey(),
+                        &config.owner.pubkey(),
+                        &signer.label,
+                    )?);
+                }
+                let valid_signer = keypair.pubkey();
+                valid_signer_keypair = Some(keypair);
+                valid_signer
+            }
+            None => {
+                let (first_address, other_addresses) = signer
+                    .eth_addresses
+                    .split_first()
+                    .ok_or("Secp256k1 signer has no eth addresses")?;
+                instructions.push(init_valid_signer_with_label(
+                    &audius::id(),
+                    &signer_group.pubkey(),
+                    &config.owner.pubkey(),
+                    &config.fee_payer.pubkey(),
+                    last_signer.as_ref(),
+                    *first_address,
+                    &signer.label,
+                )?);
+                let (valid_signer, _) =
+                    find_valid_signer_address(&audius::id(), &signer_group.pubkey(), first_address);
+                for eth_address in other_addresses {
+                    instructions.push(add_signer_address(
+                        &audius::id(),
+                        &valid_signer,
+                        &signer_group.pubkey(),
+                        &config.owner.pubkey(),
+                        *eth_address,
+                    )?);
+                }
+                valid_signer
+            }
+        };
+        if let Some(expiry_slot) = signer.expiry_slot {
+            instructions.push(set_signer_expiry(
+                &audius::id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &config.owner.pubkey(),
+                expiry_slot,
+            )?);
+        }
+        let transaction =
+            Transaction::new_with_payer(&instructions, Some(&config.fee_payer.pubkey()));
+        let mut transaction_signers: Vec<&dyn Signer> =
+            vec![config.fee_payer.as_ref(), config.owner.as_ref()];
+        if let Some(keypair) = &valid_signer_keypair {
+            transaction_signers.push(keypair);
+        }
+        send_transaction(config, transaction, &transaction_signers)?;
+        println!(
+            "Valid signer {} imported as {}",
+            signer.pubkey, valid_signer
+        );
+        last_signer = Some(valid_signer);
+    }
+    Ok(None)
+}
+
+/// Signs transaction with a fresh blockhash and sends it, for commands sending several transactions
+fn send_transaction(
+    config: &Config,
+    mut transaction: Transaction,
+    signers: &[&dyn Signer],
+) -> Result<(), Error> {
+    let (recent_blockhash, _) = config.rpc_client.get_recent_blockhash()?;
+    transaction.sign(&signers.to_vec(), recent_blockhash);
+    let signature = send_and_confirm_transaction(config, transaction, signers)?;
+    println!("Signature: {}", signature);
+    Ok(())
+}
+
+/// Checks if transaction failed because its blockhash expired, so it can be resubmitted
+/// with a fresh one
+fn is_retryable(err: &ClientError) -> bool {
+    err.kind().get_transaction_error() == Some(TransactionError::BlockhashNotFound)
+}
+
+/// Sends signed transaction and waits for its confirmation, resubmitting it with a fresh
+/// blockhash up to `--max-retries` times if the blockhash expires
+fn send_and_confirm_transaction(
+    config: &Config,
+    mut transaction: Transaction,
+    signers: &[&dyn Signer],
+) -> Result<Signature, Error> {
+    let mut retries = 0;
+    loop {
+        match confirm_transaction(config, &transaction) {
+            Err(err) if retries < config.max_retries && is_retryable(&err) => {
+                retries += 1;
+                println!(
+                    "Blockhash expired, resubmitting transaction ({}/{})",
+                    retries, config.max_retries
+                );
+                let required_signers =
+                    &transaction.message.account_keys[..transaction.signatures.len()];
+                let signers: Vec<&dyn Signer> = signers
+                    .iter()
+                    .copied()
+                    .filter(|signer| required_signers.contains(&signer.pubkey()))
+                    .collect();
+                let (recent_blockhash, _) = config.rpc_client.get_recent_blockhash()?;
+                transaction.try_sign(&signers, recent_blockhash)?;
+            }
+            result => return Ok(result?),
+        }
+    }
+}
+
+/// Sends transaction and polls its status until it's confirmed with the configured commitment,
+/// its blockhash expires or `--confirm-timeout` elapses
+fn confirm_transaction(
+    config: &Config,
+    transaction: &Transaction,
+) -> Result<Signature, ClientError> {
+    let signature = config.rpc_client.send_transaction(transaction)?;
+    let started = Instant::now();
+    loop {
+        if let Some(result) = config
+            .rpc_client
+            .get_signature_status_with_commitment(&signature, config.commitment_config)?
+        {
+            return result.map(|_| signature).map_err(Into::into);
+        }
+        // transaction which already landed is not resubmitted, even if its blockhash expired
+        let landed = config
+            .rpc_client
+            .get_signature_status_with_commitment(&signature, CommitmentConfig::processed())?
+            .is_some();
+        if !landed
+            && config
+                .rpc_client
+                .get_fee_calculator_for_blockhash(&transaction.message.recent_blockhash)?
+                .is_none()
+        {
+            return Err(TransactionError::BlockhashNotFound.into());
+        }
+        if started.elapsed() >= config.confirm_timeout {
+            return Err(ClientErrorKind::Custom(format!(
+                "Transaction {} is not confirmed in {} seconds",
+                signature,
+                config.confirm_timeout.as_secs()
+            ))
+            .into());
+        }
+        sleep(CONFIRM_POLL_INTERVAL);
+    }
+}
+
+fn command_recover_eth_address(
+    signature: String,
+    recovery_id: u8,
+    message: String,
+    expected_address: Option<String>,
+) -> CommandResult {
+    let signature = <[u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE]>::from_hex(signature)
+        .map_err(|_| "Signature decoding failed")?;
+    let eth_address = recover_eth_address(&signature, recovery_id, message.as_bytes())?;
+    println!(
+        "Recovered address: {}",
+        eth_address_to_checksum(&eth_address)
+    );
+
+    if let Some(expected_address) = expected_address {
+        let expected_address = parse_eth_address(&expected_address)?;
+        if eth_address != expected_address {
+            return Err(format!(
+                "Recovered address doesn't match the expected {}",
+                eth_address_to_checksum(&expected_address)
+            )
+            .into());
+        }
+        println!("Recovered address matches the expected one");
+    }
+    Ok(None)
+}
+
+fn main() {
+    let matches = App::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg({
+            let arg = Arg::with_name("config_file")
+                .short("C")
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .global(true)
+                .help("Configuration file to use");
+            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
+                arg.default_value(&config_file)
+            } else {
+                arg
+            }
+        })
+        .arg(
+            Arg::with_name("verbose")
+                .long("verbose")
+                .short("v")
+                .takes_value(false)
+                .global(true)
+                .help("Show additional information"),
+        )
+        .arg(
+            Arg::with_name("json_rpc_url")
+                .long("url")
+                .value_name("URL")
+                .takes_value(true)
+                .validator(is_url)
+                .help("JSON RPC URL for the cluster.  Default from the configuration file."),
+        )
+        .arg(
+            Arg::with_name("commitment")
+                .long("commitment")
+                .value_name("LEVEL")
+                .takes_value(true)
+                .possible_values(COMMITMENT_LEVELS)
+                .default_value("confirmed")
+                .global(true)
+                .help("Commitment level of the cluster state to query and to wait for"),
+        )
+        .arg(
+            Arg::with_name("confirm_timeout")
+                .long("confirm-timeout")
+                .value_name("SECONDS")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .default_value("90")
+                .global(true)
+                .help("How long to wait for transaction confirmation"),
+        )
+        .arg(
+            Arg::with_name("max_retries")
+                .long("max-retries")
+                .value_name("N")
+                .takes_value(true)
+                .validator(is_parsable::<usize>)
+                .default_value("0")
+                .global(true)
+                .help("How many times to resubmit transaction with a fresh blockhash if it expires"),
+        )
+        .arg(
+            Arg::with_name("owner")
+                .long("owner")
+                .value_name("KEYPAIR")
+                .validator(is_keypair)
+                .takes_value(true)
+                .help(
+                    "Specify the signer group's owner. \
+                     This may be a keypair file, the ASK keyword. \
+                     Defaults to the client keypair.",
+                ),
+        )
+        .arg(
+            Arg::with_name("fee_payer")
+                .long("fee-payer")
+                .value_name("KEYPAIR")
+                .validator(is_keypair)
+                .takes_value(true)
+                .help(
+                    "Specify the fee-payer account. \
+                     This may be a keypair file, the ASK keyword. \
+                     Defaults to the client keypair.",
+                ),
+        )
+        .subcommand(SubCommand::with_name("create-signer-group").about("Create a new signer group"))
+        .subcommand(
+            SubCommand::with_name("bootstrap")
+                .about(
+                    "Airdrop to the fee payer and create a signer group, \
+                     devnet and testnet only",
+                )
+                .arg(
+                    Arg::with_name("lamports")
+                        .long("lamports")
+                        .value_name("N")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_parsable::<u64>)
+                        .help("Lamports to airdrop to the fee payer."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("create-valid-signer")
+                .about("Create new valid signer and add to the signer group")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Group for Valid Signer to join with."),
+                )
+                .arg(
+                    Arg::with_name("eth_address")
+                        .index(2)
+                        .validator(is_eth_address)
+                        .value_name("ETH_ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Ethereum address calculated valid signer's private key, 0x prefix is optional."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("clear-valid-signer")
+                .about("Remove valid signer from the signer group")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer to be removed."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("migrate-valid-signer")
+                .about(
+                    "Move valid signer to another signer group, both groups must be owned \
+                     by the configured owner",
+                )
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer to be moved."),
+                )
+                .arg(
+                    Arg::with_name("new_signer_group")
+                        .index(2)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group the valid signer joins."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("update-max-signers")
+                .about("Set maximum number of valid signers in the signer group")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to update."),
+                )
+                .arg(
+                    Arg::with_name("max_valid_signers")
+                        .index(2)
+                        .validator(is_parsable::<u32>)
+                        .value_name("COUNT")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Maximum number of valid signers, 0 removes the limit."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set-delegate-operator")
+                .about("Allow an operator key to add valid signers to the signer group")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to update."),
+                )
+                .arg(
+                    Arg::with_name("delegate_operator")
+                        .index(2)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Operator key allowed to add valid signers."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("clear-delegate-operator")
+                .about("Remove delegate operator of the signer group")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to update."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set-governance-realm")
+                .about("Let proposals of the SPL Governance realm add and remove valid signers")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to update."),
+                )
+                .arg(
+                    Arg::with_name("governance_realm")
+                        .index(2)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("SPL Governance realm, the default pubkey disables its proposals."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set-lockdown-mode")
+                .about(
+                    "Stop creation and removal of signer groups and valid signers program-wide, \
+                     the configured owner must be the config authority",
+                )
+                .arg(
+                    Arg::with_name("lockdown_mode")
+                        .index(1)
+                        .validator(is_parsable::<u8>)
+                        .value_name("MODE")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Lockdown mode, 0 lifts the lockdown."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("update-merkle-root")
+                .about("Set the members Merkle root of the signer group from its valid signers")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .long("signer-group")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to update."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("add-signer-address")
+                .about("Register one more Ethereum address for the valid signer")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer."),
+                )
+                .arg(
+                    Arg::with_name("eth_address")
+                        .index(2)
+                        .validator(is_eth_address)
+                        .value_name("ETH_ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Ethereum address to register, 0x prefix is optional."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-signer-address")
+                .about("Unregister Ethereum address of the valid signer")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer."),
+                )
+                .arg(
+                    Arg::with_name("eth_address")
+                        .index(2)
+                        .validator(is_eth_address)
+                        .value_name("ETH_ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Ethereum address to unregister, 0x prefix is optional."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("revoke-eth-address")
+                .about("Revoke Ethereum address so no valid signer of the group can use it")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to revoke the address in."),
+                )
+                .arg(
+                    Arg::with_name("eth_address")
+                        .index(2)
+                        .validator(is_eth_address)
+                        .value_name("ETH_ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Ethereum address to revoke, 0x prefix is optional."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("send-message")
+                .about("Validate signer's signature")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer."),
+                )
+                .arg(
+                    Arg::with_name("secret_key")
+                        .index(2)
+                        .validator(is_secret_key)
+                        .value_name("SECRET")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Valid signer's private key in hex, 0x prefix is optional."),
+                )
+                .arg(
+                    Arg::with_name("message")
+                        .index(3)
+                        .value_name("MESSAGE")
+                        .takes_value(true)
+                        .required_unless("message_file")
+                        .conflicts_with("message_file")
+                        .help("Message to sign and send, decoded according to --message-encoding."),
+                )
                 .arg(
-                    Arg::with_name("secret_key")
-                        .index(2)
-                        .value_name("SECRET")
+                    Arg::with_name("message_encoding")
+                        .long("message-encoding")
+                        .value_name("ENCODING")
+                        .takes_value(true)
+                        .possible_values(MESSAGE_ENCODINGS)
+                        .default_value("utf8")
+                        .help("Encoding of the message string, signature is made over the decoded bytes."),
+                )
+                .arg(
+                    Arg::with_name("message_file")
+                        .long("message-file")
+                        .value_name("PATH")
+                        .takes_value(true)
+                        .help("File with raw bytes of the message to sign and send, e.g. a hash or a protobuf blob."),
+                )
+                .arg(
+                    Arg::with_name("skip_preflight_checks")
+                        .long("skip-preflight-checks")
+                        .takes_value(false)
+                        .help("Send the message without checking locally that the signature recovers to valid signer's eth address."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("set-valid-signer-metadata")
+                .about("Create or update valid signer metadata")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer."),
+                )
+                .arg(
+                    Arg::with_name("endpoint_url")
+                        .long("endpoint-url")
+                        .value_name("URL")
+                        .takes_value(true)
+                        .required(true)
+                        .help("URL of the signer's node endpoint."),
+                )
+                .arg(
+                    Arg::with_name("operator_name")
+                        .long("operator-name")
+                        .value_name("NAME")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Name of the signer's operator."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("get-valid-signer-metadata")
+                .about("Show valid signer metadata")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("get-valid-signer-info")
+                .about("Show valid signer details and usage")
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Account of valid signer."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("init-program-stats")
+                .about("Create program statistics account"),
+        )
+        .subcommand(SubCommand::with_name("get-program-stats").about("Show program statistics"))
+        .subcommand(
+            SubCommand::with_name("get-ownership-history")
+                .about("Show latest ownership transfers of the signer group")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .long("signer-group")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to show the history of."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("estimate-rent")
+                .about("Show minimum balance required to create rent exempt account")
+                .arg(
+                    Arg::with_name("account_type")
+                        .long("account-type")
+                        .value_name("TYPE")
+                        .takes_value(true)
+                        .required(true)
+                        .possible_values(RENT_ACCOUNT_TYPES)
+                        .help("Type of the account to create."),
+                )
+                .arg(
+                    Arg::with_name("extra_bytes")
+                        .long("extra-bytes")
+                        .value_name("N")
+                        .takes_value(true)
+                        .validator(is_parsable::<usize>)
+                        .default_value("0")
+                        .help("Bytes to add to the account size."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("lookup-valid-signer")
+                .about("Find valid signer account by its Ethereum address")
+                .arg(
+                    Arg::with_name("eth_address")
+                        .long("eth-address")
+                        .validator(is_eth_address)
+                        .value_name("HEX")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Ethereum address of valid signer, 0x prefix is optional."),
+                )
+                .arg(
+                    Arg::with_name("signer_group")
+                        .long("signer-group")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Group valid signer belongs to."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("list-valid-signers")
+                .about("List valid signers of the group in the order they were added")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .help("Signer group to list."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("export-signer-group")
+                .about("Export signer group and its valid signers to a JSON file")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .long("signer-group")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to export."),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .value_name("PATH")
+                        .takes_value(true)
+                        .required(true)
+                        .help("JSON file to write the backup to."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("clone-signer-group")
+                .about("Create signer group with the same valid signers as the source group")
+                .arg(
+                    Arg::with_name("source_group")
+                        .index(1)
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Signer group to copy the valid signers of."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("layout-status")
+                .about("Check if the account is stored at the current layout or an outdated one")
+                .arg(
+                    Arg::with_name("signer_group")
+                        .long("signer-group")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .help("Signer group to check."),
+                )
+                .arg(
+                    Arg::with_name("valid_signer")
+                        .long("valid-signer")
+                        .validator(is_pubkey)
+                        .value_name("ADDRESS")
+                        .takes_value(true)
+                        .help("Valid signer to check."),
+                )
+                .group(
+                    ArgGroup::with_name("account")
+                        .args(&["signer_group", "valid_signer"])
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("import-signer-group")
+                .about("Re-create signer group and its valid signers from a JSON file")
+                .arg(
+                    Arg::with_name("input")
+                        .long("input")
+                        .value_name("PATH")
+                        .takes_value(true)
+                        .required(true)
+                        .help("JSON file produced by export-signer-group."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("recover-eth-address")
+                .alias("recover")
+                .about("Recover Ethereum address from the signature and message")
+                .arg(
+                    Arg::with_name("signature")
+                        .long("signature")
+                        .validator(is_hex)
+                        .value_name("HEX")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Secp256k1 signature (64 bytes, without recovery ID)."),
+                )
+                .arg(
+                    Arg::with_name("recovery_id")
+                        .long("recovery-id")
+                        .value_name("N")
                         .takes_value(true)
                         .required(true)
-                        .help("Valid signer's private key."),
+                        .help("Signature recovery ID."),
                 )
                 .arg(
                     Arg::with_name("message")
-                        .index(3)
+                        .long("message")
                         .value_name("MESSAGE")
                         .takes_value(true)
                         .required(true)
-                        .help("Message to sign and send."),
+                        .help("Signed message."),
+                )
+                .arg(
+                    Arg::with_name("expected_address")
+                        .long("expected-address")
+                        .validator(is_eth_address)
+                        .value_name("HEX")
+                        .takes_value(true)
+                        .help("Ethereum address the signature is expected to recover to."),
                 ),
         )
         .get_matches();
@@ -404,13 +2114,25 @@ fn main() {
             exit(1);
         });
         let verbose = matches.is_present("verbose");
+        let commitment_config = parse_commitment(matches.value_of("commitment").unwrap())
+            .unwrap_or_else(|e| {
+                eprintln!("error: {}", e);
+                exit(1);
+            });
+        let confirm_timeout =
+            Duration::from_secs(value_t_or_exit!(matches, "confirm_timeout", u64));
+        let max_retries = value_t_or_exit!(matches, "max_retries", usize);
 
         Config {
-            rpc_client: RpcClient::new(json_rpc_url),
+            cluster: Cluster::from_rpc_url(&json_rpc_url),
+            // balance, blockhash and account queries use the client's commitment
+            rpc_client: RpcClient::new_with_commitment(json_rpc_url, commitment_config),
             verbose,
             owner,
             fee_payer,
-            commitment_config: CommitmentConfig::confirmed(),
+            commitment_config,
+            confirm_timeout,
+            max_retries,
         }
     };
 
@@ -418,6 +2140,10 @@ fn main() {
 
     let _ = match matches.subcommand() {
         ("create-signer-group", Some(_)) => command_create_signer_group(&config),
+        ("bootstrap", Some(arg_matches)) => {
+            let lamports: u64 = value_t_or_exit!(arg_matches, "lamports", u64);
+            command_bootstrap(&config, lamports)
+        }
         ("create-valid-signer", Some(arg_matches)) => {
             let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
             let eth_address: String = value_t_or_exit!(arg_matches, "eth_address", String);
@@ -427,28 +2153,447 @@ fn main() {
             let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
             command_clear_valid_signer(&config, &valid_signer)
         }
+        ("migrate-valid-signer", Some(arg_matches)) => {
+            let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+            let new_signer_group: Pubkey = pubkey_of(arg_matches, "new_signer_group").unwrap();
+            command_migrate_valid_signer(&config, &valid_signer, &new_signer_group)
+        }
+        ("update-max-signers", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            let max_valid_signers: u32 = value_t_or_exit!(arg_matches, "max_valid_signers", u32);
+            command_update_max_signers(&config, &signer_group, max_valid_signers)
+        }
+        ("set-delegate-operator", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            let delegate_operator: Pubkey = pubkey_of(arg_matches, "delegate_operator").unwrap();
+            command_set_delegate_operator(&config, &signer_group, Some(&delegate_operator))
+        }
+        ("clear-delegate-operator", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            command_set_delegate_operator(&config, &signer_group, None)
+        }
+        ("set-governance-realm", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            let governance_realm: Pubkey = pubkey_of(arg_matches, "governance_realm").unwrap();
+            command_set_governance_realm(&config, &signer_group, &governance_realm)
+        }
+        ("set-lockdown-mode", Some(arg_matches)) => {
+            let lockdown_mode: u8 = value_t_or_exit!(arg_matches, "lockdown_mode", u8);
+            command_set_lockdown_mode(&config, lockdown_mode)
+        }
+        ("update-merkle-root", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            command_update_merkle_root(&config, &signer_group)
+        }
+        ("add-signer-address", Some(arg_matches)) => {
+            let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+            let eth_address: String = value_t_or_exit!(arg_matches, "eth_address", String);
+            command_update_signer_address(&config, &valid_signer, eth_address, false)
+        }
+        ("remove-signer-address", Some(arg_matches)) => {
+            let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+            let eth_address: String = value_t_or_exit!(arg_matches, "eth_address", String);
+            command_update_signer_address(&config, &valid_signer, eth_address, true)
+        }
+        ("revoke-eth-address", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            let eth_address: String = value_t_or_exit!(arg_matches, "eth_address", String);
+            command_revoke_eth_address(&config, &signer_group, eth_address)
+        }
         ("send-message", Some(arg_matches)) => {
             let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
             let secret_key: String = value_t_or_exit!(arg_matches, "secret_key", String);
+            let message = read_message(
+                value_t!(arg_matches, "message", String).ok(),
+                value_t!(arg_matches, "message_file", String).ok(),
+                arg_matches.value_of("message_encoding").unwrap(),
+            );
+            let skip_preflight_checks = arg_matches.is_present("skip_preflight_checks");
+            message.and_then(|message| {
+                command_send_message(
+                    &config,
+                    &valid_signer,
+                    secret_key,
+                    message,
+                    skip_preflight_checks,
+                )
+            })
+        }
+        ("set-valid-signer-metadata", Some(arg_matches)) => {
+            let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+            let endpoint_url: String = value_t_or_exit!(arg_matches, "endpoint_url", String);
+            let operator_name: String = value_t_or_exit!(arg_matches, "operator_name", String);
+            command_set_valid_signer_metadata(&config, &valid_signer, endpoint_url, operator_name)
+        }
+        ("get-valid-signer-metadata", Some(arg_matches)) => {
+            let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+            command_get_valid_signer_metadata(&config, &valid_signer)
+        }
+        ("get-valid-signer-info", Some(arg_matches)) => {
+            let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+            command_get_valid_signer_info(&config, &valid_signer)
+        }
+        ("init-program-stats", Some(_)) => command_init_program_stats(&config),
+        ("get-program-stats", Some(_)) => command_get_program_stats(&config),
+        ("get-ownership-history", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            command_get_ownership_history(&config, &signer_group)
+        }
+        ("estimate-rent", Some(arg_matches)) => {
+            let extra_bytes: usize = value_t_or_exit!(arg_matches, "extra_bytes", usize);
+            command_estimate_rent(
+                &config,
+                arg_matches.value_of("account_type").unwrap(),
+                extra_bytes,
+            )
+        }
+        ("lookup-valid-signer", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            let eth_address: String = value_t_or_exit!(arg_matches, "eth_address", String);
+            command_lookup_valid_signer(&config, &signer_group, eth_address)
+        }
+        ("list-valid-signers", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            command_list_valid_signers(&config, &signer_group)
+        }
+        ("export-signer-group", Some(arg_matches)) => {
+            let signer_group: Pubkey = pubkey_of(arg_matches, "signer_group").unwrap();
+            let output: String = value_t_or_exit!(arg_matches, "output", String);
+            command_export_signer_group(&config, &signer_group, &output)
+        }
+        ("clone-signer-group", Some(arg_matches)) => {
+            let source_group: Pubkey = pubkey_of(arg_matches, "source_group").unwrap();
+            command_clone_signer_group(&config, &source_group)
+        }
+        ("layout-status", Some(arg_matches)) => {
+            if let Some(signer_group) = pubkey_of(arg_matches, "signer_group") {
+                command_layout_status::<SignerGroup>(
+                    &config,
+                    &signer_group,
+                    Processor::SIGNER_GROUP_VERSION,
+                    SignerGroup::LEN,
+                )
+            } else {
+                let valid_signer: Pubkey = pubkey_of(arg_matches, "valid_signer").unwrap();
+                command_layout_status::<ValidSigner>(
+                    &config,
+                    &valid_signer,
+                    Processor::VALID_SIGNER_VERSION,
+                    ValidSigner::LEN,
+                )
+            }
+        }
+        ("import-signer-group", Some(arg_matches)) => {
+            let input: String = value_t_or_exit!(arg_matches, "input", String);
+            command_import_signer_group(&config, &input)
+        }
+        ("recover-eth-address", Some(arg_matches)) => {
+            let signature: String = value_t_or_exit!(arg_matches, "signature", String);
+            let recovery_id: u8 = value_t_or_exit!(arg_matches, "recovery_id", u8);
             let message: String = value_t_or_exit!(arg_matches, "message", String);
-            command_send_message(&config, &valid_signer, secret_key, message)
+            let expected_address = value_t!(arg_matches, "expected_address", String).ok();
+            command_recover_eth_address(signature, recovery_id, message, expected_address)
         }
         _ => unreachable!(),
     }
     .and_then(|transaction| {
         if let Some(transaction) = transaction {
-            let signature = config
-                .rpc_client
-                .send_and_confirm_transaction_with_spinner_and_commitment(
-                    &transaction,
-                    config.commitment_config,
-                )?;
+            let signature = send_and_confirm_transaction(
+                &config,
+                transaction,
+                &[config.fee_payer.as_ref(), config.owner.as_ref()],
+            )?;
             println!("Signature: {}", signature);
         }
         Ok(())
     })
     .map_err(|err| {
-        eprintln!("{}", err);
+        eprintln!("error: {}", err);
         exit(1);
     });
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use audius::{crypto::construct_eth_address, instruction::AudiusInstruction};
+    use secp256k1::PublicKey;
+
+    #[test]
+    fn test_check_signature_preflight() {
+        let private_key = SecretKey::parse(&[7u8; 32]).unwrap();
+        let eth_address = construct_eth_address(&PublicKey::from_secret_key(&private_key));
+        let (_, signature_data) = sign_message(&private_key, b"Test message".to_vec()).unwrap();
+
+        let mut valid_signer = ValidSigner {
+            eth_addresses: [[1; 20], eth_address, [0; 20]],
+            eth_address_count: 2,
+            ..ValidSigner::default()
+        };
+        assert!(check_signature_preflight(&valid_signer, &signature_data).is_ok());
+
+        // wrong recovery ID recovers some other address
+        let mut wrong_recovery_id = signature_data.clone();
+        wrong_recovery_id.recovery_id ^= 1;
+        assert!(check_signature_preflight(&valid_signer, &wrong_recovery_id).is_err());
+
+        // so does the message signed in one encoding and sent in another
+        let mut wrong_message = signature_data.clone();
+        wrong_message.message = hex::encode(b"Test message").into_bytes();
+        assert!(check_signature_preflight(&valid_signer, &wrong_message).is_err());
+
+        valid_signer.eth_address_count = 1;
+        assert!(check_signature_preflight(&valid_signer, &signature_data).is_err());
+
+        valid_signer.eth_address_count = 2;
+        valid_signer.key_type = SignerKeyType::ED25519;
+        assert!(check_signature_preflight(&valid_signer, &signature_data).is_err());
+    }
+
+    #[test]
+    fn test_sign_binary_message_file() {
+        // non-UTF-8 bytes, including zeros and an invalid sequence
+        let message: Vec<u8> = vec![0x00, 0xff, 0xfe, 0x80, 0x0a, 0x00, 0xc3, 0x28];
+        let message_file =
+            std::env::temp_dir().join(format!("audius-cli-message-{}.bin", std::process::id()));
+        std::fs::write(&message_file, &message).unwrap();
+        let read = read_message(
+            None,
+            Some(message_file.to_string_lossy().to_string()),
+            "utf8",
+        );
+        std::fs::remove_file(&message_file).unwrap();
+        let read = read.unwrap();
+        assert_eq!(read, message);
+
+        let private_key = SecretKey::parse(&[7u8; 32]).unwrap();
+        let (secp256_program_instruction, signature_data) =
+            sign_message(&private_key, read).unwrap();
+        assert_eq!(signature_data.message, message);
+
+        // message signed by Secp256 program is the one validated by the program
+        let message_start = SecpSignatureOffsets::MESSAGE_DATA_OFFSET;
+        assert_eq!(
+            &secp256_program_instruction.data[message_start..],
+            message.as_slice()
+        );
+        let validate_instruction = validate_signature_raw(
+            &audius::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            signature_data,
+        )
+        .unwrap();
+        match AudiusInstruction::unpack(&validate_instruction.data).unwrap() {
+            AudiusInstruction::ValidateSignature(unpacked) => assert_eq!(unpacked.message, message),
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        assert!(read_message(None, None, "utf8").is_err());
+    }
+
+    #[test]
+    fn test_message_encodings() {
+        let message = vec![0x00, 0xff, 0x10, 0x80, 0x7f];
+        for (encoding, encoded) in &[
+            ("hex", hex::encode(&message)),
+            ("base58", bs58::encode(&message).into_string()),
+            ("base64", base64::encode(&message)),
+        ] {
+            assert_eq!(
+                read_message(Some(encoded.clone()), None, encoding).unwrap(),
+                message,
+                "{} message",
+                encoding
+            );
+            // every encoding produces the same signed message
+            let private_key = SecretKey::parse(&[7u8; 32]).unwrap();
+            let (_, signature_data) = sign_message(
+                &private_key,
+                decode_message(encoded.clone(), encoding).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(signature_data.message, message);
+        }
+
+        assert_eq!(
+            read_message(Some("Test message".to_string()), None, "utf8").unwrap(),
+            b"Test message".to_vec()
+        );
+        assert_eq!(
+            decode_message("0x00ff".to_string(), "hex").unwrap(),
+            vec![0x00, 0xff]
+        );
+        assert!(decode_message("0g".to_string(), "hex").is_err());
+        assert!(decode_message("0OIl".to_string(), "base58").is_err());
+        assert!(decode_message("!!".to_string(), "base64").is_err());
+        assert!(decode_message("00".to_string(), "base32").is_err());
+    }
+
+    #[test]
+    fn test_parse_commitment() {
+        for level in COMMITMENT_LEVELS {
+            assert!(parse_commitment(level).is_ok(), "{} commitment", level);
+        }
+        assert_eq!(
+            parse_commitment("processed").unwrap(),
+            CommitmentConfig::processed()
+        );
+        assert_eq!(
+            parse_commitment("confirmed").unwrap(),
+            CommitmentConfig::confirmed()
+        );
+        assert_eq!(
+            parse_commitment("finalized").unwrap(),
+            CommitmentConfig::finalized()
+        );
+        assert!(parse_commitment("max").is_err());
+    }
+
+    #[test]
+    fn test_bootstrap_cluster() {
+        let cluster = |genesis_hash: &str| bootstrap_cluster(&genesis_hash.parse().unwrap());
+        assert_eq!(cluster(DEVNET_GENESIS_HASH).unwrap(), Cluster::Devnet);
+        assert_eq!(cluster(TESTNET_GENESIS_HASH).unwrap(), Cluster::Testnet);
+        assert!(cluster(MAINNET_BETA_GENESIS_HASH).is_err());
+        assert!(bootstrap_cluster(&Hash::default()).is_err());
+    }
+
+    #[test]
+    fn test_account_type_len() {
+        for account_type in RENT_ACCOUNT_TYPES {
+            assert!(account_type_len(account_type).is_ok(), "{}", account_type);
+        }
+        assert_eq!(account_type_len("signer-group").unwrap(), SignerGroup::LEN);
+        assert_eq!(account_type_len("valid-signer").unwrap(), ValidSigner::LEN);
+        assert_eq!(account_type_len("nonce-account").unwrap(), 80);
+        assert!(account_type_len("metadata").is_err());
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        use solana_client::{
+            rpc_request::{RpcError, RpcResponseErrorData},
+            rpc_response::RpcSimulateTransactionResult,
+        };
+        use solana_sdk::instruction::InstructionError;
+
+        assert!(is_retryable(&TransactionError::BlockhashNotFound.into()));
+        let preflight_failure = |err| -> ClientError {
+            RpcError::RpcResponseError {
+                code: -32002,
+                message: "Transaction simulation failed".to_string(),
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(
+                    RpcSimulateTransactionResult {
+                        err: Some(err),
+                        logs: None,
+                    },
+                ),
+            }
+            .into()
+        };
+        assert!(is_retryable(&preflight_failure(
+            TransactionError::BlockhashNotFound
+        )));
+
+        assert!(!is_retryable(&preflight_failure(
+            TransactionError::InstructionError(0, InstructionError::Custom(1))
+        )));
+        assert!(!is_retryable(&TransactionError::AccountNotFound.into()));
+        assert!(!is_retryable(
+            &ClientErrorKind::Custom("Transaction is not confirmed".to_string()).into()
+        ));
+    }
+
+    #[test]
+    fn test_parse_eth_address() {
+        let address = <[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>::from_hex(
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        )
+        .unwrap();
+        for valid in &[
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        ] {
+            assert_eq!(parse_eth_address(valid), Ok(address));
+            assert_eq!(is_eth_address(valid.to_string()), Ok(()));
+        }
+
+        // 19 and 21 bytes
+        assert!(parse_eth_address("5aaeb6053f3e94c9b9a09f33669435e7ef1bea")
+            .unwrap_err()
+            .contains("must be 40 hex characters, got 38"));
+        assert!(
+            parse_eth_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed00")
+                .unwrap_err()
+                .contains("must be 40 hex characters, got 42")
+        );
+        assert!(
+            parse_eth_address("5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg")
+                .unwrap_err()
+                .contains("isn't valid hex")
+        );
+        // one letter of the checksummed address in the wrong case
+        assert!(
+            parse_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD")
+                .unwrap_err()
+                .contains("expected 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+        );
+    }
+
+    #[test]
+    fn test_parse_secret_key() {
+        let secret_key = hex::encode([7u8; 32]);
+        let private_key = SecretKey::parse(&[7u8; 32]).unwrap();
+        assert_eq!(parse_secret_key(&secret_key), Ok(private_key.clone()));
+        assert_eq!(
+            parse_secret_key(&format!("0x{}", secret_key)),
+            Ok(private_key)
+        );
+
+        assert_eq!(
+            parse_secret_key(&secret_key[2..]).unwrap_err(),
+            "Secp256k1 secret key must be 64 hex characters"
+        );
+        assert!(is_secret_key(format!("{}zz", &secret_key[2..])).is_err());
+        // zero isn't a valid secret key
+        assert_eq!(
+            parse_secret_key(&hex::encode([0u8; 32])).unwrap_err(),
+            "Secp256k1 secret key is out of range"
+        );
+    }
+
+    #[test]
+    fn test_recover_eth_address() {
+        let private_key = SecretKey::parse(&[7u8; 32]).unwrap();
+        let message = b"Test message";
+
+        let secp256_program_instruction =
+            secp256k1_instruction::new_secp256k1_instruction(&private_key, message);
+
+        let start = 1;
+        let end = start + SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let offsets =
+            SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])
+                .unwrap();
+
+        let sig_start = offsets.signature_offset as usize;
+        let sig_end = sig_start + SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+        let mut signature = [0u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+        signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
+        let recovery_id = secp256_program_instruction.data[sig_end];
+
+        let eth_address = construct_eth_address(&PublicKey::from_secret_key(&private_key));
+
+        assert_eq!(
+            recover_eth_address(&signature, recovery_id, message).unwrap(),
+            eth_address
+        );
+        assert_ne!(
+            recover_eth_address(&signature, recovery_id, b"Other message").unwrap(),
+            eth_address
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Layout checks of the accounts stored by older program versions
+
+/// Stored account compared with the layout the program currently writes
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutStatus {
+    /// Account is stored at the current layout, nothing to migrate
+    Current,
+    /// Account is stored at an older layout and has to be migrated
+    Outdated {
+        /// Layout version the account is stored at
+        version: u8,
+        /// Bytes the account has to grow by
+        missing_len: usize,
+    },
+    /// Account is written by a newer program than the one the CLI is built with
+    Newer {
+        /// Layout version the account is stored at
+        version: u8,
+    },
+}
+
+impl LayoutStatus {
+    /// Compares stored version and data length with the current ones. Accounts of an older
+    /// version are outdated even if they are long enough, the program allocates the current
+    /// length for new accounts and older versions leave the added fields unset.
+    pub fn of(stored_version: u8, stored_len: usize, version: u8, len: usize) -> Self {
+        if stored_version > version {
+            Self::Newer {
+                version: stored_version,
+            }
+        } else if stored_version < version || stored_len < len {
+            Self::Outdated {
+                version: stored_version,
+                missing_len: len.saturating_sub(stored_len),
+            }
+        } else {
+            Self::Current
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_layout_status() {
+        assert_eq!(LayoutStatus::of(2, 100, 2, 100), LayoutStatus::Current);
+        // longer accounts are read by the current layout just as well
+        assert_eq!(LayoutStatus::of(2, 120, 2, 100), LayoutStatus::Current);
+
+        assert_eq!(
+            LayoutStatus::of(1, 80, 2, 100),
+            LayoutStatus::Outdated {
+                version: 1,
+                missing_len: 20
+            }
+        );
+        assert_eq!(
+            LayoutStatus::of(1, 100, 2, 100),
+            LayoutStatus::Outdated {
+                version: 1,
+                missing_len: 0
+            }
+        );
+        assert_eq!(
+            LayoutStatus::of(2, 80, 2, 100),
+            LayoutStatus::Outdated {
+                version: 2,
+                missing_len: 20
+            }
+        );
+
+        assert_eq!(
+            LayoutStatus::of(3, 80, 2, 100),
+            LayoutStatus::Newer { version: 3 }
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "test-bpf")]
+
+//! Drives the typed client against a local test validator running the program's BPF build,
+//! build it first and run with
+//! `cargo build-bpf --manifest-path ../program/Cargo.toml && cargo test --features test-bpf`
+
+use audius::crypto::construct_eth_address;
+use audius_cli::client::AudiusClient;
+use secp256k1::{PublicKey, SecretKey};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{Keypair, Signer},
+};
+use solana_test_validator::TestValidatorGenesis;
+use std::env;
+
+#[test]
+fn client_manages_signer_group() {
+    env::set_var("BPF_OUT_DIR", "../program/target/deploy");
+    let (test_validator, payer) = TestValidatorGenesis::default()
+        .add_program("audius", audius::id())
+        .start();
+    let client = AudiusClient::new(
+        RpcClient::new_with_commitment(test_validator.rpc_url(), CommitmentConfig::confirmed()),
+        audius::id(),
+        payer,
+    );
+
+    let signer_group = Keypair::new();
+    let owner = Keypair::new();
+    client
+        .init_signer_group(&signer_group, &owner.pubkey())
+        .unwrap();
+    let signer_group_data = client.get_signer_group(&signer_group.pubkey()).unwrap();
+    assert_eq!(signer_group_data.owner, owner.pubkey());
+
+    let secret_key = SecretKey::parse(&[7; 32]).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&secret_key));
+    let valid_signer = client
+        .init_valid_signer(&signer_group.pubkey(), &owner, eth_address)
+        .unwrap();
+    let valid_signer_data = client.get_valid_signer(&valid_signer).unwrap();
+    assert_eq!(valid_signer_data.signer_group, signer_group.pubkey());
+    assert!(valid_signer_data.has_eth_address(&eth_address));
+
+    client
+        .validate_signature(&valid_signer, &secret_key, b"Test message".to_vec())
+        .unwrap();
+    let valid_signer_data = client.get_valid_signer(&valid_signer).unwrap();
+    assert_eq!(u32::from(valid_signer_data.total_validations), 1);
+    // signature of another key isn't accepted
+    client
+        .validate_signature(
+            &valid_signer,
+            &SecretKey::parse(&[8; 32]).unwrap(),
+            b"Test message".to_vec(),
+        )
+        .unwrap_err();
+
+    client.clear_valid_signer(&valid_signer, &owner).unwrap();
+    client.get_valid_signer(&valid_signer).unwrap_err();
+    let signer_group_data = client.get_signer_group(&signer_group.pubkey()).unwrap();
+    assert_eq!(u32::from(signer_group_data.valid_signer_count), 0);
+}
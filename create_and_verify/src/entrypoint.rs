@@ -2,7 +2,7 @@
 
 #![cfg(all(target_arch = "bpf", not(feature = "no-entrypoint")))]
 
-use crate::{error::ProgramTemplateError, processor::Processor};
+use crate::{error::CreateAndVerifyError, processor::Processor};
 use solana_program::program_error::PrintProgramError;
 use solana_program::{
     account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
@@ -16,7 +16,7 @@ fn process_instruction(
 ) -> ProgramResult {
     if let Err(error) = Processor::process_instruction(program_id, accounts, instruction_data) {
         // catch the error so we can print it
-        error.print::<ProgramTemplateError>();
+        error.print::<CreateAndVerifyError>();
         return Err(error);
     }
     Ok(())
@@ -11,41 +11,61 @@ use thiserror::Error;
 
 /// Errors that may be returned by the CreateAndVerify program.
 #[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
-pub enum ProgramTemplateError {
-    /// Example error
-    #[error("Example error")]
-    ExampleError,
-    /// Instruction unpack error
-    #[error("Instruction unpack error")]
-    InstructionUnpackError,
+pub enum CreateAndVerifyError {
+    /// Instruction data can't be unpacked
+    #[error("Invalid instruction data")]
+    InvalidInstructionData,
     /// Invalid track data were passed
     #[error("Invalid track data were passed")]
     InvalidTrackData,
     /// Difference between timestamp and current time is too big
     #[error("Difference between timestamp and current time is too big")]
     InvalidTimestamp,
+    /// Secp256 instruction is missing right before the current one
+    #[error("Secp256 instruction is missing")]
+    MissingSecpInstruction,
+    /// Valid signer is not initialized or belongs to another signer group
+    #[error("Valid signer doesn't match signer group")]
+    SignerMismatch,
+    /// Secp256 instruction doesn't verify given signature and track data
+    #[error("Signature verification failed")]
+    SignatureVerificationFailed,
+    /// Audius program returned the result of another signer's validation
+    #[error("Validation result doesn't match valid signer")]
+    ValidationResultMismatch,
 }
-impl From<ProgramTemplateError> for ProgramError {
-    fn from(e: ProgramTemplateError) -> Self {
+impl From<CreateAndVerifyError> for ProgramError {
+    fn from(e: CreateAndVerifyError) -> Self {
         ProgramError::Custom(e as u32)
     }
 }
-impl<T> DecodeError<T> for ProgramTemplateError {
+impl<T> DecodeError<T> for CreateAndVerifyError {
     fn type_of() -> &'static str {
-        "ProgramTemplateError"
+        "CreateAndVerifyError"
     }
 }
 
-impl PrintProgramError for ProgramTemplateError {
+impl PrintProgramError for CreateAndVerifyError {
     fn print<E>(&self)
     where
         E: 'static + std::error::Error + DecodeError<E> + PrintProgramError + FromPrimitive,
     {
         match self {
-            ProgramTemplateError::ExampleError => msg!("Example error message"),
-            ProgramTemplateError::InstructionUnpackError => msg!("Instruction unpack error"),
-            ProgramTemplateError::InvalidTrackData => msg!("Invalid track data were passed"),
-            ProgramTemplateError::InvalidTimestamp => msg!("Difference between timestamp and current time is too big"),
+            CreateAndVerifyError::InvalidInstructionData => msg!("Invalid instruction data"),
+            CreateAndVerifyError::InvalidTrackData => msg!("Invalid track data were passed"),
+            CreateAndVerifyError::InvalidTimestamp => {
+                msg!("Difference between timestamp and current time is too big")
+            }
+            CreateAndVerifyError::MissingSecpInstruction => {
+                msg!("Secp256 instruction is missing")
+            }
+            CreateAndVerifyError::SignerMismatch => msg!("Valid signer doesn't match signer group"),
+            CreateAndVerifyError::SignatureVerificationFailed => {
+                msg!("Signature verification failed")
+            }
+            CreateAndVerifyError::ValidationResultMismatch => {
+                msg!("Validation result doesn't match valid signer")
+            }
         }
     }
 }
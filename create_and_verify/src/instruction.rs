@@ -1,6 +1,6 @@
 //! Instruction types
 
-use crate::state::TrackData;
+use crate::{error::CreateAndVerifyError, state::TrackData};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     instruction::{AccountMeta, Instruction},
@@ -27,14 +27,36 @@ pub struct InstructionArgs {
 pub enum TemplateInstruction {
     ///   Example
     ///
-    ///   1. [] Valid signer account
-    ///   2. [] Signer group
+    ///   1. [w] Valid signer account
+    ///   2. [w] Signer group
     ///   3. [] Audius program account
     ///   4. [] Sysvar instruction account
     ///   5. [] Sysvar clock account
     ExampleInstruction(InstructionArgs),
 }
 
+impl TemplateInstruction {
+    /// Smallest serialized instruction: tag, track data with empty strings,
+    /// signature and recovery ID
+    pub const MIN_LEN: usize =
+        1 + 3 * 4 + 8 + audius::state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE + 1;
+
+    /// Unpacks a byte buffer into a [TemplateInstruction](enum.TemplateInstruction.html).
+    /// Short buffers and trailing bytes are rejected.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < Self::MIN_LEN {
+            return Err(CreateAndVerifyError::InvalidInstructionData.into());
+        }
+        let mut data = input;
+        let instruction = Self::deserialize(&mut data)
+            .map_err(|_| ProgramError::from(CreateAndVerifyError::InvalidInstructionData))?;
+        if !data.is_empty() {
+            return Err(CreateAndVerifyError::InvalidInstructionData.into());
+        }
+        Ok(instruction)
+    }
+}
+
 /// Create `Example` instruction
 pub fn init(
     program_id: &Pubkey,
@@ -47,8 +69,8 @@ pub fn init(
         .try_to_vec()
         .or(Err(ProgramError::InvalidArgument))?;
     let accounts = vec![
-        AccountMeta::new_readonly(*valid_signer_account, false),
-        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
         AccountMeta::new_readonly(audius::id(), false),
         AccountMeta::new_readonly(sysvar::instructions::id(), false),
         AccountMeta::new_readonly(sysvar::clock::id(), false),
@@ -59,3 +81,48 @@ pub fn init(
         data,
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn instruction_args() -> InstructionArgs {
+        InstructionArgs {
+            track_data: TrackData {
+                user_id: "1".to_string(),
+                track_id: "2".to_string(),
+                source: "3".to_string(),
+                timestamp: 4,
+            },
+            signature: [5; audius::state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+            recovery_id: 1,
+        }
+    }
+
+    #[test]
+    fn test_unpack() {
+        let data = TemplateInstruction::ExampleInstruction(instruction_args())
+            .try_to_vec()
+            .unwrap();
+        let TemplateInstruction::ExampleInstruction(args) =
+            TemplateInstruction::unpack(&data).unwrap();
+        assert_eq!(args.track_data.source, "3");
+        assert_eq!(args.recovery_id, 1);
+    }
+
+    #[test]
+    fn test_unpack_short_payload() {
+        assert_eq!(
+            TemplateInstruction::unpack(&[0; 10]).err(),
+            Some(CreateAndVerifyError::InvalidInstructionData.into())
+        );
+
+        let data = TemplateInstruction::ExampleInstruction(instruction_args())
+            .try_to_vec()
+            .unwrap();
+        assert_eq!(
+            TemplateInstruction::unpack(&data[..data.len() - 1]).err(),
+            Some(CreateAndVerifyError::InvalidInstructionData.into())
+        );
+    }
+}
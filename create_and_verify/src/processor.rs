@@ -1,14 +1,19 @@
 //! Program state processor
 
 use crate::{
-    error::ProgramTemplateError,
+    error::CreateAndVerifyError,
     instruction::{InstructionArgs, TemplateInstruction},
 };
-use audius::instruction::SignatureData;
+use audius::{
+    instruction::SignatureData,
+    return_data::get_validation_result,
+    state::{SecpSignatureOffsets, SignerKeyType, ValidSigner},
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::next_account_info, account_info::AccountInfo, entrypoint::ProgramResult, msg,
-    program::invoke, pubkey::Pubkey, sysvar::clock::Clock, sysvar::Sysvar,
+    program::invoke, pubkey::Pubkey, secp256k1_program, sysvar, sysvar::clock::Clock,
+    sysvar::Sysvar,
 };
 
 const MAX_TIME_DIFF: i64 = 10;
@@ -36,7 +41,32 @@ impl Processor {
         let clock = Clock::from_account_info(&clock_account_info)?;
 
         if (clock.unix_timestamp - instruction_data.track_data.timestamp).abs() > MAX_TIME_DIFF {
-            return Err(ProgramTemplateError::InvalidTimestamp.into());
+            return Err(CreateAndVerifyError::InvalidTimestamp.into());
+        }
+
+        // Secp256 instruction is expected right before the current one
+        let current_index =
+            sysvar::instructions::load_current_index(&sysvar_instruction.data.borrow());
+        if current_index == 0 {
+            return Err(CreateAndVerifyError::MissingSecpInstruction.into());
+        }
+        let secp_instruction_index = current_index - 1;
+        let secp_instruction = sysvar::instructions::load_instruction_at(
+            secp_instruction_index as usize,
+            &sysvar_instruction.data.borrow(),
+        )
+        .or(Err(CreateAndVerifyError::MissingSecpInstruction))?;
+        if secp_instruction.program_id != secp256k1_program::id() {
+            return Err(CreateAndVerifyError::MissingSecpInstruction.into());
+        }
+
+        let valid_signer = ValidSigner::try_from_slice(&valid_signer_info.data.borrow())
+            .or(Err(CreateAndVerifyError::SignerMismatch))?;
+        if !valid_signer.is_initialized()
+            || valid_signer.signer_group != *signer_group_info.key
+            || valid_signer.key_type != SignerKeyType::SECP256K1
+        {
+            return Err(CreateAndVerifyError::SignerMismatch.into());
         }
 
         let signature_data = Box::new(SignatureData {
@@ -45,9 +75,20 @@ impl Processor {
             message: instruction_data
                 .track_data
                 .try_to_vec()
-                .or(Err(ProgramTemplateError::InvalidTrackData))?,
+                .or(Err(CreateAndVerifyError::InvalidTrackData))?,
+            secp_instruction_index: secp_instruction_index as u8,
+            preceding_secp_instruction: false,
+            secp_signature_index: 0,
         });
 
+        Self::check_secp_instruction(
+            &secp_instruction.data,
+            valid_signer.eth_addresses(),
+            &signature_data,
+        )?;
+
+        // track data is the raw signed message, Audius program matches it against
+        // Secp256 instruction as is instead of by digest
         invoke(
             &audius::instruction::validate_signature_with_sysvar(
                 &audius::id(),
@@ -56,7 +97,7 @@ impl Processor {
                 sysvar_instruction.key,
                 *signature_data,
             )
-            .unwrap(),
+            .or(Err(CreateAndVerifyError::InvalidTrackData))?,
             &[
                 audius_account_info.clone(),
                 valid_signer_info.clone(),
@@ -65,6 +106,52 @@ impl Processor {
             ],
         )?;
 
+        // Audius program returns which of the signer's addresses made the signature
+        let validation_result = get_validation_result()?;
+        if validation_result.signer_group != *signer_group_info.key
+            || !valid_signer
+                .eth_addresses()
+                .contains(&validation_result.eth_address)
+        {
+            return Err(CreateAndVerifyError::ValidationResultMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that Secp256 instruction carries the signature of one of the valid signer's
+    /// addresses for the track data, so the failure reason is reported before calling Audius program
+    fn check_secp_instruction(
+        secp_instruction_data: &[u8],
+        eth_addresses: &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]],
+        signature_data: &SignatureData,
+    ) -> ProgramResult {
+        let offsets = secp_instruction_data
+            .get(1..SecpSignatureOffsets::ETH_ADDRESS_OFFSET)
+            .and_then(|data| SecpSignatureOffsets::try_from_slice(data).ok())
+            .ok_or(CreateAndVerifyError::SignatureVerificationFailed)?;
+
+        let get_slice = |offset: u16, size: usize| {
+            secp_instruction_data.get(offset as usize..offset as usize + size)
+        };
+        let instruction_eth_address = get_slice(
+            offsets.eth_address_offset,
+            SecpSignatureOffsets::ETH_ADDRESS_SIZE,
+        );
+        if !eth_addresses
+            .iter()
+            .any(|eth_address| instruction_eth_address == Some(&eth_address[..]))
+            || get_slice(
+                offsets.signature_offset,
+                SecpSignatureOffsets::SECP_SIGNATURE_SIZE,
+            ) != Some(&signature_data.signature[..])
+            || get_slice(
+                offsets.message_data_offset,
+                offsets.message_data_size as usize,
+            ) != Some(signature_data.message.as_slice())
+        {
+            return Err(CreateAndVerifyError::SignatureVerificationFailed.into());
+        }
         Ok(())
     }
 
@@ -74,8 +161,7 @@ impl Processor {
         accounts: &[AccountInfo],
         input: &[u8],
     ) -> ProgramResult {
-        let instruction = TemplateInstruction::try_from_slice(input)
-            .or(Err(ProgramTemplateError::InstructionUnpackError))?;
+        let instruction = TemplateInstruction::unpack(input)?;
         match instruction {
             TemplateInstruction::ExampleInstruction(signature_data) => {
                 msg!("Instruction: ExampleInstruction");
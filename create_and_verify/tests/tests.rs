@@ -2,19 +2,28 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use chrono::Utc;
 use rand::{thread_rng, Rng};
 use secp256k1::{PublicKey, SecretKey};
 use sha3::Digest;
-use solana_program::{hash::Hash, pubkey::Pubkey, system_instruction};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, sysvar,
+};
 use solana_program_template::*;
 use solana_program_test::*;
 use solana_sdk::{
     secp256k1_instruction,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
     transport::TransportError,
 };
-use chrono::Utc;
 
 pub fn program_test() -> ProgramTest {
     ProgramTest::new(
@@ -24,6 +33,42 @@ pub fn program_test() -> ProgramTest {
     )
 }
 
+/// Id of the program reading back the result of Audius validation in tests
+fn validation_reader_id() -> Pubkey {
+    Pubkey::new(&[7; 32])
+}
+
+/// Invokes Audius program with the given instruction data and the accounts following
+/// the result account and Audius program one, then stores the validation result read
+/// back into the result account
+fn process_validation_reader(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let (result_info, audius_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let instruction = Instruction {
+        program_id: audius::id(),
+        accounts: audius_accounts
+            .iter()
+            .skip(1)
+            .map(|account_info| AccountMeta {
+                pubkey: *account_info.key,
+                is_signer: account_info.is_signer,
+                is_writable: account_info.is_writable,
+            })
+            .collect(),
+        data: input.to_vec(),
+    };
+    invoke(&instruction, audius_accounts)?;
+
+    let validation_result = audius::return_data::get_validation_result()?;
+    validation_result.serialize(&mut &mut result_info.data.borrow_mut()[..])?;
+    Ok(())
+}
+
 async fn setup() -> (BanksClient, Keypair, Hash, Keypair, Keypair) {
     let mut test_solana_env = program_test();
     test_solana_env.add_program(
@@ -31,6 +76,11 @@ async fn setup() -> (BanksClient, Keypair, Hash, Keypair, Keypair) {
         audius::id(),
         processor!(audius::processor::Processor::process),
     );
+    test_solana_env.add_program(
+        "validation_reader",
+        validation_reader_id(),
+        processor!(process_validation_reader),
+    );
 
     let (mut banks_client, payer, recent_blockhash) = test_solana_env.start().await;
 
@@ -89,10 +139,13 @@ async fn process_tx_init_signer_group(
     banks_client: &mut BanksClient,
 ) -> Result<(), TransportError> {
     let mut transaction = Transaction::new_with_payer(
-        &[
-            audius::instruction::init_signer_group(&audius::id(), signer_group, group_owner)
-                .unwrap(),
-        ],
+        &[audius::instruction::init_signer_group(
+            &audius::id(),
+            signer_group,
+            group_owner,
+            &payer.pubkey(),
+        )
+        .unwrap()],
         Some(&payer.pubkey()),
     );
     transaction.sign(&[payer], *recent_blockhash);
@@ -101,20 +154,20 @@ async fn process_tx_init_signer_group(
 }
 
 async fn process_tx_init_valid_signer(
-    valid_signer: &Pubkey,
     signer_group: &Pubkey,
     group_owner: &Keypair,
     payer: &Keypair,
     recent_blockhash: Hash,
     banks_client: &mut BanksClient,
     eth_address: [u8; audius::state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
-) -> Result<(), TransportError> {
+) -> Result<Pubkey, TransportError> {
     let mut transaction = Transaction::new_with_payer(
         &[audius::instruction::init_valid_signer(
             &audius::id(),
-            valid_signer,
             signer_group,
             &group_owner.pubkey(),
+            &payer.pubkey(),
+            None,
             eth_address,
         )
         .unwrap()],
@@ -122,7 +175,7 @@ async fn process_tx_init_valid_signer(
     );
     transaction.sign(&[payer, group_owner], recent_blockhash);
     banks_client.process_transaction(transaction).await?;
-    Ok(())
+    Ok(audius::find_valid_signer_address(&audius::id(), signer_group, &eth_address).0)
 }
 
 fn construct_eth_address(
@@ -137,8 +190,18 @@ fn construct_eth_address(
     addr
 }
 
-#[tokio::test]
-async fn test_call_example_instruction() {
+struct ExampleSetup {
+    banks_client: BanksClient,
+    payer: Keypair,
+    recent_blockhash: Hash,
+    valid_signer: Pubkey,
+    signer_group: Pubkey,
+    eth_address: [u8; audius::state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    secp256_program_instruction: Instruction,
+    instruction_args: instruction::InstructionArgs,
+}
+
+async fn setup_example(timestamp: i64) -> ExampleSetup {
     let mut rng = thread_rng();
     let key: [u8; 32] = rng.gen();
     let priv_key = SecretKey::parse(&key).unwrap();
@@ -149,7 +212,7 @@ async fn test_call_example_instruction() {
         user_id: String::from("U348512"),
         track_id: String::from("T52354"),
         source: String::from("some/path/to/source"),
-        timestamp: Utc::now().timestamp(),
+        timestamp,
     };
 
     let message = track_data.try_to_vec().unwrap();
@@ -186,20 +249,7 @@ async fn test_call_example_instruction() {
     .await
     .unwrap();
 
-    let valid_signer = Keypair::new();
-
-    create_account(
-        &mut banks_client,
-        &payer,
-        &recent_blockhash,
-        &valid_signer,
-        audius::state::ValidSigner::LEN,
-    )
-    .await
-    .unwrap();
-
-    process_tx_init_valid_signer(
-        &valid_signer.pubkey(),
+    let valid_signer = process_tx_init_valid_signer(
         &signer_group.pubkey(),
         &group_owner,
         &payer,
@@ -216,20 +266,255 @@ async fn test_call_example_instruction() {
         recovery_id,
     };
 
+    ExampleSetup {
+        banks_client,
+        payer,
+        recent_blockhash,
+        valid_signer,
+        signer_group: signer_group.pubkey(),
+        eth_address,
+        secp256_program_instruction,
+        instruction_args,
+    }
+}
+
+impl ExampleSetup {
+    fn example_instruction(&self) -> Instruction {
+        instruction::init(
+            &id(),
+            &self.valid_signer,
+            &self.signer_group,
+            self.instruction_args.clone(),
+        )
+        .unwrap()
+    }
+
+    async fn process(&mut self, instructions: &[Instruction]) -> Result<(), TransportError> {
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&self.payer.pubkey()));
+        transaction.sign(&[&self.payer], self.recent_blockhash);
+        self.banks_client.process_transaction(transaction).await
+    }
+}
+
+fn assert_example_error(
+    result: Result<(), TransportError>,
+    instruction_index: u8,
+    error: error::CreateAndVerifyError,
+) {
+    match result.unwrap_err() {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            index,
+            InstructionError::Custom(code),
+        )) => {
+            assert_eq!(index, instruction_index);
+            assert_eq!(code, error as u32);
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+/// Full flow: Secp256 program verifies the signature of the track data, create_and_verify
+/// program constructs the message and calls Audius program to validate it through CPI
+#[tokio::test]
+async fn test_call_example_instruction() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    let instructions = [
+        example.secp256_program_instruction.clone(),
+        example.example_instruction(),
+    ];
+    example.process(&instructions).await.unwrap();
+}
+
+/// Program validating the signature through CPI reads back which signer made it
+#[tokio::test]
+async fn test_validation_result_read_back_through_cpi() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    let result_account = Keypair::new();
+    let result_len = audius::return_data::ValidationResult::default()
+        .try_to_vec()
+        .unwrap()
+        .len();
+    let rent = example.banks_client.get_rent().await.unwrap();
     let mut transaction = Transaction::new_with_payer(
-        &[
-            secp256_program_instruction,
-            instruction::init(
-                &id(),
-                &valid_signer.pubkey(),
-                &signer_group.pubkey(),
-                instruction_args,
-            )
-            .unwrap(),
-        ],
-        Some(&payer.pubkey()),
+        &[system_instruction::create_account(
+            &example.payer.pubkey(),
+            &result_account.pubkey(),
+            rent.minimum_balance(result_len),
+            result_len as u64,
+            &validation_reader_id(),
+        )],
+        Some(&example.payer.pubkey()),
+    );
+    transaction.sign(&[&example.payer, &result_account], example.recent_blockhash);
+    example
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let validate_instruction = audius::instruction::validate_signature_with_sysvar(
+        &audius::id(),
+        &example.valid_signer,
+        &example.signer_group,
+        &sysvar::instructions::id(),
+        audius::instruction::SignatureData {
+            signature: example.instruction_args.signature,
+            recovery_id: example.instruction_args.recovery_id,
+            message: example.instruction_args.track_data.try_to_vec().unwrap(),
+            secp_instruction_index: 0,
+            preceding_secp_instruction: false,
+            secp_signature_index: 0,
+        },
+    )
+    .unwrap();
+    let mut accounts = vec![
+        AccountMeta::new(result_account.pubkey(), false),
+        AccountMeta::new_readonly(audius::id(), false),
+    ];
+    accounts.extend(validate_instruction.accounts);
+    let reader_instruction = Instruction {
+        program_id: validation_reader_id(),
+        accounts,
+        data: validate_instruction.data,
+    };
+
+    let instructions = [
+        example.secp256_program_instruction.clone(),
+        reader_instruction,
+    ];
+    example.process(&instructions).await.unwrap();
+
+    let result_data = example
+        .banks_client
+        .get_account(result_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    assert_eq!(
+        audius::return_data::ValidationResult::try_from_slice(&result_data).unwrap(),
+        audius::return_data::ValidationResult {
+            eth_address: example.eth_address,
+            signer_group: example.signer_group,
+        }
     );
+}
+
+#[tokio::test]
+async fn test_call_example_instruction_with_tampered_message() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    // signature doesn't match the message anymore, Secp256 program rejects the transaction
+    let mut secp256_program_instruction = example.secp256_program_instruction.clone();
+    let message_start = audius::state::SecpSignatureOffsets::MESSAGE_DATA_OFFSET;
+    // flip the first character of user ID, it goes after 4 bytes of string length
+    secp256_program_instruction.data[message_start + 4] ^= 1;
+
+    let mut instruction_args = example.instruction_args.clone();
+    instruction_args.track_data =
+        state::TrackData::try_from_slice(&secp256_program_instruction.data[message_start..])
+            .unwrap();
+    example.instruction_args = instruction_args;
+
+    let instructions = [secp256_program_instruction, example.example_instruction()];
+    assert!(example.process(&instructions).await.is_err());
+}
 
-    transaction.sign(&[&payer], recent_blockhash);
-    banks_client.process_transaction(transaction).await.unwrap();
+#[tokio::test]
+async fn test_invalid_instruction_data() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    let mut example_instruction = example.example_instruction();
+    example_instruction.data.truncate(10);
+    let instructions = [
+        example.secp256_program_instruction.clone(),
+        example_instruction,
+    ];
+    assert_example_error(
+        example.process(&instructions).await,
+        1,
+        error::CreateAndVerifyError::InvalidInstructionData,
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_timestamp() {
+    let mut example = setup_example(Utc::now().timestamp() - 1000).await;
+
+    let instructions = [
+        example.secp256_program_instruction.clone(),
+        example.example_instruction(),
+    ];
+    assert_example_error(
+        example.process(&instructions).await,
+        1,
+        error::CreateAndVerifyError::InvalidTimestamp,
+    );
+}
+
+#[tokio::test]
+async fn test_missing_secp_instruction() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    let instructions = [example.example_instruction()];
+    assert_example_error(
+        example.process(&instructions).await,
+        0,
+        error::CreateAndVerifyError::MissingSecpInstruction,
+    );
+
+    let instructions = [
+        system_instruction::transfer(&example.payer.pubkey(), &example.valid_signer, 1),
+        example.example_instruction(),
+    ];
+    assert_example_error(
+        example.process(&instructions).await,
+        1,
+        error::CreateAndVerifyError::MissingSecpInstruction,
+    );
+}
+
+#[tokio::test]
+async fn test_signer_mismatch() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    let foreign_group = Keypair::new();
+    create_account(
+        &mut example.banks_client,
+        &example.payer,
+        &example.recent_blockhash,
+        &foreign_group,
+        audius::state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    example.signer_group = foreign_group.pubkey();
+
+    let instructions = [
+        example.secp256_program_instruction.clone(),
+        example.example_instruction(),
+    ];
+    assert_example_error(
+        example.process(&instructions).await,
+        1,
+        error::CreateAndVerifyError::SignerMismatch,
+    );
+}
+
+#[tokio::test]
+async fn test_signature_verification_failed() {
+    let mut example = setup_example(Utc::now().timestamp()).await;
+
+    example.instruction_args.track_data.source = String::from("another/source");
+    let instructions = [
+        example.secp256_program_instruction.clone(),
+        example.example_instruction(),
+    ];
+    assert_example_error(
+        example.process(&instructions).await,
+        1,
+        error::CreateAndVerifyError::SignatureVerificationFailed,
+    );
 }
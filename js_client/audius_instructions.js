@@ -6,8 +6,8 @@ const secp256k1 = require("secp256k1");
 const eth_utils = require("ethereumjs-util");
 const borsh = require("borsh");
 
-let SIGNER_GROUP_SIZE = 33;
-let VALID_SIGNER_SIZE = 53;
+let SIGNER_GROUP_SIZE = 346;
+let VALID_SIGNER_SIZE = 292;
 let AUDIUS_PROGRAM = new solanaWeb3.PublicKey(
   "Eed4UXg3TqTkzbgT47WWMQtbzpxMUeHitt2jfAa6pmep"
 );
@@ -32,10 +32,28 @@ class Assignable {
 class TrackData extends Assignable {}
 class InstructionArgs extends Assignable {}
 class InstructionEnum extends Assignable {}
-class SignatureData extends Assignable {}
-class InitSignerGroup extends Assignable {}
-class InitValidSigner extends Assignable {}
-class ClearValidSigner extends Assignable {}
+
+// SignatureData serialization format and tag of its message field
+const SIGNATURE_DATA_FORMAT_V1 = 1;
+const SIGNATURE_DATA_TAG_MESSAGE = 1;
+
+function u32Bytes(value) {
+  let buffer = Buffer.alloc(4);
+  buffer.writeUInt32LE(value);
+  return Array.from(buffer);
+}
+
+// Serialize SignatureData with the message as its only field, the rest of the fields
+// are omitted and take their default values
+function packSignatureData(signature, recoveryId, message) {
+  return [SIGNATURE_DATA_FORMAT_V1]
+    .concat(Array.from(signature))
+    .concat([recoveryId])
+    .concat(u32Bytes(1))
+    .concat([SIGNATURE_DATA_TAG_MESSAGE])
+    .concat(u32Bytes(message.length))
+    .concat(Array.from(message));
+}
 
 let url = solanaWeb3.clusterApiUrl("devnet", false);
 
@@ -58,54 +76,13 @@ async function validateSignature(validSigner, privateKey, message) {
   const sigObj = secp256k1.ecdsaSign(Uint8Array.from(msg_hash), privKey);
 
   let transaction = new solanaWeb3.Transaction();
-  let instructionSchema = new Map([
-    [
-      InstructionEnum,
-      {
-        kind: "enum",
-        field: "choose",
-        values: [["initSignerGroup", InitSignerGroup], ["initValidSigner", InitValidSigner], ["clearValidSigner", ClearValidSigner], ["instruction", SignatureData]],
-      },
-    ],
-    [
-      SignatureData,
-      {
-        kind: "struct",
-        fields: [
-          ["signature", [64]],
-          ["recovery_id", "u8"],
-          ["message", [msg.length]],
-        ],
-      },
-    ],
-  ]);
-
-  let instructionArgs = new SignatureData({
-    signature: Array.from(sigObj.signature),
-    recovery_id: sigObj.recid,
-    message: msg,
-  });
-
-  let instructionData = new InstructionEnum({
-    instruction: instructionArgs,
-    choose: "instruction",
-  });
 
-  let serializedInstructionArgs = borsh.serialize(
-    instructionSchema,
-    instructionData
+  // ValidateSignature instruction in the legacy encoding, its tag followed by
+  // the signature data in the tagged format
+  let serializedInstructionArray = [3].concat(
+    packSignatureData(sigObj.signature, sigObj.recid, msg)
   );
 
-  var lenBuffer = new ArrayBuffer(4);  // size of len data in Rust 4 bytes
-  if (msg.length < 256) {
-    new DataView(lenBuffer).setInt8(0, msg.length);
-  } else {
-    new DataView(lenBuffer).setInt16(0, msg.length);
-  }
-
-  let serializedInstructionArray = serializedInstructionArgs.toJSON().data;
-  serializedInstructionArray.splice(66, 0, ...new Uint8Array(lenBuffer));
-
   let secpInstruction = solanaWeb3.Secp256k1Program.createInstructionWithPublicKey(
     {
       publicKey: pubKey,
@@ -119,8 +96,8 @@ async function validateSignature(validSigner, privateKey, message) {
 
   transaction.add({
     keys: [
-      { pubkey: validSignerPubK, isSigner: false, isWritable: false },
-      { pubkey: signerGroup, isSigner: false, isWritable: false },
+      { pubkey: validSignerPubK, isSigner: false, isWritable: true },
+      { pubkey: signerGroup, isSigner: false, isWritable: true },
       { pubkey: INSTRUCTIONS_PROGRAM, isSigner: false, isWritable: false },
     ],
     programId: AUDIUS_PROGRAM,
@@ -241,8 +218,8 @@ async function createAndVerifyMessage(
 
   transaction.add({
     keys: [
-      { pubkey: validSignerPubK, isSigner: false, isWritable: false },
-      { pubkey: signerGroup, isSigner: false, isWritable: false },
+      { pubkey: validSignerPubK, isSigner: false, isWritable: true },
+      { pubkey: signerGroup, isSigner: false, isWritable: true },
       { pubkey: AUDIUS_PROGRAM, isSigner: false, isWritable: false },
       { pubkey: INSTRUCTIONS_PROGRAM, isSigner: false, isWritable: false },
     ],
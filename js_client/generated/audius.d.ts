@@ -0,0 +1,1413 @@
+// Generated by tools/gen_ts from types/src/lib.rs, program/src/state.rs and
+// program/src/instruction.rs, run `cargo run` in tools/gen_ts after changing them. Do not edit.
+
+/**
+ * Type of the valid signer key, stored as a plain byte to keep ValidSigner `Pod`
+ */
+export type SignerKeyType = number;
+
+/**
+ * Little-endian u64 stored as plain bytes to keep ValidSigner `Pod`, serialized as u64 by Borsh
+ */
+export type PodU64 = Uint8Array;
+
+/**
+ * Little-endian u32 stored as plain bytes to keep state unaligned, serialized as u32 by Borsh
+ */
+export type PodU32 = Uint8Array;
+
+/**
+ * Signer group data
+ */
+export interface SignerGroup {
+  /**
+   * Groups version
+   */
+  version: number;
+  /**
+   * Pubkey of the account authorized to add/remove valid signers
+   */
+  owner: string;
+  /**
+   * First ValidSigner in the group's signer list, zero if group is empty
+   */
+  first_signer: string;
+  /**
+   * Last ValidSigner in the group's signer list, zero if group is empty
+   */
+  last_signer: string;
+  /**
+   * Owner proposed to take over the group, zero if there is no proposal
+   */
+  pending_owner: string;
+  /**
+   * Require Ethereum key holder's proof of possession to register Secp256k1 valid signer
+   */
+  eth_pop_required: boolean;
+  /**
+   * Lamports charged for each signature validation, zero if validations are free
+   */
+  validation_fee: PodU64;
+  /**
+   * Account validation fees are paid to
+   */
+  treasury: string;
+  /**
+   * Maximum number of signature validations per quota window, zero if unlimited
+   */
+  quota_per_window: PodU32;
+  /**
+   * Slot the current quota window started at
+   */
+  window_start_slot: PodU64;
+  /**
+   * Number of signature validations in the current quota window
+   */
+  used_in_window: PodU32;
+  /**
+   * Id of the next valid signer to join the group, ids start at 1 and are never reused
+   */
+  next_signer_id: PodU32;
+  /**
+   * Number of valid signers in the group
+   */
+  valid_signer_count: PodU32;
+  /**
+   * Maximum number of valid signers in the group, zero if unlimited
+   */
+  max_valid_signers: PodU32;
+  /**
+   * Prefixes of the messages group validates, only first `message_prefix_count` are set
+   */
+  message_prefixes: Uint8Array[];
+  /**
+   * Number of registered message prefixes, zero if any message can be validated
+   */
+  message_prefix_count: number;
+  /**
+   * Require transaction signature of the valid signer's cosigner to validate its signature
+   */
+  require_cosigner: boolean;
+  /**
+   * Number of successful signature validations by all of the group's valid signers,
+   * saturates at `u64::MAX`
+   */
+  total_validations_all: PodU64;
+  /**
+   * Allow the group's valid signers to add and remove signers without the owner
+   */
+  governed: boolean;
+  /**
+   * Number of valid signers required to approve membership change of the governed group
+   */
+  governance_threshold: number;
+  /**
+   * Number of membership changes applied by governance, included in the approval message
+   */
+  governance_nonce: PodU64;
+  /**
+   * Hot key allowed to add valid signers along with the owner, zero if there is none
+   */
+  delegate_operator: string;
+  /**
+   * Merkle root of the members' eth addresses set by the owner, see [merkle](../merkle/index.html),
+   * zero if signatures can't be validated by Merkle proof
+   */
+  members_merkle_root: Uint8Array;
+  /**
+   * SPL Governance realm whose proposals may add and remove valid signers, zero if none
+   */
+  governance_realm: string;
+}
+
+/**
+ * Membership change of the governed signer group
+ */
+export enum GovernanceActionTag {
+  AddSigner = 0,
+  RemoveSigner = 1,
+}
+
+/**
+ * Membership change of the governed signer group
+ */
+export type GovernanceAction =
+  /**
+   * Add valid signer with the eth address
+   */
+  | { kind: "AddSigner"; }
+  /**
+   * Remove valid signer derived from the eth address
+   */
+  | { kind: "RemoveSigner"; };
+
+/**
+ * Valid signer data
+ */
+export interface ValidSigner {
+  /**
+   * Signer version
+   */
+  version: number;
+  /**
+   * SignerGroup this ValidSigner belongs to
+   */
+  signer_group: string;
+  /**
+   * Ethereum addresses of signer, only first `eth_address_count` of them are registered
+   */
+  eth_addresses: Uint8Array[];
+  /**
+   * Number of registered Ethereum addresses
+   */
+  eth_address_count: number;
+  /**
+   * Next ValidSigner in the group's signer list, zero if this is the last one
+   */
+  next_signer: string;
+  /**
+   * Previous ValidSigner in the group's signer list, zero if this is the first one
+   */
+  prev_signer: string;
+  /**
+   * Type of the key signer signs with
+   */
+  key_type: SignerKeyType;
+  /**
+   * Ed25519 public key of signer, used instead of `eth_addresses` for Ed25519 signers
+   */
+  ed25519_pubkey: string;
+  /**
+   * Human readable label of signer, zero padded UTF-8
+   */
+  label: Uint8Array;
+  /**
+   * Slot signer's signatures become valid at, zero if signer is active immediately
+   */
+  active_after_slot: PodU64;
+  /**
+   * Last slot signer's signatures are valid at, zero if signer never expires
+   */
+  expires_at_slot: PodU64;
+  /**
+   * Bump seed of the account address derived from signer group and eth address,
+   * zero for Ed25519 signers
+   */
+  bump_seed: number;
+  /**
+   * Sequential id of signer within its group
+   */
+  signer_id: PodU32;
+  /**
+   * Solana key co-signing validations of signer's signatures, zero if there is none
+   */
+  cosigner: string;
+  /**
+   * Number of successful validations of signer's signatures, saturates at `u32::MAX`
+   */
+  total_validations: PodU32;
+  /**
+   * Slot of signer's last validation
+   */
+  last_validation_slot: PodU64;
+  /**
+   * Number of signer's validations in the current rate limit window
+   */
+  validations_this_window: PodU32;
+}
+
+/**
+ * Valid signer metadata, stored in a separate account derived from valid signer address
+ */
+export interface ValidSignerMetadata {
+  /**
+   * Metadata version
+   */
+  version: number;
+  /**
+   * ValidSigner this metadata belongs to
+   */
+  valid_signer: string;
+  /**
+   * URL of the signer's node endpoint, zero padded
+   */
+  endpoint_url: Uint8Array;
+  /**
+   * Name of the signer's operator, zero padded
+   */
+  operator_name: Uint8Array;
+}
+
+/**
+ * Signer groups owned by the owner, stored in an account derived from owner address.
+ * Groups are registered on creation and on ownership transfer, and leave the registry
+ * when transferred away or closed.
+ */
+export interface OwnerRegistry {
+  /**
+   * Registry version
+   */
+  version: number;
+  /**
+   * Owner of the registered signer groups
+   */
+  owner: string;
+  /**
+   * Registered signer groups in order of registration
+   */
+  groups: string[];
+}
+
+/**
+ * Eth addresses revoked by the signer group's owner, stored in an account derived
+ * from the group address
+ */
+export interface RevocationRegistry {
+  /**
+   * Registry version
+   */
+  version: number;
+  /**
+   * Signer group the addresses are revoked in
+   */
+  signer_group: string;
+  /**
+   * Revoked eth addresses in order of revocation
+   */
+  eth_addresses: Uint8Array[];
+}
+
+/**
+ * Ownership transfer of the signer group
+ */
+export interface OwnershipTransfer {
+  /**
+   * Owner which handed the group over
+   */
+  previous_owner: string;
+  /**
+   * Owner which accepted the group
+   */
+  new_owner: string;
+  /**
+   * Slot the ownership was accepted at
+   */
+  transfer_slot: bigint;
+}
+
+/**
+ * Latest ownership transfers of the signer group, stored in an account derived
+ * from the group address
+ */
+export interface OwnershipHistory {
+  /**
+   * History version
+   */
+  version: number;
+  /**
+   * Signer group the transfers belong to
+   */
+  signer_group: string;
+  /**
+   * Transfers from the most recent one, the oldest are dropped once the history is full
+   */
+  transfers: OwnershipTransfer[];
+}
+
+/**
+ * Accounts allowed to create signer groups, stored in a single account derived
+ * from program address. Anyone may create groups while the whitelist is empty.
+ */
+export interface CreationWhitelist {
+  /**
+   * Whitelist version
+   */
+  version: number;
+  /**
+   * Whitelist flags, see [CreationWhitelist::BYPASS_WHITELIST]
+   */
+  flags: number;
+  /**
+   * Accounts allowed to sign signer group creation
+   */
+  accounts: string[];
+}
+
+/**
+ * Program-wide settings, stored in a single account derived from program address
+ */
+export interface ProgramConfig {
+  /**
+   * Config version
+   */
+  version: number;
+  /**
+   * Account allowed to change the config
+   */
+  authority: string;
+  /**
+   * Non-zero while the program is locked down, see [ProgramConfig::is_locked]
+   */
+  lockdown_mode: number;
+}
+
+/**
+ * Record of a validated signature, stored in an account derived from valid signer address
+ * and message hash so other programs can check what was validated
+ */
+export interface Attestation {
+  /**
+   * Attestation version
+   */
+  version: number;
+  /**
+   * Signer group the valid signer belongs to
+   */
+  signer_group: string;
+  /**
+   * Valid signer which made the signature
+   */
+  valid_signer: string;
+  /**
+   * Eth address recovered from the signature
+   */
+  eth_address: Uint8Array;
+  /**
+   * Keccak256 hash of the signed message
+   */
+  message_hash: Uint8Array;
+  /**
+   * Slot the signature was validated at
+   */
+  slot: bigint;
+  /**
+   * Slot after which the attestation may be closed, 0 if it never expires
+   */
+  expires_at_slot: bigint;
+  /**
+   * Account which paid for the attestation and gets the rent back on close
+   */
+  payer: string;
+}
+
+/**
+ * Program-wide statistics, stored in a single account derived from program address
+ */
+export interface ProgramStats {
+  /**
+   * Stats version
+   */
+  version: number;
+  /**
+   * Number of signer groups created
+   */
+  total_groups: bigint;
+  /**
+   * Number of valid signers created
+   */
+  total_signers: bigint;
+  /**
+   * Number of successful signature validations
+   */
+  total_validations: bigint;
+  /**
+   * Number of valid signers cleared
+   */
+  total_clearances: bigint;
+}
+
+/**
+ * Secp256k1 signature offsets data
+ */
+export interface SecpSignatureOffsets {
+  /**
+   * Offset of 64+1 bytes
+   */
+  signature_offset: number;
+  /**
+   * Index of signature instruction in buffer
+   */
+  signature_instruction_index: number;
+  /**
+   * Offset to eth_address of 20 bytes
+   */
+  eth_address_offset: number;
+  /**
+   * Index of eth address instruction in buffer
+   */
+  eth_address_instruction_index: number;
+  /**
+   * Offset to start of message data
+   */
+  message_data_offset: number;
+  /**
+   * Size of message data
+   */
+  message_data_size: number;
+  /**
+   * Index on message instruction in buffer
+   */
+  message_instruction_index: number;
+}
+
+/**
+ * Ed25519 signature offsets data
+ */
+export interface Ed25519SignatureOffsets {
+  /**
+   * Offset to ed25519 signature of 64 bytes
+   */
+  signature_offset: number;
+  /**
+   * Index of signature instruction in buffer, `u16::MAX` for Ed25519 instruction itself
+   */
+  signature_instruction_index: number;
+  /**
+   * Offset to public key of 32 bytes
+   */
+  public_key_offset: number;
+  /**
+   * Index of public key instruction in buffer, `u16::MAX` for Ed25519 instruction itself
+   */
+  public_key_instruction_index: number;
+  /**
+   * Offset to start of message data
+   */
+  message_data_offset: number;
+  /**
+   * Size of message data
+   */
+  message_data_size: number;
+  /**
+   * Index of message instruction in buffer, `u16::MAX` for Ed25519 instruction itself
+   */
+  message_instruction_index: number;
+}
+
+/**
+ * ValidSigner read by [ValidSigner::from_account_info_any_version]
+ */
+export enum ValidSignerRefTag {
+  Borrowed = 0,
+  Copied = 1,
+}
+
+/**
+ * ValidSigner read by [ValidSigner::from_account_info_any_version]
+ */
+export type ValidSignerRef =
+  /**
+   * Borrowed from account data of the current layout
+   */
+  | { kind: "Borrowed"; fields: [Ref]; }
+  /**
+   * Copied from account data of an older layout
+   */
+  | { kind: "Copied"; fields: [ValidSigner]; };
+
+/**
+ * Signature with message to validate.
+ *
+ * Borsh serialized field by field in the v2 instruction encoding. Legacy instructions
+ * carry it in the tagged format: the format byte, the signature and the recovery ID followed
+ * by the list of optional fields, each one is a tag byte and a Borsh encoded byte vector.
+ * Fields with default values are omitted there, except for the message. Fields with unknown
+ * tags are skipped and unknown formats are rejected.
+ */
+export interface SignatureData {
+  /**
+   * Secp256k1 signature
+   */
+  signature: Uint8Array;
+  /**
+   * Ethereum signature recovery ID
+   */
+  recovery_id: number;
+  /**
+   * Signed message
+   */
+  message: Uint8Array;
+  /**
+   * Index of Secp256 program instruction in the transaction
+   */
+  secp_instruction_index: number;
+  /**
+   * Require Secp256 program instruction to go right before the validation,
+   * `secp_instruction_index` is ignored in this case
+   */
+  preceding_secp_instruction: boolean;
+  /**
+   * Index of the signature among the ones verified by Secp256 program instruction
+   */
+  secp_signature_index: number;
+}
+
+/**
+ * Ed25519 signature with message to validate
+ */
+export interface Ed25519SignatureData {
+  /**
+   * Ed25519 signature
+   */
+  signature: Uint8Array;
+  /**
+   * Signed message
+   */
+  message: Uint8Array;
+  /**
+   * Index of Ed25519 program instruction in the transaction
+   */
+  ed25519_instruction_index: number;
+}
+
+/**
+ * Valid signer metadata fields to set
+ */
+export interface MetadataArgs {
+  /**
+   * URL of the signer's node endpoint, zero padded
+   */
+  endpoint_url: Uint8Array;
+  /**
+   * Name of the signer's operator, zero padded
+   */
+  operator_name: Uint8Array;
+}
+
+/**
+ * Valid signer fields to set on extended initialization
+ */
+export interface InitValidSignerArgs {
+  /**
+   * Ethereum address of signer
+   */
+  eth_address: Uint8Array;
+  /**
+   * Human readable label of signer, zero padded UTF-8
+   */
+  label: Uint8Array;
+  /**
+   * Slot signer's signatures become valid at, zero if signer is active immediately
+   */
+  active_after_slot: bigint;
+}
+
+/**
+ * Compressed Secp256k1 public key, the prefix is kept apart from X coordinate
+ * as Borsh doesn't serialize 33 byte arrays
+ */
+export interface CompressedSecpPubkey {
+  /**
+   * Parity of Y coordinate, 2 if it's even and 3 if it's odd
+   */
+  prefix: number;
+  /**
+   * X coordinate of the public key
+   */
+  x: Uint8Array;
+}
+
+/**
+ * Instruction nested in `ExecuteGovernanceProposal`, serialized as the instruction's
+ * Borsh encoding. Borsh derive can't handle the recursive type itself.
+ */
+export type ProposalInstruction = AudiusInstruction;
+
+/**
+ * Instructions supported by the Audius program
+ */
+export enum AudiusInstructionTag {
+  InitSignerGroup = 0,
+  InitValidSigner = 1,
+  ClearValidSigner = 2,
+  ValidateSignature = 3,
+  QuerySignerGroup = 4,
+  InitValidSignerMetadata = 5,
+  UpdateValidSignerMetadata = 6,
+  InitProgramStats = 7,
+  GetProgramStats = 8,
+  InitValidSignerEd25519 = 9,
+  ValidateEd25519Signature = 10,
+  AddSignerAddress = 11,
+  RemoveSignerAddress = 12,
+  InitValidSignerWithLabel = 13,
+  SetSignerLabel = 14,
+  InitValidSignerExtended = 15,
+  SetActivationSlot = 16,
+  ProposeOwnership = 17,
+  AcceptOwnership = 18,
+  CancelProposedOwnership = 19,
+  SetSignerExpiry = 20,
+  SetEthPoPRequired = 21,
+  SetValidationFee = 22,
+  SetValidationQuota = 23,
+  ValidateSignatures = 24,
+  InitSignerGroupWithSigner = 25,
+  UpdateMaxSigners = 26,
+  AddMessagePrefix = 27,
+  RemoveMessagePrefix = 28,
+  SetCosignerRequired = 29,
+  SetSignerCosigner = 30,
+  SetGovernance = 31,
+  GovernedInitValidSigner = 32,
+  GovernedClearValidSigner = 33,
+  BatchValidateSignatures = 34,
+  RevokeEthAddress = 35,
+  ValidateAndAttest = 36,
+  CloseAttestation = 37,
+  GetOwnershipHistory = 38,
+  InitValidSignerFromPubkey = 39,
+  AddToCreationWhitelist = 40,
+  RemoveFromCreationWhitelist = 41,
+  SetCreationWhitelistBypass = 42,
+  ValidateSignatureDigest = 43,
+  SetDelegateOperator = 44,
+  ValidateSignatureBatch = 45,
+  MigrateValidSigner = 46,
+  ValidateSignatureWithMerkleProof = 47,
+  UpdateMerkleRoot = 48,
+  SetGovernanceRealm = 49,
+  ExecuteGovernanceProposal = 50,
+  CloneSignerGroup = 51,
+  SetLockdownMode = 52,
+  CloseSignerGroup = 53,
+  CloseValidSigner = 54,
+}
+
+/**
+ * Instructions supported by the Audius program
+ */
+export type AudiusInstruction =
+  /**
+   * Create new signer group account and add it to the owner's registry.
+   * The creation is signed by the creation authority, which has to be whitelisted
+   * while the creation whitelist is enforced.
+   *
+   * 0. `[w]` New SignerGroup to create
+   * 1. `[]` SignerGroup's owner
+   * 2. `[]` Creation whitelist, derived from program address
+   * 3. `[s]` Creation authority
+   * 4. `[w]` Owner registry, derived from owner address
+   * 5. `[ws]` Funding account to pay for the registry account
+   * 6. `[]` Rent sysvar
+   * 7. `[]` System program
+   * 8. `[w]` Optional program stats account
+   * 9. `[]` Program config, derived from program address
+   */
+  | { kind: "InitSignerGroup"; }
+  /**
+   * Create new valid signer account. Optional proof of possession is the signature
+   * of valid signer account address by the registered eth key, it's required
+   * by the groups with `eth_pop_required` set
+   *
+   * 0. `[w]` Valid signer account, derived from signer group and eth address
+   * 1. `[w]` Group for Valid Signer to join with
+   * 2. `[s]` SignerGroup's owner or its delegate operator
+   * 3. `[ws]` Funding account to pay for valid signer account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   * 6. `[]` Revocation registry of the group, derived from signer group address
+   * 7. `[]` Sysvar instruction account, only if proof of possession is given
+   * 8. `[w]` Last valid signer of the group, omitted if group is empty
+   * 9. `[w]` Optional program stats account
+   * 10. `[]` Program config, derived from program address
+   */
+  | { kind: "InitValidSigner"; fields: [Uint8Array, SignatureData | null]; }
+  /**
+   * Remove valid signer from the group
+   *
+   * 0. `[w]` Initialized valid signer to remove
+   * 1. `[w]` Signer group to remove from
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[w]` Previous valid signer in the group's list, omitted if there is none
+   * 4. `[w]` Next valid signer in the group's list, omitted if there is none
+   * 5. `[w]` Optional program stats account
+   * 6. `[]` Program config, derived from program address
+   */
+  | { kind: "ClearValidSigner"; }
+  /**
+   * Validate signature issued by valid signer. Signature data carries the raw signed
+   * message, it must match the message of Secp256 instruction which the Secp256k1
+   * program hashes with Keccak256 before verifying the signature.
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[w]` Signer group signer belongs to
+   * 2. `[]` Sysvar instruction account
+   * 3. `[s]` Valid signer's cosigner, only if the group requires one
+   * 4. `[ws]` Validation fee payer, omitted along with 5 and 6 if group charges no fee
+   * 5. `[w]` Group's treasury account
+   * 6. `[]` System program
+   * 7. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateSignature"; fields: [SignatureData]; }
+  /**
+   * Log signer group state as JSON
+   *
+   * 0. `[]` Signer group to query
+   */
+  | { kind: "QuerySignerGroup"; }
+  /**
+   * Create metadata account for the valid signer
+   *
+   * 0. `[w]` Metadata account, derived from valid signer address
+   * 1. `[]` Initialized valid signer
+   * 2. `[]` Signer group valid signer belongs to
+   * 3. `[s]` SignerGroup's owner
+   * 4. `[ws]` Funding account to pay for metadata account
+   * 5. `[]` Rent sysvar
+   * 6. `[]` System program
+   * 7. `[]` Program config, derived from program address
+   */
+  | { kind: "InitValidSignerMetadata"; fields: [MetadataArgs]; }
+  /**
+   * Update metadata of the valid signer
+   *
+   * 0. `[w]` Initialized metadata account
+   * 1. `[]` Initialized valid signer
+   * 2. `[]` Signer group valid signer belongs to
+   * 3. `[s]` SignerGroup's owner
+   * 4. `[]` Program config, derived from program address
+   */
+  | { kind: "UpdateValidSignerMetadata"; fields: [MetadataArgs]; }
+  /**
+   * Create program statistics account
+   *
+   * 0. `[w]` Program stats account, derived from program address
+   * 1. `[ws]` Funding account to pay for stats account
+   * 2. `[]` Rent sysvar
+   * 3. `[]` System program
+   */
+  | { kind: "InitProgramStats"; }
+  /**
+   * Log program statistics
+   *
+   * 0. `[]` Program stats account
+   */
+  | { kind: "GetProgramStats"; }
+  /**
+   * Create new valid signer account signing with Ed25519 key
+   *
+   * 0. `[w]` Uninitialized valid signer account
+   * 1. `[w]` Group for Valid Signer to join with
+   * 2. `[s]` SignerGroup's owner or its delegate operator
+   * 3. `[w]` Last valid signer of the group, omitted if group is empty
+   * 4. `[w]` Optional program stats account
+   * 5. `[]` Program config, derived from program address
+   */
+  | { kind: "InitValidSignerEd25519"; fields: [string]; }
+  /**
+   * Validate Ed25519 signature issued by valid signer,
+   * groups requiring a cosigner are rejected
+   *
+   * 0. `[w]` Initialized Ed25519 valid signer
+   * 1. `[w]` Signer group signer belongs to
+   * 2. `[]` Sysvar instruction account
+   * 3. `[ws]` Validation fee payer, omitted along with 4 and 5 if group charges no fee
+   * 4. `[w]` Group's treasury account
+   * 5. `[]` System program
+   * 6. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateEd25519Signature"; fields: [Ed25519SignatureData]; }
+  /**
+   * Register one more eth address for Secp256k1 valid signer, the address must not be
+   * revoked in the group
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[]` Signer group valid signer belongs to
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[]` Revocation registry of the group, derived from signer group address
+   * 4. `[]` Program config, derived from program address
+   */
+  | { kind: "AddSignerAddress"; fields: [Uint8Array]; }
+  /**
+   * Unregister eth address of Secp256k1 valid signer, the last address can't be removed
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[]` Signer group valid signer belongs to
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[]` Program config, derived from program address
+   */
+  | { kind: "RemoveSignerAddress"; fields: [Uint8Array]; }
+  /**
+   * Create new valid signer account with a human readable label
+   *
+   * 0. `[w]` Valid signer account, derived from signer group and eth address
+   * 1. `[w]` Group for Valid Signer to join with
+   * 2. `[s]` SignerGroup's owner or its delegate operator
+   * 3. `[ws]` Funding account to pay for valid signer account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   * 6. `[]` Revocation registry of the group, derived from signer group address
+   * 7. `[w]` Last valid signer of the group, omitted if group is empty
+   * 8. `[w]` Optional program stats account
+   * 9. `[]` Program config, derived from program address
+   */
+  | { kind: "InitValidSignerWithLabel"; fields: [Uint8Array, Uint8Array]; }
+  /**
+   * Set human readable label of the valid signer
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[]` Signer group valid signer belongs to
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[]` Program config, derived from program address
+   */
+  | { kind: "SetSignerLabel"; fields: [Uint8Array]; }
+  /**
+   * Create new valid signer account with label and activation slot
+   *
+   * 0. `[w]` Valid signer account, derived from signer group and eth address
+   * 1. `[w]` Group for Valid Signer to join with
+   * 2. `[s]` SignerGroup's owner or its delegate operator
+   * 3. `[ws]` Funding account to pay for valid signer account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   * 6. `[]` Revocation registry of the group, derived from signer group address
+   * 7. `[w]` Last valid signer of the group, omitted if group is empty
+   * 8. `[w]` Optional program stats account
+   * 9. `[]` Program config, derived from program address
+   */
+  | { kind: "InitValidSignerExtended"; fields: [InitValidSignerArgs]; }
+  /**
+   * Amend activation slot of the valid signer which is not active yet
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[]` Signer group valid signer belongs to
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[]` Program config, derived from program address
+   */
+  | { kind: "SetActivationSlot"; fields: [bigint]; }
+  /**
+   * Propose new owner of the signer group, the ownership is transferred once
+   * the new owner accepts it
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "ProposeOwnership"; fields: [string]; }
+  /**
+   * Accept proposed ownership of the signer group, the transfer is recorded
+   * in group's ownership history and the group moves to the new owner's registry
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's pending owner
+   * 2. `[w]` Ownership history of the group, derived from signer group address
+   * 3. `[ws]` Funding account to pay for ownership history and registry accounts
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   * 6. `[w]` Owner registry of the previous owner, derived from its address
+   * 7. `[w]` Owner registry of the pending owner, derived from its address
+   * 8. `[]` Program config, derived from program address
+   */
+  | { kind: "AcceptOwnership"; }
+  /**
+   * Withdraw ownership proposal of the signer group
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "CancelProposedOwnership"; }
+  /**
+   * Set the last slot valid signer's signatures are accepted at, zero to never expire.
+   * Expired signer stays in the group until it's cleared
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[]` Signer group valid signer belongs to
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[]` Program config, derived from program address
+   */
+  | { kind: "SetSignerExpiry"; fields: [bigint]; }
+  /**
+   * Require or stop requiring proof of possession to register Secp256k1 valid signers
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetEthPoPRequired"; fields: [boolean]; }
+  /**
+   * Set lamports charged for each signature validation and the treasury they're paid to,
+   * zero fee disables charging
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetValidationFee"; fields: [bigint, string]; }
+  /**
+   * Set maximum number of signature validations per quota window of the group,
+   * zero quota disables the limit
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetValidationQuota"; fields: [number]; }
+  /**
+   * Validate that at least `threshold` valid signers of the group signed the same message.
+   * Each signature is checked against the valid signer account at its position,
+   * signatures which don't verify aren't counted and each signer is counted once.
+   * Groups requiring a cosigner are rejected
+   *
+   * 0..N. `[w]` Initialized valid signers, one per signature
+   * N. `[w]` Signer group signers belong to
+   * N+1. `[]` Sysvar instruction account
+   * N+2. `[ws]` Validation fee payer, omitted along with N+3 and N+4 if group charges no fee
+   * N+3. `[w]` Group's treasury account
+   * N+4. `[]` System program
+   * N+5. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateSignatures";
+      /**
+       * Number of distinct valid signers required to sign the message
+       */
+      threshold: number;
+      /**
+       * Signatures of the message, one per valid signer account
+       */
+      signatures: SignatureData[];
+  }
+  /**
+   * Initialize new signer group along with its first Secp256k1 valid signer,
+   * so the group never exists without signers. The owner is the creation authority
+   * checked against the creation whitelist.
+   *
+   * 0. `[w]` New SignerGroup to initialize
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Creation whitelist, derived from program address
+   * 3. `[w]` Owner registry, derived from owner address
+   * 4. `[w]` Valid signer account, derived from signer group and eth address
+   * 5. `[ws]` Funding account to pay for valid signer and registry accounts
+   * 6. `[]` Rent sysvar
+   * 7. `[]` System program
+   * 8. `[]` Revocation registry of the group, derived from signer group address
+   * 9. `[w]` Optional program stats account
+   * 10. `[]` Program config, derived from program address
+   */
+  | { kind: "InitSignerGroupWithSigner"; fields: [Uint8Array]; }
+  /**
+   * Set maximum number of valid signers in the group, zero removes the limit.
+   * The limit can't be lower than the number of signers already in the group
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "UpdateMaxSigners"; fields: [number]; }
+  /**
+   * Register prefix of the messages signer group validates. Once the group has prefixes,
+   * messages not starting with any of them are rejected
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "AddMessagePrefix"; fields: [Uint8Array]; }
+  /**
+   * Unregister message prefix, group validates any message after the last one is removed
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "RemoveMessagePrefix"; fields: [Uint8Array]; }
+  /**
+   * Require or stop requiring valid signer's cosigner to sign signature validations
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetCosignerRequired"; fields: [boolean]; }
+  /**
+   * Set Solana key co-signing validations of the valid signer, zero key removes it
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[]` Signer group valid signer belongs to
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[]` Program config, derived from program address
+   */
+  | { kind: "SetSignerCosigner"; fields: [string]; }
+  /**
+   * Let the group's valid signers add and remove signers with the given number
+   * of approvals, or stop it. The threshold can't exceed the number of signers in the group
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetGovernance"; fields: [boolean, number]; }
+  /**
+   * Create new Secp256k1 valid signer of the governed group approved by its signers
+   * instead of the owner. Each signature is checked against the valid signer account
+   * at its position and must be made over the group's governance message adding
+   * the eth address. Groups requiring proof of possession are rejected
+   *
+   * 0..N. `[]` Approving valid signers, one per signature
+   * N. `[w]` Valid signer account, derived from signer group and eth address
+   * N+1. `[w]` Governed group for Valid Signer to join with
+   * N+2. `[]` Sysvar instruction account
+   * N+3. `[ws]` Funding account to pay for valid signer account
+   * N+4. `[]` Rent sysvar
+   * N+5. `[]` System program
+   * N+6. `[]` Revocation registry of the group, derived from signer group address
+   * N+7. `[w]` Last valid signer of the group, omitted if group is empty
+   * N+8. `[w]` Optional program stats account
+   * N+9. `[]` Program config, derived from program address
+   */
+  | { kind: "GovernedInitValidSigner";
+      /**
+       * Ethereum address of the new signer
+       */
+      eth_address: Uint8Array;
+      /**
+       * Approvals of the group's signers, one per approving valid signer account
+       */
+      signatures: SignatureData[];
+  }
+  /**
+   * Remove valid signer from the governed group approved by its signers instead
+   * of the owner. Approvals are made over the group's governance message removing
+   * the eth address, the signer is the account derived from it
+   *
+   * 0..N. `[]` Approving valid signers, one per signature
+   * N. `[w]` Valid signer to remove, derived from signer group and eth address
+   * N+1. `[w]` Governed group to remove from
+   * N+2. `[]` Sysvar instruction account
+   * N+3. `[w]` Previous valid signer in the group's list, omitted if there is none
+   * N+4. `[w]` Next valid signer in the group's list, omitted if there is none
+   * N+5. `[w]` Optional program stats account
+   * N+6. `[]` Program config, derived from program address
+   */
+  | { kind: "GovernedClearValidSigner";
+      /**
+       * Ethereum address the removed signer is derived from
+       */
+      eth_address: Uint8Array;
+      /**
+       * Approvals of the group's signers, one per approving valid signer account
+       */
+      signatures: SignatureData[];
+  }
+  /**
+   * Validate that every signature is made by the valid signer at its position, fails
+   * unless all of them verify. Signatures may come from the same or different signers
+   * of one group and are verified by a single Secp256 instruction preceding this one.
+   * Groups requiring a cosigner are rejected
+   *
+   * 0..N. `[w]` Initialized valid signers, one per signature
+   * N. `[w]` Signer group signers belong to
+   * N+1. `[]` Sysvar instruction account
+   * N+2. `[ws]` Validation fee payer, omitted along with N+3 and N+4 if group charges no fee
+   * N+3. `[w]` Group's treasury account
+   * N+4. `[]` System program
+   * N+5. `[w]` Optional program stats account
+   */
+  | { kind: "BatchValidateSignatures";
+      /**
+       * Number of signatures in the batch, up to
+       * [MAX_BATCH_SIGNATURES](../constant.MAX_BATCH_SIGNATURES.html)
+       */
+      count: number;
+      /**
+       * Signatures to validate, one per valid signer account
+       */
+      signatures: SignatureData[];
+  }
+  /**
+   * Revoke eth address in the signer group, no valid signer can be initialized with it
+   * afterwards. Revocation registry is created with the first revoked address paid
+   * by the funding account
+   *
+   * 0. `[]` Signer group to revoke the address in
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[w]` Revocation registry of the group, derived from signer group address
+   * 3. `[ws]` Funding account to pay for revocation registry account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   * 6. `[]` Program config, derived from program address
+   */
+  | { kind: "RevokeEthAddress";
+      /**
+       * Ethereum address to revoke
+       */
+      eth_address: Uint8Array;
+  }
+  /**
+   * Validate signature issued by valid signer and record it in an attestation account
+   * other programs can read. Every message is attested by the signer only once.
+   * Groups requiring a cosigner are rejected
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[w]` Signer group signer belongs to
+   * 2. `[]` Sysvar instruction account
+   * 3. `[w]` Attestation account, derived from valid signer and message hash
+   * 4. `[ws]` Funding account to pay for attestation account
+   * 5. `[]` Rent sysvar
+   * 6. `[]` System program
+   * 7. `[ws]` Validation fee payer, omitted along with 8 and 9 if group charges no fee
+   * 8. `[w]` Group's treasury account
+   * 9. `[]` System program
+   * 10. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateAndAttest";
+      /**
+       * Signature to validate
+       */
+      signature_data: SignatureData;
+      /**
+       * Slot after which the attestation may be closed, 0 if it never expires
+       */
+      expires_at_slot: bigint;
+  }
+  /**
+   * Close expired attestation returning its rent to the account which paid it
+   *
+   * 0. `[w]` Expired attestation account
+   * 1. `[w]` Account which paid for the attestation
+   */
+  | { kind: "CloseAttestation"; }
+  /**
+   * Log latest ownership transfers of the signer group, from the most recent one
+   *
+   * 0. `[]` Signer group
+   * 1. `[]` Ownership history of the group, derived from signer group address
+   */
+  | { kind: "GetOwnershipHistory"; }
+  /**
+   * Create new valid signer account from the compressed Secp256k1 public key,
+   * eth address of the signer is derived from the key
+   *
+   * 0. `[w]` Valid signer account, derived from signer group and eth address
+   * 1. `[w]` Group for Valid Signer to join with
+   * 2. `[s]` SignerGroup's owner or its delegate operator
+   * 3. `[ws]` Funding account to pay for valid signer account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   * 6. `[]` Revocation registry of the group, derived from signer group address
+   * 7. `[w]` Last valid signer of the group, omitted if group is empty
+   * 8. `[w]` Optional program stats account
+   * 9. `[]` Program config, derived from program address
+   */
+  | { kind: "InitValidSignerFromPubkey"; fields: [CompressedSecpPubkey]; }
+  /**
+   * Allow the account to create signer groups, creating the whitelist on first use
+   *
+   * 0. `[w]` Creation whitelist, derived from program address
+   * 1. `[]` Program data account, derived from program address by the upgradeable loader
+   * 2. `[s]` Upgrade authority of the program
+   * 3. `[ws]` Funding account to pay for whitelist account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   */
+  | { kind: "AddToCreationWhitelist"; fields: [string]; }
+  /**
+   * Disallow the account to create signer groups, creating the whitelist on first use
+   *
+   * 0. `[w]` Creation whitelist, derived from program address
+   * 1. `[]` Program data account, derived from program address by the upgradeable loader
+   * 2. `[s]` Upgrade authority of the program
+   * 3. `[ws]` Funding account to pay for whitelist account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   */
+  | { kind: "RemoveFromCreationWhitelist"; fields: [string]; }
+  /**
+   * Set or clear `BYPASS_WHITELIST` flag letting anyone create signer groups,
+   * creating the whitelist on first use
+   *
+   * 0. `[w]` Creation whitelist, derived from program address
+   * 1. `[]` Program data account, derived from program address by the upgradeable loader
+   * 2. `[s]` Upgrade authority of the program
+   * 3. `[ws]` Funding account to pay for whitelist account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   */
+  | { kind: "SetCreationWhitelistBypass"; fields: [boolean]; }
+  /**
+   * Validate signature issued by valid signer given Keccak256 digest of the signed
+   * message in place of the message. The digest must be exactly 32 bytes and match
+   * the hash of Secp256 instruction's message.
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[w]` Signer group signer belongs to
+   * 2. `[]` Sysvar instruction account
+   * 3. `[s]` Valid signer's cosigner, only if the group requires one
+   * 4. `[ws]` Validation fee payer, omitted along with 5 and 6 if group charges no fee
+   * 5. `[w]` Group's treasury account
+   * 6. `[]` System program
+   * 7. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateSignatureDigest"; fields: [SignatureData]; }
+  /**
+   * Set operator key allowed to add valid signers to the group along with the owner,
+   * default pubkey removes the delegate operator
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetDelegateOperator"; fields: [string]; }
+  /**
+   * Validate several messages signed by the same valid signer, fails unless every
+   * signature verifies. Signatures may be verified by one or more Secp256 instructions
+   * of the transaction, up to [MAX_BATCH_SIGNATURES](../constant.MAX_BATCH_SIGNATURES.html)
+   * of them are validated at once
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[w]` Signer group signer belongs to
+   * 2. `[]` Sysvar instruction account
+   * 3. `[s]` Valid signer's cosigner, only if the group requires one
+   * 4. `[ws]` Validation fee payer, omitted along with 5 and 6 if group charges no fee
+   * 5. `[w]` Group's treasury account
+   * 6. `[]` System program
+   * 7. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateSignatureBatch"; fields: [SignatureData[]]; }
+  /**
+   * Move valid signer to another group keeping its keys, label and counters. Signers at
+   * addresses derived from their group and eth address can't migrate, neither can signers
+   * joining a governed group or a group requiring eth proof of possession of Secp256k1 ones
+   *
+   * 0. `[w]` Initialized valid signer
+   * 1. `[w]` Signer group the valid signer leaves
+   * 2. `[s]` Owner of the group the valid signer leaves
+   * 3. `[w]` Signer group the valid signer joins
+   * 4. `[s]` Owner of the group the valid signer joins
+   * 5. `[]` Revocation registry of the joined group, derived from its address
+   * 6. `[w]` Previous valid signer in the left group's list, omitted if there is none
+   * 7. `[w]` Next valid signer in the left group's list, omitted if there is none
+   * 8. `[w]` Last valid signer of the joined group, omitted if the group is empty
+   * 9. `[]` Program config, derived from program address
+   */
+  | { kind: "MigrateValidSigner";
+      /**
+       * Signer group the valid signer joins
+       */
+      new_signer_group: string;
+  }
+  /**
+   * Validate signature of the group member proven by the Merkle proof of its eth address
+   * against the group's members Merkle root instead of its valid signer account. Signer's
+   * activation and expiry slots aren't checked and its validations aren't counted,
+   * groups requiring a cosigner are rejected, revoked eth addresses are rejected
+   *
+   * 0. `[w]` Signer group signer belongs to
+   * 1. `[]` Sysvar instruction account
+   * 2. `[]` Revocation registry of the group, derived from signer group address
+   * 3. `[ws]` Validation fee payer, omitted along with 4 and 5 if group charges no fee
+   * 4. `[w]` Group's treasury account
+   * 5. `[]` System program
+   * 6. `[w]` Optional program stats account
+   */
+  | { kind: "ValidateSignatureWithMerkleProof";
+      /**
+       * Signature with the signed message
+       */
+      signature_data: SignatureData;
+      /**
+       * Siblings of the eth address leaf from the bottom level of the tree up, at most
+       * [MAX_MERKLE_PROOF_LEN](../constant.MAX_MERKLE_PROOF_LEN.html) of them
+       */
+      proof: Uint8Array[];
+  }
+  /**
+   * Set Merkle root of the members' eth addresses, zero disables validation by Merkle proof
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "UpdateMerkleRoot"; fields: [Uint8Array]; }
+  /**
+   * Set SPL Governance realm whose proposals may add and remove valid signers,
+   * zero disables execution of the proposals
+   *
+   * 0. `[w]` Initialized signer group
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[]` Program config, derived from program address
+   */
+  | { kind: "SetGovernanceRealm"; fields: [string]; }
+  /**
+   * Execute the instruction approved by SPL Governance proposal of the group's realm.
+   * Only `InitValidSigner` without proof of possession and `ClearValidSigner` are
+   * executed. Accounts are the ones of the executed instruction, with the realm's
+   * governance account signing in place of the group's owner.
+   */
+  | { kind: "ExecuteGovernanceProposal";
+      /**
+       * Instruction approved by the proposal
+       */
+      instruction: ProposalInstruction;
+  }
+  /**
+   * Create signer group with the same valid signers as the source group, copying their
+   * keys, labels, activation and expiry slots and cosigners. Other settings of the source
+   * group aren't copied. Copies of Ed25519 signers are created by the client beforehand
+   * and assigned to the program, as for `InitValidSignerEd25519`.
+   *
+   * 0. `[w]` New signer group, created beforehand and assigned to the program
+   * 1. `[s]` New signer group's owner
+   * 2. `[]` Creation whitelist, derived from program address
+   * 3. `[w]` Owner registry, derived from owner address
+   * 4. `[]` Source signer group
+   * 5. `[ws]` Funding account to pay for valid signer and registry accounts
+   * 6. `[]` Rent sysvar
+   * 7. `[]` System program
+   * 8. `[]` Revocation registry of the new group, derived from the new group address
+   * 9. ..9+2N Pairs of source valid signer `[]` and its copy `[w]`, in the order of
+   * the source group's signer list. Copies of Secp256k1 signers are derived from
+   * the new group and their first eth address. Program config `[]`, derived from
+   * program address, follows the pairs.
+   */
+  | { kind: "CloneSignerGroup";
+      /**
+       * Signer group the valid signers are copied from
+       */
+      source_group: string;
+      /**
+       * Number of valid signers in the source group
+       */
+      signer_count: number;
+  }
+  /**
+   * Set lockdown mode of the program, non-zero mode stops every change of signer groups
+   * and valid signers while signatures are still validated. The config is created
+   * on first use and its authority is the upgrade authority of the program.
+   *
+   * 0. `[w]` Program config, derived from program address
+   * 1. `[]` Program data account, derived from program address by the upgradeable loader
+   * 2. `[s]` Config authority
+   * 3. `[ws]` Funding account to pay for config account
+   * 4. `[]` Rent sysvar
+   * 5. `[]` System program
+   */
+  | { kind: "SetLockdownMode"; fields: [number]; }
+  /**
+   * Close empty signer group returning its rent to the receiver and remove it from
+   * the owner's registry. The group is left with a tombstone which `InitSignerGroup`
+   * rejects, it stays in place if the group account is refunded within the same
+   * transaction, otherwise the runtime purges the account.
+   *
+   * 0. `[w]` Signer group without valid signers
+   * 1. `[s]` SignerGroup's owner
+   * 2. `[w]` Account receiving the rent of the group
+   * 3. `[w]` Owner registry, derived from owner address
+   * 4. `[]` Program config, derived from program address
+   */
+  | { kind: "CloseSignerGroup"; }
+  /**
+   * Remove valid signer from the group and close its account returning the rent to
+   * the receiver. The account is left with a tombstone like the closed group.
+   *
+   * 0. `[w]` Initialized valid signer to close
+   * 1. `[w]` Signer group to remove from
+   * 2. `[s]` SignerGroup's owner
+   * 3. `[w]` Account receiving the rent of the valid signer
+   * 4. `[w]` Previous valid signer in the group's list, omitted if there is none
+   * 5. `[w]` Next valid signer in the group's list, omitted if there is none
+   * 6. `[w]` Optional program stats account
+   * 7. `[]` Program config, derived from program address
+   */
+  | { kind: "CloseValidSigner"; };
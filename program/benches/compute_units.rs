@@ -0,0 +1,269 @@
+//! Compute units benchmarks of the program instructions.
+//!
+//! Program is loaded from its BPF build, so build it first and run with
+//! `cargo build-bpf && cargo bench --features test-bpf`.
+//! Every transaction is processed with compute units limited by the instruction budget,
+//! exceeding the budget fails the benchmark.
+
+use audius::*;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use rand::{thread_rng, Rng};
+use secp256k1::{PublicKey, SecretKey};
+use sha3::Digest;
+use solana_program::{hash::Hash, instruction::Instruction, pubkey::Pubkey, system_instruction};
+use solana_program_test::{tokio::runtime::Runtime, BanksClient, ProgramTest};
+use solana_sdk::{
+    secp256k1_instruction,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::time::Duration;
+
+/// Compute units budget of `InitSignerGroup`, includes owner registry address derivation,
+/// account creation and base58 encoding of logged addresses
+const INIT_SIGNER_GROUP_BUDGET: u64 = 50_000;
+/// Compute units budget of `InitValidSigner`, includes valid signer address derivation,
+/// account creation and base58 encoding of logged addresses
+const INIT_VALID_SIGNER_BUDGET: u64 = 50_000;
+/// Compute units budget of `ClearValidSigner`, includes base58 encoding of logged addresses
+const CLEAR_VALID_SIGNER_BUDGET: u64 = 40_000;
+/// Compute units budget of `ValidateSignature`, Secp256 program call is not metered
+const VALIDATE_SIGNATURE_BUDGET: u64 = 350_000;
+
+struct Bench {
+    runtime: Runtime,
+    banks_client: BanksClient,
+    payer: Keypair,
+    recent_blockhash: Hash,
+}
+
+impl Bench {
+    fn new(compute_max_units: u64) -> Self {
+        let runtime = Runtime::new().unwrap();
+        let mut program_test = ProgramTest::new("audius", id(), None);
+        program_test.set_bpf_compute_max_units(compute_max_units);
+        let (banks_client, payer, recent_blockhash) = runtime.block_on(program_test.start());
+        Self {
+            runtime,
+            banks_client,
+            payer,
+            recent_blockhash,
+        }
+    }
+
+    fn process(&self, instructions: &[Instruction], signers: &[&Keypair]) {
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&self.payer.pubkey()));
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        transaction.sign(&all_signers, self.recent_blockhash);
+
+        let mut banks_client = self.banks_client.clone();
+        self.runtime
+            .block_on(banks_client.process_transaction(transaction))
+            .unwrap();
+    }
+
+    fn create_account(&self, account: &Keypair, size: usize) -> Instruction {
+        let mut banks_client = self.banks_client.clone();
+        let rent = self.runtime.block_on(banks_client.get_rent()).unwrap();
+        system_instruction::create_account(
+            &self.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(size),
+            size as u64,
+            &id(),
+        )
+    }
+
+    /// Creates signer group and returns it with its owner
+    fn setup_signer_group(&self) -> (Keypair, Keypair) {
+        let signer_group = Keypair::new();
+        let group_owner = Keypair::new();
+        self.process(
+            &[
+                self.create_account(&signer_group, state::SignerGroup::LEN),
+                instruction::init_signer_group(
+                    &id(),
+                    &signer_group.pubkey(),
+                    &group_owner.pubkey(),
+                    &self.payer.pubkey(),
+                )
+                .unwrap(),
+            ],
+            &[&signer_group],
+        );
+        (signer_group, group_owner)
+    }
+
+    /// Creates the only valid signer of a new signer group
+    fn setup_valid_signer(
+        &self,
+        eth_address: [u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> (Pubkey, Keypair, Keypair) {
+        let (signer_group, group_owner) = self.setup_signer_group();
+        self.process(
+            &[instruction::init_valid_signer(
+                &id(),
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                &self.payer.pubkey(),
+                None,
+                eth_address,
+            )
+            .unwrap()],
+            &[&group_owner],
+        );
+        let (valid_signer, _) =
+            find_valid_signer_address(&id(), &signer_group.pubkey(), &eth_address);
+        (valid_signer, signer_group, group_owner)
+    }
+}
+
+fn configure(c: &mut Criterion) -> criterion::BenchmarkGroup<criterion::measurement::WallTime> {
+    let mut group = c.benchmark_group("compute_units");
+    // every iteration processes a transaction, keep the number of samples low
+    group
+        .sample_size(10)
+        .measurement_time(Duration::from_secs(10));
+    group
+}
+
+fn init_signer_group(c: &mut Criterion) {
+    let bench = Bench::new(INIT_SIGNER_GROUP_BUDGET);
+    let mut group = configure(c);
+    group.bench_function("init_signer_group", |b| {
+        b.iter_batched(
+            || {
+                let signer_group = Keypair::new();
+                bench.process(
+                    &[bench.create_account(&signer_group, state::SignerGroup::LEN)],
+                    &[&signer_group],
+                );
+                signer_group
+            },
+            |signer_group| {
+                bench.process(
+                    &[instruction::init_signer_group(
+                        &id(),
+                        &signer_group.pubkey(),
+                        &Keypair::new().pubkey(),
+                        &bench.payer.pubkey(),
+                    )
+                    .unwrap()],
+                    &[],
+                )
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+fn init_valid_signer(c: &mut Criterion) {
+    let bench = Bench::new(INIT_VALID_SIGNER_BUDGET);
+    let mut group = configure(c);
+    group.bench_function("init_valid_signer", |b| {
+        b.iter_batched(
+            || bench.setup_signer_group(),
+            |(signer_group, group_owner)| {
+                bench.process(
+                    &[instruction::init_valid_signer(
+                        &id(),
+                        &signer_group.pubkey(),
+                        &group_owner.pubkey(),
+                        &bench.payer.pubkey(),
+                        None,
+                        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+                    )
+                    .unwrap()],
+                    &[&group_owner],
+                )
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+fn clear_valid_signer(c: &mut Criterion) {
+    let bench = Bench::new(CLEAR_VALID_SIGNER_BUDGET);
+    let mut group = configure(c);
+    group.bench_function("clear_valid_signer", |b| {
+        b.iter_batched(
+            || bench.setup_valid_signer([1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE]),
+            |(valid_signer, signer_group, group_owner)| {
+                bench.process(
+                    &[instruction::clear_valid_signer(
+                        &id(),
+                        &valid_signer,
+                        &signer_group.pubkey(),
+                        &group_owner.pubkey(),
+                        None,
+                        None,
+                    )
+                    .unwrap()],
+                    &[&group_owner],
+                )
+            },
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+fn validate_signature(c: &mut Criterion) {
+    let bench = Bench::new(VALIDATE_SIGNATURE_BUDGET);
+    let mut group = configure(c);
+    group.bench_function("validate_signature", |b| {
+        b.iter_batched(
+            || {
+                let key: [u8; 32] = thread_rng().gen();
+                let priv_key = SecretKey::parse(&key).unwrap();
+                let mut eth_address = [0u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+                eth_address.copy_from_slice(
+                    &sha3::Keccak256::digest(
+                        &PublicKey::from_secret_key(&priv_key).serialize()[1..],
+                    )[12..],
+                );
+                let (valid_signer, signer_group, _) = bench.setup_valid_signer(eth_address);
+
+                // random message makes every transaction unique
+                let message: [u8; 32] = thread_rng().gen();
+                let secp_instruction =
+                    secp256k1_instruction::new_secp256k1_instruction(&priv_key, &message);
+                let sig_start = state::SecpSignatureOffsets::SIGNATURE_OFFSET;
+                let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+                let mut signature = [0u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+                signature.copy_from_slice(&secp_instruction.data[sig_start..sig_end]);
+                let signature_data = instruction::SignatureData::new(
+                    signature,
+                    secp_instruction.data[sig_end],
+                    message.to_vec(),
+                );
+
+                [
+                    secp_instruction,
+                    instruction::validate_signature_raw(
+                        &id(),
+                        &valid_signer,
+                        &signer_group.pubkey(),
+                        signature_data,
+                    )
+                    .unwrap(),
+                ]
+            },
+            |instructions| bench.process(&instructions, &[]),
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    init_signer_group,
+    init_valid_signer,
+    clear_valid_signer,
+    validate_signature
+);
+criterion_main!(benches);
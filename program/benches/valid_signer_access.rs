@@ -0,0 +1,64 @@
+//! Benchmarks of ValidSigner account access, Borsh deserialization copies the data
+//! while bytemuck borrows it in place.
+
+use audius::state::ValidSigner;
+use borsh::{BorshDeserialize, BorshSerialize};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+fn valid_signer_access(c: &mut Criterion) {
+    let valid_signer = ValidSigner {
+        version: 1,
+        signer_group: Pubkey::new_from_array([1; 32]),
+        eth_addresses: [[7; 20], [0; 20], [0; 20]],
+        eth_address_count: 1,
+        ..ValidSigner::default()
+    };
+
+    let key = Pubkey::new_from_array([2; 32]);
+    let owner = Pubkey::new_from_array([3; 32]);
+    let mut lamports = 0;
+    let mut data = valid_signer.try_to_vec().unwrap();
+    let info = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let mut group = c.benchmark_group("valid_signer_access");
+    group.bench_function("borsh", |b| {
+        b.iter(|| {
+            let valid_signer = ValidSigner::try_from_slice(&info.data.borrow()).unwrap();
+            black_box(valid_signer.eth_addresses());
+        })
+    });
+    group.bench_function("bytemuck", |b| {
+        b.iter(|| {
+            let valid_signer = ValidSigner::from_account_info(&info).unwrap();
+            black_box(valid_signer.eth_addresses());
+        })
+    });
+    group.bench_function("borsh_mut", |b| {
+        b.iter(|| {
+            let mut valid_signer = ValidSigner::try_from_slice(&info.data.borrow()).unwrap();
+            valid_signer.version = black_box(1);
+            valid_signer
+                .serialize(&mut *info.data.borrow_mut())
+                .unwrap();
+        })
+    });
+    group.bench_function("bytemuck_mut", |b| {
+        b.iter(|| {
+            ValidSigner::from_account_info_mut(&info).unwrap().version = black_box(1);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, valid_signer_access);
+criterion_main!(benches);
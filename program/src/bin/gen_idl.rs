@@ -0,0 +1,16 @@
+//! Writes the Anchor IDL of the program to `audius.idl.json`, or the path given as the argument
+
+use audius::idl::idl_json;
+use std::{env, fs, path::PathBuf, process::exit};
+
+fn main() {
+    let output = env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("audius.idl.json"));
+    if let Err(err) = fs::write(&output, idl_json()) {
+        eprintln!("error: can't write {}: {}", output.display(), err);
+        exit(1);
+    }
+    println!("Generated {}", output.display());
+}
@@ -0,0 +1,65 @@
+//! Off-chain Secp256k1 helpers for signature diagnostics, too expensive to run on-chain
+
+use crate::{error::AudiusError, state::SecpSignatureOffsets};
+use secp256k1::{recover, Message, PublicKey, RecoveryId, Signature};
+use sha3::{Digest, Keccak256};
+
+/// Ethereum address of the Secp256k1 public key
+pub fn construct_eth_address(pubkey: &PublicKey) -> [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE] {
+    let mut eth_address = [0u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    eth_address.copy_from_slice(&Keccak256::digest(&pubkey.serialize()[1..])[12..]);
+    eth_address
+}
+
+/// Recover Ethereum address of the key which signed the message with the given Keccak256 hash,
+/// the same way Secp256k1 program does
+pub fn recover_eth_address(
+    signature: &[u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+    recovery_id: u8,
+    message_hash: &[u8; 32],
+) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], AudiusError> {
+    let message = Message::parse(message_hash);
+    let signature =
+        Signature::parse_slice(signature).map_err(|_| AudiusError::EthAddressRecoveryFailed)?;
+    let recovery_id =
+        RecoveryId::parse(recovery_id).map_err(|_| AudiusError::EthAddressRecoveryFailed)?;
+    let pubkey = recover(&message, &signature, &recovery_id)
+        .map_err(|_| AudiusError::EthAddressRecoveryFailed)?;
+    Ok(construct_eth_address(&pubkey))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use secp256k1::{sign, SecretKey};
+
+    #[test]
+    fn test_recover_eth_address() {
+        let secret_key = SecretKey::parse(&[7; 32]).unwrap();
+        let eth_address = construct_eth_address(&PublicKey::from_secret_key(&secret_key));
+
+        let mut message_hash = [0u8; 32];
+        message_hash.copy_from_slice(&Keccak256::digest(b"Test message"));
+        let (signature, recovery_id) = sign(&Message::parse(&message_hash), &secret_key);
+        let signature = signature.serialize();
+
+        assert_eq!(
+            recover_eth_address(&signature, recovery_id.serialize(), &message_hash),
+            Ok(eth_address)
+        );
+
+        // the other recovery ID recovers a different key
+        assert_ne!(
+            recover_eth_address(&signature, recovery_id.serialize() ^ 1, &message_hash),
+            Ok(eth_address)
+        );
+        assert_eq!(
+            recover_eth_address(&signature, 4, &message_hash),
+            Err(AudiusError::EthAddressRecoveryFailed)
+        );
+        assert_eq!(
+            recover_eth_address(&[0; 64], recovery_id.serialize(), &message_hash),
+            Err(AudiusError::EthAddressRecoveryFailed)
+        );
+    }
+}
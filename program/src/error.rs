@@ -22,9 +22,9 @@ pub enum AudiusError {
     /// Valid signer isn't initialized
     #[error("Valid signer isn't initialized")]
     ValidSignerNotInitialized,
-    /// Signer doesn't belong to this group
+    /// Signer group passed in doesn't match the one valid signer belongs to
     #[error("Signer doesnt belong to this group")]
-    WrongSignerGroup,
+    SignerGroupMismatch,
     /// Wrong owner
     #[error("Wrong owner")]
     WrongOwner,
@@ -37,6 +37,241 @@ pub enum AudiusError {
     /// Secp256 instruction losing
     #[error("Secp256 instruction losing")]
     Secp256InstructionLosing,
+    /// Secp256 instruction offsets are invalid
+    #[error("Secp256 instruction offsets are invalid")]
+    InvalidSecpOffsets,
+    /// Metadata account address doesn't match valid signer
+    #[error("Metadata account address doesn't match valid signer")]
+    WrongMetadataAccount,
+    /// Valid signer metadata already initialized
+    #[error("Valid signer metadata already initialized")]
+    MetadataAlreadyInitialized,
+    /// Uninitialized valid signer metadata
+    #[error("Uninitialized valid signer metadata")]
+    UninitializedMetadata,
+    /// Message is too large
+    #[error("Message is too large")]
+    MessageTooLarge,
+    /// Signature is malleable, s must be in the lower half of curve order
+    #[error("Signature is malleable, s must be in the lower half of curve order")]
+    MalleableSignature,
+    /// Malformed base58 pubkey
+    #[error("Malformed base58 pubkey")]
+    MalformedPubkey,
+    /// Stats account address doesn't match program stats address
+    #[error("Stats account address doesn't match program stats address")]
+    WrongStatsAccount,
+    /// Program stats already initialized
+    #[error("Program stats already initialized")]
+    StatsAlreadyInitialized,
+    /// Uninitialized program stats
+    #[error("Uninitialized program stats")]
+    UninitializedStats,
+    /// Signer list neighbour account mismatch
+    #[error("Signer list neighbour account mismatch")]
+    WrongSignerListAccount,
+    /// Valid signer key type doesn't match validated signature
+    #[error("Wrong valid signer key type")]
+    WrongSignerKeyType,
+    /// Ed25519 instruction missing
+    #[error("Ed25519 instruction missing")]
+    Ed25519InstructionMissing,
+    /// Eth address is already registered for valid signer
+    #[error("Eth address is already registered for valid signer")]
+    EthAddressAlreadyRegistered,
+    /// Eth address is not registered for valid signer
+    #[error("Eth address is not registered for valid signer")]
+    EthAddressNotRegistered,
+    /// Valid signer can't hold more eth addresses
+    #[error("Valid signer eth address limit reached")]
+    EthAddressLimitReached,
+    /// Last eth address of valid signer can't be removed
+    #[error("Last eth address of valid signer can't be removed")]
+    LastEthAddress,
+    /// Signer label doesn't fit into the label field
+    #[error("Signer label is too long")]
+    SignerLabelTooLong,
+    /// Signer label is not a zero padded UTF-8 string
+    #[error("Invalid signer label")]
+    InvalidSignerLabel,
+    /// Valid signer's activation slot hasn't come yet
+    #[error("Signer is not yet active")]
+    SignerNotYetActive,
+    /// Valid signer's activation slot has already passed
+    #[error("Signer is already active")]
+    SignerAlreadyActive,
+    /// Signer group has no pending owner to accept or cancel
+    #[error("Ownership is not proposed")]
+    OwnershipNotProposed,
+    /// Valid signer's expiry slot has passed
+    #[error("Signer is expired")]
+    SignerExpired,
+    /// Instruction account flags don't match the instruction's account layout
+    #[error("Invalid account meta")]
+    InvalidAccountMeta,
+    /// Signer group requires Ethereum key holder's consent to register valid signer
+    #[error("Proof of possession is missing")]
+    ProofOfPossessionMissing,
+    /// Proof of possession isn't signed over valid signer account address
+    #[error("Invalid proof of possession")]
+    InvalidProofOfPossession,
+    /// Signer group charges validation fee but fee payer isn't given
+    #[error("Validation fee payer is missing")]
+    ValidationFeePayerMissing,
+    /// Treasury account doesn't match the one set for the group
+    #[error("Wrong treasury account")]
+    WrongTreasury,
+    /// Valid signer account isn't derived from signer group and eth address
+    #[error("Wrong valid signer account address")]
+    WrongValidSignerAddress,
+    /// Signer group has run out of validations in the current quota window
+    #[error("Validation quota exceeded")]
+    QuotaExceeded,
+    /// Fewer valid signers than the threshold signed the message
+    #[error("Signature threshold not reached")]
+    ThresholdNotReached,
+    /// Owner registry account isn't derived from the owner address
+    #[error("Wrong owner registry account address")]
+    WrongOwnerRegistryAddress,
+    /// Owner registry has no room for another signer group, one of the owner's groups
+    /// has to be closed or transferred first
+    #[error("Owner registry is full, close or transfer one of the owner's signer groups")]
+    OwnerRegistryFull,
+    /// Signer group has assigned all the signer ids
+    #[error("Signer ids are exhausted")]
+    SignerIdOverflow,
+    /// Signer group reached its maximum number of valid signers
+    #[error("Signer group reached its valid signer limit")]
+    MaxSignersReached,
+    /// Message prefix is already added to the signer group
+    #[error("Message prefix is already added")]
+    MessagePrefixAlreadyAdded,
+    /// Signer group message prefix limit reached
+    #[error("Message prefix limit reached")]
+    MessagePrefixLimitReached,
+    /// Message prefix is not added to the signer group
+    #[error("Message prefix is not added")]
+    MessagePrefixNotFound,
+    /// Message doesn't start with any of the signer group's prefixes
+    #[error("Message prefix is not allowed by the signer group")]
+    MessagePrefixNotAllowed,
+    /// Instruction is given more accounts than it takes
+    #[error("Too many accounts")]
+    TooManyAccounts,
+    /// Signer group requires valid signer's cosigner to sign the transaction
+    #[error("Cosigner signature missing")]
+    MissingCosigner,
+    /// Zero key or the signer group itself can't own the group
+    #[error("Invalid owner")]
+    InvalidOwner,
+    /// Signature, recovery ID or message hash is malformed
+    #[error("Eth address recovery failed")]
+    EthAddressRecoveryFailed,
+    /// Membership change approved by the signers of the group which isn't governed
+    #[error("Signer group governance is disabled")]
+    GovernanceDisabled,
+    /// Governance threshold is zero or more than the group's signers can reach
+    #[error("Invalid governance threshold")]
+    InvalidGovernanceThreshold,
+    /// Approval is signed over a message of another change or an outdated nonce
+    #[error("Wrong governance approval message")]
+    WrongGovernanceMessage,
+    /// Valid signer reached the limit of validations in the current window
+    #[error("Rate limit exceeded")]
+    RateLimitExceeded,
+    /// Account is stored at an older layout too short for the current one
+    #[error("Account must be migrated to the current layout")]
+    AccountMigrationRequired,
+    /// Batch signatures aren't all verified by one Secp256 instruction preceding the batch
+    #[error("Batch signatures must share one preceding Secp256 instruction")]
+    BatchSecpInstructionMismatch,
+    /// Counter would overflow
+    #[error("Arithmetic overflow")]
+    Overflow,
+    /// Account data doesn't start with the discriminator of the expected account
+    #[error("Account discriminator mismatch")]
+    InvalidAccountDiscriminator,
+    /// Eth address is revoked by the signer group's owner
+    #[error("Eth address is revoked")]
+    EthAddressRevoked,
+    /// Revocation registry account isn't derived from the signer group address
+    #[error("Wrong revocation registry account address")]
+    WrongRevocationRegistryAddress,
+    /// Revocation registry has no room for another eth address
+    #[error("Revocation registry is full")]
+    RevocationRegistryFull,
+    /// Attestation account isn't derived from valid signer address and message hash
+    #[error("Wrong attestation account address")]
+    WrongAttestationAddress,
+    /// Signature of the message is already attested by the valid signer
+    #[error("Attestation already exists")]
+    AttestationAlreadyExists,
+    /// Attestation account is not initialized
+    #[error("Attestation is not initialized")]
+    UninitializedAttestation,
+    /// Attestation never expires or its expiry slot hasn't passed yet
+    #[error("Attestation is not expired")]
+    AttestationNotExpired,
+    /// Rent of the closed account is returned to another account than the one which paid it
+    #[error("Wrong rent receiver")]
+    WrongRentReceiver,
+    /// Ownership history account isn't derived from the signer group address
+    #[error("Wrong ownership history account address")]
+    WrongOwnershipHistoryAddress,
+    /// Secp256k1 public key isn't a valid compressed curve point
+    #[error("Malformed Secp256k1 public key")]
+    MalformedSecpPubkey,
+    /// Creation whitelist account isn't derived from the program address
+    #[error("Wrong creation whitelist account address")]
+    WrongCreationWhitelistAddress,
+    /// Signer isn't allowed to perform the operation
+    #[error("Unauthorized operation")]
+    UnauthorizedOperation,
+    /// Creation whitelist has no room for another account
+    #[error("Creation whitelist is full")]
+    CreationWhitelistFull,
+    /// Prehashed message isn't a 32 byte Keccak256 digest
+    #[error("Message digest must be 32 bytes")]
+    InvalidDigestLength,
+    /// Signer group account to initialize isn't assigned to the program
+    #[error("Signer group account must be created with the program as its owner")]
+    SignerGroupNotProgramOwned,
+    /// Signer group has no members Merkle root to validate signatures against
+    #[error("Members Merkle root is not set")]
+    MerkleRootNotSet,
+    /// Merkle proof doesn't lead from the signer's eth address to the group's members root
+    #[error("Invalid Merkle proof of membership")]
+    InvalidMerkleProof,
+    /// Signer group has no governance realm whose proposals it executes
+    #[error("Governance realm is not set")]
+    GovernanceRealmNotSet,
+    /// Governance account signing the proposal doesn't belong to the group's realm
+    #[error("Wrong governance realm")]
+    WrongGovernanceRealm,
+    /// Governance proposals may only add and remove valid signers
+    #[error("Instruction can't be executed by governance proposal")]
+    UnsupportedProposalInstruction,
+    /// Program config account isn't the one derived from program address
+    #[error("Wrong program config account address")]
+    WrongProgramConfigAddress,
+    /// Program is locked down, only signature validation is allowed
+    #[error("Program is locked down")]
+    ProgramLocked,
+    /// Zero Ethereum address can't be registered for signer
+    #[error("Invalid Ethereum address")]
+    InvalidEthAddress,
+    /// Valid signer account address is derived from the group it was created in
+    #[error("Valid signer with derived address can't migrate")]
+    DerivedSignerMigration,
+    /// Governed group's signers are added with approvals of its signers only
+    #[error("Signer group is governed")]
+    SignerGroupGoverned,
+    /// Closed account keeps its tombstone and can't be initialized again
+    #[error("Account is closed")]
+    AccountTombstoned,
+    /// Signer group is closed once all of its valid signers are closed or cleared
+    #[error("Signer group still has valid signers")]
+    SignerGroupNotEmpty,
 }
 impl From<AudiusError> for ProgramError {
     fn from(e: AudiusError) -> Self {
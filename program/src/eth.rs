@@ -0,0 +1,69 @@
+//! Ethereum address derivation from Secp256k1 public keys
+
+use crate::{error::AudiusError, state::SecpSignatureOffsets};
+use secp256k1::PublicKey;
+use solana_program::{keccak, program_error::ProgramError};
+
+/// Size of the compressed Secp256k1 public key
+pub const COMPRESSED_PUBKEY_SIZE: usize = 33;
+
+/// Ethereum address of the compressed Secp256k1 public key: last 20 bytes
+/// of Keccak256 hash of the uncompressed key without its prefix byte
+pub fn eth_address_from_pubkey(
+    pubkey: &[u8],
+) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], ProgramError> {
+    if pubkey.len() != COMPRESSED_PUBKEY_SIZE {
+        return Err(AudiusError::MalformedSecpPubkey.into());
+    }
+    let mut compressed = [0u8; COMPRESSED_PUBKEY_SIZE];
+    compressed.copy_from_slice(pubkey);
+    let pubkey =
+        PublicKey::parse_compressed(&compressed).map_err(|_| AudiusError::MalformedSecpPubkey)?;
+
+    let mut eth_address = [0u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    eth_address.copy_from_slice(&keccak::hash(&pubkey.serialize()[1..]).0[12..]);
+    Ok(eth_address)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_eth_address_from_pubkey() {
+        // public key and address of the secret key 1
+        let mut pubkey = [0u8; COMPRESSED_PUBKEY_SIZE];
+        pubkey.copy_from_slice(&from_hex(
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        ));
+        let eth_address = from_hex("7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+        assert_eq!(
+            eth_address_from_pubkey(&pubkey).unwrap()[..],
+            eth_address[..]
+        );
+
+        // the other point with the same x coordinate has a different address
+        let mut odd_pubkey = pubkey;
+        odd_pubkey[0] = 3;
+        assert_ne!(
+            eth_address_from_pubkey(&odd_pubkey).unwrap()[..],
+            eth_address[..]
+        );
+
+        let mut uncompressed_prefix = pubkey;
+        uncompressed_prefix[0] = 4;
+        for malformed in [&uncompressed_prefix[..], &pubkey[1..], &[0; 65][..]].iter() {
+            assert_eq!(
+                eth_address_from_pubkey(malformed),
+                Err(AudiusError::MalformedSecpPubkey.into())
+            );
+        }
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,894 @@
+//! Anchor compatible IDL of the program, lets Anchor tooling generate clients.
+//! The program isn't an Anchor program, so instructions are identified by
+//! the v2 encoding byte and the one byte Borsh enum tag given in `instructionDiscriminator`.
+
+use crate::{
+    error::AudiusError,
+    instruction::AudiusInstruction,
+    state::{
+        Ed25519SignatureOffsets, SecpSignatureOffsets, SignerGroup, ValidSigner,
+        ValidSignerMetadata,
+    },
+};
+use num_traits::FromPrimitive;
+use serde_json::{json, Value};
+
+/// Anchor IDL spec the document follows
+pub const IDL_SPEC: &str = "0.28.0";
+
+/// Account of instruction as described by Anchor IDL
+fn account(name: &str, is_mut: bool, is_signer: bool) -> Value {
+    json!({ "name": name, "isMut": is_mut, "isSigner": is_signer })
+}
+
+/// Account which may be omitted from instruction
+fn optional_account(name: &str, is_mut: bool, is_signer: bool) -> Value {
+    json!({ "name": name, "isMut": is_mut, "isSigner": is_signer, "isOptional": true })
+}
+
+fn instruction(name: &str, accounts: Vec<Value>, args: Vec<Value>) -> Value {
+    json!({ "name": name, "accounts": accounts, "args": args })
+}
+
+fn with_docs(mut instruction: Value, docs: &[&str]) -> Value {
+    instruction["docs"] = json!(docs);
+    instruction
+}
+
+fn arg(name: &str, arg_type: Value) -> Value {
+    json!({ "name": name, "type": arg_type })
+}
+
+fn byte_array(len: usize) -> Value {
+    json!({ "array": ["u8", len] })
+}
+
+fn defined(name: &str) -> Value {
+    json!({ "defined": name })
+}
+
+/// Accounts shared by the instructions updating signer group
+fn signer_group_update_accounts(authority: &str) -> Vec<Value> {
+    vec![
+        account("signerGroup", true, false),
+        account(authority, false, true),
+    ]
+}
+
+/// Accounts shared by the instructions updating creation whitelist
+fn creation_whitelist_update_accounts() -> Vec<Value> {
+    vec![
+        account("creationWhitelist", true, false),
+        account("programData", false, false),
+        account("upgradeAuthority", false, true),
+        account("payer", true, true),
+        account("rent", false, false),
+        account("systemProgram", false, false),
+    ]
+}
+
+/// Accounts shared by the instructions updating valid signer
+fn valid_signer_update_accounts() -> Vec<Value> {
+    vec![
+        account("validSigner", true, false),
+        account("signerGroup", false, false),
+        account("groupOwner", false, true),
+    ]
+}
+
+/// Accounts shared by the instructions creating Secp256k1 valid signer
+fn init_valid_signer_accounts(with_pop: bool) -> Vec<Value> {
+    let mut accounts = vec![
+        account("validSigner", true, false),
+        account("signerGroup", true, false),
+        account("groupOwner", false, true),
+        account("programConfig", false, false),
+        account("payer", true, true),
+        account("rent", false, false),
+        account("systemProgram", false, false),
+        account("revocationRegistry", false, false),
+    ];
+    if with_pop {
+        accounts.push(optional_account("instructions", false, false));
+    }
+    accounts.push(optional_account("lastSigner", true, false));
+    accounts.push(optional_account("programStats", true, false));
+    accounts
+}
+
+/// Accounts shared by the signature validation instructions
+fn validation_accounts(valid_signer: bool) -> Vec<Value> {
+    let mut accounts = vec![];
+    if valid_signer {
+        accounts.push(account("validSigner", true, false));
+    }
+    accounts.extend(vec![
+        account("signerGroup", true, false),
+        account("instructions", false, false),
+        optional_account("feePayer", true, true),
+        optional_account("treasury", true, false),
+        optional_account("systemProgram", false, false),
+        optional_account("programStats", true, false),
+    ]);
+    accounts
+}
+
+/// Instructions in the order of `AudiusInstruction` variants
+fn instructions() -> Vec<Value> {
+    let eth_address = byte_array(SecpSignatureOffsets::ETH_ADDRESS_SIZE);
+    let label = byte_array(ValidSigner::LABEL_SIZE);
+    let metadata_accounts = |init: bool| {
+        let mut accounts = vec![
+            account("metadata", true, false),
+            account("validSigner", false, false),
+            account("signerGroup", false, false),
+            account("groupOwner", false, true),
+        ];
+        if init {
+            accounts.extend(vec![
+                account("payer", true, true),
+                account("rent", false, false),
+                account("systemProgram", false, false),
+            ]);
+        }
+        accounts
+    };
+    let mut validate_signature_accounts = validation_accounts(true);
+    // cosigner goes right after the sysvar instruction account
+    validate_signature_accounts.insert(3, optional_account("cosigner", false, true));
+    let mut validate_and_attest_accounts = validation_accounts(true);
+    // attestation accounts go right after the sysvar instruction account
+    validate_and_attest_accounts.splice(
+        3..3,
+        vec![
+            account("attestation", true, false),
+            account("payer", true, true),
+            account("rent", false, false),
+            account("systemProgram", false, false),
+        ],
+    );
+
+    vec![
+        instruction(
+            "initSignerGroup",
+            vec![
+                account("signerGroup", true, false),
+                account("owner", false, false),
+                account("creationWhitelist", false, false),
+                account("creationAuthority", false, true),
+                account("ownerRegistry", true, false),
+                account("payer", true, true),
+                account("rent", false, false),
+                account("systemProgram", false, false),
+                account("programConfig", false, false),
+                optional_account("programStats", true, false),
+            ],
+            vec![],
+        ),
+        instruction(
+            "initValidSigner",
+            init_valid_signer_accounts(true),
+            vec![
+                arg("ethAddress", eth_address.clone()),
+                arg(
+                    "ethPopSignature",
+                    json!({ "option": defined("SignatureData") }),
+                ),
+            ],
+        ),
+        instruction(
+            "clearValidSigner",
+            vec![
+                account("validSigner", true, false),
+                account("signerGroup", true, false),
+                account("groupOwner", false, true),
+                account("programConfig", false, false),
+                optional_account("prevSigner", true, false),
+                optional_account("nextSigner", true, false),
+                optional_account("programStats", true, false),
+            ],
+            vec![],
+        ),
+        instruction(
+            "validateSignature",
+            validate_signature_accounts.clone(),
+            vec![arg("signatureData", defined("SignatureData"))],
+        ),
+        instruction(
+            "querySignerGroup",
+            vec![account("signerGroup", false, false)],
+            vec![],
+        ),
+        instruction(
+            "initValidSignerMetadata",
+            metadata_accounts(true),
+            vec![arg("args", defined("MetadataArgs"))],
+        ),
+        instruction(
+            "updateValidSignerMetadata",
+            metadata_accounts(false),
+            vec![arg("args", defined("MetadataArgs"))],
+        ),
+        instruction(
+            "initProgramStats",
+            vec![
+                account("programStats", true, false),
+                account("payer", true, true),
+                account("rent", false, false),
+                account("systemProgram", false, false),
+            ],
+            vec![],
+        ),
+        instruction(
+            "getProgramStats",
+            vec![account("programStats", false, false)],
+            vec![],
+        ),
+        instruction(
+            "initValidSignerEd25519",
+            vec![
+                account("validSigner", true, false),
+                account("signerGroup", true, false),
+                account("groupOwner", false, true),
+                account("programConfig", false, false),
+                optional_account("lastSigner", true, false),
+                optional_account("programStats", true, false),
+            ],
+            vec![arg("ed25519Pubkey", json!("publicKey"))],
+        ),
+        instruction(
+            "validateEd25519Signature",
+            validation_accounts(true),
+            vec![arg("signatureData", defined("Ed25519SignatureData"))],
+        ),
+        instruction(
+            "addSignerAddress",
+            valid_signer_update_accounts(),
+            vec![arg("ethAddress", eth_address.clone())],
+        ),
+        instruction(
+            "removeSignerAddress",
+            valid_signer_update_accounts(),
+            vec![arg("ethAddress", eth_address.clone())],
+        ),
+        instruction(
+            "initValidSignerWithLabel",
+            init_valid_signer_accounts(false),
+            vec![
+                arg("ethAddress", eth_address.clone()),
+                arg("label", label.clone()),
+            ],
+        ),
+        instruction(
+            "setSignerLabel",
+            valid_signer_update_accounts(),
+            vec![arg("label", label)],
+        ),
+        instruction(
+            "initValidSignerExtended",
+            init_valid_signer_accounts(false),
+            vec![arg("args", defined("InitValidSignerArgs"))],
+        ),
+        instruction(
+            "setActivationSlot",
+            valid_signer_update_accounts(),
+            vec![arg("activeAfterSlot", json!("u64"))],
+        ),
+        instruction(
+            "proposeOwnership",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("newOwner", json!("publicKey"))],
+        ),
+        instruction(
+            "acceptOwnership",
+            vec![
+                account("signerGroup", true, false),
+                account("pendingOwner", false, true),
+                account("ownershipHistory", true, false),
+                account("payer", true, true),
+                account("rent", false, false),
+                account("systemProgram", false, false),
+                account("ownerRegistry", true, false),
+                account("pendingOwnerRegistry", true, false),
+                account("programConfig", false, false),
+            ],
+            vec![],
+        ),
+        instruction(
+            "cancelProposedOwnership",
+            signer_group_update_accounts("groupOwner"),
+            vec![],
+        ),
+        instruction(
+            "setSignerExpiry",
+            valid_signer_update_accounts(),
+            vec![arg("expiresAtSlot", json!("u64"))],
+        ),
+        instruction(
+            "setEthPopRequired",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("ethPopRequired", json!("bool"))],
+        ),
+        instruction(
+            "setValidationFee",
+            signer_group_update_accounts("groupOwner"),
+            vec![
+                arg("validationFee", json!("u64")),
+                arg("treasury", json!("publicKey")),
+            ],
+        ),
+        instruction(
+            "setValidationQuota",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("quotaPerWindow", json!("u32"))],
+        ),
+        with_docs(
+            instruction(
+                "validateSignatures",
+                validation_accounts(false),
+                vec![
+                    arg("threshold", json!("u8")),
+                    arg("signatures", json!({ "vec": defined("SignatureData") })),
+                ],
+            ),
+            &["Writable valid signer accounts, one per signature, go before the listed accounts"],
+        ),
+        instruction(
+            "initSignerGroupWithSigner",
+            vec![
+                account("signerGroup", true, false),
+                account("groupOwner", false, true),
+                account("creationWhitelist", false, false),
+                account("ownerRegistry", true, false),
+                account("validSigner", true, false),
+                account("payer", true, true),
+                account("rent", false, false),
+                account("systemProgram", false, false),
+                account("revocationRegistry", false, false),
+                account("programConfig", false, false),
+                optional_account("programStats", true, false),
+            ],
+            vec![arg(
+                "ethAddress",
+                byte_array(SecpSignatureOffsets::ETH_ADDRESS_SIZE),
+            )],
+        ),
+        instruction(
+            "updateMaxSigners",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("maxValidSigners", json!("u32"))],
+        ),
+        instruction(
+            "addMessagePrefix",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("prefix", byte_array(SignerGroup::MESSAGE_PREFIX_SIZE))],
+        ),
+        instruction(
+            "removeMessagePrefix",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("prefix", byte_array(SignerGroup::MESSAGE_PREFIX_SIZE))],
+        ),
+        instruction(
+            "setCosignerRequired",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("requireCosigner", json!("bool"))],
+        ),
+        instruction(
+            "setSignerCosigner",
+            valid_signer_update_accounts(),
+            vec![arg("cosigner", json!("publicKey"))],
+        ),
+        instruction(
+            "setGovernance",
+            signer_group_update_accounts("groupOwner"),
+            vec![
+                arg("governed", json!("bool")),
+                arg("threshold", json!("u8")),
+            ],
+        ),
+        with_docs(
+            instruction(
+                "governedInitValidSigner",
+                vec![
+                    account("validSigner", true, false),
+                    account("signerGroup", true, false),
+                    account("instructions", false, false),
+                    account("programConfig", false, false),
+                    account("payer", true, true),
+                    account("rent", false, false),
+                    account("systemProgram", false, false),
+                    account("revocationRegistry", false, false),
+                    optional_account("lastSigner", true, false),
+                    optional_account("programStats", true, false),
+                ],
+                vec![
+                    arg("ethAddress", eth_address.clone()),
+                    arg("signatures", json!({ "vec": defined("SignatureData") })),
+                ],
+            ),
+            &["Approving valid signer accounts, one per signature, go before the listed accounts"],
+        ),
+        with_docs(
+            instruction(
+                "governedClearValidSigner",
+                vec![
+                    account("validSigner", true, false),
+                    account("signerGroup", true, false),
+                    account("instructions", false, false),
+                    account("programConfig", false, false),
+                    optional_account("prevSigner", true, false),
+                    optional_account("nextSigner", true, false),
+                    optional_account("programStats", true, false),
+                ],
+                vec![
+                    arg("ethAddress", eth_address.clone()),
+                    arg("signatures", json!({ "vec": defined("SignatureData") })),
+                ],
+            ),
+            &["Approving valid signer accounts, one per signature, go before the listed accounts"],
+        ),
+        with_docs(
+            instruction(
+                "batchValidateSignatures",
+                validation_accounts(false),
+                vec![
+                    arg("count", json!("u8")),
+                    arg("signatures", json!({ "vec": defined("SignatureData") })),
+                ],
+            ),
+            &["Writable valid signer accounts, one per signature, go before the listed accounts"],
+        ),
+        instruction(
+            "revokeEthAddress",
+            vec![
+                account("signerGroup", false, false),
+                account("groupOwner", false, true),
+                account("revocationRegistry", true, false),
+                account("payer", true, true),
+                account("rent", false, false),
+                account("systemProgram", false, false),
+            ],
+            vec![arg("ethAddress", eth_address)],
+        ),
+        instruction(
+            "validateAndAttest",
+            validate_and_attest_accounts,
+            vec![
+                arg("signatureData", defined("SignatureData")),
+                arg("expiresAtSlot", json!("u64")),
+            ],
+        ),
+        instruction(
+            "closeAttestation",
+            vec![
+                account("attestation", true, false),
+                account("payer", true, false),
+            ],
+            vec![],
+        ),
+        instruction(
+            "getOwnershipHistory",
+            vec![
+                account("signerGroup", false, false),
+                account("ownershipHistory", false, false),
+            ],
+            vec![],
+        ),
+        instruction(
+            "initValidSignerFromPubkey",
+            init_valid_signer_accounts(false),
+            vec![arg("pubkey", defined("CompressedSecpPubkey"))],
+        ),
+        instruction(
+            "addToCreationWhitelist",
+            creation_whitelist_update_accounts(),
+            vec![arg("account", json!("publicKey"))],
+        ),
+        instruction(
+            "removeFromCreationWhitelist",
+            creation_whitelist_update_accounts(),
+            vec![arg("account", json!("publicKey"))],
+        ),
+        instruction(
+            "setCreationWhitelistBypass",
+            creation_whitelist_update_accounts(),
+            vec![arg("bypass", json!("bool"))],
+        ),
+        with_docs(
+            instruction(
+                "validateSignatureDigest",
+                validate_signature_accounts.clone(),
+                vec![arg("signatureData", defined("SignatureData"))],
+            ),
+            &["Message field of the signature data is Keccak256 digest of the signed message"],
+        ),
+        instruction(
+            "setDelegateOperator",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("delegateOperator", json!("publicKey"))],
+        ),
+        instruction(
+            "validateSignatureBatch",
+            validate_signature_accounts,
+            vec![arg(
+                "signatures",
+                json!({ "vec": defined("SignatureData") }),
+            )],
+        ),
+        instruction(
+            "migrateValidSigner",
+            vec![
+                account("validSigner", true, false),
+                account("signerGroup", true, false),
+                account("groupOwner", false, true),
+                account("newSignerGroup", true, false),
+                account("newGroupOwner", false, true),
+                account("newRevocationRegistry", false, false),
+                optional_account("prevSigner", true, false),
+                optional_account("nextSigner", true, false),
+                optional_account("newLastSigner", true, false),
+            ],
+            vec![arg("newSignerGroup", json!("publicKey"))],
+        ),
+        instruction(
+            "validateSignatureWithMerkleProof",
+            validation_accounts(false),
+            vec![
+                arg("signatureData", defined("SignatureData")),
+                arg("proof", json!({ "vec": byte_array(32) })),
+            ],
+        ),
+        instruction(
+            "updateMerkleRoot",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("membersMerkleRoot", byte_array(32))],
+        ),
+        instruction(
+            "setGovernanceRealm",
+            signer_group_update_accounts("groupOwner"),
+            vec![arg("governanceRealm", json!("publicKey"))],
+        ),
+        with_docs(
+            instruction("executeGovernanceProposal", vec![], vec![]),
+            &[
+                "Discriminator is followed by the executed initValidSigner or clearValidSigner \
+                 instruction without its encoding byte",
+                "Accounts are the ones of the executed instruction",
+            ],
+        ),
+        with_docs(
+            instruction(
+                "cloneSignerGroup",
+                vec![
+                    account("signerGroup", true, false),
+                    account("groupOwner", false, true),
+                    account("creationWhitelist", false, false),
+                    account("ownerRegistry", true, false),
+                    account("sourceGroup", false, false),
+                    account("payer", true, true),
+                    account("rent", false, false),
+                    account("systemProgram", false, false),
+                    account("revocationRegistry", false, false),
+                ],
+                vec![
+                    arg("sourceGroup", json!("publicKey")),
+                    arg("signerCount", json!("u8")),
+                ],
+            ),
+            &["Source valid signer and its writable copy follow the listed accounts for each signer of the source group, the program config follows the pairs"],
+        ),
+        with_docs(
+            instruction(
+                "setLockdownMode",
+                vec![
+                    account("programConfig", true, false),
+                    account("programData", false, false),
+                    account("authority", false, true),
+                    account("payer", true, true),
+                    account("rent", false, false),
+                    account("systemProgram", false, false),
+                ],
+                vec![arg("lockdownMode", json!("u8"))],
+            ),
+            &["Non-zero mode stops creation and removal of signer groups and valid signers"],
+        ),
+        with_docs(
+            instruction(
+                "closeSignerGroup",
+                vec![
+                    account("signerGroup", true, false),
+                    account("groupOwner", false, true),
+                    account("rentReceiver", true, false),
+                    account("ownerRegistry", true, false),
+                    account("programConfig", false, false),
+                ],
+                vec![],
+            ),
+            &[
+                "Group without valid signers is closed leaving a tombstone initSignerGroup \
+                 rejects",
+            ],
+        ),
+        with_docs(
+            instruction(
+                "closeValidSigner",
+                vec![
+                    account("validSigner", true, false),
+                    account("signerGroup", true, false),
+                    account("groupOwner", false, true),
+                    account("rentReceiver", true, false),
+                    account("programConfig", false, false),
+                    optional_account("prevSigner", true, false),
+                    optional_account("nextSigner", true, false),
+                    optional_account("programStats", true, false),
+                ],
+                vec![],
+            ),
+            &["Closed valid signer account is left with a tombstone init rejects"],
+        ),
+    ]
+}
+
+/// Argument types shared by the instructions
+fn types() -> Value {
+    let field = arg;
+    let struct_type = |name: &str, fields: Vec<Value>| json!({ "name": name, "type": { "kind": "struct", "fields": fields } });
+    json!([
+        struct_type(
+            "SignatureData",
+            vec![
+                field(
+                    "signature",
+                    byte_array(SecpSignatureOffsets::SECP_SIGNATURE_SIZE)
+                ),
+                field("recoveryId", json!("u8")),
+                field("message", json!("bytes")),
+                field("secpInstructionIndex", json!("u8")),
+                field("precedingSecpInstruction", json!("bool")),
+                field("secpSignatureIndex", json!("u8")),
+            ]
+        ),
+        struct_type(
+            "Ed25519SignatureData",
+            vec![
+                field(
+                    "signature",
+                    byte_array(Ed25519SignatureOffsets::SIGNATURE_SIZE)
+                ),
+                field("message", json!("bytes")),
+                field("ed25519InstructionIndex", json!("u8")),
+            ]
+        ),
+        struct_type(
+            "MetadataArgs",
+            vec![
+                field(
+                    "endpointUrl",
+                    byte_array(ValidSignerMetadata::ENDPOINT_URL_SIZE)
+                ),
+                field(
+                    "operatorName",
+                    byte_array(ValidSignerMetadata::OPERATOR_NAME_SIZE)
+                ),
+            ]
+        ),
+        struct_type(
+            "InitValidSignerArgs",
+            vec![
+                field(
+                    "ethAddress",
+                    byte_array(SecpSignatureOffsets::ETH_ADDRESS_SIZE)
+                ),
+                field("label", byte_array(ValidSigner::LABEL_SIZE)),
+                field("activeAfterSlot", json!("u64")),
+            ]
+        ),
+        struct_type(
+            "CompressedSecpPubkey",
+            vec![field("prefix", json!("u8")), field("x", byte_array(32))]
+        ),
+    ])
+}
+
+/// Program errors with their custom error codes
+fn errors() -> Vec<Value> {
+    (0..)
+        .map(AudiusError::from_u32)
+        .take_while(Option::is_some)
+        .flatten()
+        .map(|error| {
+            json!({
+                "code": error.clone() as u32,
+                "name": format!("{:?}", error),
+                "msg": error.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Anchor IDL describing program instructions, their accounts and arguments, and errors
+pub fn get_idl() -> Value {
+    let mut instructions = instructions();
+    for (tag, instruction) in instructions.iter_mut().enumerate() {
+        instruction["instructionDiscriminator"] = json!([AudiusInstruction::ENCODING_V2, tag]);
+    }
+
+    json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "name": "audius",
+        "instructions": instructions,
+        "types": types(),
+        "errors": errors(),
+        "metadata": {
+            "address": crate::id().to_string(),
+            "spec": IDL_SPEC,
+        },
+    })
+}
+
+/// IDL as pretty printed JSON, in the form `audius.idl.json` is generated in
+pub fn idl_json() -> String {
+    let mut json = serde_json::to_string_pretty(&get_idl()).expect("IDL is valid JSON");
+    json.push('\n');
+    json
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::instruction::{CompressedSecpPubkey, ProposalInstruction, SignatureData};
+    use solana_program::pubkey::Pubkey;
+    use std::{fs, path::Path};
+
+    fn instruction_by_name<'a>(idl: &'a Value, name: &str) -> &'a Value {
+        idl["instructions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|instruction| instruction["name"] == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_get_idl() {
+        let idl: Value = serde_json::from_str(&get_idl().to_string()).unwrap();
+        assert_eq!(idl["name"], "audius");
+        assert_eq!(idl["metadata"]["address"], crate::id().to_string());
+
+        let instructions = idl["instructions"].as_array().unwrap();
+        for (tag, instruction) in instructions.iter().enumerate() {
+            assert!(instruction["name"].is_string());
+            assert_eq!(
+                instruction["instructionDiscriminator"],
+                json!([AudiusInstruction::ENCODING_V2, tag])
+            );
+            for account in instruction["accounts"].as_array().unwrap() {
+                assert!(account["name"].is_string());
+                assert!(account["isMut"].is_boolean());
+                assert!(account["isSigner"].is_boolean());
+            }
+            for arg in instruction["args"].as_array().unwrap() {
+                assert!(arg["name"].is_string());
+                assert!(!arg["type"].is_null());
+            }
+        }
+
+        // discriminators are the leading bytes instructions are packed with
+        for (name, instruction) in &[
+            ("initSignerGroup", AudiusInstruction::InitSignerGroup),
+            (
+                "setValidationQuota",
+                AudiusInstruction::SetValidationQuota(1),
+            ),
+            (
+                "validateSignatures",
+                AudiusInstruction::ValidateSignatures {
+                    threshold: 1,
+                    signatures: vec![],
+                },
+            ),
+            (
+                "batchValidateSignatures",
+                AudiusInstruction::BatchValidateSignatures {
+                    count: 0,
+                    signatures: vec![],
+                },
+            ),
+            ("closeAttestation", AudiusInstruction::CloseAttestation),
+            (
+                "getOwnershipHistory",
+                AudiusInstruction::GetOwnershipHistory,
+            ),
+            (
+                "initValidSignerFromPubkey",
+                AudiusInstruction::InitValidSignerFromPubkey(CompressedSecpPubkey {
+                    prefix: 2,
+                    x: [0; 32],
+                }),
+            ),
+            (
+                "setCreationWhitelistBypass",
+                AudiusInstruction::SetCreationWhitelistBypass(false),
+            ),
+            (
+                "setDelegateOperator",
+                AudiusInstruction::SetDelegateOperator(Pubkey::default()),
+            ),
+            (
+                "validateSignatureBatch",
+                AudiusInstruction::ValidateSignatureBatch(vec![]),
+            ),
+            (
+                "migrateValidSigner",
+                AudiusInstruction::MigrateValidSigner {
+                    new_signer_group: Pubkey::default(),
+                },
+            ),
+            (
+                "revokeEthAddress",
+                AudiusInstruction::RevokeEthAddress {
+                    eth_address: [0; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+                },
+            ),
+            (
+                "validateSignatureWithMerkleProof",
+                AudiusInstruction::ValidateSignatureWithMerkleProof {
+                    signature_data: SignatureData {
+                        signature: [0; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+                        recovery_id: 0,
+                        message: vec![],
+                        secp_instruction_index: 0,
+                        preceding_secp_instruction: true,
+                        secp_signature_index: 0,
+                    },
+                    proof: vec![],
+                },
+            ),
+            (
+                "updateMerkleRoot",
+                AudiusInstruction::UpdateMerkleRoot([0; 32]),
+            ),
+            (
+                "setGovernanceRealm",
+                AudiusInstruction::SetGovernanceRealm(Pubkey::default()),
+            ),
+            ("setLockdownMode", AudiusInstruction::SetLockdownMode(1)),
+            (
+                "executeGovernanceProposal",
+                AudiusInstruction::ExecuteGovernanceProposal {
+                    instruction: ProposalInstruction(Box::new(AudiusInstruction::ClearValidSigner)),
+                },
+            ),
+            (
+                "cloneSignerGroup",
+                AudiusInstruction::CloneSignerGroup {
+                    source_group: Pubkey::default(),
+                    signer_count: 0,
+                },
+            ),
+            ("closeSignerGroup", AudiusInstruction::CloseSignerGroup),
+            ("closeValidSigner", AudiusInstruction::CloseValidSigner),
+        ] {
+            assert_eq!(
+                instruction_by_name(&idl, name)["instructionDiscriminator"],
+                json!(instruction.pack().unwrap()[..2])
+            );
+        }
+
+        // every error is listed
+        let errors = idl["errors"].as_array().unwrap();
+        assert_eq!(errors[0]["name"], "InvalidInstruction");
+        assert_eq!(errors[0]["msg"], "Invalid instruction");
+        assert_eq!(AudiusError::from_u32(errors.len() as u32), None);
+    }
+
+    #[test]
+    fn test_idl_up_to_date() {
+        let idl_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("audius.idl.json");
+        assert_eq!(
+            idl_json(),
+            fs::read_to_string(idl_file).unwrap(),
+            "audius.idl.json is stale, run `cargo run --features anchor-idl --bin gen-idl` in program"
+        );
+    }
+}
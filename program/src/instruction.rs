@@ -1,17 +1,41 @@
 //! Instruction types
 
-use crate::state::SecpSignatureOffsets;
+use crate::{
+    ed25519_program,
+    error::AudiusError,
+    eth::{eth_address_from_pubkey, COMPRESSED_PUBKEY_SIZE},
+    find_attestation_address, find_creation_whitelist_address, find_owner_registry_address,
+    find_ownership_history_address, find_program_config_address, find_program_data_address,
+    find_program_stats_address, find_revocation_registry_address, find_valid_signer_address,
+    find_valid_signer_metadata_address,
+    state::{
+        Ed25519SignatureOffsets, SecpSignatureOffsets, SignerGroup, ValidSigner,
+        ValidSignerMetadata,
+    },
+    MAX_BATCH_SIGNATURES, MAX_INSTRUCTION_LEN, MAX_MERKLE_PROOF_LEN, MAX_MESSAGE_LEN,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     instruction::{AccountMeta, Instruction},
+    keccak,
     program_error::ProgramError,
     pubkey::Pubkey,
-    sysvar,
+    secp256k1_program, system_program, sysvar,
 };
+use std::convert::TryFrom;
+use std::io::{self, Write};
+use std::mem::size_of;
+use std::str::FromStr;
 
-/// Signature with message to validate
+/// Signature with message to validate.
+///
+/// Borsh serialized field by field in the v2 instruction encoding. Legacy instructions
+/// carry it in the tagged format: the format byte, the signature and the recovery ID followed
+/// by the list of optional fields, each one is a tag byte and a Borsh encoded byte vector.
+/// Fields with default values are omitted there, except for the message. Fields with unknown
+/// tags are skipped and unknown formats are rejected.
 #[repr(C)]
-#[derive(Clone, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
 pub struct SignatureData {
     /// Secp256k1 signature
     pub signature: [u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
@@ -19,70 +43,1731 @@ pub struct SignatureData {
     pub recovery_id: u8,
     /// Signed message
     pub message: Vec<u8>,
+    /// Index of Secp256 program instruction in the transaction
+    pub secp_instruction_index: u8,
+    /// Require Secp256 program instruction to go right before the validation,
+    /// `secp_instruction_index` is ignored in this case
+    pub preceding_secp_instruction: bool,
+    /// Index of the signature among the ones verified by Secp256 program instruction
+    pub secp_signature_index: u8,
+}
+
+impl SignatureData {
+    /// Default index of Secp256 program instruction, it is expected to be the first one
+    pub const DEFAULT_SECP_INSTRUCTION_INDEX: u8 = 0;
+
+    /// Format of the legacy tagged serialization
+    pub const FORMAT_V1: u8 = 1;
+
+    /// Tag of the signed message field
+    pub const TAG_MESSAGE: u8 = 1;
+    /// Tag of the Secp256 program instruction index field
+    pub const TAG_SECP_INSTRUCTION_INDEX: u8 = 2;
+    /// Tag of the preceding Secp256 program instruction flag field
+    pub const TAG_PRECEDING_SECP_INSTRUCTION: u8 = 3;
+    /// Tag of the Secp256 signature index field
+    pub const TAG_SECP_SIGNATURE_INDEX: u8 = 4;
+
+    /// Length of the Borsh serialized fields other than the message bytes
+    const FIXED_LEN: usize = SecpSignatureOffsets::SECP_SIGNATURE_SIZE + 1 + 4 + 3;
+
+    /// Length of the format byte, signature, recovery ID and number of fields
+    /// in the legacy tagged serialization
+    const LEGACY_HEADER_LEN: usize = 1 + SecpSignatureOffsets::SECP_SIGNATURE_SIZE + 1 + 4;
+
+    /// Length of the field's tag and value length in the legacy tagged serialization
+    const LEGACY_FIELD_HEADER_LEN: usize = 1 + 4;
+
+    /// Creates signature data expecting Secp256 instruction at the default index
+    pub fn new(
+        signature: [u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+        recovery_id: u8,
+        message: Vec<u8>,
+    ) -> Self {
+        Self {
+            signature,
+            recovery_id,
+            message,
+            secp_instruction_index: Self::DEFAULT_SECP_INSTRUCTION_INDEX,
+            preceding_secp_instruction: false,
+            secp_signature_index: 0,
+        }
+    }
+
+    /// Borsh serialized length of the signature data
+    pub fn packed_len(&self) -> usize {
+        Self::packed_len_with_message(self.message.len())
+    }
+
+    /// Borsh serialized length of the signature data with the message of the given length
+    pub const fn packed_len_with_message(message_len: usize) -> usize {
+        Self::FIXED_LEN + message_len
+    }
+
+    /// Length of the signature data in the legacy tagged serialization
+    pub fn legacy_packed_len(&self) -> usize {
+        Self::LEGACY_HEADER_LEN
+            + Self::LEGACY_FIELD_HEADER_LEN
+            + self.message.len()
+            + self
+                .optional_fields()
+                .iter()
+                .map(|(_, value)| Self::LEGACY_FIELD_HEADER_LEN + value.len())
+                .sum::<usize>()
+    }
+
+    /// Fields other than the message which differ from their defaults, with their tags
+    fn optional_fields(&self) -> Vec<(u8, [u8; 1])> {
+        let mut fields = vec![];
+        if self.secp_instruction_index != Self::DEFAULT_SECP_INSTRUCTION_INDEX {
+            fields.push((
+                Self::TAG_SECP_INSTRUCTION_INDEX,
+                [self.secp_instruction_index],
+            ));
+        }
+        if self.preceding_secp_instruction {
+            fields.push((Self::TAG_PRECEDING_SECP_INSTRUCTION, [1]));
+        }
+        if self.secp_signature_index != 0 {
+            fields.push((Self::TAG_SECP_SIGNATURE_INDEX, [self.secp_signature_index]));
+        }
+        fields
+    }
+
+    /// Write signature data in the legacy tagged serialization
+    pub fn pack_legacy_into<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let optional_fields = self.optional_fields();
+        writer.write_all(&[Self::FORMAT_V1])?;
+        writer.write_all(&self.signature)?;
+        writer.write_all(&[self.recovery_id])?;
+        (1 + optional_fields.len() as u32).serialize(writer)?;
+        Self::TAG_MESSAGE.serialize(writer)?;
+        self.message.serialize(writer)?;
+        for (tag, value) in optional_fields {
+            tag.serialize(writer)?;
+            value[..].serialize(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Read signature data in the legacy tagged serialization from the start of the buffer,
+    /// advancing it past the data
+    pub fn unpack_legacy_from(buf: &mut &[u8]) -> io::Result<Self> {
+        let invalid_data = |error: &str| io::Error::new(io::ErrorKind::InvalidData, error);
+
+        if u8::deserialize(buf)? != Self::FORMAT_V1 {
+            return Err(invalid_data("Unsupported signature data format"));
+        }
+        let mut signature_data = Self::new(
+            <[u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE]>::deserialize(buf)?,
+            u8::deserialize(buf)?,
+            vec![],
+        );
+
+        let field_count = u32::deserialize(buf)?;
+        let mut read_tags = vec![];
+        for _ in 0..field_count {
+            let tag = u8::deserialize(buf)?;
+            let len = u32::deserialize(buf)? as usize;
+            if len > buf.len() {
+                return Err(invalid_data("Signature data field is out of bounds"));
+            }
+            let (value, rest) = buf.split_at(len);
+            *buf = rest;
+
+            if read_tags.contains(&tag) {
+                return Err(invalid_data("Duplicate signature data field"));
+            }
+            read_tags.push(tag);
+            let flag = || match value {
+                [value] => Ok(*value),
+                _ => Err(invalid_data("Malformed signature data field")),
+            };
+            match tag {
+                Self::TAG_MESSAGE => signature_data.message = value.to_vec(),
+                Self::TAG_SECP_INSTRUCTION_INDEX => signature_data.secp_instruction_index = flag()?,
+                Self::TAG_PRECEDING_SECP_INSTRUCTION => {
+                    signature_data.preceding_secp_instruction = match flag()? {
+                        0 => false,
+                        1 => true,
+                        _ => return Err(invalid_data("Malformed signature data field")),
+                    }
+                }
+                Self::TAG_SECP_SIGNATURE_INDEX => signature_data.secp_signature_index = flag()?,
+                // fields added by later versions are skipped
+                _ => {}
+            }
+        }
+        Ok(signature_data)
+    }
+
+    /// Serialize signature data in the legacy tagged format into a buffer of its exact length
+    pub fn pack_legacy(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut data = Vec::with_capacity(self.legacy_packed_len());
+        self.pack_legacy_into(&mut data)?;
+        Ok(data)
+    }
+
+    /// Deserialize signature data in the legacy tagged format, the whole buffer must be consumed
+    pub fn unpack_legacy(input: &[u8]) -> Result<Self, ProgramError> {
+        let mut data = input;
+        let signature_data =
+            Self::unpack_legacy_from(&mut data).map_err(|_| AudiusError::InvalidInstruction)?;
+        if !data.is_empty() {
+            return Err(AudiusError::InvalidInstruction.into());
+        }
+        Ok(signature_data)
+    }
+
+    /// Check that message doesn't exceed [MAX_MESSAGE_LEN](../constant.MAX_MESSAGE_LEN.html)
+    pub fn check_message_len(&self) -> Result<(), ProgramError> {
+        if self.message.len() > MAX_MESSAGE_LEN {
+            return Err(AudiusError::MessageTooLarge.into());
+        }
+        Ok(())
+    }
+}
+
+/// Ed25519 signature with message to validate
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Ed25519SignatureData {
+    /// Ed25519 signature
+    pub signature: [u8; Ed25519SignatureOffsets::SIGNATURE_SIZE],
+    /// Signed message
+    pub message: Vec<u8>,
+    /// Index of Ed25519 program instruction in the transaction
+    pub ed25519_instruction_index: u8,
+}
+
+impl Ed25519SignatureData {
+    /// Serialized length of the signature data
+    pub fn packed_len(&self) -> usize {
+        Ed25519SignatureOffsets::SIGNATURE_SIZE + 4 + self.message.len() + 1
+    }
+
+    /// Check that message doesn't exceed [MAX_MESSAGE_LEN](../constant.MAX_MESSAGE_LEN.html)
+    pub fn check_message_len(&self) -> Result<(), ProgramError> {
+        if self.message.len() > MAX_MESSAGE_LEN {
+            return Err(AudiusError::MessageTooLarge.into());
+        }
+        Ok(())
+    }
+}
+
+/// Valid signer metadata fields to set
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct MetadataArgs {
+    /// URL of the signer's node endpoint, zero padded
+    pub endpoint_url: [u8; ValidSignerMetadata::ENDPOINT_URL_SIZE],
+    /// Name of the signer's operator, zero padded
+    pub operator_name: [u8; ValidSignerMetadata::OPERATOR_NAME_SIZE],
+}
+
+impl MetadataArgs {
+    /// Serialized length of the args
+    pub const LEN: usize =
+        ValidSignerMetadata::ENDPOINT_URL_SIZE + ValidSignerMetadata::OPERATOR_NAME_SIZE;
+}
+
+/// Valid signer fields to set on extended initialization
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct InitValidSignerArgs {
+    /// Ethereum address of signer
+    pub eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    /// Human readable label of signer, zero padded UTF-8
+    pub label: [u8; ValidSigner::LABEL_SIZE],
+    /// Slot signer's signatures become valid at, zero if signer is active immediately
+    pub active_after_slot: u64,
+}
+
+impl InitValidSignerArgs {
+    /// Serialized length of the args
+    pub const LEN: usize = SecpSignatureOffsets::ETH_ADDRESS_SIZE + ValidSigner::LABEL_SIZE + 8;
+
+    /// Create args packing the label
+    pub fn new(
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        label: &str,
+        active_after_slot: u64,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            eth_address,
+            label: ValidSigner::pack_label(label)?,
+            active_after_slot,
+        })
+    }
+}
+
+/// Compressed Secp256k1 public key, the prefix is kept apart from X coordinate
+/// as Borsh doesn't serialize 33 byte arrays
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct CompressedSecpPubkey {
+    /// Parity of Y coordinate, 2 if it's even and 3 if it's odd
+    pub prefix: u8,
+    /// X coordinate of the public key
+    pub x: [u8; 32],
+}
+
+impl CompressedSecpPubkey {
+    /// Serialized length of the public key
+    pub const LEN: usize = COMPRESSED_PUBKEY_SIZE;
+
+    /// Split 33 byte compressed public key
+    pub fn new(pubkey: &[u8; COMPRESSED_PUBKEY_SIZE]) -> Self {
+        let mut x = [0u8; 32];
+        x.copy_from_slice(&pubkey[1..]);
+        Self {
+            prefix: pubkey[0],
+            x,
+        }
+    }
+
+    /// Compressed public key in its usual 33 byte encoding
+    pub fn to_bytes(&self) -> [u8; COMPRESSED_PUBKEY_SIZE] {
+        let mut pubkey = [0u8; COMPRESSED_PUBKEY_SIZE];
+        pubkey[0] = self.prefix;
+        pubkey[1..].copy_from_slice(&self.x);
+        pubkey
+    }
+
+    /// Ethereum address of the public key
+    pub fn eth_address(
+        &self,
+    ) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], ProgramError> {
+        eth_address_from_pubkey(&self.to_bytes())
+    }
+}
+
+/// Instruction nested in `ExecuteGovernanceProposal`, serialized as the instruction's
+/// Borsh encoding. Borsh derive can't handle the recursive type itself.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalInstruction(pub Box<AudiusInstruction>);
+
+impl BorshSerialize for ProposalInstruction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for ProposalInstruction {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        AudiusInstruction::deserialize(buf).map(|instruction| Self(Box::new(instruction)))
+    }
 }
 
 /// Instructions supported by the Audius program
 #[repr(C)]
-#[derive(Clone, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
 pub enum AudiusInstruction {
-    ///   Create new signer group account
+    ///   Create new signer group account and add it to the owner's registry.
+    ///   The creation is signed by the creation authority, which has to be whitelisted
+    ///   while the creation whitelist is enforced.
     ///
     ///   0. `[w]` New SignerGroup to create
     ///   1. `[]` SignerGroup's owner
+    ///   2. `[]` Creation whitelist, derived from program address
+    ///   3. `[s]` Creation authority
+    ///   4. `[w]` Owner registry, derived from owner address
+    ///   5. `[ws]` Funding account to pay for the registry account
+    ///   6. `[]` Rent sysvar
+    ///   7. `[]` System program
+    ///   8. `[w]` Optional program stats account
+    ///   9. `[]` Program config, derived from program address
     InitSignerGroup,
-    ///   Create new valid signer account
+    ///   Create new valid signer account. Optional proof of possession is the signature
+    ///   of valid signer account address by the registered eth key, it's required
+    ///   by the groups with `eth_pop_required` set
     ///
-    ///   0. `[w]` Uninitialized valid signer account
-    ///   1. `[]` Group for Valid Signer to join with
-    ///   2. `[s]` SignerGroup's owner
-    InitValidSigner([u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]),
+    ///   0. `[w]` Valid signer account, derived from signer group and eth address
+    ///   1. `[w]` Group for Valid Signer to join with
+    ///   2. `[s]` SignerGroup's owner or its delegate operator
+    ///   3. `[ws]` Funding account to pay for valid signer account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    ///   6. `[]` Revocation registry of the group, derived from signer group address
+    ///   7. `[]` Sysvar instruction account, only if proof of possession is given
+    ///   8. `[w]` Last valid signer of the group, omitted if group is empty
+    ///   9. `[w]` Optional program stats account
+    ///   10. `[]` Program config, derived from program address
+    InitValidSigner(
+        [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        Option<SignatureData>,
+    ),
     ///   Remove valid signer from the group
     ///
     ///   0. `[w]` Initialized valid signer to remove
-    ///   1. `[]` Signer group to remove from
+    ///   1. `[w]` Signer group to remove from
     ///   2. `[s]` SignerGroup's owner
+    ///   3. `[w]` Previous valid signer in the group's list, omitted if there is none
+    ///   4. `[w]` Next valid signer in the group's list, omitted if there is none
+    ///   5. `[w]` Optional program stats account
+    ///   6. `[]` Program config, derived from program address
     ClearValidSigner,
-    ///   Validate signature issued by valid signer
+    ///   Validate signature issued by valid signer. Signature data carries the raw signed
+    ///   message, it must match the message of Secp256 instruction which the Secp256k1
+    ///   program hashes with Keccak256 before verifying the signature.
     ///
-    ///   0. `[]` Initialized valid signer
-    ///   1. `[]` Signer group signer belongs to
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[w]` Signer group signer belongs to
+    ///   2. `[]` Sysvar instruction account
+    ///   3. `[s]` Valid signer's cosigner, only if the group requires one
+    ///   4. `[ws]` Validation fee payer, omitted along with 5 and 6 if group charges no fee
+    ///   5. `[w]` Group's treasury account
+    ///   6. `[]` System program
+    ///   7. `[w]` Optional program stats account
     ValidateSignature(SignatureData),
+    ///   Log signer group state as JSON
+    ///
+    ///   0. `[]` Signer group to query
+    QuerySignerGroup,
+    ///   Create metadata account for the valid signer
+    ///
+    ///   0. `[w]` Metadata account, derived from valid signer address
+    ///   1. `[]` Initialized valid signer
+    ///   2. `[]` Signer group valid signer belongs to
+    ///   3. `[s]` SignerGroup's owner
+    ///   4. `[ws]` Funding account to pay for metadata account
+    ///   5. `[]` Rent sysvar
+    ///   6. `[]` System program
+    ///   7. `[]` Program config, derived from program address
+    InitValidSignerMetadata(MetadataArgs),
+    ///   Update metadata of the valid signer
+    ///
+    ///   0. `[w]` Initialized metadata account
+    ///   1. `[]` Initialized valid signer
+    ///   2. `[]` Signer group valid signer belongs to
+    ///   3. `[s]` SignerGroup's owner
+    ///   4. `[]` Program config, derived from program address
+    UpdateValidSignerMetadata(MetadataArgs),
+    ///   Create program statistics account
+    ///
+    ///   0. `[w]` Program stats account, derived from program address
+    ///   1. `[ws]` Funding account to pay for stats account
+    ///   2. `[]` Rent sysvar
+    ///   3. `[]` System program
+    InitProgramStats,
+    ///   Log program statistics
+    ///
+    ///   0. `[]` Program stats account
+    GetProgramStats,
+    ///   Create new valid signer account signing with Ed25519 key
+    ///
+    ///   0. `[w]` Uninitialized valid signer account
+    ///   1. `[w]` Group for Valid Signer to join with
+    ///   2. `[s]` SignerGroup's owner or its delegate operator
+    ///   3. `[w]` Last valid signer of the group, omitted if group is empty
+    ///   4. `[w]` Optional program stats account
+    ///   5. `[]` Program config, derived from program address
+    InitValidSignerEd25519(Pubkey),
+    ///   Validate Ed25519 signature issued by valid signer,
+    ///   groups requiring a cosigner are rejected
+    ///
+    ///   0. `[w]` Initialized Ed25519 valid signer
+    ///   1. `[w]` Signer group signer belongs to
+    ///   2. `[]` Sysvar instruction account
+    ///   3. `[ws]` Validation fee payer, omitted along with 4 and 5 if group charges no fee
+    ///   4. `[w]` Group's treasury account
+    ///   5. `[]` System program
+    ///   6. `[w]` Optional program stats account
+    ValidateEd25519Signature(Ed25519SignatureData),
+    ///   Register one more eth address for Secp256k1 valid signer, the address must not be
+    ///   revoked in the group
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[]` Signer group valid signer belongs to
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[]` Revocation registry of the group, derived from signer group address
+    ///   4. `[]` Program config, derived from program address
+    AddSignerAddress([u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]),
+    ///   Unregister eth address of Secp256k1 valid signer, the last address can't be removed
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[]` Signer group valid signer belongs to
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[]` Program config, derived from program address
+    RemoveSignerAddress([u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]),
+    ///   Create new valid signer account with a human readable label
+    ///
+    ///   0. `[w]` Valid signer account, derived from signer group and eth address
+    ///   1. `[w]` Group for Valid Signer to join with
+    ///   2. `[s]` SignerGroup's owner or its delegate operator
+    ///   3. `[ws]` Funding account to pay for valid signer account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    ///   6. `[]` Revocation registry of the group, derived from signer group address
+    ///   7. `[w]` Last valid signer of the group, omitted if group is empty
+    ///   8. `[w]` Optional program stats account
+    ///   9. `[]` Program config, derived from program address
+    InitValidSignerWithLabel(
+        [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        [u8; ValidSigner::LABEL_SIZE],
+    ),
+    ///   Set human readable label of the valid signer
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[]` Signer group valid signer belongs to
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[]` Program config, derived from program address
+    SetSignerLabel([u8; ValidSigner::LABEL_SIZE]),
+    ///   Create new valid signer account with label and activation slot
+    ///
+    ///   0. `[w]` Valid signer account, derived from signer group and eth address
+    ///   1. `[w]` Group for Valid Signer to join with
+    ///   2. `[s]` SignerGroup's owner or its delegate operator
+    ///   3. `[ws]` Funding account to pay for valid signer account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    ///   6. `[]` Revocation registry of the group, derived from signer group address
+    ///   7. `[w]` Last valid signer of the group, omitted if group is empty
+    ///   8. `[w]` Optional program stats account
+    ///   9. `[]` Program config, derived from program address
+    InitValidSignerExtended(InitValidSignerArgs),
+    ///   Amend activation slot of the valid signer which is not active yet
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[]` Signer group valid signer belongs to
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[]` Program config, derived from program address
+    SetActivationSlot(u64),
+    ///   Propose new owner of the signer group, the ownership is transferred once
+    ///   the new owner accepts it
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    ProposeOwnership(Pubkey),
+    ///   Accept proposed ownership of the signer group, the transfer is recorded
+    ///   in group's ownership history and the group moves to the new owner's registry
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's pending owner
+    ///   2. `[w]` Ownership history of the group, derived from signer group address
+    ///   3. `[ws]` Funding account to pay for ownership history and registry accounts
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    ///   6. `[w]` Owner registry of the previous owner, derived from its address
+    ///   7. `[w]` Owner registry of the pending owner, derived from its address
+    ///   8. `[]` Program config, derived from program address
+    AcceptOwnership,
+    ///   Withdraw ownership proposal of the signer group
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    CancelProposedOwnership,
+    ///   Set the last slot valid signer's signatures are accepted at, zero to never expire.
+    ///   Expired signer stays in the group until it's cleared
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[]` Signer group valid signer belongs to
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[]` Program config, derived from program address
+    SetSignerExpiry(u64),
+    ///   Require or stop requiring proof of possession to register Secp256k1 valid signers
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetEthPoPRequired(bool),
+    ///   Set lamports charged for each signature validation and the treasury they're paid to,
+    ///   zero fee disables charging
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetValidationFee(u64, Pubkey),
+    ///   Set maximum number of signature validations per quota window of the group,
+    ///   zero quota disables the limit
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetValidationQuota(u32),
+    ///   Validate that at least `threshold` valid signers of the group signed the same message.
+    ///   Each signature is checked against the valid signer account at its position,
+    ///   signatures which don't verify aren't counted and each signer is counted once.
+    ///   Groups requiring a cosigner are rejected
+    ///
+    ///   0..N. `[w]` Initialized valid signers, one per signature
+    ///   N. `[w]` Signer group signers belong to
+    ///   N+1. `[]` Sysvar instruction account
+    ///   N+2. `[ws]` Validation fee payer, omitted along with N+3 and N+4 if group charges no fee
+    ///   N+3. `[w]` Group's treasury account
+    ///   N+4. `[]` System program
+    ///   N+5. `[w]` Optional program stats account
+    ValidateSignatures {
+        /// Number of distinct valid signers required to sign the message
+        threshold: u8,
+        /// Signatures of the message, one per valid signer account
+        signatures: Vec<SignatureData>,
+    },
+    ///   Initialize new signer group along with its first Secp256k1 valid signer,
+    ///   so the group never exists without signers. The owner is the creation authority
+    ///   checked against the creation whitelist.
+    ///
+    ///   0. `[w]` New SignerGroup to initialize
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Creation whitelist, derived from program address
+    ///   3. `[w]` Owner registry, derived from owner address
+    ///   4. `[w]` Valid signer account, derived from signer group and eth address
+    ///   5. `[ws]` Funding account to pay for valid signer and registry accounts
+    ///   6. `[]` Rent sysvar
+    ///   7. `[]` System program
+    ///   8. `[]` Revocation registry of the group, derived from signer group address
+    ///   9. `[w]` Optional program stats account
+    ///   10. `[]` Program config, derived from program address
+    InitSignerGroupWithSigner([u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]),
+    ///   Set maximum number of valid signers in the group, zero removes the limit.
+    ///   The limit can't be lower than the number of signers already in the group
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    UpdateMaxSigners(u32),
+    ///   Register prefix of the messages signer group validates. Once the group has prefixes,
+    ///   messages not starting with any of them are rejected
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    AddMessagePrefix([u8; SignerGroup::MESSAGE_PREFIX_SIZE]),
+    ///   Unregister message prefix, group validates any message after the last one is removed
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    RemoveMessagePrefix([u8; SignerGroup::MESSAGE_PREFIX_SIZE]),
+    ///   Require or stop requiring valid signer's cosigner to sign signature validations
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetCosignerRequired(bool),
+    ///   Set Solana key co-signing validations of the valid signer, zero key removes it
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[]` Signer group valid signer belongs to
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[]` Program config, derived from program address
+    SetSignerCosigner(Pubkey),
+    ///   Let the group's valid signers add and remove signers with the given number
+    ///   of approvals, or stop it. The threshold can't exceed the number of signers in the group
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetGovernance(bool, u8),
+    ///   Create new Secp256k1 valid signer of the governed group approved by its signers
+    ///   instead of the owner. Each signature is checked against the valid signer account
+    ///   at its position and must be made over the group's governance message adding
+    ///   the eth address. Groups requiring proof of possession are rejected
+    ///
+    ///   0..N. `[]` Approving valid signers, one per signature
+    ///   N. `[w]` Valid signer account, derived from signer group and eth address
+    ///   N+1. `[w]` Governed group for Valid Signer to join with
+    ///   N+2. `[]` Sysvar instruction account
+    ///   N+3. `[ws]` Funding account to pay for valid signer account
+    ///   N+4. `[]` Rent sysvar
+    ///   N+5. `[]` System program
+    ///   N+6. `[]` Revocation registry of the group, derived from signer group address
+    ///   N+7. `[w]` Last valid signer of the group, omitted if group is empty
+    ///   N+8. `[w]` Optional program stats account
+    ///   N+9. `[]` Program config, derived from program address
+    GovernedInitValidSigner {
+        /// Ethereum address of the new signer
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        /// Approvals of the group's signers, one per approving valid signer account
+        signatures: Vec<SignatureData>,
+    },
+    ///   Remove valid signer from the governed group approved by its signers instead
+    ///   of the owner. Approvals are made over the group's governance message removing
+    ///   the eth address, the signer is the account derived from it
+    ///
+    ///   0..N. `[]` Approving valid signers, one per signature
+    ///   N. `[w]` Valid signer to remove, derived from signer group and eth address
+    ///   N+1. `[w]` Governed group to remove from
+    ///   N+2. `[]` Sysvar instruction account
+    ///   N+3. `[w]` Previous valid signer in the group's list, omitted if there is none
+    ///   N+4. `[w]` Next valid signer in the group's list, omitted if there is none
+    ///   N+5. `[w]` Optional program stats account
+    ///   N+6. `[]` Program config, derived from program address
+    GovernedClearValidSigner {
+        /// Ethereum address the removed signer is derived from
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        /// Approvals of the group's signers, one per approving valid signer account
+        signatures: Vec<SignatureData>,
+    },
+    ///   Validate that every signature is made by the valid signer at its position, fails
+    ///   unless all of them verify. Signatures may come from the same or different signers
+    ///   of one group and are verified by a single Secp256 instruction preceding this one.
+    ///   Groups requiring a cosigner are rejected
+    ///
+    ///   0..N. `[w]` Initialized valid signers, one per signature
+    ///   N. `[w]` Signer group signers belong to
+    ///   N+1. `[]` Sysvar instruction account
+    ///   N+2. `[ws]` Validation fee payer, omitted along with N+3 and N+4 if group charges no fee
+    ///   N+3. `[w]` Group's treasury account
+    ///   N+4. `[]` System program
+    ///   N+5. `[w]` Optional program stats account
+    BatchValidateSignatures {
+        /// Number of signatures in the batch, up to
+        /// [MAX_BATCH_SIGNATURES](../constant.MAX_BATCH_SIGNATURES.html)
+        count: u8,
+        /// Signatures to validate, one per valid signer account
+        signatures: Vec<SignatureData>,
+    },
+    ///   Revoke eth address in the signer group, no valid signer can be initialized with it
+    ///   afterwards. Revocation registry is created with the first revoked address paid
+    ///   by the funding account
+    ///
+    ///   0. `[]` Signer group to revoke the address in
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[w]` Revocation registry of the group, derived from signer group address
+    ///   3. `[ws]` Funding account to pay for revocation registry account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    ///   6. `[]` Program config, derived from program address
+    RevokeEthAddress {
+        /// Ethereum address to revoke
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    },
+    ///   Validate signature issued by valid signer and record it in an attestation account
+    ///   other programs can read. Every message is attested by the signer only once.
+    ///   Groups requiring a cosigner are rejected
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[w]` Signer group signer belongs to
+    ///   2. `[]` Sysvar instruction account
+    ///   3. `[w]` Attestation account, derived from valid signer and message hash
+    ///   4. `[ws]` Funding account to pay for attestation account
+    ///   5. `[]` Rent sysvar
+    ///   6. `[]` System program
+    ///   7. `[ws]` Validation fee payer, omitted along with 8 and 9 if group charges no fee
+    ///   8. `[w]` Group's treasury account
+    ///   9. `[]` System program
+    ///   10. `[w]` Optional program stats account
+    ValidateAndAttest {
+        /// Signature to validate
+        signature_data: SignatureData,
+        /// Slot after which the attestation may be closed, 0 if it never expires
+        expires_at_slot: u64,
+    },
+    ///   Close expired attestation returning its rent to the account which paid it
+    ///
+    ///   0. `[w]` Expired attestation account
+    ///   1. `[w]` Account which paid for the attestation
+    CloseAttestation,
+    ///   Log latest ownership transfers of the signer group, from the most recent one
+    ///
+    ///   0. `[]` Signer group
+    ///   1. `[]` Ownership history of the group, derived from signer group address
+    GetOwnershipHistory,
+    ///   Create new valid signer account from the compressed Secp256k1 public key,
+    ///   eth address of the signer is derived from the key
+    ///
+    ///   0. `[w]` Valid signer account, derived from signer group and eth address
+    ///   1. `[w]` Group for Valid Signer to join with
+    ///   2. `[s]` SignerGroup's owner or its delegate operator
+    ///   3. `[ws]` Funding account to pay for valid signer account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    ///   6. `[]` Revocation registry of the group, derived from signer group address
+    ///   7. `[w]` Last valid signer of the group, omitted if group is empty
+    ///   8. `[w]` Optional program stats account
+    ///   9. `[]` Program config, derived from program address
+    InitValidSignerFromPubkey(CompressedSecpPubkey),
+    ///   Allow the account to create signer groups, creating the whitelist on first use
+    ///
+    ///   0. `[w]` Creation whitelist, derived from program address
+    ///   1. `[]` Program data account, derived from program address by the upgradeable loader
+    ///   2. `[s]` Upgrade authority of the program
+    ///   3. `[ws]` Funding account to pay for whitelist account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    AddToCreationWhitelist(Pubkey),
+    ///   Disallow the account to create signer groups, creating the whitelist on first use
+    ///
+    ///   0. `[w]` Creation whitelist, derived from program address
+    ///   1. `[]` Program data account, derived from program address by the upgradeable loader
+    ///   2. `[s]` Upgrade authority of the program
+    ///   3. `[ws]` Funding account to pay for whitelist account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    RemoveFromCreationWhitelist(Pubkey),
+    ///   Set or clear `BYPASS_WHITELIST` flag letting anyone create signer groups,
+    ///   creating the whitelist on first use
+    ///
+    ///   0. `[w]` Creation whitelist, derived from program address
+    ///   1. `[]` Program data account, derived from program address by the upgradeable loader
+    ///   2. `[s]` Upgrade authority of the program
+    ///   3. `[ws]` Funding account to pay for whitelist account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    SetCreationWhitelistBypass(bool),
+    ///   Validate signature issued by valid signer given Keccak256 digest of the signed
+    ///   message in place of the message. The digest must be exactly 32 bytes and match
+    ///   the hash of Secp256 instruction's message.
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[w]` Signer group signer belongs to
+    ///   2. `[]` Sysvar instruction account
+    ///   3. `[s]` Valid signer's cosigner, only if the group requires one
+    ///   4. `[ws]` Validation fee payer, omitted along with 5 and 6 if group charges no fee
+    ///   5. `[w]` Group's treasury account
+    ///   6. `[]` System program
+    ///   7. `[w]` Optional program stats account
+    ValidateSignatureDigest(SignatureData),
+    ///   Set operator key allowed to add valid signers to the group along with the owner,
+    ///   default pubkey removes the delegate operator
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetDelegateOperator(Pubkey),
+    ///   Validate several messages signed by the same valid signer, fails unless every
+    ///   signature verifies. Signatures may be verified by one or more Secp256 instructions
+    ///   of the transaction, up to [MAX_BATCH_SIGNATURES](../constant.MAX_BATCH_SIGNATURES.html)
+    ///   of them are validated at once
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[w]` Signer group signer belongs to
+    ///   2. `[]` Sysvar instruction account
+    ///   3. `[s]` Valid signer's cosigner, only if the group requires one
+    ///   4. `[ws]` Validation fee payer, omitted along with 5 and 6 if group charges no fee
+    ///   5. `[w]` Group's treasury account
+    ///   6. `[]` System program
+    ///   7. `[w]` Optional program stats account
+    ValidateSignatureBatch(Vec<SignatureData>),
+    ///   Move valid signer to another group keeping its keys, label and counters. Signers at
+    ///   addresses derived from their group and eth address can't migrate, neither can signers
+    ///   joining a governed group or a group requiring eth proof of possession of Secp256k1 ones
+    ///
+    ///   0. `[w]` Initialized valid signer
+    ///   1. `[w]` Signer group the valid signer leaves
+    ///   2. `[s]` Owner of the group the valid signer leaves
+    ///   3. `[w]` Signer group the valid signer joins
+    ///   4. `[s]` Owner of the group the valid signer joins
+    ///   5. `[]` Revocation registry of the joined group, derived from its address
+    ///   6. `[w]` Previous valid signer in the left group's list, omitted if there is none
+    ///   7. `[w]` Next valid signer in the left group's list, omitted if there is none
+    ///   8. `[w]` Last valid signer of the joined group, omitted if the group is empty
+    ///   9. `[]` Program config, derived from program address
+    MigrateValidSigner {
+        /// Signer group the valid signer joins
+        new_signer_group: Pubkey,
+    },
+    ///   Validate signature of the group member proven by the Merkle proof of its eth address
+    ///   against the group's members Merkle root instead of its valid signer account. Signer's
+    ///   activation and expiry slots aren't checked and its validations aren't counted,
+    ///   groups requiring a cosigner are rejected, revoked eth addresses are rejected
+    ///
+    ///   0. `[w]` Signer group signer belongs to
+    ///   1. `[]` Sysvar instruction account
+    ///   2. `[]` Revocation registry of the group, derived from signer group address
+    ///   3. `[ws]` Validation fee payer, omitted along with 4 and 5 if group charges no fee
+    ///   4. `[w]` Group's treasury account
+    ///   5. `[]` System program
+    ///   6. `[w]` Optional program stats account
+    ValidateSignatureWithMerkleProof {
+        /// Signature with the signed message
+        signature_data: SignatureData,
+        /// Siblings of the eth address leaf from the bottom level of the tree up, at most
+        /// [MAX_MERKLE_PROOF_LEN](../constant.MAX_MERKLE_PROOF_LEN.html) of them
+        proof: Vec<[u8; 32]>,
+    },
+    ///   Set Merkle root of the members' eth addresses, zero disables validation by Merkle proof
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    UpdateMerkleRoot([u8; 32]),
+    ///   Set SPL Governance realm whose proposals may add and remove valid signers,
+    ///   zero disables execution of the proposals
+    ///
+    ///   0. `[w]` Initialized signer group
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[]` Program config, derived from program address
+    SetGovernanceRealm(Pubkey),
+    ///   Execute the instruction approved by SPL Governance proposal of the group's realm.
+    ///   Only `InitValidSigner` without proof of possession and `ClearValidSigner` are
+    ///   executed. Accounts are the ones of the executed instruction, with the realm's
+    ///   governance account signing in place of the group's owner.
+    ExecuteGovernanceProposal {
+        /// Instruction approved by the proposal
+        instruction: ProposalInstruction,
+    },
+    ///   Create signer group with the same valid signers as the source group, copying their
+    ///   keys, labels, activation and expiry slots and cosigners. Other settings of the source
+    ///   group aren't copied. Copies of Ed25519 signers are created by the client beforehand
+    ///   and assigned to the program, as for `InitValidSignerEd25519`.
+    ///
+    ///   0. `[w]` New signer group, created beforehand and assigned to the program
+    ///   1. `[s]` New signer group's owner
+    ///   2. `[]` Creation whitelist, derived from program address
+    ///   3. `[w]` Owner registry, derived from owner address
+    ///   4. `[]` Source signer group
+    ///   5. `[ws]` Funding account to pay for valid signer and registry accounts
+    ///   6. `[]` Rent sysvar
+    ///   7. `[]` System program
+    ///   8. `[]` Revocation registry of the new group, derived from the new group address
+    ///   9. ..9+2N Pairs of source valid signer `[]` and its copy `[w]`, in the order of
+    ///      the source group's signer list. Copies of Secp256k1 signers are derived from
+    ///      the new group and their first eth address. Program config `[]`, derived from
+    ///      program address, follows the pairs.
+    CloneSignerGroup {
+        /// Signer group the valid signers are copied from
+        source_group: Pubkey,
+        /// Number of valid signers in the source group
+        signer_count: u8,
+    },
+    ///   Set lockdown mode of the program, non-zero mode stops every change of signer groups
+    ///   and valid signers while signatures are still validated. The config is created
+    ///   on first use and its authority is the upgrade authority of the program.
+    ///
+    ///   0. `[w]` Program config, derived from program address
+    ///   1. `[]` Program data account, derived from program address by the upgradeable loader
+    ///   2. `[s]` Config authority
+    ///   3. `[ws]` Funding account to pay for config account
+    ///   4. `[]` Rent sysvar
+    ///   5. `[]` System program
+    SetLockdownMode(u8),
+    ///   Close empty signer group returning its rent to the receiver and remove it from
+    ///   the owner's registry. The group is left with a tombstone which `InitSignerGroup`
+    ///   rejects, it stays in place if the group account is refunded within the same
+    ///   transaction, otherwise the runtime purges the account.
+    ///
+    ///   0. `[w]` Signer group without valid signers
+    ///   1. `[s]` SignerGroup's owner
+    ///   2. `[w]` Account receiving the rent of the group
+    ///   3. `[w]` Owner registry, derived from owner address
+    ///   4. `[]` Program config, derived from program address
+    CloseSignerGroup,
+    ///   Remove valid signer from the group and close its account returning the rent to
+    ///   the receiver. The account is left with a tombstone like the closed group.
+    ///
+    ///   0. `[w]` Initialized valid signer to close
+    ///   1. `[w]` Signer group to remove from
+    ///   2. `[s]` SignerGroup's owner
+    ///   3. `[w]` Account receiving the rent of the valid signer
+    ///   4. `[w]` Previous valid signer in the group's list, omitted if there is none
+    ///   5. `[w]` Next valid signer in the group's list, omitted if there is none
+    ///   6. `[w]` Optional program stats account
+    ///   7. `[]` Program config, derived from program address
+    CloseValidSigner,
+}
+
+impl AudiusInstruction {
+    /// Leading byte of the v2 encoding, the Borsh serialized instruction follows it.
+    /// Legacy encoding starts with the variant tag instead.
+    pub const ENCODING_V2: u8 = 0xff;
+
+    /// Unpacks a byte buffer into an [AudiusInstruction](enum.AudiusInstruction.html).
+    /// Buffers in the v2 encoding start with [ENCODING_V2](#associatedconstant.ENCODING_V2),
+    /// the rest are read in the legacy encoding of the first four instructions.
+    /// The whole buffer must be consumed, trailing bytes are rejected.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let mut data = input;
+        let instruction = match data.split_first() {
+            Some((&Self::ENCODING_V2, rest)) => {
+                data = rest;
+                Self::deserialize(&mut data)
+            }
+            _ => Self::deserialize_legacy(&mut data),
+        }
+        .map_err(|_| ProgramError::from(AudiusError::InvalidInstruction))?;
+        if !data.is_empty() {
+            return Err(AudiusError::InvalidInstruction.into());
+        }
+        instruction.check_args()?;
+        Ok(instruction)
+    }
+
+    /// Reads an instruction in the legacy encoding: the variant tag followed by
+    /// the Borsh serialized fields, with signature data in its tagged serialization.
+    /// Only the instructions with tags 0-3 are accepted, clients predating the v2 encoding
+    /// don't send the others.
+    fn deserialize_legacy(buf: &mut &[u8]) -> io::Result<Self> {
+        let invalid_data = |error: &str| io::Error::new(io::ErrorKind::InvalidData, error);
+        match u8::deserialize(buf)? {
+            0 => Ok(Self::InitSignerGroup),
+            1 => {
+                let eth_address = <[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>::deserialize(buf)?;
+                let signature_data = match u8::deserialize(buf)? {
+                    0 => None,
+                    1 => Some(SignatureData::unpack_legacy_from(buf)?),
+                    _ => return Err(invalid_data("Invalid option tag")),
+                };
+                Ok(Self::InitValidSigner(eth_address, signature_data))
+            }
+            2 => Ok(Self::ClearValidSigner),
+            3 => Ok(Self::ValidateSignature(SignatureData::unpack_legacy_from(
+                buf,
+            )?)),
+            _ => Err(invalid_data(
+                "Instruction isn't available in legacy encoding",
+            )),
+        }
+    }
+
+    /// Checks the argument constraints Borsh deserialization doesn't enforce
+    fn check_args(&self) -> Result<(), ProgramError> {
+        match self {
+            Self::ValidateSignature(signature_data)
+            | Self::ValidateSignatureDigest(signature_data)
+            | Self::InitValidSigner(_, Some(signature_data))
+            | Self::ValidateAndAttest { signature_data, .. } => {
+                signature_data.check_message_len()?
+            }
+            Self::ValidateEd25519Signature(signature_data) => signature_data.check_message_len()?,
+            Self::ValidateSignatures {
+                threshold,
+                signatures,
+            } => {
+                if *threshold == 0
+                    || signatures
+                        .iter()
+                        .any(|signature_data| signature_data.message != signatures[0].message)
+                {
+                    return Err(AudiusError::InvalidInstruction.into());
+                }
+                for signature_data in signatures {
+                    signature_data.check_message_len()?;
+                }
+            }
+            Self::InitValidSignerWithLabel(_, label) | Self::SetSignerLabel(label) => {
+                ValidSigner::unpack_label(label)?;
+            }
+            Self::InitValidSignerExtended(args) => {
+                ValidSigner::unpack_label(&args.label)?;
+            }
+            Self::GovernedInitValidSigner { signatures, .. }
+            | Self::GovernedClearValidSigner { signatures, .. } => {
+                for signature_data in signatures {
+                    signature_data.check_message_len()?;
+                }
+            }
+            Self::BatchValidateSignatures { count, signatures } => {
+                if *count as usize != signatures.len()
+                    || signatures.is_empty()
+                    || signatures.len() > MAX_BATCH_SIGNATURES
+                {
+                    return Err(AudiusError::InvalidInstruction.into());
+                }
+                for signature_data in signatures {
+                    signature_data.check_message_len()?;
+                }
+            }
+            Self::ValidateSignatureBatch(signatures) => {
+                if signatures.is_empty() || signatures.len() > MAX_BATCH_SIGNATURES {
+                    return Err(AudiusError::InvalidInstruction.into());
+                }
+                for signature_data in signatures {
+                    signature_data.check_message_len()?;
+                }
+            }
+            Self::ValidateSignatureWithMerkleProof {
+                signature_data,
+                proof,
+            } => {
+                if proof.len() > MAX_MERKLE_PROOF_LEN {
+                    return Err(AudiusError::InvalidInstruction.into());
+                }
+                signature_data.check_message_len()?;
+            }
+            Self::ExecuteGovernanceProposal { instruction } => match *instruction.0 {
+                Self::InitValidSigner(_, None) | Self::ClearValidSigner => {}
+                _ => return Err(AudiusError::UnsupportedProposalInstruction.into()),
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Packs instruction in the v2 encoding into a buffer of its exact length. Instructions
+    /// which don't fit into [MAX_INSTRUCTION_LEN](../constant.MAX_INSTRUCTION_LEN.html)
+    /// are rejected.
+    pub fn pack(&self) -> Result<Vec<u8>, ProgramError> {
+        let len = self.packed_len();
+        if len > MAX_INSTRUCTION_LEN {
+            return Err(AudiusError::InvalidInstruction.into());
+        }
+        let mut data = Vec::with_capacity(len);
+        data.push(Self::ENCODING_V2);
+        self.serialize(&mut data)?;
+        debug_assert_eq!(data.len(), len);
+        Ok(data)
+    }
+
+    /// Length of the instruction in the v2 encoding, including the encoding byte
+    /// and one byte variant tag
+    pub fn packed_len(&self) -> usize {
+        let pubkey_len = size_of::<Pubkey>();
+        let eth_address_len = SecpSignatureOffsets::ETH_ADDRESS_SIZE;
+        2 + match self {
+            Self::InitSignerGroup
+            | Self::ClearValidSigner
+            | Self::QuerySignerGroup
+            | Self::InitProgramStats
+            | Self::GetProgramStats
+            | Self::AcceptOwnership
+            | Self::CancelProposedOwnership
+            | Self::CloseAttestation
+            | Self::GetOwnershipHistory
+            | Self::CloseSignerGroup
+            | Self::CloseValidSigner => 0,
+            Self::InitValidSigner(_, signature_data) => {
+                eth_address_len
+                    + 1
+                    + signature_data
+                        .as_ref()
+                        .map_or(0, |signature_data| signature_data.packed_len())
+            }
+            Self::ValidateSignature(signature_data)
+            | Self::ValidateSignatureDigest(signature_data) => signature_data.packed_len(),
+            Self::ValidateAndAttest { signature_data, .. } => {
+                signature_data.packed_len() + size_of::<u64>()
+            }
+            Self::InitValidSignerMetadata(_) | Self::UpdateValidSignerMetadata(_) => {
+                MetadataArgs::LEN
+            }
+            Self::InitValidSignerEd25519(_)
+            | Self::ProposeOwnership(_)
+            | Self::SetSignerCosigner(_)
+            | Self::AddToCreationWhitelist(_)
+            | Self::RemoveFromCreationWhitelist(_)
+            | Self::SetDelegateOperator(_)
+            | Self::MigrateValidSigner { .. }
+            | Self::SetGovernanceRealm(_) => pubkey_len,
+            Self::CloneSignerGroup { .. } => pubkey_len + 1,
+            Self::ValidateEd25519Signature(signature_data) => signature_data.packed_len(),
+            Self::AddSignerAddress(_)
+            | Self::RemoveSignerAddress(_)
+            | Self::InitSignerGroupWithSigner(_)
+            | Self::RevokeEthAddress { .. } => eth_address_len,
+            Self::InitValidSignerWithLabel(_, _) => eth_address_len + ValidSigner::LABEL_SIZE,
+            Self::InitValidSignerFromPubkey(_) => CompressedSecpPubkey::LEN,
+            Self::SetSignerLabel(_) => ValidSigner::LABEL_SIZE,
+            Self::InitValidSignerExtended(_) => InitValidSignerArgs::LEN,
+            Self::SetActivationSlot(_) | Self::SetSignerExpiry(_) => size_of::<u64>(),
+            Self::SetEthPoPRequired(_)
+            | Self::SetCosignerRequired(_)
+            | Self::SetCreationWhitelistBypass(_)
+            | Self::SetLockdownMode(_) => 1,
+            Self::SetGovernance(..) => 2,
+            Self::SetValidationFee(_, _) => size_of::<u64>() + pubkey_len,
+            Self::SetValidationQuota(_) | Self::UpdateMaxSigners(_) => size_of::<u32>(),
+            Self::ValidateSignatures { signatures, .. }
+            | Self::BatchValidateSignatures { signatures, .. } => {
+                1 + 4
+                    + signatures
+                        .iter()
+                        .map(SignatureData::packed_len)
+                        .sum::<usize>()
+            }
+            Self::ValidateSignatureBatch(signatures) => {
+                4 + signatures
+                    .iter()
+                    .map(SignatureData::packed_len)
+                    .sum::<usize>()
+            }
+            Self::GovernedInitValidSigner { signatures, .. }
+            | Self::GovernedClearValidSigner { signatures, .. } => {
+                eth_address_len
+                    + 4
+                    + signatures
+                        .iter()
+                        .map(SignatureData::packed_len)
+                        .sum::<usize>()
+            }
+            Self::AddMessagePrefix(_) | Self::RemoveMessagePrefix(_) => {
+                SignerGroup::MESSAGE_PREFIX_SIZE
+            }
+            Self::ValidateSignatureWithMerkleProof {
+                signature_data,
+                proof,
+            } => signature_data.packed_len() + 4 + proof.len() * keccak::HASH_BYTES,
+            Self::UpdateMerkleRoot(_) => keccak::HASH_BYTES,
+            // nested instruction is serialized without the encoding byte
+            Self::ExecuteGovernanceProposal { instruction } => instruction.0.packed_len() - 1,
+        }
+    }
+}
+
+/// `is_writable` and `is_signer` flags of the account expected by instruction
+type AccountFlags = (bool, bool);
+
+/// Flags of the accounts required by instruction followed by the groups of optional
+/// accounts, each group is either given in full or omitted
+type AccountLayout = (&'static [AccountFlags], &'static [&'static [AccountFlags]]);
+
+const READONLY: AccountFlags = (false, false);
+const WRITABLE: AccountFlags = (true, false);
+const SIGNER: AccountFlags = (false, true);
+const WRITABLE_SIGNER: AccountFlags = (true, true);
+
+impl AudiusInstruction {
+    /// Account layout of the instruction, as documented for each variant
+    fn account_layout(&self) -> AccountLayout {
+        match self {
+            Self::InitSignerGroup => (
+                &[
+                    WRITABLE,
+                    READONLY,
+                    READONLY,
+                    SIGNER,
+                    WRITABLE,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                ],
+                &[&[WRITABLE]],
+            ),
+            Self::InitValidSigner(_, Some(_)) => (
+                &[
+                    WRITABLE,
+                    WRITABLE,
+                    SIGNER,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                    READONLY,
+                    READONLY,
+                ],
+                &[&[WRITABLE], &[WRITABLE]],
+            ),
+            Self::InitValidSigner(_, None)
+            | Self::InitValidSignerWithLabel(..)
+            | Self::InitValidSignerExtended(_)
+            | Self::InitValidSignerFromPubkey(_) => (
+                &[
+                    WRITABLE,
+                    WRITABLE,
+                    SIGNER,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                    READONLY,
+                ],
+                &[&[WRITABLE], &[WRITABLE]],
+            ),
+            Self::InitValidSignerEd25519(_) => {
+                (&[WRITABLE, WRITABLE, SIGNER], &[&[WRITABLE], &[WRITABLE]])
+            }
+            Self::ClearValidSigner => (
+                &[WRITABLE, WRITABLE, SIGNER],
+                &[&[WRITABLE], &[WRITABLE], &[WRITABLE]],
+            ),
+            Self::GovernedInitValidSigner { .. } => (
+                &[
+                    WRITABLE,
+                    WRITABLE,
+                    READONLY,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                    READONLY,
+                ],
+                &[&[WRITABLE], &[WRITABLE]],
+            ),
+            Self::GovernedClearValidSigner { .. } => (
+                &[WRITABLE, WRITABLE, READONLY],
+                &[&[WRITABLE], &[WRITABLE], &[WRITABLE]],
+            ),
+            Self::ValidateSignature(_)
+            | Self::ValidateSignatureDigest(_)
+            | Self::ValidateSignatureBatch(_) => (
+                &[WRITABLE, WRITABLE, READONLY],
+                &[
+                    &[SIGNER],
+                    &[WRITABLE_SIGNER, WRITABLE, READONLY],
+                    &[WRITABLE],
+                ],
+            ),
+            Self::ValidateEd25519Signature(_) => (
+                &[WRITABLE, WRITABLE, READONLY],
+                &[&[WRITABLE_SIGNER, WRITABLE, READONLY], &[WRITABLE]],
+            ),
+            Self::ValidateSignatures { .. } | Self::BatchValidateSignatures { .. } => (
+                &[WRITABLE, READONLY],
+                &[&[WRITABLE_SIGNER, WRITABLE, READONLY], &[WRITABLE]],
+            ),
+            Self::ValidateSignatureWithMerkleProof { .. } => (
+                &[WRITABLE, READONLY, READONLY],
+                &[&[WRITABLE_SIGNER, WRITABLE, READONLY], &[WRITABLE]],
+            ),
+            Self::InitSignerGroupWithSigner(_) => (
+                &[
+                    WRITABLE,
+                    SIGNER,
+                    READONLY,
+                    WRITABLE,
+                    WRITABLE,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                    READONLY,
+                ],
+                &[&[WRITABLE]],
+            ),
+            Self::MigrateValidSigner { .. } => (
+                &[WRITABLE, WRITABLE, SIGNER, WRITABLE, SIGNER, READONLY],
+                &[&[WRITABLE], &[WRITABLE], &[WRITABLE]],
+            ),
+            Self::RevokeEthAddress { .. } => (
+                &[
+                    READONLY,
+                    SIGNER,
+                    WRITABLE,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                ],
+                &[],
+            ),
+            Self::ValidateAndAttest { .. } => (
+                &[
+                    WRITABLE,
+                    WRITABLE,
+                    READONLY,
+                    WRITABLE,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                ],
+                &[&[WRITABLE_SIGNER, WRITABLE, READONLY], &[WRITABLE]],
+            ),
+            Self::CloseAttestation => (&[WRITABLE, WRITABLE], &[]),
+            Self::CloseSignerGroup => (&[WRITABLE, SIGNER, WRITABLE, WRITABLE], &[]),
+            Self::CloseValidSigner => (
+                &[WRITABLE, WRITABLE, SIGNER, WRITABLE],
+                &[&[WRITABLE], &[WRITABLE], &[WRITABLE]],
+            ),
+            Self::GetOwnershipHistory => (&[READONLY, READONLY], &[]),
+            Self::AddToCreationWhitelist(_)
+            | Self::RemoveFromCreationWhitelist(_)
+            | Self::SetCreationWhitelistBypass(_)
+            | Self::SetLockdownMode(_) => (
+                &[
+                    WRITABLE,
+                    READONLY,
+                    SIGNER,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                ],
+                &[],
+            ),
+            Self::AcceptOwnership => (
+                &[
+                    WRITABLE,
+                    SIGNER,
+                    WRITABLE,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                    WRITABLE,
+                    WRITABLE,
+                ],
+                &[],
+            ),
+            Self::QuerySignerGroup | Self::GetProgramStats => (&[READONLY], &[]),
+            Self::InitValidSignerMetadata(_) => (
+                &[
+                    WRITABLE,
+                    READONLY,
+                    READONLY,
+                    SIGNER,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                ],
+                &[],
+            ),
+            Self::UpdateValidSignerMetadata(_) => (&[WRITABLE, READONLY, READONLY, SIGNER], &[]),
+            Self::InitProgramStats => (&[WRITABLE, WRITABLE_SIGNER, READONLY, READONLY], &[]),
+            Self::AddSignerAddress(_) => (&[WRITABLE, READONLY, SIGNER, READONLY], &[]),
+            Self::RemoveSignerAddress(_)
+            | Self::SetSignerLabel(_)
+            | Self::SetActivationSlot(_)
+            | Self::SetSignerExpiry(_)
+            | Self::SetSignerCosigner(_) => (&[WRITABLE, READONLY, SIGNER], &[]),
+            Self::ProposeOwnership(_)
+            | Self::SetDelegateOperator(_)
+            | Self::CancelProposedOwnership
+            | Self::SetEthPoPRequired(_)
+            | Self::SetValidationFee(..)
+            | Self::SetValidationQuota(_)
+            | Self::UpdateMaxSigners(_)
+            | Self::AddMessagePrefix(_)
+            | Self::RemoveMessagePrefix(_)
+            | Self::SetCosignerRequired(_)
+            | Self::SetGovernance(..)
+            | Self::UpdateMerkleRoot(_)
+            | Self::SetGovernanceRealm(_) => (&[WRITABLE, SIGNER], &[]),
+            Self::ExecuteGovernanceProposal { instruction } => instruction.0.account_layout(),
+            Self::CloneSignerGroup { .. } => (
+                &[
+                    WRITABLE,
+                    SIGNER,
+                    READONLY,
+                    WRITABLE,
+                    READONLY,
+                    WRITABLE_SIGNER,
+                    READONLY,
+                    READONLY,
+                    READONLY,
+                ],
+                &[],
+            ),
+        }
+    }
+
+    /// Minimum and maximum number of accounts the instruction takes,
+    /// with optional accounts omitted and given in full
+    pub fn account_count_bounds(&self) -> (usize, usize) {
+        let (required, optional) = self.account_layout();
+        let (trailing_count, trailing_flags) = self.trailing_accounts();
+        let min_count = self.leading_signers().0
+            + required.len()
+            + trailing_count * trailing_flags.len()
+            + usize::from(self.takes_program_config());
+        let optional_count: usize = optional.iter().map(|group| group.len()).sum();
+        (min_count, min_count + optional_count)
+    }
+
+    /// Number and flags of valid signer accounts the instruction's layout starts with
+    fn leading_signers(&self) -> (usize, AccountFlags) {
+        match self {
+            Self::ValidateSignatures { signatures, .. }
+            | Self::BatchValidateSignatures { signatures, .. } => (signatures.len(), WRITABLE),
+            Self::GovernedInitValidSigner { signatures, .. }
+            | Self::GovernedClearValidSigner { signatures, .. } => (signatures.len(), READONLY),
+            _ => (0, READONLY),
+        }
+    }
+
+    /// Number of repeats and flags of the account group the instruction's layout ends with
+    fn trailing_accounts(&self) -> (usize, &'static [AccountFlags]) {
+        match self {
+            Self::CloneSignerGroup { signer_count, .. } => {
+                (*signer_count as usize, &[READONLY, WRITABLE])
+            }
+            _ => (0, &[]),
+        }
+    }
+
+    /// Check if the instruction changes groups or valid signers, such instructions take
+    /// the program config as their last account and fail while the program is locked down
+    pub fn takes_program_config(&self) -> bool {
+        match self {
+            Self::ValidateSignature(_)
+            | Self::ValidateSignatureDigest(_)
+            | Self::ValidateSignatureBatch(_)
+            | Self::ValidateEd25519Signature(_)
+            | Self::ValidateSignatures { .. }
+            | Self::BatchValidateSignatures { .. }
+            | Self::ValidateSignatureWithMerkleProof { .. }
+            | Self::ValidateAndAttest { .. }
+            | Self::CloseAttestation
+            | Self::QuerySignerGroup
+            | Self::GetOwnershipHistory
+            | Self::InitProgramStats
+            | Self::GetProgramStats
+            | Self::AddToCreationWhitelist(_)
+            | Self::RemoveFromCreationWhitelist(_)
+            | Self::SetCreationWhitelistBypass(_)
+            | Self::SetLockdownMode(_) => false,
+            Self::ExecuteGovernanceProposal { instruction } => instruction.0.takes_program_config(),
+            _ => true,
+        }
+    }
+}
+
+/// Checks that leading accounts have exactly the given flags
+fn accounts_match(accounts: &[AccountMeta], flags: &[AccountFlags]) -> bool {
+    accounts.len() >= flags.len()
+        && accounts
+            .iter()
+            .zip(flags)
+            .all(|(account, (is_writable, is_signer))| {
+                account.is_writable == *is_writable && account.is_signer == *is_signer
+            })
+}
+
+/// Checks that `is_writable` and `is_signer` flags of instruction accounts match
+/// the layout documented for the instruction
+pub fn verify_accounts(instruction: &Instruction) -> Result<(), ProgramError> {
+    let audius_instruction = AudiusInstruction::unpack(&instruction.data)?;
+    let accounts = if audius_instruction.takes_program_config() {
+        match instruction.accounts.split_last() {
+            Some((program_config, accounts))
+                if !program_config.is_writable && !program_config.is_signer =>
+            {
+                accounts
+            }
+            _ => return Err(AudiusError::InvalidAccountMeta.into()),
+        }
+    } else {
+        &instruction.accounts[..]
+    };
+    let (required, optional) = audius_instruction.account_layout();
+    let (leading_signer_count, leading_signer_flags) = audius_instruction.leading_signers();
+    if !accounts_match(accounts, &vec![leading_signer_flags; leading_signer_count]) {
+        return Err(AudiusError::InvalidAccountMeta.into());
+    }
+    let accounts = &accounts[leading_signer_count..];
+    if !accounts_match(accounts, required) {
+        return Err(AudiusError::InvalidAccountMeta.into());
+    }
+    let mut accounts = &accounts[required.len()..];
+    for group in optional {
+        if accounts_match(accounts, group) {
+            accounts = &accounts[group.len()..];
+        }
+    }
+    let (trailing_count, trailing_flags) = audius_instruction.trailing_accounts();
+    if accounts.len() != trailing_count * trailing_flags.len()
+        || !accounts
+            .chunks(trailing_flags.len().max(1))
+            .all(|group| accounts_match(group, trailing_flags))
+    {
+        return Err(AudiusError::InvalidAccountMeta.into());
+    }
+    Ok(())
+}
+
+/// Returns instruction built by the program's builder after verifying its accounts
+fn verified(instruction: Instruction) -> Result<Instruction, ProgramError> {
+    verify_accounts(&instruction)?;
+    Ok(instruction)
+}
+
+/// Appends program stats account if stats tracking is enabled
+fn push_stats_account(program_id: &Pubkey, accounts: &mut Vec<AccountMeta>) {
+    if cfg!(feature = "track-stats") {
+        let (stats_account, _) = find_program_stats_address(program_id);
+        accounts.push(AccountMeta::new(stats_account, false));
+    }
+}
+
+/// Appends program config account, checked by the program for lockdown mode
+fn push_program_config(program_id: &Pubkey, accounts: &mut Vec<AccountMeta>) {
+    let (program_config, _) = find_program_config_address(program_id);
+    accounts.push(AccountMeta::new_readonly(program_config, false));
 }
 
-/// Creates `InitSignerGroup` instruction
+/// Creates `InitSignerGroup` instruction signed by the creation authority, which has to be
+/// in the creation whitelist while it's enforced. The creation authority pays for the owner
+/// registry, its address is derived with [find_owner_registry_address].
 pub fn init_signer_group(
     program_id: &Pubkey,
     signer_group: &Pubkey,
     owner: &Pubkey,
+    creation_authority: &Pubkey,
 ) -> Result<Instruction, ProgramError> {
-    let accounts = vec![
+    SignerGroup::check_new_owner(signer_group, owner)?;
+    let (owner_registry, _) = find_owner_registry_address(program_id, owner);
+    let (creation_whitelist, _) = find_creation_whitelist_address(program_id);
+    let mut accounts = vec![
         AccountMeta::new(*signer_group, false),
         AccountMeta::new_readonly(*owner, false),
+        AccountMeta::new_readonly(creation_whitelist, false),
+        AccountMeta::new_readonly(*creation_authority, true),
+        AccountMeta::new(owner_registry, false),
+        AccountMeta::new(*creation_authority, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
     ];
-    Ok(Instruction {
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::InitSignerGroup.pack()?,
+    })
+}
+
+/// Creates `InitSignerGroupWithSigner` instruction, valid signer account address is derived
+/// with [find_valid_signer_address]
+pub fn init_signer_group_with_signer(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    eth_pubkey: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+) -> Result<Instruction, ProgramError> {
+    SignerGroup::check_new_owner(signer_group, owner)?;
+    let (valid_signer_account, _) =
+        find_valid_signer_address(program_id, signer_group, &eth_pubkey);
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+    let (owner_registry, _) = find_owner_registry_address(program_id, owner);
+    let (creation_whitelist, _) = find_creation_whitelist_address(program_id);
+    let mut accounts = vec![
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new_readonly(creation_whitelist, false),
+        AccountMeta::new(owner_registry, false),
+        AccountMeta::new(valid_signer_account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(revocation_registry, false),
+    ];
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
         program_id: *program_id,
         accounts,
-        data: AudiusInstruction::InitSignerGroup.try_to_vec()?,
+        data: AudiusInstruction::InitSignerGroupWithSigner(eth_pubkey).pack()?,
     })
 }
 
-/// Creates `InitValidSigner` instruction
+/// Creates `InitValidSigner` instruction, valid signer account address is derived
+/// with [find_valid_signer_address]
 pub fn init_valid_signer(
     program_id: &Pubkey,
-    valid_signer_account: &Pubkey,
     signer_group: &Pubkey,
     groups_owner: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
     eth_pubkey: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
 ) -> Result<Instruction, ProgramError> {
-    let args = AudiusInstruction::InitValidSigner(eth_pubkey);
-    let data = args.try_to_vec()?;
+    init_valid_signer_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        payer,
+        last_signer,
+        &eth_pubkey,
+        AudiusInstruction::InitValidSigner(eth_pubkey, None),
+    )
+}
 
-    let accounts = vec![
-        AccountMeta::new(*valid_signer_account, false),
-        AccountMeta::new_readonly(*signer_group, false),
+/// Creates `InitValidSigner` instruction with eth key holder's proof of possession,
+/// Secp256 instruction verifying it must be a part of the same transaction
+pub fn init_valid_signer_with_pop(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    eth_pubkey: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    eth_pop_signature: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    eth_pop_signature.check_message_len()?;
+    init_valid_signer_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        payer,
+        last_signer,
+        &eth_pubkey,
+        AudiusInstruction::InitValidSigner(eth_pubkey, Some(eth_pop_signature)),
+    )
+}
+
+/// Creates `InitValidSignerWithLabel` instruction
+pub fn init_valid_signer_with_label(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    eth_pubkey: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    label: &str,
+) -> Result<Instruction, ProgramError> {
+    init_valid_signer_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        payer,
+        last_signer,
+        &eth_pubkey,
+        AudiusInstruction::InitValidSignerWithLabel(eth_pubkey, ValidSigner::pack_label(label)?),
+    )
+}
+
+/// Creates `InitValidSignerExtended` instruction
+pub fn init_valid_signer_extended(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    args: InitValidSignerArgs,
+) -> Result<Instruction, ProgramError> {
+    ValidSigner::unpack_label(&args.label)?;
+    let eth_address = args.eth_address;
+    init_valid_signer_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        payer,
+        last_signer,
+        &eth_address,
+        AudiusInstruction::InitValidSignerExtended(args),
+    )
+}
+
+/// Creates `InitValidSignerFromPubkey` instruction, valid signer account address is derived
+/// from eth address of the compressed public key
+pub fn init_valid_signer_from_pubkey(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    pubkey: &[u8; COMPRESSED_PUBKEY_SIZE],
+) -> Result<Instruction, ProgramError> {
+    let pubkey = CompressedSecpPubkey::new(pubkey);
+    init_valid_signer_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        payer,
+        last_signer,
+        &pubkey.eth_address()?,
+        AudiusInstruction::InitValidSignerFromPubkey(pubkey),
+    )
+}
+
+/// Creates instruction initializing Secp256k1 valid signer and appending it to the group
+fn init_valid_signer_instruction(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    args: AudiusInstruction,
+) -> Result<Instruction, ProgramError> {
+    let data = args.pack()?;
+    let (valid_signer_account, _) =
+        find_valid_signer_address(program_id, signer_group, eth_address);
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+
+    let mut accounts = vec![
+        AccountMeta::new(valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
         AccountMeta::new_readonly(*groups_owner, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(revocation_registry, false),
     ];
-    Ok(Instruction {
+    if let AudiusInstruction::InitValidSigner(_, Some(_)) = &args {
+        accounts.push(AccountMeta::new_readonly(sysvar::instructions::id(), false));
+    }
+    if let Some(last_signer) = last_signer {
+        accounts.push(AccountMeta::new(*last_signer, false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
         program_id: *program_id,
         accounts,
         data,
@@ -95,60 +1780,2830 @@ pub fn clear_valid_signer(
     valid_signer_account: &Pubkey,
     signer_group: &Pubkey,
     groups_owner: &Pubkey,
+    prev_signer: Option<&Pubkey>,
+    next_signer: Option<&Pubkey>,
 ) -> Result<Instruction, ProgramError> {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*valid_signer_account, false),
-        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new(*signer_group, false),
         AccountMeta::new_readonly(*groups_owner, true),
     ];
-    Ok(Instruction {
+    for list_signer in prev_signer.iter().chain(next_signer.iter()) {
+        accounts.push(AccountMeta::new(**list_signer, false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::ClearValidSigner.pack()?,
+    })
+}
+
+/// Creates `MigrateValidSigner` instruction, list signers are the neighbours of the valid
+/// signer in the group it leaves and the last signer of the group it joins
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_valid_signer(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    old_signer_group: &Pubkey,
+    new_signer_group: &Pubkey,
+    old_owner: &Pubkey,
+    new_owner: &Pubkey,
+    prev_signer: Option<&Pubkey>,
+    next_signer: Option<&Pubkey>,
+    new_last_signer: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, new_signer_group);
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*old_signer_group, false),
+        AccountMeta::new_readonly(*old_owner, true),
+        AccountMeta::new(*new_signer_group, false),
+        AccountMeta::new_readonly(*new_owner, true),
+        AccountMeta::new_readonly(revocation_registry, false),
+    ];
+    for list_signer in prev_signer
+        .iter()
+        .chain(next_signer.iter())
+        .chain(new_last_signer.iter())
+    {
+        accounts.push(AccountMeta::new(**list_signer, false));
+    }
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
         program_id: *program_id,
         accounts,
-        data: AudiusInstruction::ClearValidSigner.try_to_vec()?,
+        data: AudiusInstruction::MigrateValidSigner {
+            new_signer_group: *new_signer_group,
+        }
+        .pack()?,
     })
 }
 
-/// Creates `ValidateSignature` instruction
-pub fn validate_signature(
+/// Creates `ValidateSignature` instruction, signature data carries the raw signed message
+pub fn validate_signature_raw(
     program_id: &Pubkey,
     valid_signer_account: &Pubkey,
     signer_group: &Pubkey,
     signature_data: SignatureData,
 ) -> Result<Instruction, ProgramError> {
-    let args = AudiusInstruction::ValidateSignature(signature_data);
-    let data = args.try_to_vec()?;
+    signature_data.check_message_len()?;
+    validation_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        None,
+        None,
+        AudiusInstruction::ValidateSignature(signature_data),
+    )
+}
 
-    let accounts = vec![
-        AccountMeta::new_readonly(*valid_signer_account, false),
-        AccountMeta::new_readonly(*signer_group, false),
+/// Creates `ValidateSignatureDigest` instruction, signature data carries Keccak256 digest
+/// of the signed message instead of the message
+pub fn validate_signature_digest(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    signature_data: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    if signature_data.message.len() != keccak::HASH_BYTES {
+        return Err(AudiusError::InvalidDigestLength.into());
+    }
+    validation_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        None,
+        None,
+        AudiusInstruction::ValidateSignatureDigest(signature_data),
+    )
+}
+
+/// Creates `ValidateSignature` instruction paying group's validation fee
+pub fn validate_signature_with_fee(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    fee_payer: &Pubkey,
+    treasury: &Pubkey,
+    signature_data: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    signature_data.check_message_len()?;
+    validation_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        None,
+        Some((fee_payer, treasury)),
+        AudiusInstruction::ValidateSignature(signature_data),
+    )
+}
+
+/// Creates `ValidateSignature` instruction co-signed by valid signer's cosigner
+pub fn validate_signature_with_cosigner(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    cosigner: &Pubkey,
+    signature_data: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    signature_data.check_message_len()?;
+    validation_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        Some(cosigner),
+        None,
+        AudiusInstruction::ValidateSignature(signature_data),
+    )
+}
+
+/// Creates signature validation instruction, fee accounts are given as payer and treasury
+fn validation_instruction(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    cosigner: Option<&Pubkey>,
+    fee_accounts: Option<(&Pubkey, &Pubkey)>,
+    args: AudiusInstruction,
+) -> Result<Instruction, ProgramError> {
+    let data = args.pack()?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
         AccountMeta::new_readonly(sysvar::instructions::id(), false),
     ];
-    Ok(Instruction {
+    if let Some(cosigner) = cosigner {
+        accounts.push(AccountMeta::new_readonly(*cosigner, true));
+    }
+    if let Some((fee_payer, treasury)) = fee_accounts {
+        accounts.push(AccountMeta::new(*fee_payer, true));
+        accounts.push(AccountMeta::new(*treasury, false));
+        accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    verified(Instruction {
         program_id: *program_id,
         accounts,
         data,
     })
 }
 
-/// Creates `ValidateSignatureWithSysvar` instruction
-pub fn validate_signature_with_sysvar(
+/// Creates `ValidateAndAttest` instruction, attestation account address is derived
+/// with [find_attestation_address]. Fee accounts are given as payer and treasury
+/// for the groups charging validation fee
+pub fn validate_and_attest(
     program_id: &Pubkey,
     valid_signer_account: &Pubkey,
     signer_group: &Pubkey,
-    sysvar_instruction: &Pubkey,
+    payer: &Pubkey,
+    fee_accounts: Option<(&Pubkey, &Pubkey)>,
     signature_data: SignatureData,
+    expires_at_slot: u64,
 ) -> Result<Instruction, ProgramError> {
-    let args = AudiusInstruction::ValidateSignature(signature_data);
-    let data = args.try_to_vec()?;
+    signature_data.check_message_len()?;
+    let (attestation, _) = find_attestation_address(
+        program_id,
+        valid_signer_account,
+        &keccak::hash(&signature_data.message).0,
+    );
 
-    let accounts = vec![
-        AccountMeta::new_readonly(*valid_signer_account, false),
-        AccountMeta::new_readonly(*signer_group, false),
-        AccountMeta::new_readonly(*sysvar_instruction, false),
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(attestation, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
     ];
-    Ok(Instruction {
+    if let Some((fee_payer, treasury)) = fee_accounts {
+        accounts.push(AccountMeta::new(*fee_payer, true));
+        accounts.push(AccountMeta::new(*treasury, false));
+        accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    verified(Instruction {
         program_id: *program_id,
         accounts,
-        data,
+        data: AudiusInstruction::ValidateAndAttest {
+            signature_data,
+            expires_at_slot,
+        }
+        .pack()?,
     })
 }
+
+/// Creates `CloseAttestation` instruction, rent goes back to `payer` of the attestation
+pub fn close_attestation(
+    program_id: &Pubkey,
+    attestation: &Pubkey,
+    payer: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![
+        AccountMeta::new(*attestation, false),
+        AccountMeta::new(*payer, false),
+    ];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::CloseAttestation.pack()?,
+    })
+}
+
+/// Creates `CloseSignerGroup` instruction, rent of the group goes to `rent_receiver`
+pub fn close_signer_group(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    rent_receiver: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (owner_registry, _) = find_owner_registry_address(program_id, groups_owner);
+    let mut accounts = vec![
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+        AccountMeta::new(*rent_receiver, false),
+        AccountMeta::new(owner_registry, false),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::CloseSignerGroup.pack()?,
+    })
+}
+
+/// Creates `CloseValidSigner` instruction, rent of the valid signer goes to `rent_receiver`
+pub fn close_valid_signer(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    rent_receiver: &Pubkey,
+    prev_signer: Option<&Pubkey>,
+    next_signer: Option<&Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+        AccountMeta::new(*rent_receiver, false),
+    ];
+    for list_signer in prev_signer.iter().chain(next_signer.iter()) {
+        accounts.push(AccountMeta::new(**list_signer, false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::CloseValidSigner.pack()?,
+    })
+}
+
+/// Creates `ValidateSignature` instruction requiring Secp256 instruction
+/// to go right before it in the transaction
+pub fn validate_signature_preceding(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    mut signature_data: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    signature_data.preceding_secp_instruction = true;
+    validate_signature_raw(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        signature_data,
+    )
+}
+
+/// Creates Secp256 program instruction verifying all the given signatures
+/// followed by `ValidateSignature` instruction for each of them.
+/// Secp256 instruction is expected at `secp_instruction_index` in the transaction.
+pub fn validate_signature_multi(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    secp_instruction_index: u8,
+    signatures: Vec<(SignatureData, [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE])>,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let (secp_instruction, signatures) =
+        new_secp256k1_multi_instruction(secp_instruction_index, signatures)?;
+
+    let mut instructions = vec![secp_instruction];
+    for signature_data in signatures {
+        instructions.push(validate_signature_raw(
+            program_id,
+            valid_signer_account,
+            signer_group,
+            signature_data,
+        )?);
+    }
+    Ok(instructions)
+}
+
+/// Creates Secp256 program instruction verifying all the given signatures, returned
+/// signature data points at the signature's position in it
+fn new_secp256k1_multi_instruction(
+    secp_instruction_index: u8,
+    signatures: Vec<(SignatureData, [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE])>,
+) -> Result<(Instruction, Vec<SignatureData>), ProgramError> {
+    let num_signatures =
+        u8::try_from(signatures.len()).map_err(|_| AudiusError::InvalidInstruction)?;
+    if num_signatures == 0 {
+        return Err(AudiusError::InvalidInstruction.into());
+    }
+
+    let mut offsets_data = vec![num_signatures];
+    let mut signatures_data = vec![];
+    let mut signature_datas = vec![];
+    let data_start = 1 + signatures.len() * SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+    for (secp_signature_index, (mut signature_data, eth_address)) in
+        signatures.into_iter().enumerate()
+    {
+        let offsets = SecpSignatureOffsets::new_at(
+            secp_instruction_index,
+            data_start + signatures_data.len(),
+            signature_data.message.len(),
+        )?;
+        offsets_data.extend_from_slice(&offsets.try_to_vec()?);
+        signatures_data.extend_from_slice(&eth_address);
+        signatures_data.extend_from_slice(&signature_data.signature);
+        signatures_data.push(signature_data.recovery_id);
+        signatures_data.extend_from_slice(&signature_data.message);
+
+        signature_data.secp_instruction_index = secp_instruction_index;
+        signature_data.preceding_secp_instruction = false;
+        signature_data.secp_signature_index = secp_signature_index as u8;
+        signature_datas.push(signature_data);
+    }
+    offsets_data.extend_from_slice(&signatures_data);
+
+    let secp_instruction = Instruction {
+        program_id: secp256k1_program::id(),
+        accounts: vec![],
+        data: offsets_data,
+    };
+    Ok((secp_instruction, signature_datas))
+}
+
+/// Creates Secp256 program instruction verifying all the given signatures followed by
+/// `ValidateSignatureBatch` instruction checking all of them against the valid signer.
+/// Secp256 instruction is expected at `secp_instruction_index` in the transaction.
+pub fn validate_signature_batch(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    secp_instruction_index: u8,
+    signatures: Vec<(SignatureData, [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE])>,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let (secp_instruction, signatures) =
+        new_secp256k1_multi_instruction(secp_instruction_index, signatures)?;
+    Ok(vec![
+        secp_instruction,
+        validation_instruction(
+            program_id,
+            valid_signer_account,
+            signer_group,
+            None,
+            None,
+            AudiusInstruction::ValidateSignatureBatch(signatures),
+        )?,
+    ])
+}
+
+/// Creates `ValidateSignatures` instruction, each signature is paired with
+/// the valid signer account it's checked against
+pub fn validate_signatures(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    threshold: u8,
+    signatures: Vec<(Pubkey, SignatureData)>,
+) -> Result<Instruction, ProgramError> {
+    let mut accounts: Vec<AccountMeta> = signatures
+        .iter()
+        .map(|(valid_signer_account, _)| AccountMeta::new(*valid_signer_account, false))
+        .collect();
+    accounts.push(AccountMeta::new(*signer_group, false));
+    accounts.push(AccountMeta::new_readonly(sysvar::instructions::id(), false));
+    push_stats_account(program_id, &mut accounts);
+
+    let args = AudiusInstruction::ValidateSignatures {
+        threshold,
+        signatures: signatures
+            .into_iter()
+            .map(|(_, signature_data)| signature_data)
+            .collect(),
+    };
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: args.pack()?,
+    })
+}
+
+/// Creates Secp256 program instruction verifying all the given signatures followed by
+/// `BatchValidateSignatures` instruction checking each of them against its valid signer.
+/// Secp256 instruction is expected at `secp_instruction_index` in the transaction.
+pub fn batch_validate_signatures(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    secp_instruction_index: u8,
+    signatures: Vec<(
+        Pubkey,
+        SignatureData,
+        [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )>,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let count = u8::try_from(signatures.len()).map_err(|_| AudiusError::InvalidInstruction)?;
+    let mut accounts: Vec<AccountMeta> = signatures
+        .iter()
+        .map(|(valid_signer_account, _, _)| AccountMeta::new(*valid_signer_account, false))
+        .collect();
+    accounts.push(AccountMeta::new(*signer_group, false));
+    accounts.push(AccountMeta::new_readonly(sysvar::instructions::id(), false));
+    push_stats_account(program_id, &mut accounts);
+
+    let (secp_instruction, signatures) = new_secp256k1_multi_instruction(
+        secp_instruction_index,
+        signatures
+            .into_iter()
+            .map(|(_, signature_data, eth_address)| (signature_data, eth_address))
+            .collect(),
+    )?;
+    let args = AudiusInstruction::BatchValidateSignatures { count, signatures };
+    Ok(vec![
+        secp_instruction,
+        verified(Instruction {
+            program_id: *program_id,
+            accounts,
+            data: args.pack()?,
+        })?,
+    ])
+}
+
+/// Creates `ValidateSignatureWithSysvar` instruction
+pub fn validate_signature_with_sysvar(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    sysvar_instruction: &Pubkey,
+    signature_data: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    signature_data.check_message_len()?;
+    let args = AudiusInstruction::ValidateSignature(signature_data);
+    let data = args.pack()?;
+
+    let accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*sysvar_instruction, false),
+    ];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `InitValidSignerEd25519` instruction
+pub fn init_valid_signer_ed25519(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    ed25519_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let args = AudiusInstruction::InitValidSignerEd25519(*ed25519_pubkey);
+    let data = args.pack()?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+    ];
+    if let Some(last_signer) = last_signer {
+        accounts.push(AccountMeta::new(*last_signer, false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `ValidateEd25519Signature` instruction
+pub fn validate_ed25519_signature(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    signature_data: Ed25519SignatureData,
+) -> Result<Instruction, ProgramError> {
+    signature_data.check_message_len()?;
+    validation_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        None,
+        None,
+        AudiusInstruction::ValidateEd25519Signature(signature_data),
+    )
+}
+
+/// Creates `ValidateEd25519Signature` instruction paying group's validation fee
+pub fn validate_ed25519_signature_with_fee(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    fee_payer: &Pubkey,
+    treasury: &Pubkey,
+    signature_data: Ed25519SignatureData,
+) -> Result<Instruction, ProgramError> {
+    signature_data.check_message_len()?;
+    validation_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        None,
+        Some((fee_payer, treasury)),
+        AudiusInstruction::ValidateEd25519Signature(signature_data),
+    )
+}
+
+/// Creates Ed25519 program instruction verifying the signature of the message
+pub fn new_ed25519_instruction(
+    pubkey: &Pubkey,
+    signature: &[u8; Ed25519SignatureOffsets::SIGNATURE_SIZE],
+    message: &[u8],
+) -> Result<Instruction, ProgramError> {
+    let offsets = Ed25519SignatureOffsets::new(message.len())?;
+
+    // number of signatures followed by one byte of padding
+    let mut data = vec![1u8, 0u8];
+    data.extend_from_slice(&offsets.try_to_vec()?);
+    data.extend_from_slice(pubkey.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Ok(Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    })
+}
+
+/// Creates `AddSignerAddress` instruction
+pub fn add_signer_address(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+) -> Result<Instruction, ProgramError> {
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+        AccountMeta::new_readonly(revocation_registry, false),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::AddSignerAddress(eth_address).pack()?,
+    })
+}
+
+/// Creates `RemoveSignerAddress` instruction
+pub fn remove_signer_address(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+) -> Result<Instruction, ProgramError> {
+    valid_signer_update_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::RemoveSignerAddress(eth_address),
+    )
+}
+
+/// Creates `SetSignerLabel` instruction
+pub fn set_signer_label(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    label: &str,
+) -> Result<Instruction, ProgramError> {
+    valid_signer_update_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetSignerLabel(ValidSigner::pack_label(label)?),
+    )
+}
+
+/// Creates `SetActivationSlot` instruction
+pub fn set_activation_slot(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    active_after_slot: u64,
+) -> Result<Instruction, ProgramError> {
+    valid_signer_update_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetActivationSlot(active_after_slot),
+    )
+}
+
+/// Creates `SetSignerExpiry` instruction
+pub fn set_signer_expiry(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    expires_at_slot: u64,
+) -> Result<Instruction, ProgramError> {
+    valid_signer_update_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetSignerExpiry(expires_at_slot),
+    )
+}
+
+/// Creates `SetSignerCosigner` instruction
+pub fn set_signer_cosigner(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    cosigner: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    valid_signer_update_instruction(
+        program_id,
+        valid_signer_account,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetSignerCosigner(*cosigner),
+    )
+}
+
+/// Creates owner-gated instruction updating valid signer in place
+fn valid_signer_update_instruction(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    args: AudiusInstruction,
+) -> Result<Instruction, ProgramError> {
+    let data = args.pack()?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*valid_signer_account, false),
+        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `ProposeOwnership` instruction
+pub fn propose_ownership(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    new_owner: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::ProposeOwnership(*new_owner),
+    )
+}
+
+/// Creates `SetDelegateOperator` instruction
+pub fn set_delegate_operator(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    delegate_operator: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetDelegateOperator(*delegate_operator),
+    )
+}
+
+/// Creates `SetDelegateOperator` instruction removing the group's delegate operator
+pub fn clear_delegate_operator(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    set_delegate_operator(program_id, signer_group, groups_owner, &Pubkey::default())
+}
+
+/// Creates `UpdateMerkleRoot` instruction, the root is computed with [merkle::root](../merkle/fn.root.html)
+/// over the leaves of the members' eth addresses
+pub fn update_merkle_root(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    members_merkle_root: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::UpdateMerkleRoot(members_merkle_root),
+    )
+}
+
+/// Creates `ValidateSignatureWithMerkleProof` instruction, fee accounts are given as payer
+/// and treasury for the groups charging validation fee
+pub fn validate_signature_with_merkle_proof(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    fee_accounts: Option<(&Pubkey, &Pubkey)>,
+    signature_data: SignatureData,
+    proof: Vec<[u8; 32]>,
+) -> Result<Instruction, ProgramError> {
+    signature_data.check_message_len()?;
+    let data = AudiusInstruction::ValidateSignatureWithMerkleProof {
+        signature_data,
+        proof,
+    }
+    .pack()?;
+
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+    let mut accounts = vec![
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new_readonly(revocation_registry, false),
+    ];
+    if let Some((fee_payer, treasury)) = fee_accounts {
+        accounts.push(AccountMeta::new(*fee_payer, true));
+        accounts.push(AccountMeta::new(*treasury, false));
+        accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `SetGovernanceRealm` instruction, zero realm disables execution of its proposals
+pub fn set_governance_realm(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    governance_realm: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetGovernanceRealm(*governance_realm),
+    )
+}
+
+/// Creates `ExecuteGovernanceProposal` instruction to add to the proposal, wrapping
+/// the instruction built with the realm's governance account as the group's owner,
+/// e.g. by [init_valid_signer]() or [clear_valid_signer]()
+pub fn execute_governance_proposal(instruction: Instruction) -> Result<Instruction, ProgramError> {
+    let data = AudiusInstruction::ExecuteGovernanceProposal {
+        instruction: ProposalInstruction(Box::new(AudiusInstruction::unpack(&instruction.data)?)),
+    }
+    .pack()?;
+    verified(Instruction {
+        data,
+        ..instruction
+    })
+}
+
+/// Creates `CloneSignerGroup` instruction, `signers` pair each valid signer of the source
+/// group, in the order of its signer list, with the address of its copy. Copies of Secp256k1
+/// signers are derived with [find_valid_signer_address] from the new group.
+pub fn clone_signer_group(
+    program_id: &Pubkey,
+    source_group: &Pubkey,
+    signer_group: &Pubkey,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    signers: &[(Pubkey, Pubkey)],
+) -> Result<Instruction, ProgramError> {
+    SignerGroup::check_new_owner(signer_group, owner)?;
+    let signer_count = u8::try_from(signers.len()).map_err(|_| AudiusError::InvalidInstruction)?;
+    let (creation_whitelist, _) = find_creation_whitelist_address(program_id);
+    let (owner_registry, _) = find_owner_registry_address(program_id, owner);
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+    let mut accounts = vec![
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new_readonly(creation_whitelist, false),
+        AccountMeta::new(owner_registry, false),
+        AccountMeta::new_readonly(*source_group, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(revocation_registry, false),
+    ];
+    for (source_signer, valid_signer) in signers {
+        accounts.push(AccountMeta::new_readonly(*source_signer, false));
+        accounts.push(AccountMeta::new(*valid_signer, false));
+    }
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::CloneSignerGroup {
+            source_group: *source_group,
+            signer_count,
+        }
+        .pack()?,
+    })
+}
+
+/// Creates `AcceptOwnership` instruction, ownership history account address is derived
+/// with [find_ownership_history_address] and owner registry addresses of the current
+/// `owner` and the pending owner with [find_owner_registry_address]
+pub fn accept_ownership(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    owner: &Pubkey,
+    pending_owner: &Pubkey,
+    payer: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (ownership_history, _) = find_ownership_history_address(program_id, signer_group);
+    let (owner_registry, _) = find_owner_registry_address(program_id, owner);
+    let (pending_owner_registry, _) = find_owner_registry_address(program_id, pending_owner);
+    let mut accounts = vec![
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*pending_owner, true),
+        AccountMeta::new(ownership_history, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(owner_registry, false),
+        AccountMeta::new(pending_owner_registry, false),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::AcceptOwnership.pack()?,
+    })
+}
+
+/// Creates `CancelProposedOwnership` instruction
+pub fn cancel_proposed_ownership(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::CancelProposedOwnership,
+    )
+}
+
+/// Creates `SetEthPoPRequired` instruction
+pub fn set_eth_pop_required(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    eth_pop_required: bool,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetEthPoPRequired(eth_pop_required),
+    )
+}
+
+/// Creates `SetValidationFee` instruction
+pub fn set_validation_fee(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    validation_fee: u64,
+    treasury: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetValidationFee(validation_fee, *treasury),
+    )
+}
+
+/// Creates `SetValidationQuota` instruction
+pub fn set_validation_quota(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    quota_per_window: u32,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetValidationQuota(quota_per_window),
+    )
+}
+
+/// Creates `UpdateMaxSigners` instruction
+pub fn update_max_signers(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    max_valid_signers: u32,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::UpdateMaxSigners(max_valid_signers),
+    )
+}
+
+/// Creates `AddMessagePrefix` instruction
+pub fn add_message_prefix(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    prefix: [u8; SignerGroup::MESSAGE_PREFIX_SIZE],
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::AddMessagePrefix(prefix),
+    )
+}
+
+/// Creates `RemoveMessagePrefix` instruction
+pub fn remove_message_prefix(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    prefix: [u8; SignerGroup::MESSAGE_PREFIX_SIZE],
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::RemoveMessagePrefix(prefix),
+    )
+}
+
+/// Creates `SetCosignerRequired` instruction
+pub fn set_cosigner_required(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    require_cosigner: bool,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetCosignerRequired(require_cosigner),
+    )
+}
+
+/// Creates `SetGovernance` instruction
+pub fn set_governance(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    governed: bool,
+    threshold: u8,
+) -> Result<Instruction, ProgramError> {
+    signer_group_update_instruction(
+        program_id,
+        signer_group,
+        groups_owner,
+        AudiusInstruction::SetGovernance(governed, threshold),
+    )
+}
+
+/// Creates `GovernedInitValidSigner` instruction, approvals are given as approving
+/// valid signer accounts with their signatures of the governance message
+pub fn governed_init_valid_signer(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    payer: &Pubkey,
+    last_signer: Option<&Pubkey>,
+    eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    approvals: Vec<(Pubkey, SignatureData)>,
+) -> Result<Instruction, ProgramError> {
+    let (valid_signer_account, _) =
+        find_valid_signer_address(program_id, signer_group, &eth_address);
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+    let (mut accounts, signatures) = split_approvals(approvals);
+    accounts.extend(vec![
+        AccountMeta::new(valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(revocation_registry, false),
+    ]);
+    if let Some(last_signer) = last_signer {
+        accounts.push(AccountMeta::new(*last_signer, false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::GovernedInitValidSigner {
+            eth_address,
+            signatures,
+        }
+        .pack()?,
+    })
+}
+
+/// Creates `GovernedClearValidSigner` instruction, approvals are given as approving
+/// valid signer accounts with their signatures of the governance message
+pub fn governed_clear_valid_signer(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    prev_signer: Option<&Pubkey>,
+    next_signer: Option<&Pubkey>,
+    approvals: Vec<(Pubkey, SignatureData)>,
+) -> Result<Instruction, ProgramError> {
+    let (valid_signer_account, _) =
+        find_valid_signer_address(program_id, signer_group, &eth_address);
+    let (mut accounts, signatures) = split_approvals(approvals);
+    accounts.extend(vec![
+        AccountMeta::new(valid_signer_account, false),
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ]);
+    for list_signer in prev_signer.iter().chain(next_signer.iter()) {
+        accounts.push(AccountMeta::new(**list_signer, false));
+    }
+    push_stats_account(program_id, &mut accounts);
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::GovernedClearValidSigner {
+            eth_address,
+            signatures,
+        }
+        .pack()?,
+    })
+}
+
+/// Splits governance approvals into readonly approving valid signer accounts and signatures
+fn split_approvals(
+    approvals: Vec<(Pubkey, SignatureData)>,
+) -> (Vec<AccountMeta>, Vec<SignatureData>) {
+    approvals
+        .into_iter()
+        .map(|(valid_signer_account, signature_data)| {
+            (
+                AccountMeta::new_readonly(valid_signer_account, false),
+                signature_data,
+            )
+        })
+        .unzip()
+}
+
+/// Creates `RevokeEthAddress` instruction, revocation registry account address is derived
+/// with [find_revocation_registry_address]
+pub fn revoke_eth_address(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+) -> Result<Instruction, ProgramError> {
+    let (revocation_registry, _) = find_revocation_registry_address(program_id, signer_group);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+        AccountMeta::new(revocation_registry, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::RevokeEthAddress { eth_address }.pack()?,
+    })
+}
+
+/// Creates instruction updating signer group in place, signed by its authority
+fn signer_group_update_instruction(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    authority: &Pubkey,
+    args: AudiusInstruction,
+) -> Result<Instruction, ProgramError> {
+    let data = args.pack()?;
+
+    let mut accounts = vec![
+        AccountMeta::new(*signer_group, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `QuerySignerGroup` instruction
+pub fn query_signer_group(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![AccountMeta::new_readonly(*signer_group, false)];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::QuerySignerGroup.pack()?,
+    })
+}
+
+/// Creates `InitValidSignerMetadata` instruction
+pub fn init_valid_signer_metadata(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    payer: &Pubkey,
+    metadata: MetadataArgs,
+) -> Result<Instruction, ProgramError> {
+    let args = AudiusInstruction::InitValidSignerMetadata(metadata);
+    let data = args.pack()?;
+
+    let (metadata_account, _) =
+        find_valid_signer_metadata_address(program_id, valid_signer_account);
+    let mut accounts = vec![
+        AccountMeta::new(metadata_account, false),
+        AccountMeta::new_readonly(*valid_signer_account, false),
+        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `UpdateValidSignerMetadata` instruction
+pub fn update_valid_signer_metadata(
+    program_id: &Pubkey,
+    valid_signer_account: &Pubkey,
+    signer_group: &Pubkey,
+    groups_owner: &Pubkey,
+    metadata: MetadataArgs,
+) -> Result<Instruction, ProgramError> {
+    let args = AudiusInstruction::UpdateValidSignerMetadata(metadata);
+    let data = args.pack()?;
+
+    let (metadata_account, _) =
+        find_valid_signer_metadata_address(program_id, valid_signer_account);
+    let mut accounts = vec![
+        AccountMeta::new(metadata_account, false),
+        AccountMeta::new_readonly(*valid_signer_account, false),
+        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new_readonly(*groups_owner, true),
+    ];
+    push_program_config(program_id, &mut accounts);
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates `InitProgramStats` instruction
+pub fn init_program_stats(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (stats_account, _) = find_program_stats_address(program_id);
+    let accounts = vec![
+        AccountMeta::new(stats_account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::InitProgramStats.pack()?,
+    })
+}
+
+/// Creates `GetProgramStats` instruction
+pub fn get_program_stats(program_id: &Pubkey) -> Result<Instruction, ProgramError> {
+    let (stats_account, _) = find_program_stats_address(program_id);
+    let accounts = vec![AccountMeta::new_readonly(stats_account, false)];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::GetProgramStats.pack()?,
+    })
+}
+
+/// Creates `GetOwnershipHistory` instruction
+pub fn get_ownership_history(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (ownership_history, _) = find_ownership_history_address(program_id, signer_group);
+    let accounts = vec![
+        AccountMeta::new_readonly(*signer_group, false),
+        AccountMeta::new_readonly(ownership_history, false),
+    ];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::GetOwnershipHistory.pack()?,
+    })
+}
+
+/// Creates instruction updating the creation whitelist, signed by the upgrade authority
+/// of the program
+fn creation_whitelist_instruction(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    payer: &Pubkey,
+    instruction: AudiusInstruction,
+) -> Result<Instruction, ProgramError> {
+    let (creation_whitelist, _) = find_creation_whitelist_address(program_id);
+    let (program_data, _) = find_program_data_address(program_id);
+    let accounts = vec![
+        AccountMeta::new(creation_whitelist, false),
+        AccountMeta::new_readonly(program_data, false),
+        AccountMeta::new_readonly(*upgrade_authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.pack()?,
+    })
+}
+
+/// Creates `AddToCreationWhitelist` instruction
+pub fn add_to_creation_whitelist(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    payer: &Pubkey,
+    account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    creation_whitelist_instruction(
+        program_id,
+        upgrade_authority,
+        payer,
+        AudiusInstruction::AddToCreationWhitelist(*account),
+    )
+}
+
+/// Creates `RemoveFromCreationWhitelist` instruction
+pub fn remove_from_creation_whitelist(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    payer: &Pubkey,
+    account: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    creation_whitelist_instruction(
+        program_id,
+        upgrade_authority,
+        payer,
+        AudiusInstruction::RemoveFromCreationWhitelist(*account),
+    )
+}
+
+/// Creates `SetCreationWhitelistBypass` instruction
+pub fn set_creation_whitelist_bypass(
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+    payer: &Pubkey,
+    bypass: bool,
+) -> Result<Instruction, ProgramError> {
+    creation_whitelist_instruction(
+        program_id,
+        upgrade_authority,
+        payer,
+        AudiusInstruction::SetCreationWhitelistBypass(bypass),
+    )
+}
+
+/// Creates `SetLockdownMode` instruction, the authority is the upgrade authority
+/// of the program until the config is created
+pub fn set_lockdown_mode(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    lockdown_mode: u8,
+) -> Result<Instruction, ProgramError> {
+    let (program_config, _) = find_program_config_address(program_id);
+    let (program_data, _) = find_program_data_address(program_id);
+    let accounts = vec![
+        AccountMeta::new(program_config, false),
+        AccountMeta::new_readonly(program_data, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    verified(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: AudiusInstruction::SetLockdownMode(lockdown_mode).pack()?,
+    })
+}
+
+/// Parses base58 encoded pubkey
+fn parse_pubkey(pubkey: &str) -> Result<Pubkey, ProgramError> {
+    Pubkey::from_str(pubkey).map_err(|_| AudiusError::MalformedPubkey.into())
+}
+
+/// Creates `InitSignerGroup` instruction from base58 encoded pubkeys
+pub fn init_signer_group_str(
+    program_id: &str,
+    signer_group: &str,
+    owner: &str,
+    creation_authority: &str,
+) -> Result<Instruction, ProgramError> {
+    init_signer_group(
+        &parse_pubkey(program_id)?,
+        &parse_pubkey(signer_group)?,
+        &parse_pubkey(owner)?,
+        &parse_pubkey(creation_authority)?,
+    )
+}
+
+/// Creates `InitValidSigner` instruction from base58 encoded pubkeys
+pub fn init_valid_signer_str(
+    program_id: &str,
+    signer_group: &str,
+    groups_owner: &str,
+    payer: &str,
+    last_signer: Option<&str>,
+    eth_pubkey: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+) -> Result<Instruction, ProgramError> {
+    init_valid_signer(
+        &parse_pubkey(program_id)?,
+        &parse_pubkey(signer_group)?,
+        &parse_pubkey(groups_owner)?,
+        &parse_pubkey(payer)?,
+        last_signer.map(parse_pubkey).transpose()?.as_ref(),
+        eth_pubkey,
+    )
+}
+
+/// Creates `ClearValidSigner` instruction from base58 encoded pubkeys
+pub fn clear_valid_signer_str(
+    program_id: &str,
+    valid_signer_account: &str,
+    signer_group: &str,
+    groups_owner: &str,
+    prev_signer: Option<&str>,
+    next_signer: Option<&str>,
+) -> Result<Instruction, ProgramError> {
+    clear_valid_signer(
+        &parse_pubkey(program_id)?,
+        &parse_pubkey(valid_signer_account)?,
+        &parse_pubkey(signer_group)?,
+        &parse_pubkey(groups_owner)?,
+        prev_signer.map(parse_pubkey).transpose()?.as_ref(),
+        next_signer.map(parse_pubkey).transpose()?.as_ref(),
+    )
+}
+
+/// Creates `ValidateSignature` instruction from base58 encoded pubkeys
+pub fn validate_signature_str(
+    program_id: &str,
+    valid_signer_account: &str,
+    signer_group: &str,
+    signature_data: SignatureData,
+) -> Result<Instruction, ProgramError> {
+    validate_signature_raw(
+        &parse_pubkey(program_id)?,
+        &parse_pubkey(valid_signer_account)?,
+        &parse_pubkey(signer_group)?,
+        signature_data,
+    )
+}
+
+/// Creates `QuerySignerGroup` instruction from base58 encoded pubkeys
+pub fn query_signer_group_str(
+    program_id: &str,
+    signer_group: &str,
+) -> Result<Instruction, ProgramError> {
+    query_signer_group(&parse_pubkey(program_id)?, &parse_pubkey(signer_group)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_verify_accounts() {
+        let program_id = Pubkey::new_unique();
+        let instructions = vec![
+            init_signer_group(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            init_valid_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            )
+            .unwrap(),
+            clear_valid_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                Some(&Pubkey::new_unique()),
+            )
+            .unwrap(),
+            init_program_stats(&program_id, &Pubkey::new_unique()).unwrap(),
+            accept_ownership(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            get_ownership_history(&program_id, &Pubkey::new_unique()).unwrap(),
+            add_to_creation_whitelist(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            remove_from_creation_whitelist(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            set_lockdown_mode(&program_id, &Pubkey::new_unique(), &Pubkey::new_unique(), 1)
+                .unwrap(),
+            set_creation_whitelist_bypass(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                true,
+            )
+            .unwrap(),
+            validate_signature_digest(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                signature_data(32),
+            )
+            .unwrap(),
+            set_delegate_operator(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            validation_instruction(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                None,
+                AudiusInstruction::ValidateSignatureBatch(vec![signature_data(10)]),
+            )
+            .unwrap(),
+            migrate_valid_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                None,
+                Some(&Pubkey::new_unique()),
+            )
+            .unwrap(),
+            validate_signature_with_merkle_proof(
+                &program_id,
+                &Pubkey::new_unique(),
+                Some((&Pubkey::new_unique(), &Pubkey::new_unique())),
+                signature_data(10),
+                vec![[1; 32]; 3],
+            )
+            .unwrap(),
+            update_merkle_root(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                [1; 32],
+            )
+            .unwrap(),
+            set_governance_realm(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            execute_governance_proposal(
+                init_valid_signer(
+                    &program_id,
+                    &Pubkey::new_unique(),
+                    &Pubkey::new_unique(),
+                    &Pubkey::new_unique(),
+                    Some(&Pubkey::new_unique()),
+                    [1; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+            execute_governance_proposal(
+                clear_valid_signer(
+                    &program_id,
+                    &Pubkey::new_unique(),
+                    &Pubkey::new_unique(),
+                    &Pubkey::new_unique(),
+                    None,
+                    Some(&Pubkey::new_unique()),
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+            validate_signature_with_fee(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                SignatureData::new([0; SecpSignatureOffsets::SECP_SIGNATURE_SIZE], 0, vec![]),
+            )
+            .unwrap(),
+            validate_signatures(
+                &program_id,
+                &Pubkey::new_unique(),
+                2,
+                vec![
+                    (Pubkey::new_unique(), signature_data(10)),
+                    (Pubkey::new_unique(), signature_data(10)),
+                ],
+            )
+            .unwrap(),
+            init_signer_group_with_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                [1; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            )
+            .unwrap(),
+            update_max_signers(&program_id, &Pubkey::new_unique(), &Pubkey::new_unique(), 3)
+                .unwrap(),
+            revoke_eth_address(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                [1; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            )
+            .unwrap(),
+            validate_and_attest(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some((&Pubkey::new_unique(), &Pubkey::new_unique())),
+                signature_data(10),
+                0,
+            )
+            .unwrap(),
+            close_attestation(&program_id, &Pubkey::new_unique(), &Pubkey::new_unique()).unwrap(),
+            close_signer_group(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            close_valid_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                None,
+            )
+            .unwrap(),
+            add_message_prefix(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                *b"track:v1",
+            )
+            .unwrap(),
+            remove_message_prefix(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                *b"track:v1",
+            )
+            .unwrap(),
+            validate_signature_with_cosigner(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                signature_data(10),
+            )
+            .unwrap(),
+            set_cosigner_required(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                true,
+            )
+            .unwrap(),
+            set_signer_cosigner(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+            )
+            .unwrap(),
+            set_governance(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                true,
+                2,
+            )
+            .unwrap(),
+            governed_init_valid_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+                vec![
+                    (Pubkey::new_unique(), signature_data(10)),
+                    (Pubkey::new_unique(), signature_data(10)),
+                ],
+            )
+            .unwrap(),
+            governed_clear_valid_signer(
+                &program_id,
+                &Pubkey::new_unique(),
+                [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+                Some(&Pubkey::new_unique()),
+                None,
+                vec![(Pubkey::new_unique(), signature_data(10))],
+            )
+            .unwrap(),
+            clone_signer_group(
+                &program_id,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &[
+                    (Pubkey::new_unique(), Pubkey::new_unique()),
+                    (Pubkey::new_unique(), Pubkey::new_unique()),
+                ],
+            )
+            .unwrap(),
+        ];
+        for instruction in &instructions {
+            assert_eq!(verify_accounts(instruction), Ok(()));
+            let (min_count, max_count) = AudiusInstruction::unpack(&instruction.data)
+                .unwrap()
+                .account_count_bounds();
+            assert!((min_count..=max_count).contains(&instruction.accounts.len()));
+
+            // every flag of every account is a part of the layout
+            for index in 0..instruction.accounts.len() {
+                let mut tampered = instruction.clone();
+                tampered.accounts[index].is_writable ^= true;
+                assert_eq!(
+                    verify_accounts(&tampered),
+                    Err(AudiusError::InvalidAccountMeta.into())
+                );
+                let mut tampered = instruction.clone();
+                tampered.accounts[index].is_signer ^= true;
+                assert_eq!(
+                    verify_accounts(&tampered),
+                    Err(AudiusError::InvalidAccountMeta.into())
+                );
+            }
+        }
+
+        // optional accounts may be omitted, required ones and program config may not
+        let mut instruction = instructions[2].clone();
+        let program_config = instruction.accounts.pop().unwrap();
+        instruction.accounts.truncate(3);
+        assert_eq!(
+            verify_accounts(&instruction),
+            Err(AudiusError::InvalidAccountMeta.into())
+        );
+        instruction.accounts.push(program_config);
+        assert_eq!(verify_accounts(&instruction), Ok(()));
+        instruction.accounts.remove(2);
+        assert_eq!(
+            verify_accounts(&instruction),
+            Err(AudiusError::InvalidAccountMeta.into())
+        );
+
+        let mut instruction = instructions[4].clone();
+        instruction
+            .accounts
+            .push(AccountMeta::new(Pubkey::new_unique(), false));
+        assert_eq!(
+            verify_accounts(&instruction),
+            Err(AudiusError::InvalidAccountMeta.into())
+        );
+
+        instruction.data = vec![u8::MAX];
+        assert_eq!(
+            verify_accounts(&instruction),
+            Err(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_init_valid_signer_extended() {
+        let args =
+            InitValidSignerArgs::new([7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], "dn", 1000)
+                .unwrap();
+        let program_id = Pubkey::new_unique();
+        let signer_group = Pubkey::new_unique();
+        let instruction = init_valid_signer_extended(
+            &program_id,
+            &signer_group,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            None,
+            args,
+        )
+        .unwrap();
+
+        // valid signer account is derived from the group and eth address
+        let (valid_signer, bump_seed) =
+            find_valid_signer_address(&program_id, &signer_group, &[7u8; 20]);
+        assert_eq!(instruction.accounts[0].pubkey, valid_signer);
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[signer_group.as_ref(), &[7u8; 20], &[bump_seed]],
+                &program_id
+            ),
+            Ok(valid_signer)
+        );
+        assert_ne!(
+            find_valid_signer_address(&program_id, &signer_group, &[8u8; 20]).0,
+            valid_signer
+        );
+        assert_ne!(
+            find_valid_signer_address(&program_id, &Pubkey::new_unique(), &[7u8; 20]).0,
+            valid_signer
+        );
+
+        match AudiusInstruction::unpack(&instruction.data).unwrap() {
+            AudiusInstruction::InitValidSignerExtended(unpacked) => {
+                assert_eq!(unpacked.eth_address, [7u8; 20]);
+                assert_eq!(ValidSigner::unpack_label(&unpacked.label), Ok("dn"));
+                assert_eq!(unpacked.active_after_slot, 1000);
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        let mut args = InitValidSignerArgs::new([7u8; 20], "", 0).unwrap();
+        args.label[1] = b'x';
+        assert_eq!(
+            init_valid_signer_extended(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                None,
+                args,
+            )
+            .err(),
+            Some(AudiusError::InvalidSignerLabel.into())
+        );
+    }
+
+    #[test]
+    fn test_signer_label_instructions() {
+        let instruction = init_valid_signer_with_label(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            None,
+            [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            "",
+        )
+        .unwrap();
+        match AudiusInstruction::unpack(&instruction.data).unwrap() {
+            AudiusInstruction::InitValidSignerWithLabel(_, label) => {
+                assert_eq!(label, [0; ValidSigner::LABEL_SIZE])
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        let mut instruction = set_signer_label(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            "node-1",
+        )
+        .unwrap();
+        match AudiusInstruction::unpack(&instruction.data).unwrap() {
+            AudiusInstruction::SetSignerLabel(label) => {
+                assert_eq!(ValidSigner::unpack_label(&label), Ok("node-1"))
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        // invalid UTF-8 is rejected on unpack
+        instruction.data[2] = 0xff;
+        assert_eq!(
+            AudiusInstruction::unpack(&instruction.data).err(),
+            Some(AudiusError::InvalidSignerLabel.into())
+        );
+
+        assert_eq!(
+            set_signer_label(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &"x".repeat(ValidSigner::LABEL_SIZE + 1),
+            )
+            .err(),
+            Some(AudiusError::SignerLabelTooLong.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_signer_address() {
+        let eth_address = [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        let instruction = add_signer_address(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            eth_address,
+        )
+        .unwrap();
+        assert_eq!(instruction.data[..2], [AudiusInstruction::ENCODING_V2, 11]);
+        assert!(instruction.accounts[0].is_writable);
+        assert!(instruction.accounts[2].is_signer);
+        assert!(!instruction.accounts[3].is_writable);
+        match AudiusInstruction::unpack(&instruction.data).unwrap() {
+            AudiusInstruction::AddSignerAddress(unpacked) => assert_eq!(unpacked, eth_address),
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        let mut data = vec![AudiusInstruction::ENCODING_V2, 12];
+        data.extend_from_slice(&eth_address);
+        match AudiusInstruction::unpack(&data).unwrap() {
+            AudiusInstruction::RemoveSignerAddress(unpacked) => assert_eq!(unpacked, eth_address),
+            _ => panic!("wrong instruction unpacked"),
+        }
+        assert_eq!(
+            AudiusInstruction::unpack(&data[..SecpSignatureOffsets::ETH_ADDRESS_SIZE]).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+        // only the first instructions are available in the legacy encoding
+        assert_eq!(
+            AudiusInstruction::unpack(&data[1..]).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_init_valid_signer() {
+        let eth_address = [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        let mut data = vec![1u8];
+        data.extend_from_slice(&eth_address);
+        // no proof of possession
+        data.push(0);
+
+        match AudiusInstruction::unpack(&data).unwrap() {
+            AudiusInstruction::InitValidSigner(unpacked, None) => {
+                assert_eq!(unpacked, eth_address)
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        data.push(0);
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_short_payload() {
+        let data = [1u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_init_signer_group_str() {
+        let program_id = Pubkey::new_from_array([1; 32]);
+        let signer_group = Pubkey::new_from_array([2; 32]);
+        let owner = Pubkey::new_from_array([3; 32]);
+        let creation_authority = Pubkey::new_from_array([4; 32]);
+
+        let instruction = init_signer_group_str(
+            &program_id.to_string(),
+            &signer_group.to_string(),
+            &owner.to_string(),
+            &creation_authority.to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            instruction,
+            init_signer_group(&program_id, &signer_group, &owner, &creation_authority).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_init_signer_group_invalid_owner() {
+        let program_id = Pubkey::new_from_array([1; 32]);
+        let signer_group = Pubkey::new_from_array([2; 32]);
+        let payer = Pubkey::new_from_array([3; 32]);
+
+        for owner in &[Pubkey::default(), signer_group] {
+            assert_eq!(
+                init_signer_group(&program_id, &signer_group, owner, &payer).err(),
+                Some(AudiusError::InvalidOwner.into())
+            );
+            assert_eq!(
+                init_signer_group_with_signer(&program_id, &signer_group, owner, &payer, [4; 20])
+                    .err(),
+                Some(AudiusError::InvalidOwner.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_signer_group_str_malformed() {
+        let program_id = Pubkey::new_from_array([1; 32]).to_string();
+        let owner = Pubkey::new_from_array([3; 32]).to_string();
+
+        // '0' is not a base58 character
+        assert_eq!(
+            init_signer_group_str(&program_id, "0OIl", &owner, &owner).err(),
+            Some(AudiusError::MalformedPubkey.into())
+        );
+        assert_eq!(
+            query_signer_group_str(&program_id, "").err(),
+            Some(AudiusError::MalformedPubkey.into())
+        );
+    }
+
+    #[test]
+    fn test_clear_valid_signer_list_accounts() {
+        let program_id = Pubkey::new_from_array([1; 32]);
+        let valid_signer = Pubkey::new_from_array([2; 32]);
+        let signer_group = Pubkey::new_from_array([3; 32]);
+        let owner = Pubkey::new_from_array([4; 32]);
+        let prev_signer = Pubkey::new_from_array([5; 32]);
+        let next_signer = Pubkey::new_from_array([6; 32]);
+
+        let instruction = clear_valid_signer(
+            &program_id,
+            &valid_signer,
+            &signer_group,
+            &owner,
+            Some(&prev_signer),
+            Some(&next_signer),
+        )
+        .unwrap();
+        assert_eq!(
+            instruction.accounts[3],
+            AccountMeta::new(prev_signer, false)
+        );
+        assert_eq!(
+            instruction.accounts[4],
+            AccountMeta::new(next_signer, false)
+        );
+
+        let instruction = clear_valid_signer(
+            &program_id,
+            &valid_signer,
+            &signer_group,
+            &owner,
+            None,
+            Some(&next_signer),
+        )
+        .unwrap();
+        assert_eq!(
+            instruction.accounts[3],
+            AccountMeta::new(next_signer, false)
+        );
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&AccountMeta::new_readonly(
+                find_program_config_address(&program_id).0,
+                false
+            ))
+        );
+    }
+
+    fn signature_data(message_len: usize) -> SignatureData {
+        SignatureData::new(
+            [1; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+            0,
+            vec![2; message_len],
+        )
+    }
+
+    #[test]
+    fn test_validate_signature_message_len() {
+        let program_id = Pubkey::new_from_array([1; 32]);
+        let valid_signer = Pubkey::new_from_array([2; 32]);
+        let signer_group = Pubkey::new_from_array([3; 32]);
+
+        assert!(validate_signature_raw(
+            &program_id,
+            &valid_signer,
+            &signer_group,
+            signature_data(MAX_MESSAGE_LEN)
+        )
+        .is_ok());
+        assert_eq!(
+            validate_signature_raw(
+                &program_id,
+                &valid_signer,
+                &signer_group,
+                signature_data(MAX_MESSAGE_LEN + 1)
+            )
+            .err(),
+            Some(AudiusError::MessageTooLarge.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_message_len() {
+        let data = AudiusInstruction::ValidateSignature(signature_data(MAX_MESSAGE_LEN))
+            .pack()
+            .unwrap();
+        assert!(AudiusInstruction::unpack(&data).is_ok());
+
+        let data = AudiusInstruction::ValidateSignature(signature_data(MAX_MESSAGE_LEN + 1))
+            .pack()
+            .unwrap();
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::MessageTooLarge.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_validate_signatures() {
+        let valid_signers = vec![
+            (Pubkey::new_unique(), signature_data(10)),
+            (Pubkey::new_unique(), signature_data(10)),
+        ];
+        let instruction = validate_signatures(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            2,
+            valid_signers,
+        )
+        .unwrap();
+        match AudiusInstruction::unpack(&instruction.data).unwrap() {
+            AudiusInstruction::ValidateSignatures {
+                threshold,
+                signatures,
+            } => {
+                assert_eq!(threshold, 2);
+                assert_eq!(signatures.len(), 2);
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        let data = AudiusInstruction::ValidateSignatures {
+            threshold: 0,
+            signatures: vec![signature_data(10)],
+        }
+        .pack()
+        .unwrap();
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+
+        // all signers sign the same message
+        let data = AudiusInstruction::ValidateSignatures {
+            threshold: 1,
+            signatures: vec![signature_data(10), signature_data(11)],
+        }
+        .pack()
+        .unwrap();
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_unpack_batch_validate_signatures() {
+        let signatures = (0..3)
+            .map(|_| (Pubkey::new_unique(), signature_data(10), [1; 20]))
+            .collect();
+        let instructions =
+            batch_validate_signatures(&Pubkey::new_unique(), &Pubkey::new_unique(), 0, signatures)
+                .unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program_id, secp256k1_program::id());
+        assert_eq!(instructions[0].data[0], 3);
+        match AudiusInstruction::unpack(&instructions[1].data).unwrap() {
+            AudiusInstruction::BatchValidateSignatures { count, signatures } => {
+                assert_eq!(count, 3);
+                for (secp_signature_index, signature_data) in signatures.iter().enumerate() {
+                    assert_eq!(
+                        signature_data.secp_signature_index as usize,
+                        secp_signature_index
+                    );
+                }
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        for (count, signatures) in vec![
+            (2, vec![signature_data(10)]),
+            (0, vec![]),
+            (5, vec![signature_data(10); MAX_BATCH_SIGNATURES + 1]),
+        ] {
+            let data = AudiusInstruction::BatchValidateSignatures { count, signatures }
+                .pack()
+                .unwrap();
+            assert_eq!(
+                AudiusInstruction::unpack(&data).err(),
+                Some(AudiusError::InvalidInstruction.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_unpack_validate_signature_batch() {
+        let signatures = (0..3).map(|_| (signature_data(10), [1; 20])).collect();
+        let instructions = validate_signature_batch(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            0,
+            signatures,
+        )
+        .unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program_id, secp256k1_program::id());
+        assert_eq!(instructions[0].data[0], 3);
+        match AudiusInstruction::unpack(&instructions[1].data).unwrap() {
+            AudiusInstruction::ValidateSignatureBatch(signatures) => {
+                assert_eq!(signatures.len(), 3);
+                for (secp_signature_index, signature_data) in signatures.iter().enumerate() {
+                    assert_eq!(
+                        signature_data.secp_signature_index as usize,
+                        secp_signature_index
+                    );
+                }
+            }
+            _ => panic!("wrong instruction unpacked"),
+        }
+
+        for signatures in vec![vec![], vec![signature_data(10); MAX_BATCH_SIGNATURES + 1]] {
+            let data = AudiusInstruction::ValidateSignatureBatch(signatures)
+                .pack()
+                .unwrap();
+            assert_eq!(
+                AudiusInstruction::unpack(&data).err(),
+                Some(AudiusError::InvalidInstruction.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_unpack_merkle_proof_len() {
+        for (proof_len, result) in vec![
+            (MAX_MERKLE_PROOF_LEN, Ok(())),
+            (
+                MAX_MERKLE_PROOF_LEN + 1,
+                Err(AudiusError::InvalidInstruction.into()),
+            ),
+        ] {
+            let data = AudiusInstruction::ValidateSignatureWithMerkleProof {
+                signature_data: signature_data(10),
+                proof: vec![[1; 32]; proof_len],
+            }
+            .pack()
+            .unwrap();
+            assert_eq!(AudiusInstruction::unpack(&data).map(|_| ()), result);
+        }
+    }
+
+    #[test]
+    fn test_packed_len() {
+        let metadata = MetadataArgs {
+            endpoint_url: [1; ValidSignerMetadata::ENDPOINT_URL_SIZE],
+            operator_name: [2; ValidSignerMetadata::OPERATOR_NAME_SIZE],
+        };
+        let instructions = vec![
+            (AudiusInstruction::InitSignerGroup, 2),
+            (AudiusInstruction::InitValidSigner([1; 20], None), 23),
+            (
+                AudiusInstruction::InitValidSigner([1; 20], Some(signature_data(10))),
+                105,
+            ),
+            (AudiusInstruction::ClearValidSigner, 2),
+            (AudiusInstruction::ValidateSignature(signature_data(10)), 84),
+            (
+                AudiusInstruction::ValidateSignature(signature_data(MAX_MESSAGE_LEN)),
+                330,
+            ),
+            (AudiusInstruction::QuerySignerGroup, 2),
+            (
+                AudiusInstruction::InitValidSignerMetadata(metadata.clone()),
+                194,
+            ),
+            (AudiusInstruction::UpdateValidSignerMetadata(metadata), 194),
+            (AudiusInstruction::InitProgramStats, 2),
+            (AudiusInstruction::GetProgramStats, 2),
+            (
+                AudiusInstruction::InitValidSignerEd25519(Pubkey::new_unique()),
+                34,
+            ),
+            (
+                AudiusInstruction::ValidateEd25519Signature(Ed25519SignatureData {
+                    signature: [1; Ed25519SignatureOffsets::SIGNATURE_SIZE],
+                    message: vec![2; 10],
+                    ed25519_instruction_index: 0,
+                }),
+                81,
+            ),
+            (AudiusInstruction::AddSignerAddress([1; 20]), 22),
+            (AudiusInstruction::RemoveSignerAddress([1; 20]), 22),
+            (
+                AudiusInstruction::InitValidSignerWithLabel([1; 20], [2; ValidSigner::LABEL_SIZE]),
+                54,
+            ),
+            (
+                AudiusInstruction::SetSignerLabel([2; ValidSigner::LABEL_SIZE]),
+                34,
+            ),
+            (
+                AudiusInstruction::InitValidSignerExtended(
+                    InitValidSignerArgs::new([1; 20], "label", 3).unwrap(),
+                ),
+                62,
+            ),
+            (AudiusInstruction::SetActivationSlot(3), 10),
+            (
+                AudiusInstruction::ProposeOwnership(Pubkey::new_unique()),
+                34,
+            ),
+            (AudiusInstruction::AcceptOwnership, 2),
+            (AudiusInstruction::CancelProposedOwnership, 2),
+            (AudiusInstruction::SetSignerExpiry(3), 10),
+            (AudiusInstruction::SetEthPoPRequired(true), 3),
+            (
+                AudiusInstruction::SetValidationFee(3, Pubkey::new_unique()),
+                42,
+            ),
+            (AudiusInstruction::SetValidationQuota(3), 6),
+            (
+                AudiusInstruction::ValidateSignatures {
+                    threshold: 2,
+                    signatures: vec![signature_data(10), signature_data(10)],
+                },
+                171,
+            ),
+            (AudiusInstruction::InitSignerGroupWithSigner([1; 20]), 22),
+            (AudiusInstruction::UpdateMaxSigners(3), 6),
+            (
+                AudiusInstruction::AddMessagePrefix([1; SignerGroup::MESSAGE_PREFIX_SIZE]),
+                10,
+            ),
+            (
+                AudiusInstruction::RemoveMessagePrefix([1; SignerGroup::MESSAGE_PREFIX_SIZE]),
+                10,
+            ),
+            (AudiusInstruction::SetCosignerRequired(true), 3),
+            (
+                AudiusInstruction::SetSignerCosigner(Pubkey::new_unique()),
+                34,
+            ),
+            (AudiusInstruction::SetGovernance(true, 2), 4),
+            (
+                AudiusInstruction::GovernedInitValidSigner {
+                    eth_address: [1; 20],
+                    signatures: vec![signature_data(10), signature_data(10)],
+                },
+                190,
+            ),
+            (
+                AudiusInstruction::GovernedClearValidSigner {
+                    eth_address: [1; 20],
+                    signatures: vec![signature_data(10)],
+                },
+                108,
+            ),
+            (
+                AudiusInstruction::BatchValidateSignatures {
+                    count: 2,
+                    signatures: vec![signature_data(10), signature_data(10)],
+                },
+                171,
+            ),
+            (
+                AudiusInstruction::RevokeEthAddress {
+                    eth_address: [1; 20],
+                },
+                22,
+            ),
+            (
+                AudiusInstruction::ValidateAndAttest {
+                    signature_data: signature_data(10),
+                    expires_at_slot: 100,
+                },
+                92,
+            ),
+            (AudiusInstruction::CloseAttestation, 2),
+            (AudiusInstruction::GetOwnershipHistory, 2),
+            (
+                AudiusInstruction::InitValidSignerFromPubkey(CompressedSecpPubkey {
+                    prefix: 2,
+                    x: [1; 32],
+                }),
+                35,
+            ),
+            (
+                AudiusInstruction::AddToCreationWhitelist(Pubkey::new_unique()),
+                34,
+            ),
+            (
+                AudiusInstruction::RemoveFromCreationWhitelist(Pubkey::new_unique()),
+                34,
+            ),
+            (AudiusInstruction::SetCreationWhitelistBypass(true), 3),
+            (
+                AudiusInstruction::ValidateSignatureDigest(signature_data(32)),
+                106,
+            ),
+            (
+                AudiusInstruction::SetDelegateOperator(Pubkey::new_unique()),
+                34,
+            ),
+            (
+                AudiusInstruction::ValidateSignatureBatch(vec![
+                    signature_data(32),
+                    signature_data(10),
+                ]),
+                2 + 4 + 104 + 82,
+            ),
+            (
+                AudiusInstruction::MigrateValidSigner {
+                    new_signer_group: Pubkey::new_unique(),
+                },
+                34,
+            ),
+            (
+                AudiusInstruction::ValidateSignatureWithMerkleProof {
+                    signature_data: signature_data(10),
+                    proof: vec![[1; 32]; 3],
+                },
+                2 + 82 + 4 + 3 * 32,
+            ),
+            (AudiusInstruction::UpdateMerkleRoot([1; 32]), 34),
+            (AudiusInstruction::SetLockdownMode(1), 3),
+            (AudiusInstruction::CloseSignerGroup, 2),
+            (AudiusInstruction::CloseValidSigner, 2),
+            (
+                AudiusInstruction::CloneSignerGroup {
+                    source_group: Pubkey::new_unique(),
+                    signer_count: 3,
+                },
+                35,
+            ),
+            (
+                AudiusInstruction::SetGovernanceRealm(Pubkey::new_unique()),
+                34,
+            ),
+            (
+                AudiusInstruction::ExecuteGovernanceProposal {
+                    instruction: ProposalInstruction(Box::new(AudiusInstruction::InitValidSigner(
+                        [1; 20], None,
+                    ))),
+                },
+                2 + 1 + 20 + 1,
+            ),
+            (
+                AudiusInstruction::ExecuteGovernanceProposal {
+                    instruction: ProposalInstruction(Box::new(AudiusInstruction::ClearValidSigner)),
+                },
+                3,
+            ),
+        ];
+
+        for (instruction, len) in instructions {
+            assert_eq!(instruction.packed_len(), len);
+            let data = instruction.pack().unwrap();
+            assert_eq!(data.len(), len);
+            assert_eq!(data[0], AudiusInstruction::ENCODING_V2);
+            assert_eq!(data[1..], instruction.try_to_vec().unwrap()[..]);
+            assert_eq!(AudiusInstruction::unpack(&data).unwrap(), instruction);
+        }
+    }
+
+    #[test]
+    fn test_clone_signer_group_accounts() {
+        let program_id = Pubkey::new_unique();
+        let signers = vec![(Pubkey::new_unique(), Pubkey::new_unique()); 3];
+        let instruction = clone_signer_group(
+            &program_id,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &signers,
+        )
+        .unwrap();
+        assert_eq!(instruction.accounts.len(), 9 + 2 * 3 + 1);
+
+        // every source signer comes with its copy
+        let mut truncated = instruction.clone();
+        truncated.accounts.remove(9 + 2 * 3 - 1);
+        assert_eq!(
+            verify_accounts(&truncated),
+            Err(AudiusError::InvalidAccountMeta.into())
+        );
+        let mut extended = instruction;
+        extended
+            .accounts
+            .splice(9..9, truncated.accounts[9..11].to_vec());
+        assert_eq!(
+            verify_accounts(&extended),
+            Err(AudiusError::InvalidAccountMeta.into())
+        );
+    }
+
+    #[test]
+    fn test_execute_governance_proposal() {
+        let program_id = Pubkey::new_unique();
+        let governance = Pubkey::new_unique();
+        let clear = clear_valid_signer(
+            &program_id,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &governance,
+            None,
+            None,
+        )
+        .unwrap();
+        let proposal = execute_governance_proposal(clear.clone()).unwrap();
+        assert_eq!(proposal.accounts, clear.accounts);
+        assert_eq!(
+            AudiusInstruction::unpack(&proposal.data).unwrap(),
+            AudiusInstruction::ExecuteGovernanceProposal {
+                instruction: ProposalInstruction(Box::new(AudiusInstruction::ClearValidSigner))
+            }
+        );
+
+        // only adding and removing valid signers is executed
+        let update =
+            update_merkle_root(&program_id, &Pubkey::new_unique(), &governance, [1; 32]).unwrap();
+        assert_eq!(
+            execute_governance_proposal(update).unwrap_err(),
+            AudiusError::UnsupportedProposalInstruction.into()
+        );
+        assert_eq!(
+            execute_governance_proposal(proposal).unwrap_err(),
+            AudiusError::UnsupportedProposalInstruction.into()
+        );
+        let with_pop = AudiusInstruction::ExecuteGovernanceProposal {
+            instruction: ProposalInstruction(Box::new(AudiusInstruction::InitValidSigner(
+                [1; 20],
+                Some(signature_data(10)),
+            ))),
+        };
+        assert_eq!(
+            AudiusInstruction::unpack(&with_pop.pack().unwrap()).unwrap_err(),
+            AudiusError::UnsupportedProposalInstruction.into()
+        );
+    }
+
+    /// Append field to signature data in the tagged serialization, bumping the number of fields
+    fn with_field(mut data: Vec<u8>, tag: u8, value: &[u8]) -> Vec<u8> {
+        let count_offset = 1 + SecpSignatureOffsets::SECP_SIGNATURE_SIZE + 1;
+        let count = u32::try_from_slice(&data[count_offset..count_offset + 4]).unwrap() + 1;
+        data[count_offset..count_offset + 4].copy_from_slice(&count.to_le_bytes());
+        data.push(tag);
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value);
+        data
+    }
+
+    #[test]
+    fn test_signature_data_round_trip() {
+        let signature_data = signature_data(10);
+        let data = signature_data.try_to_vec().unwrap();
+        assert_eq!(data.len(), signature_data.packed_len());
+        assert_eq!(data.len(), SignatureData::packed_len_with_message(10));
+        assert_eq!(
+            SignatureData::try_from_slice(&data).unwrap(),
+            signature_data
+        );
+
+        let data = signature_data.pack_legacy().unwrap();
+        assert_eq!(data.len(), signature_data.legacy_packed_len());
+        assert_eq!(data[0], SignatureData::FORMAT_V1);
+        assert_eq!(SignatureData::unpack_legacy(&data).unwrap(), signature_data);
+
+        let signature_data = SignatureData {
+            secp_instruction_index: 3,
+            preceding_secp_instruction: true,
+            secp_signature_index: 1,
+            ..signature_data
+        };
+        let data = signature_data.try_to_vec().unwrap();
+        assert_eq!(data.len(), signature_data.packed_len());
+        assert_eq!(
+            SignatureData::try_from_slice(&data).unwrap(),
+            signature_data
+        );
+        let data = signature_data.pack_legacy().unwrap();
+        assert_eq!(data.len(), signature_data.legacy_packed_len());
+        assert_eq!(SignatureData::unpack_legacy(&data).unwrap(), signature_data);
+
+        let instruction = AudiusInstruction::ValidateSignature(signature_data.clone());
+        match AudiusInstruction::unpack(&instruction.pack().unwrap()).unwrap() {
+            AudiusInstruction::ValidateSignature(unpacked) => assert_eq!(unpacked, signature_data),
+            _ => panic!("Wrong instruction unpacked"),
+        }
+    }
+
+    #[test]
+    fn test_signature_data_forward_compat() {
+        let signature_data = SignatureData {
+            secp_signature_index: 2,
+            ..signature_data(10)
+        };
+        let data = signature_data.pack_legacy().unwrap();
+
+        // fields with tags introduced later are skipped wherever they are
+        let future_data = with_field(with_field(data.clone(), 0x80, &[7; 40]), 0xff, &[]);
+        assert_eq!(
+            SignatureData::unpack_legacy(&future_data).unwrap(),
+            signature_data
+        );
+        let mut instruction_data = vec![3];
+        instruction_data.extend_from_slice(&future_data);
+        match AudiusInstruction::unpack(&instruction_data).unwrap() {
+            AudiusInstruction::ValidateSignature(unpacked) => assert_eq!(unpacked, signature_data),
+            _ => panic!("Wrong instruction unpacked"),
+        }
+
+        // unknown format can't be read
+        let mut future_format = data.clone();
+        future_format[0] = SignatureData::FORMAT_V1 + 1;
+        assert!(SignatureData::unpack_legacy(&future_format).is_err());
+
+        let malformed = vec![
+            with_field(data.clone(), SignatureData::TAG_SECP_SIGNATURE_INDEX, &[1]),
+            with_field(
+                data.clone(),
+                SignatureData::TAG_SECP_INSTRUCTION_INDEX,
+                &[1, 2],
+            ),
+            with_field(
+                data.clone(),
+                SignatureData::TAG_PRECEDING_SECP_INSTRUCTION,
+                &[2],
+            ),
+            data[..data.len() - 1].to_vec(),
+        ];
+        for data in malformed {
+            assert_eq!(
+                SignatureData::unpack_legacy(&data),
+                Err(AudiusError::InvalidInstruction.into())
+            );
+        }
+    }
+
+    /// Instruction in the legacy encoding, only the first four instructions have one
+    fn pack_legacy(instruction: &AudiusInstruction) -> Vec<u8> {
+        let mut data = vec![];
+        match instruction {
+            AudiusInstruction::InitSignerGroup => data.push(0),
+            AudiusInstruction::InitValidSigner(eth_address, signature_data) => {
+                data.push(1);
+                data.extend_from_slice(eth_address);
+                match signature_data {
+                    Some(signature_data) => {
+                        data.push(1);
+                        signature_data.pack_legacy_into(&mut data).unwrap();
+                    }
+                    None => data.push(0),
+                }
+            }
+            AudiusInstruction::ClearValidSigner => data.push(2),
+            AudiusInstruction::ValidateSignature(signature_data) => {
+                data.push(3);
+                signature_data.pack_legacy_into(&mut data).unwrap();
+            }
+            _ => panic!("instruction has no legacy encoding"),
+        }
+        data
+    }
+
+    #[test]
+    fn test_legacy_encoding() {
+        let indexed_signature_data = SignatureData {
+            secp_instruction_index: 2,
+            secp_signature_index: 1,
+            ..signature_data(10)
+        };
+        let instructions = vec![
+            AudiusInstruction::InitSignerGroup,
+            AudiusInstruction::InitValidSigner([1; 20], None),
+            AudiusInstruction::InitValidSigner([1; 20], Some(indexed_signature_data.clone())),
+            AudiusInstruction::ClearValidSigner,
+            AudiusInstruction::ValidateSignature(signature_data(MAX_MESSAGE_LEN)),
+            AudiusInstruction::ValidateSignature(indexed_signature_data),
+        ];
+        for instruction in instructions {
+            let legacy_data = pack_legacy(&instruction);
+            let data = instruction.pack().unwrap();
+            assert_ne!(legacy_data, data);
+            assert_eq!(
+                AudiusInstruction::unpack(&legacy_data).unwrap(),
+                instruction
+            );
+            assert_eq!(
+                AudiusInstruction::unpack(&legacy_data),
+                AudiusInstruction::unpack(&data)
+            );
+
+            let mut trailing_data = legacy_data.clone();
+            trailing_data.push(0);
+            assert_eq!(
+                AudiusInstruction::unpack(&trailing_data).err(),
+                Some(AudiusError::InvalidInstruction.into())
+            );
+        }
+
+        // legacy instructions are checked like the v2 ones
+        let data = pack_legacy(&AudiusInstruction::ValidateSignature(signature_data(
+            MAX_MESSAGE_LEN + 1,
+        )));
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::MessageTooLarge.into())
+        );
+        assert_eq!(
+            AudiusInstruction::unpack(&[]).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+        assert_eq!(
+            AudiusInstruction::unpack(&[AudiusInstruction::ENCODING_V2]).err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_pack_too_large() {
+        let signatures = vec![signature_data(MAX_MESSAGE_LEN); 4];
+        let instruction = AudiusInstruction::ValidateSignatures {
+            threshold: 1,
+            signatures,
+        };
+        assert!(instruction.packed_len() > MAX_INSTRUCTION_LEN);
+        assert_eq!(
+            instruction.pack().err(),
+            Some(AudiusError::InvalidInstruction.into())
+        );
+    }
+
+    #[test]
+    fn test_max_message_len_fits_offsets() {
+        assert!(MAX_MESSAGE_LEN <= SecpSignatureOffsets::MAX_MESSAGE_SIZE);
+    }
+
+    #[test]
+    fn test_new_ed25519_instruction() {
+        let pubkey = Pubkey::new_from_array([1; 32]);
+        let signature = [2; Ed25519SignatureOffsets::SIGNATURE_SIZE];
+        let message = [3; 10];
+
+        let instruction = new_ed25519_instruction(&pubkey, &signature, &message).unwrap();
+        assert_eq!(instruction.program_id, ed25519_program::id());
+
+        let data = instruction.data;
+        assert_eq!(&data[..2], &[1, 0]);
+        assert_eq!(
+            &data
+                [Ed25519SignatureOffsets::PUBKEY_OFFSET..Ed25519SignatureOffsets::SIGNATURE_OFFSET],
+            pubkey.as_ref()
+        );
+        assert_eq!(
+            &data[Ed25519SignatureOffsets::SIGNATURE_OFFSET
+                ..Ed25519SignatureOffsets::MESSAGE_DATA_OFFSET],
+            &signature[..]
+        );
+        assert_eq!(
+            &data[Ed25519SignatureOffsets::MESSAGE_DATA_OFFSET..],
+            &message[..]
+        );
+    }
+
+    #[test]
+    fn test_unpack_ed25519_message_len() {
+        let signature_data = Ed25519SignatureData {
+            signature: [1; Ed25519SignatureOffsets::SIGNATURE_SIZE],
+            message: vec![2; MAX_MESSAGE_LEN + 1],
+            ed25519_instruction_index: 0,
+        };
+        let data = AudiusInstruction::ValidateEd25519Signature(signature_data)
+            .pack()
+            .unwrap();
+        assert_eq!(
+            AudiusInstruction::unpack(&data).err(),
+            Some(AudiusError::MessageTooLarge.into())
+        );
+    }
+}
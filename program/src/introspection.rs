@@ -0,0 +1,102 @@
+//! Access to the instructions of the current transaction
+
+use solana_program::{
+    account_info::AccountInfo, instruction::Instruction, program_error::ProgramError, sysvar,
+};
+
+/// Source of the current transaction's instructions Secp256 instructions are read from
+pub trait SecpIntrospector {
+    /// Index of the currently executing instruction in the transaction
+    fn current_index(&self) -> u16;
+
+    /// Instruction at the given index in the transaction
+    fn instruction_at(&self, index: usize) -> Result<Instruction, ProgramError>;
+}
+
+/// Introspector reading the instructions sysvar account
+pub struct SysvarIntrospector<'a, 'b> {
+    /// Sysvar instruction account
+    pub instruction_info: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> SysvarIntrospector<'a, 'b> {
+    /// Create introspector over the sysvar instruction account, any other account
+    /// could hold forged instructions
+    pub fn new(instruction_info: &'a AccountInfo<'b>) -> Result<Self, ProgramError> {
+        if *instruction_info.key != sysvar::instructions::id() {
+            return Err(ProgramError::UnsupportedSysvar);
+        }
+        Ok(Self { instruction_info })
+    }
+}
+
+impl SecpIntrospector for SysvarIntrospector<'_, '_> {
+    fn current_index(&self) -> u16 {
+        sysvar::instructions::load_current_index(&self.instruction_info.data.borrow())
+    }
+
+    fn instruction_at(&self, index: usize) -> Result<Instruction, ProgramError> {
+        sysvar::instructions::load_instruction_at(index, &self.instruction_info.data.borrow())
+            .map_err(|_| ProgramError::InvalidArgument)
+    }
+}
+
+/// In-memory introspector returning canned instructions, lets the verification logic
+/// be tested without the runtime
+#[cfg(any(test, feature = "introspection-mock"))]
+#[derive(Clone, Debug, Default)]
+pub struct MockIntrospector {
+    /// Instructions of the mocked transaction
+    pub instructions: Vec<Instruction>,
+    /// Index of the currently executing instruction
+    pub current_index: u16,
+}
+
+#[cfg(any(test, feature = "introspection-mock"))]
+impl SecpIntrospector for MockIntrospector {
+    fn current_index(&self) -> u16 {
+        self.current_index
+    }
+
+    fn instruction_at(&self, index: usize) -> Result<Instruction, ProgramError> {
+        self.instructions
+            .get(index)
+            .cloned()
+            .ok_or(ProgramError::InvalidArgument)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+
+    #[test]
+    fn test_sysvar_introspector_checks_id() {
+        let key = sysvar::instructions::id();
+        let owner = sysvar::id();
+        let mut lamports = 0;
+        let mut data = vec![];
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        assert!(SysvarIntrospector::new(&info).is_ok());
+
+        let forged_key = Pubkey::new_unique();
+        let forged_info = AccountInfo {
+            key: &forged_key,
+            ..info.clone()
+        };
+        assert_eq!(
+            SysvarIntrospector::new(&forged_info).err(),
+            Some(ProgramError::UnsupportedSysvar)
+        );
+    }
+}
@@ -2,18 +2,152 @@
 
 //! A program signature service for the Audius
 
+#[cfg(feature = "crypto")]
+pub mod crypto;
 pub mod error;
+pub mod eth;
+#[cfg(feature = "anchor-idl")]
+pub mod idl;
 pub mod instruction;
+pub mod introspection;
+pub mod logging;
+pub mod merkle;
 pub mod processor;
+pub mod return_data;
 pub mod state;
 
 /// Current program version
 pub const PROGRAM_VERSION: u8 = 1;
 
+/// Max size of the signed message. The message is included twice in a transaction,
+/// in Secp256 and in validation instructions, with all the accounts and instruction
+/// headers included 256 bytes still fit into 1232 bytes transaction packet.
+pub const MAX_MESSAGE_LEN: usize = 256;
+
+/// Max size of packed instruction data, it can't exceed 1232 bytes transaction packet
+/// the instruction is sent in. Buffers of this size fit any instruction of the program.
+pub const MAX_INSTRUCTION_LEN: usize = 1232;
+
+/// Max number of signatures validated by a single batch validation instruction
+pub const MAX_BATCH_SIGNATURES: usize = 4;
+
+/// Max number of nodes in the Merkle proof of group membership, enough for a million members
+pub const MAX_MERKLE_PROOF_LEN: usize = 20;
+
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
 
 // Export current sdk types for downstream users building with a different sdk version
 pub use solana_program;
+use solana_program::pubkey::Pubkey;
+
+/// Seed for valid signer metadata account address
+pub const METADATA_SEED: &[u8] = b"metadata";
+
+/// Find address of the metadata account for the given valid signer
+pub fn find_valid_signer_metadata_address(
+    program_id: &Pubkey,
+    valid_signer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[METADATA_SEED, valid_signer.as_ref()], program_id)
+}
+
+/// Find address of the valid signer registered for the given eth address in the signer group
+pub fn find_valid_signer_address(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+    eth_address: &[u8; 20],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[signer_group.as_ref(), eth_address.as_ref()], program_id)
+}
+
+/// Seed for program statistics account address
+pub const STATS_SEED: &[u8] = b"stats";
+
+/// Find address of the program statistics account
+pub fn find_program_stats_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATS_SEED], program_id)
+}
+
+/// Seed for owner registry account address
+pub const OWNER_REGISTRY_SEED: &[u8] = b"owner-registry";
+
+/// Find address of the registry of signer groups created by the owner
+pub fn find_owner_registry_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OWNER_REGISTRY_SEED, owner.as_ref()], program_id)
+}
+
+/// Seed for revocation registry account address
+pub const REVOCATION_SEED: &[u8] = b"revocation";
+
+/// Find address of the registry of eth addresses revoked in the signer group
+pub fn find_revocation_registry_address(
+    program_id: &Pubkey,
+    signer_group: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REVOCATION_SEED, signer_group.as_ref()], program_id)
+}
+
+/// Seed for ownership history account address
+pub const OWNERSHIP_HISTORY_SEED: &[u8] = b"owner_history";
+
+/// Find address of the ownership transfer history of the signer group
+pub fn find_ownership_history_address(program_id: &Pubkey, signer_group: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[OWNERSHIP_HISTORY_SEED, signer_group.as_ref()], program_id)
+}
+
+/// Seed for attestation account address
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+
+/// Find address of the attestation of the message signed by the valid signer,
+/// message is given by its Keccak256 hash
+pub fn find_attestation_address(
+    program_id: &Pubkey,
+    valid_signer: &Pubkey,
+    message_hash: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ATTESTATION_SEED,
+            valid_signer.as_ref(),
+            message_hash.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Seed for creation whitelist account address
+pub const CREATION_WHITELIST_SEED: &[u8] = b"creation_whitelist";
+
+/// Find address of the whitelist of accounts allowed to create signer groups
+pub fn find_creation_whitelist_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATION_WHITELIST_SEED], program_id)
+}
+
+/// Seed for program config account address
+pub const PROGRAM_CONFIG_SEED: &[u8] = b"program_config";
+
+/// Find address of the program-wide config account
+pub fn find_program_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROGRAM_CONFIG_SEED], program_id)
+}
+
+/// Find address of the program data account keeping the upgrade authority of the program
+pub fn find_program_data_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &solana_program::bpf_loader_upgradeable::id(),
+    )
+}
+
+/// Ed25519 native signature verification program
+pub mod ed25519_program {
+    solana_program::declare_id!("Ed25519SigVerify111111111111111111111111111");
+}
+
+/// SPL Governance program whose realms may govern signer groups
+pub mod spl_governance {
+    solana_program::declare_id!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+}
 
 solana_program::declare_id!("Eed4UXg3TqTkzbgT47WWMQtbzpxMUeHitt2jfAa6pmep");
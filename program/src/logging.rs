@@ -0,0 +1,63 @@
+//! Structured program logs
+
+/// Log program event as a JSON line, e.g. `{"event":"group_init","owner":"..."}`
+#[macro_export]
+macro_rules! log_event {
+    ($event_type:expr, $fields:expr) => {
+        $crate::solana_program::msg!("{}", $crate::logging::format_event($event_type, $fields))
+    };
+}
+
+/// Format event type and its fields as a JSON object with string values
+pub fn format_event(event_type: &str, fields: &[(&str, &str)]) -> String {
+    let mut line = String::from("{\"event\":");
+    push_json_string(&mut line, event_type);
+    for (key, value) in fields {
+        line.push(',');
+        push_json_string(&mut line, key);
+        line.push(':');
+        push_json_string(&mut line, value);
+    }
+    line.push('}');
+    line
+}
+
+fn push_json_string(line: &mut String, value: &str) {
+    line.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => line.push_str("\\\""),
+            '\\' => line.push_str("\\\\"),
+            c if c.is_control() => line.push_str(&format!("\\u{:04x}", c as u32)),
+            c => line.push(c),
+        }
+    }
+    line.push('"');
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_event() {
+        assert_eq!(
+            format_event("group_init", &[("owner", "Owner111")]),
+            "{\"event\":\"group_init\",\"owner\":\"Owner111\"}"
+        );
+        assert_eq!(
+            format_event("sig_validated", &[("signer", "S"), ("group", "G")]),
+            "{\"event\":\"sig_validated\",\"signer\":\"S\",\"group\":\"G\"}"
+        );
+        assert_eq!(
+            format_event("stats_init", &[]),
+            "{\"event\":\"stats_init\"}"
+        );
+
+        // labels are user input, they can't break the line
+        assert_eq!(
+            format_event("signer_label_set", &[("label", "dn \"1\"\\\n")]),
+            "{\"event\":\"signer_label_set\",\"label\":\"dn \\\"1\\\"\\\\\\u000a\"}"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Keccak256 Merkle tree of the signer group members' eth addresses
+
+use crate::state::SecpSignatureOffsets;
+use solana_program::keccak;
+
+/// Node of the Merkle tree
+pub type MerkleNode = [u8; keccak::HASH_BYTES];
+
+/// Leaf of the member's eth address
+pub fn leaf(eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]) -> MerkleNode {
+    keccak::hash(eth_address).0
+}
+
+/// Parent of the two nodes. Nodes are hashed in sorted order, so proofs don't need
+/// to tell which side each sibling is on.
+pub fn parent(node: &MerkleNode, sibling: &MerkleNode) -> MerkleNode {
+    let (left, right) = if node <= sibling {
+        (node, sibling)
+    } else {
+        (sibling, node)
+    };
+    keccak::hashv(&[&left[..], &right[..]]).0
+}
+
+/// Next level of the tree, the node left without a pair is carried over as is
+fn next_level(nodes: &[MerkleNode]) -> Vec<MerkleNode> {
+    nodes
+        .chunks(2)
+        .map(|pair| match pair {
+            [node, sibling] => parent(node, sibling),
+            [node] => *node,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Root of the tree over the leaves, zero if there are none
+pub fn root(leaves: &[MerkleNode]) -> MerkleNode {
+    let mut nodes = leaves.to_vec();
+    while nodes.len() > 1 {
+        nodes = next_level(&nodes);
+    }
+    nodes.first().copied().unwrap_or_default()
+}
+
+/// Proof of the leaf at the index, its siblings from the bottom level up
+pub fn proof(leaves: &[MerkleNode], mut index: usize) -> Option<Vec<MerkleNode>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut proof = vec![];
+    let mut nodes = leaves.to_vec();
+    while nodes.len() > 1 {
+        if let Some(sibling) = nodes.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        nodes = next_level(&nodes);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Check that the proof leads from the leaf to the root
+pub fn verify(root: &MerkleNode, leaf: &MerkleNode, proof: &[MerkleNode]) -> bool {
+    proof
+        .iter()
+        .fold(*leaf, |node, sibling| parent(&node, sibling))
+        == *root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merkle_proofs() {
+        assert_eq!(root(&[]), [0; 32]);
+        assert_eq!(proof(&[], 0), None);
+
+        for count in 1..10u8 {
+            let leaves: Vec<MerkleNode> = (0..count).map(|i| leaf(&[i; 20])).collect();
+            let root = root(&leaves);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = proof(&leaves, index).unwrap();
+                assert!(proof.len() <= 4);
+                assert!(verify(&root, leaf, &proof));
+                if !proof.is_empty() {
+                    assert!(!verify(&root, leaf, &proof[1..]));
+                }
+            }
+            assert!(!verify(&root, &super::leaf(&[count; 20]), &[]));
+            assert_eq!(proof(&leaves, leaves.len()), None);
+        }
+
+        // sorted hashing makes the sibling order irrelevant
+        let (a, b) = (leaf(&[1; 20]), leaf(&[2; 20]));
+        assert_eq!(parent(&a, &b), parent(&b, &a));
+        assert_eq!(root(&[a, b]), root(&[b, a]));
+    }
+}
@@ -1,271 +1,4918 @@
 //! Program state processor
 
 use crate::error::AudiusError;
-use crate::instruction::{AudiusInstruction, SignatureData};
-use crate::state::{SecpSignatureOffsets, SignerGroup, ValidSigner};
-use borsh::{BorshDeserialize, BorshSerialize};
+use crate::instruction::{
+    AudiusInstruction, CompressedSecpPubkey, Ed25519SignatureData, InitValidSignerArgs,
+    MetadataArgs, SignatureData,
+};
+use crate::introspection::{SecpIntrospector, SysvarIntrospector};
+use crate::merkle::{self, MerkleNode};
+use crate::return_data::{set_validation_result, ValidationResult};
+use crate::state::{
+    ct_eq, format_hex, Attestation, CreationWhitelist, Ed25519SignatureOffsets, GovernanceAction,
+    OwnerRegistry, OwnershipHistory, OwnershipTransfer, PodU64, ProgramAccount, ProgramConfig,
+    ProgramStats, RevocationRegistry, SecpSignatureOffsets, SignerGroup, SignerKeyType,
+    ValidSigner, ValidSignerMetadata, ValidSignerRef,
+};
+use crate::{
+    ed25519_program, find_attestation_address, find_creation_whitelist_address,
+    find_owner_registry_address, find_ownership_history_address, find_program_config_address,
+    find_program_data_address, find_program_stats_address, find_revocation_registry_address,
+    find_valid_signer_address, find_valid_signer_metadata_address, log_event, spl_governance,
+    ATTESTATION_SEED, CREATION_WHITELIST_SEED, MAX_MESSAGE_LEN, METADATA_SEED,
+    OWNERSHIP_HISTORY_SEED, OWNER_REGISTRY_SEED, PROGRAM_CONFIG_SEED, REVOCATION_SEED, STATS_SEED,
+};
+use borsh::BorshDeserialize;
 use num_traits::FromPrimitive;
 use solana_program::decode_error::DecodeError;
 use solana_program::program_error::PrintProgramError;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    bpf_loader_upgradeable,
+    clock::Clock,
     entrypoint::ProgramResult,
-    msg,
+    instruction::Instruction,
+    keccak, msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
     pubkey::Pubkey,
-    sysvar,
+    rent::Rent,
+    secp256k1_program, system_instruction, system_program,
+    sysvar::{self, Sysvar},
 };
+use std::convert::TryFrom;
+use std::slice::Iter;
+
+/// Authority approving membership change of the signer group
+enum MembershipAuthority<'a, 'b> {
+    /// Group's owner signing the transaction
+    Owner,
+    /// Group's owner or its delegate operator signing the transaction
+    OwnerOrDelegate,
+    /// Valid signers of the governed group approving the change with their eth signatures
+    Governance {
+        action: GovernanceAction,
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        approver_infos: Vec<&'a AccountInfo<'b>>,
+        signatures: &'a [SignatureData],
+    },
+    /// Governance account of the group's SPL Governance realm executing its approved proposal
+    GovernanceRealm,
+}
 
 /// Program state handler
 pub struct Processor {}
 impl Processor {
     /// SignerGroup version indicating group initialization
-    pub const SIGNER_GROUP_VERSION: u8 = 1;
+    pub const SIGNER_GROUP_VERSION: u8 = 2;
 
     /// ValidSigner version indicating signer initialization
-    pub const VALID_SIGNER_VERSION: u8 = 1;
+    pub const VALID_SIGNER_VERSION: u8 = 8;
 
     /// ValidSigner version indicating signer uninitialization
     pub const VALID_SIGNER_UNINITIALIZED_VERSION: u8 = 0;
 
+    /// ValidSignerMetadata version indicating metadata initialization
+    pub const METADATA_VERSION: u8 = 1;
+
+    /// ProgramStats version indicating stats initialization
+    pub const STATS_VERSION: u8 = 1;
+
+    /// OwnerRegistry version indicating registry initialization
+    pub const OWNER_REGISTRY_VERSION: u8 = 1;
+
+    /// RevocationRegistry version indicating registry initialization
+    pub const REVOCATION_REGISTRY_VERSION: u8 = 1;
+
+    /// Attestation version indicating attestation initialization
+    pub const ATTESTATION_VERSION: u8 = 1;
+
+    /// OwnershipHistory version indicating history initialization
+    pub const OWNERSHIP_HISTORY_VERSION: u8 = 1;
+
+    /// CreationWhitelist version indicating whitelist initialization
+    pub const CREATION_WHITELIST_VERSION: u8 = 1;
+
+    /// ProgramConfig version indicating config initialization
+    pub const PROGRAM_CONFIG_VERSION: u8 = 1;
+
+    /// Number of slots without validations after which signer's rate limit window restarts
+    pub const RATE_LIMIT_WINDOW_SLOTS: u64 = 100;
+
+    /// Maximum number of validations of one valid signer within the rate limit window
+    pub const MAX_VALIDATIONS_PER_WINDOW: u32 = 10;
+
     /// Process [InitSignerGroup]().
-    pub fn process_init_signer_group(accounts: &[AccountInfo]) -> ProgramResult {
+    pub fn process_init_signer_group(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         // signer group account
         let signer_group_info = next_account_info(account_info_iter)?;
         // signer group owner account
         let group_owner_info = next_account_info(account_info_iter)?;
+        // creation whitelist account
+        let creation_whitelist_info = next_account_info(account_info_iter)?;
+        // creation authority account
+        let creation_authority_info = next_account_info(account_info_iter)?;
+        // registry of the owner's signer groups
+        let registry_info = next_account_info(account_info_iter)?;
+        // account paying for the registry
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
 
-        let mut signer_group = Box::new(SignerGroup::try_from_slice(
-            &signer_group_info.data.borrow(),
-        )?);
+        // the account is created beforehand by the client, which has to assign it to the
+        // program, otherwise writing the group fails without telling why
+        if signer_group_info.owner != program_id {
+            return Err(AudiusError::SignerGroupNotProgramOwned.into());
+        }
+        let mut signer_group =
+            Box::new(SignerGroup::deserialize_any_version(&signer_group_info.data.borrow())?.0);
 
+        if signer_group.is_tombstoned() {
+            return Err(AudiusError::AccountTombstoned.into());
+        }
         if signer_group.is_initialized() {
             return Err(AudiusError::SignerGroupAlreadyInitialized.into());
         }
+        SignerGroup::check_new_owner(signer_group_info.key, group_owner_info.key)?;
+        Self::check_creation_authority(
+            program_id,
+            creation_whitelist_info,
+            creation_authority_info,
+        )?;
 
         signer_group.version = Self::SIGNER_GROUP_VERSION;
         signer_group.owner = *group_owner_info.key;
+        signer_group.next_signer_id = 1.into();
+
+        signer_group.pack(&mut signer_group_info.data.borrow_mut())?;
+
+        log_event!(
+            "group_init",
+            &[
+                ("group", &signer_group_info.key.to_string()),
+                ("owner", &signer_group.owner.to_string()),
+            ]
+        );
+
+        Self::register_owner_group(
+            program_id,
+            signer_group_info.key,
+            group_owner_info.key,
+            registry_info,
+            payer_info,
+            rent_info,
+            system_program_info,
+        )?;
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_groups
+        })
+    }
+
+    /// Check the group creation is signed by the creation authority, which has to be
+    /// a whitelisted account while the creation whitelist is enforced
+    fn check_creation_authority(
+        program_id: &Pubkey,
+        creation_whitelist_info: &AccountInfo,
+        creation_authority_info: &AccountInfo,
+    ) -> ProgramResult {
+        let (whitelist_address, _) = find_creation_whitelist_address(program_id);
+        if whitelist_address != *creation_whitelist_info.key {
+            return Err(AudiusError::WrongCreationWhitelistAddress.into());
+        }
+        if !creation_authority_info.is_signer {
+            return Err(AudiusError::SignatureMissing.into());
+        }
+
+        // whitelist account is created by the first whitelist update
+        if creation_whitelist_info.data_is_empty() {
+            return Ok(());
+        }
+        Self::check_account_owner(creation_whitelist_info, program_id)?;
+        let whitelist = CreationWhitelist::unpack(&creation_whitelist_info.data.borrow())?;
+        if !whitelist.is_enforced() {
+            return Ok(());
+        }
+        if !whitelist.contains(creation_authority_info.key) {
+            return Err(AudiusError::UnauthorizedOperation.into());
+        }
+        Ok(())
+    }
+
+    /// Add signer group to its owner's registry, the registry is created with the first
+    /// group of the owner
+    fn register_owner_group<'a>(
+        program_id: &Pubkey,
+        signer_group: &Pubkey,
+        owner: &Pubkey,
+        registry_info: &AccountInfo<'a>,
+        payer_info: &AccountInfo<'a>,
+        rent_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let (registry_address, bump_seed) = find_owner_registry_address(program_id, owner);
+        if registry_address != *registry_info.key {
+            return Err(AudiusError::WrongOwnerRegistryAddress.into());
+        }
+
+        if registry_info.data_is_empty() {
+            let rent = Rent::from_account_info(rent_info)?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    registry_info.key,
+                    rent.minimum_balance(OwnerRegistry::LEN),
+                    OwnerRegistry::LEN as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    registry_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[&[OWNER_REGISTRY_SEED, owner.as_ref(), &[bump_seed]]],
+            )?;
+        }
+        Self::check_account_owner(registry_info, program_id)?;
+
+        let mut registry = OwnerRegistry::unpack(&registry_info.data.borrow())?;
+        if !registry.is_initialized() {
+            registry.version = Self::OWNER_REGISTRY_VERSION;
+            registry.owner = *owner;
+        }
+        registry.add_group(*signer_group).map_err(|error| {
+            msg!(
+                "Owner {} already has {} registered signer groups",
+                owner,
+                OwnerRegistry::MAX_GROUPS
+            );
+            error
+        })?;
+        registry.pack(&mut registry_info.data.borrow_mut())
+    }
+
+    /// Remove signer group from its owner's registry. Registry of the owner whose groups
+    /// were all created before the registration was required may not exist yet.
+    fn unregister_owner_group(
+        program_id: &Pubkey,
+        signer_group: &Pubkey,
+        owner: &Pubkey,
+        registry_info: &AccountInfo,
+    ) -> ProgramResult {
+        let (registry_address, _) = find_owner_registry_address(program_id, owner);
+        if registry_address != *registry_info.key {
+            return Err(AudiusError::WrongOwnerRegistryAddress.into());
+        }
+        if registry_info.data_is_empty() {
+            return Ok(());
+        }
+        Self::check_account_owner(registry_info, program_id)?;
+
+        let mut registry = OwnerRegistry::unpack(&registry_info.data.borrow())?;
+        if !registry.remove_group(signer_group) {
+            return Ok(());
+        }
+        // the shorter list leaves the last group's key behind, it isn't kept readable
+        let mut data = registry_info.data.borrow_mut();
+        data.fill(0);
+        registry.pack(&mut data)
+    }
+
+    /// Process [InitSignerGroupWithSigner]().
+    pub fn process_init_signer_group_with_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group owner account
+        let group_owner_info = next_account_info(account_info_iter)?;
+        // creation whitelist account
+        let creation_whitelist_info = next_account_info(account_info_iter)?;
+        // registry of the owner's signer groups
+        let registry_info = next_account_info(account_info_iter)?;
+        // valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // payer, rent, system program, revocation registry and optional stats accounts
+        // of valid signer creation
+        let signer_creation_accounts = account_info_iter.as_slice();
+        let payer_accounts = signer_creation_accounts.get(..3).unwrap_or(&[]);
+        let stats_accounts = signer_creation_accounts.get(4..).unwrap_or(&[]);
+
+        // both halves run in the same instruction, failure of either reverts the other,
+        // the signing owner is the creation authority of the group and the payer of
+        // the valid signer pays for the registry too
+        let mut group_accounts = vec![
+            signer_group_info.clone(),
+            group_owner_info.clone(),
+            creation_whitelist_info.clone(),
+            group_owner_info.clone(),
+            registry_info.clone(),
+        ];
+        group_accounts.extend_from_slice(payer_accounts);
+        group_accounts.extend_from_slice(stats_accounts);
+        Self::process_init_signer_group(program_id, &group_accounts)?;
+
+        let mut signer_accounts = vec![
+            valid_signer_info.clone(),
+            signer_group_info.clone(),
+            group_owner_info.clone(),
+        ];
+        signer_accounts.extend_from_slice(signer_creation_accounts);
+        Self::init_secp_valid_signer(
+            program_id,
+            &signer_accounts,
+            InitValidSignerArgs {
+                eth_address,
+                label: [0; ValidSigner::LABEL_SIZE],
+                active_after_slot: 0,
+            },
+            None,
+            MembershipAuthority::Owner,
+        )
+    }
+
+    /// Process [CloneSignerGroup]().
+    pub fn process_clone_signer_group(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        source_group: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // new signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // new signer group's owner
+        let group_owner_info = next_account_info(account_info_iter)?;
+        // creation whitelist account
+        let creation_whitelist_info = next_account_info(account_info_iter)?;
+        // registry of the new owner's signer groups
+        let registry_info = next_account_info(account_info_iter)?;
+        // signer group the valid signers are copied from
+        let source_group_info = next_account_info(account_info_iter)?;
+        // payer, rent, system program and revocation registry accounts of valid signer creation
+        let signer_creation_accounts = account_info_iter.as_slice().get(..4).unwrap_or(&[]);
+        // payer, rent and system program accounts of the registry
+        let payer_accounts = signer_creation_accounts.get(..3).unwrap_or(&[]);
+        // pairs of source valid signer and its copy
+        let signer_pairs = account_info_iter.as_slice().get(4..).unwrap_or(&[]);
+
+        if *source_group_info.key != source_group || source_group_info.key == signer_group_info.key
+        {
+            return Err(AudiusError::SignerGroupMismatch.into());
+        }
+        let source: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(source_group_info, program_id)?);
+
+        // the signing owner is the creation authority of the group
+        let mut group_accounts = vec![
+            signer_group_info.clone(),
+            group_owner_info.clone(),
+            creation_whitelist_info.clone(),
+            group_owner_info.clone(),
+            registry_info.clone(),
+        ];
+        group_accounts.extend_from_slice(payer_accounts);
+        Self::process_init_signer_group(program_id, &group_accounts)?;
+
+        let mut source_signer_key = Pubkey::default();
+        let mut last_signer_info: Option<&AccountInfo> = None;
+        for pair in signer_pairs.chunks(2) {
+            let (source_signer_info, valid_signer_info) = match pair {
+                [source_signer_info, valid_signer_info] => (source_signer_info, valid_signer_info),
+                _ => return Err(ProgramError::NotEnoughAccountKeys),
+            };
+            let source_signer: Box<ValidSigner> =
+                Box::new(Self::load_account_checked(source_signer_info, program_id)?);
+            // walking the source list from its head makes sure no signer is skipped
+            if source_signer.signer_group != source_group
+                || source_signer.prev_signer != source_signer_key
+            {
+                return Err(AudiusError::WrongSignerListAccount.into());
+            }
+            source_signer_key = *source_signer_info.key;
+
+            let mut signer_accounts = vec![
+                valid_signer_info.clone(),
+                signer_group_info.clone(),
+                group_owner_info.clone(),
+            ];
+            // copies of Ed25519 signers are created by the client
+            let pda_eth_address = if source_signer.key_type == SignerKeyType::SECP256K1 {
+                signer_accounts.extend_from_slice(signer_creation_accounts);
+                Some(&source_signer.eth_addresses[0])
+            } else {
+                None
+            };
+            signer_accounts.extend(last_signer_info.cloned());
+            Self::init_valid_signer(
+                program_id,
+                &signer_accounts,
+                pda_eth_address,
+                None,
+                MembershipAuthority::Owner,
+                |valid_signer| {
+                    valid_signer.key_type = source_signer.key_type;
+                    valid_signer.eth_addresses = source_signer.eth_addresses;
+                    valid_signer.eth_address_count = source_signer.eth_address_count;
+                    valid_signer.ed25519_pubkey = source_signer.ed25519_pubkey;
+                    valid_signer.label = source_signer.label;
+                    valid_signer.active_after_slot = source_signer.active_after_slot;
+                    valid_signer.expires_at_slot = source_signer.expires_at_slot;
+                    valid_signer.cosigner = source_signer.cosigner;
+                },
+            )?;
+            last_signer_info = Some(valid_signer_info);
+        }
+        if source_signer_key != source.last_signer {
+            return Err(AudiusError::WrongSignerListAccount.into());
+        }
 
-        signer_group
-            .serialize(&mut *signer_group_info.data.borrow_mut())
-            .map_err(|e| e.into())
+        log_event!(
+            "group_cloned",
+            &[
+                ("group", &signer_group_info.key.to_string()),
+                ("source", &source_group.to_string()),
+            ]
+        );
+        Ok(())
     }
 
     /// Process [InitValidSigner]().
     pub fn process_init_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        eth_pop_signature: Option<SignatureData>,
+    ) -> ProgramResult {
+        Self::init_secp_valid_signer(
+            program_id,
+            accounts,
+            InitValidSignerArgs {
+                eth_address,
+                label: [0; ValidSigner::LABEL_SIZE],
+                active_after_slot: 0,
+            },
+            eth_pop_signature.as_ref(),
+            MembershipAuthority::OwnerOrDelegate,
+        )
+    }
+
+    /// Process [InitValidSignerFromPubkey]().
+    pub fn process_init_valid_signer_from_pubkey(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        pubkey: CompressedSecpPubkey,
+    ) -> ProgramResult {
+        Self::process_init_valid_signer(program_id, accounts, pubkey.eth_address()?, None)
+    }
+
+    /// Process [AddToCreationWhitelist]().
+    pub fn process_add_to_creation_whitelist(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        account: Pubkey,
+    ) -> ProgramResult {
+        Self::update_creation_whitelist(program_id, accounts, |whitelist| whitelist.add(account))?;
+        log_event!("creation_whitelisted", &[("account", &account.to_string())]);
+        Ok(())
+    }
+
+    /// Process [RemoveFromCreationWhitelist]().
+    pub fn process_remove_from_creation_whitelist(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        account: Pubkey,
+    ) -> ProgramResult {
+        Self::update_creation_whitelist(program_id, accounts, |whitelist| {
+            whitelist.remove(&account);
+            Ok(())
+        })?;
+        log_event!(
+            "creation_unwhitelisted",
+            &[("account", &account.to_string())]
+        );
+        Ok(())
+    }
+
+    /// Process [SetCreationWhitelistBypass]().
+    pub fn process_set_creation_whitelist_bypass(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        bypass: bool,
+    ) -> ProgramResult {
+        Self::update_creation_whitelist(program_id, accounts, |whitelist| {
+            if bypass {
+                whitelist.flags |= CreationWhitelist::BYPASS_WHITELIST;
+            } else {
+                whitelist.flags &= !CreationWhitelist::BYPASS_WHITELIST;
+            }
+            Ok(())
+        })
+    }
+
+    /// Apply the update to creation whitelist approved by the upgrade authority
+    /// of the program, creating the whitelist account on first use
+    fn update_creation_whitelist<F>(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        update: F,
+    ) -> ProgramResult
+    where
+        F: FnOnce(&mut CreationWhitelist) -> ProgramResult,
+    {
+        let account_info_iter = &mut accounts.iter();
+        // creation whitelist account
+        let whitelist_info = next_account_info(account_info_iter)?;
+        // program data account of the program
+        let program_data_info = next_account_info(account_info_iter)?;
+        // upgrade authority of the program
+        let authority_info = next_account_info(account_info_iter)?;
+        // account paying for the whitelist
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let upgrade_authority = Self::program_upgrade_authority(program_id, program_data_info)?;
+        if !authority_info.is_signer
+            || !upgrade_authority.iter().any(|upgrade_authority| {
+                ct_eq(upgrade_authority.as_ref(), authority_info.key.as_ref())
+            })
+        {
+            return Err(AudiusError::UnauthorizedOperation.into());
+        }
+
+        let (whitelist_address, bump_seed) = find_creation_whitelist_address(program_id);
+        if whitelist_address != *whitelist_info.key {
+            return Err(AudiusError::WrongCreationWhitelistAddress.into());
+        }
+
+        if whitelist_info.data_is_empty() {
+            let rent = Rent::from_account_info(rent_info)?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    whitelist_info.key,
+                    rent.minimum_balance(CreationWhitelist::LEN),
+                    CreationWhitelist::LEN as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    whitelist_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[&[CREATION_WHITELIST_SEED, &[bump_seed]]],
+            )?;
+        } else {
+            Self::check_account_owner(whitelist_info, program_id)?;
+        }
+
+        let mut whitelist = CreationWhitelist::unpack(&whitelist_info.data.borrow())?;
+        if !whitelist.is_initialized() {
+            whitelist.version = Self::CREATION_WHITELIST_VERSION;
+        }
+        update(&mut whitelist)?;
+        whitelist.pack(&mut whitelist_info.data.borrow_mut())
+    }
+
+    /// Read upgrade authority of the program from its program data account kept by
+    /// the upgradeable loader, `None` if the program is immutable
+    fn program_upgrade_authority(
+        program_id: &Pubkey,
+        program_data_info: &AccountInfo,
+    ) -> Result<Option<Pubkey>, ProgramError> {
+        // `UpgradeableLoaderState::ProgramData` tag, deployment slot and optional authority
+        const PROGRAM_DATA_TAG: u32 = 3;
+        const AUTHORITY_OFFSET: usize = 4 + 8;
+
+        let (program_data_address, _) = find_program_data_address(program_id);
+        if program_data_address != *program_data_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Self::check_account_owner(program_data_info, &bpf_loader_upgradeable::id())?;
+
+        let data = program_data_info.data.borrow();
+        let header = data
+            .get(..AUTHORITY_OFFSET + 1 + 32)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let tag = u32::try_from_slice(&header[..4])?;
+        if tag != PROGRAM_DATA_TAG {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        match header[AUTHORITY_OFFSET] {
+            0 => Ok(None),
+            1 => Ok(Some(Pubkey::new(&header[AUTHORITY_OFFSET + 1..]))),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Process [SetLockdownMode]().
+    pub fn process_set_lockdown_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        lockdown_mode: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // program config account
+        let config_info = next_account_info(account_info_iter)?;
+        // program data account of the program
+        let program_data_info = next_account_info(account_info_iter)?;
+        // config authority, upgrade authority of the program until the config is created
+        let authority_info = next_account_info(account_info_iter)?;
+        // account paying for the config
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let (config_address, bump_seed) = find_program_config_address(program_id);
+        if config_address != *config_info.key {
+            return Err(AudiusError::WrongProgramConfigAddress.into());
+        }
+
+        if config_info.data_is_empty() {
+            let rent = Rent::from_account_info(rent_info)?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    config_info.key,
+                    rent.minimum_balance(ProgramConfig::LEN),
+                    ProgramConfig::LEN as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    config_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[&[PROGRAM_CONFIG_SEED, &[bump_seed]]],
+            )?;
+        } else {
+            Self::check_account_owner(config_info, program_id)?;
+        }
+
+        let mut config = ProgramConfig::unpack(&config_info.data.borrow())?;
+        // the config is taken over by the upgrade authority on creation
+        if !config.is_initialized() {
+            config.version = Self::PROGRAM_CONFIG_VERSION;
+            config.authority = Self::program_upgrade_authority(program_id, program_data_info)?
+                .ok_or(AudiusError::UnauthorizedOperation)?;
+        }
+        if !authority_info.is_signer
+            || !ct_eq(config.authority.as_ref(), authority_info.key.as_ref())
+        {
+            return Err(AudiusError::UnauthorizedOperation.into());
+        }
+        config.lockdown_mode = lockdown_mode;
+        config.pack(&mut config_info.data.borrow_mut())?;
+
+        log_event!(
+            "lockdown_mode_set",
+            &[("lockdown_mode", &lockdown_mode.to_string())]
+        );
+        Ok(())
+    }
+
+    /// Check that the program isn't locked down, config account is created by the first
+    /// lockdown mode update and the program isn't locked until then
+    fn check_not_locked(program_id: &Pubkey, program_config_info: &AccountInfo) -> ProgramResult {
+        let (config_address, _) = find_program_config_address(program_id);
+        if config_address != *program_config_info.key {
+            return Err(AudiusError::WrongProgramConfigAddress.into());
+        }
+        if program_config_info.data_is_empty() {
+            return Ok(());
+        }
+        Self::check_account_owner(program_config_info, program_id)?;
+        if ProgramConfig::unpack(&program_config_info.data.borrow())?.is_locked() {
+            return Err(AudiusError::ProgramLocked.into());
+        }
+        Ok(())
+    }
+
+    /// Process [InitValidSignerWithLabel]().
+    pub fn process_init_valid_signer_with_label(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
         eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        label: [u8; ValidSigner::LABEL_SIZE],
+    ) -> ProgramResult {
+        Self::process_init_valid_signer_extended(
+            program_id,
+            accounts,
+            InitValidSignerArgs {
+                eth_address,
+                label,
+                active_after_slot: 0,
+            },
+        )
+    }
+
+    /// Process [InitValidSignerExtended]().
+    pub fn process_init_valid_signer_extended(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args: InitValidSignerArgs,
+    ) -> ProgramResult {
+        Self::init_secp_valid_signer(
+            program_id,
+            accounts,
+            args,
+            None,
+            MembershipAuthority::OwnerOrDelegate,
+        )
+    }
+
+    /// Initialize Secp256k1 valid signer, checking eth key holder's proof of possession if given
+    fn init_secp_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        args: InitValidSignerArgs,
+        eth_pop_signature: Option<&SignatureData>,
+        authority: MembershipAuthority,
+    ) -> ProgramResult {
+        ValidSigner::unpack_label(&args.label)?;
+        Self::init_valid_signer(
+            program_id,
+            accounts,
+            Some(&args.eth_address),
+            eth_pop_signature,
+            authority,
+            |valid_signer| {
+                valid_signer.key_type = SignerKeyType::SECP256K1;
+                valid_signer.eth_addresses = Default::default();
+                valid_signer.eth_addresses[0] = args.eth_address;
+                valid_signer.eth_address_count = 1;
+                valid_signer.ed25519_pubkey = Pubkey::default();
+                valid_signer.label = args.label;
+                valid_signer.active_after_slot = args.active_after_slot.into();
+                valid_signer.expires_at_slot = 0.into();
+            },
+        )
+    }
+
+    /// Check valid signer account is derived from signer group and eth address and create it
+    /// if it doesn't exist yet, account of the cleared signer is reused. Eth address must not be
+    /// revoked in the group. Returns the bump seed.
+    fn create_valid_signer_account<'a>(
+        program_id: &Pubkey,
+        valid_signer_info: &AccountInfo<'a>,
+        signer_group: &Pubkey,
+        eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        account_info_iter: &mut Iter<AccountInfo<'a>>,
+    ) -> Result<u8, ProgramError> {
+        // account paying for valid signer account creation
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        let rent = Rent::from_account_info(rent_info)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+        // revocation registry of the group
+        let revocation_registry_info = next_account_info(account_info_iter)?;
+
+        let (valid_signer_address, bump_seed) =
+            find_valid_signer_address(program_id, signer_group, eth_address);
+        if valid_signer_address != *valid_signer_info.key {
+            return Err(AudiusError::WrongValidSignerAddress.into());
+        }
+
+        Self::check_not_revoked(
+            program_id,
+            revocation_registry_info,
+            signer_group,
+            std::slice::from_ref(eth_address),
+        )?;
+
+        if valid_signer_info.data_is_empty() {
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    valid_signer_info.key,
+                    rent.minimum_balance(ValidSigner::LEN),
+                    ValidSigner::LEN as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    valid_signer_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[&[signer_group.as_ref(), eth_address.as_ref(), &[bump_seed]]],
+            )?;
+        }
+
+        Ok(bump_seed)
+    }
+
+    /// Process [InitValidSignerEd25519]().
+    pub fn process_init_valid_signer_ed25519(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        ed25519_pubkey: Pubkey,
+    ) -> ProgramResult {
+        Self::init_valid_signer(
+            program_id,
+            accounts,
+            None,
+            None,
+            MembershipAuthority::OwnerOrDelegate,
+            |valid_signer| {
+                valid_signer.key_type = SignerKeyType::ED25519;
+                valid_signer.eth_addresses = Default::default();
+                valid_signer.eth_address_count = 0;
+                valid_signer.ed25519_pubkey = ed25519_pubkey;
+                valid_signer.label = [0; ValidSigner::LABEL_SIZE];
+                valid_signer.active_after_slot = 0.into();
+                valid_signer.expires_at_slot = 0.into();
+            },
+        )
+    }
+
+    /// Initialize valid signer and append it to the group, key fields are set by `set_key`.
+    /// Valid signer account is created at the address derived from `pda_eth_address` if given.
+    fn init_valid_signer<F: FnOnce(&mut ValidSigner)>(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        pda_eth_address: Option<&[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]>,
+        eth_pop_signature: Option<&SignatureData>,
+        authority: MembershipAuthority,
+        set_key: F,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         // uninitialized valid signer account
         let valid_signer_info = next_account_info(account_info_iter)?;
         // signer group account
         let signer_group_info = next_account_info(account_info_iter)?;
-        // signer group's owner
-        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+        // signer group's owner, Sysvar Instruction account if approved by governance
+        let authority_info = next_account_info(account_info_iter)?;
 
-        let signer_group = Box::new(SignerGroup::try_from_slice(
-            &signer_group_info.data.borrow(),
-        )?);
+        let mut signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
 
-        if !signer_group.is_initialized() {
-            return Err(AudiusError::UninitializedSignerGroup.into());
-        }
+        Self::authorize_membership_change(
+            program_id,
+            authority,
+            &mut signer_group,
+            signer_group_info,
+            authority_info,
+        )?;
 
-        let mut valid_signer = Box::new(ValidSigner::try_from_slice(
-            &valid_signer_info.data.borrow(),
-        )?);
+        let bump_seed = match pda_eth_address {
+            Some(eth_address) => Self::create_valid_signer_account(
+                program_id,
+                valid_signer_info,
+                signer_group_info.key,
+                eth_address,
+                account_info_iter,
+            )?,
+            None => 0,
+        };
 
+        let mut valid_signer =
+            Box::new(ValidSigner::deserialize_any_version(&valid_signer_info.data.borrow())?.0);
+
+        if valid_signer.is_tombstoned() {
+            return Err(AudiusError::AccountTombstoned.into());
+        }
         if valid_signer.is_initialized() {
             return Err(AudiusError::SignerAlreadyInitialized.into());
         }
 
-        signer_group.check_owner(&signer_groups_owner_info)?;
+        set_key(&mut valid_signer);
+        valid_signer.bump_seed = bump_seed;
+        if valid_signer
+            .eth_addresses()
+            .contains(&[0; SecpSignatureOffsets::ETH_ADDRESS_SIZE])
+        {
+            return Err(AudiusError::InvalidEthAddress.into());
+        }
+
+        // eth key holder consents to the registration by signing valid signer's address
+        if let Some(eth_pop_signature) = eth_pop_signature {
+            // Sysvar Instruction account info
+            let instruction_info = next_account_info(account_info_iter)?;
+            Self::check_eth_pop(
+                instruction_info,
+                valid_signer_info.key,
+                valid_signer.eth_addresses(),
+                eth_pop_signature,
+            )?;
+        } else if signer_group.eth_pop_required && valid_signer.key_type == SignerKeyType::SECP256K1
+        {
+            return Err(AudiusError::ProofOfPossessionMissing.into());
+        }
 
-        // TODO: check if ethereum public key is valid
+        // append new signer to the tail of the group's signer list
+        Self::update_list_signer(account_info_iter, &signer_group.last_signer, |last| {
+            last.next_signer = *valid_signer_info.key
+        })?;
+        if signer_group.first_signer == Pubkey::default() {
+            signer_group.first_signer = *valid_signer_info.key;
+        }
 
         valid_signer.version = Self::VALID_SIGNER_VERSION;
         valid_signer.signer_group = *signer_group_info.key;
-        valid_signer.eth_address = eth_address;
+        valid_signer.signer_id = signer_group.assign_signer_id()?.into();
+        signer_group.add_valid_signer()?;
+        valid_signer.next_signer = Pubkey::default();
+        valid_signer.prev_signer = signer_group.last_signer;
+
+        signer_group.last_signer = *valid_signer_info.key;
+
+        valid_signer.pack(&mut valid_signer_info.data.borrow_mut())?;
+        signer_group.pack(&mut signer_group_info.data.borrow_mut())?;
 
-        valid_signer
-            .serialize(&mut *valid_signer_info.data.borrow_mut())
-            .map_err(|e| e.into())
+        log_event!(
+            "signer_init",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_signers
+        })
+    }
+
+    /// Check that none of the eth addresses is revoked in the group
+    fn check_not_revoked(
+        program_id: &Pubkey,
+        revocation_registry_info: &AccountInfo,
+        signer_group: &Pubkey,
+        eth_addresses: &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]],
+    ) -> ProgramResult {
+        let (revocation_registry_address, _) =
+            find_revocation_registry_address(program_id, signer_group);
+        if revocation_registry_address != *revocation_registry_info.key {
+            return Err(AudiusError::WrongRevocationRegistryAddress.into());
+        }
+        // registry is created with the first revocation, nothing is revoked until then
+        if revocation_registry_info.data_is_empty() {
+            return Ok(());
+        }
+        let revocation_registry =
+            RevocationRegistry::unpack(&revocation_registry_info.data.borrow())?;
+        if eth_addresses
+            .iter()
+            .any(|eth_address| revocation_registry.is_revoked(eth_address))
+        {
+            return Err(AudiusError::EthAddressRevoked.into());
+        }
+        Ok(())
     }
 
     /// Process [ClearValidSigner]().
-    pub fn process_clear_valid_signer(accounts: &[AccountInfo]) -> ProgramResult {
+    pub fn process_clear_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        Self::clear_valid_signer(program_id, accounts, MembershipAuthority::Owner, false)
+    }
+
+    /// Process [CloseValidSigner]().
+    pub fn process_close_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        Self::clear_valid_signer(program_id, accounts, MembershipAuthority::Owner, true)
+    }
+
+    /// Remove valid signer from the group once the authority approves it. Closed signer
+    /// account is left with the tombstone and its rent goes to the receiver following
+    /// the authority account.
+    fn clear_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        authority: MembershipAuthority,
+        close: bool,
+    ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         // initialized valid signer account
         let valid_signer_info = next_account_info(account_info_iter)?;
         // signer group account
         let signer_group_info = next_account_info(account_info_iter)?;
-        // signer group's owner
-        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+        // signer group's owner, Sysvar Instruction account if approved by governance
+        let authority_info = next_account_info(account_info_iter)?;
+        // account receiving the rent of the closed signer
+        let rent_receiver_info = if close {
+            Some(next_account_info(account_info_iter)?)
+        } else {
+            None
+        };
 
-        let signer_group = Box::new(SignerGroup::try_from_slice(
-            &signer_group_info.data.borrow(),
-        )?);
+        let mut signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+
+        let valid_signer: Box<ValidSigner> =
+            Box::new(Self::load_account_checked(valid_signer_info, program_id)?);
 
-        if !signer_group.is_initialized() {
-            return Err(AudiusError::UninitializedSignerGroup.into());
+        if valid_signer.signer_group != *signer_group_info.key {
+            return Err(AudiusError::SignerGroupMismatch.into());
         }
 
-        let mut valid_signer = Box::new(ValidSigner::try_from_slice(
-            &valid_signer_info.data.borrow(),
-        )?);
+        Self::authorize_membership_change(
+            program_id,
+            authority,
+            &mut signer_group,
+            signer_group_info,
+            authority_info,
+        )?;
 
-        if !valid_signer.is_initialized() {
-            return Err(AudiusError::ValidSignerNotInitialized.into());
+        // unlink signer from the group's signer list
+        if valid_signer.prev_signer == Pubkey::default() {
+            signer_group.first_signer = valid_signer.next_signer;
         }
-
-        if valid_signer.signer_group != *signer_group_info.key {
-            return Err(AudiusError::WrongSignerGroup.into());
+        Self::update_list_signer(account_info_iter, &valid_signer.prev_signer, |prev| {
+            prev.next_signer = valid_signer.next_signer
+        })?;
+        if valid_signer.next_signer == Pubkey::default() {
+            signer_group.last_signer = valid_signer.prev_signer;
         }
+        Self::update_list_signer(account_info_iter, &valid_signer.next_signer, |next| {
+            next.prev_signer = valid_signer.prev_signer
+        })?;
 
-        signer_group.check_owner(&signer_groups_owner_info)?;
+        signer_group.remove_valid_signer();
+
+        if let Some(rent_receiver_info) = rent_receiver_info {
+            // tombstone goes in before the lamports are drained, the account refunded
+            // later in the transaction can't be initialized again
+            ValidSigner::write_tombstone(&mut valid_signer_info.data.borrow_mut())?;
+            Self::drain_lamports(valid_signer_info, rent_receiver_info)?;
+        } else {
+            // zeroed account is uninitialized, nothing of the cleared signer is left readable
+            // and the reused account is initialized from scratch
+            valid_signer_info.data.borrow_mut().fill(0);
+        }
+        signer_group.pack(&mut signer_group_info.data.borrow_mut())?;
 
-        valid_signer.version = Self::VALID_SIGNER_UNINITIALIZED_VERSION;
+        log_event!(
+            if close {
+                "signer_closed"
+            } else {
+                "signer_cleared"
+            },
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+            ]
+        );
 
-        valid_signer
-            .serialize(&mut *valid_signer_info.data.borrow_mut())
-            .map_err(|e| e.into())
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_clearances
+        })
     }
 
-    /// Process [ValidateSignature]().
-    pub fn process_validate_signature(
+    /// Process [MigrateValidSigner]().
+    pub fn process_migrate_valid_signer(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
-        signature_data: SignatureData,
+        new_signer_group: Pubkey,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         // initialized valid signer account
         let valid_signer_info = next_account_info(account_info_iter)?;
-        // signer group account
+        // signer group the valid signer leaves
         let signer_group_info = next_account_info(account_info_iter)?;
-        // Sysvar Instruction account info
-        let instruction_info = next_account_info(account_info_iter)?;
-        // Index of current instruction in tx
-        let index = sysvar::instructions::load_current_index(&instruction_info.data.borrow());
+        // owner of the group the valid signer leaves
+        let owner_info = next_account_info(account_info_iter)?;
+        // signer group the valid signer joins
+        let new_signer_group_info = next_account_info(account_info_iter)?;
+        // owner of the group the valid signer joins
+        let new_owner_info = next_account_info(account_info_iter)?;
+        // revocation registry of the group the valid signer joins
+        let revocation_registry_info = next_account_info(account_info_iter)?;
 
-        if index == 0 {
-            return Err(AudiusError::Secp256InstructionLosing.into());
+        if *new_signer_group_info.key != new_signer_group
+            || new_signer_group_info.key == signer_group_info.key
+        {
+            return Err(AudiusError::SignerGroupMismatch.into());
         }
 
-        // Instruction data of Secp256 program call
-        let secp_instruction = sysvar::instructions::load_instruction_at(
-            (index - 1) as usize,
-            &instruction_info.data.borrow(),
-        )
-        .unwrap();
-
-        let signer_group = Box::new(SignerGroup::try_from_slice(
-            &signer_group_info.data.borrow(),
+        let mut signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+        let mut new_signer_group: Box<SignerGroup> = Box::new(Self::load_account_checked(
+            new_signer_group_info,
+            program_id,
         )?);
+        let valid_signer: Box<ValidSigner> =
+            Box::new(Self::load_account_checked(valid_signer_info, program_id)?);
 
-        if !signer_group.is_initialized() {
-            return Err(AudiusError::UninitializedSignerGroup.into());
+        if valid_signer.signer_group != *signer_group_info.key {
+            return Err(AudiusError::SignerGroupMismatch.into());
         }
+        signer_group.check_owner(owner_info)?;
+        new_signer_group.check_owner(new_owner_info)?;
+        // derived address ties the signer to its group and eth address, it would be found
+        // in neither group and another signer of the address could be created in the old one
+        if valid_signer.bump_seed != 0 {
+            return Err(AudiusError::DerivedSignerMigration.into());
+        }
+        // the joined group's rules for new signers apply
+        if new_signer_group.governed {
+            return Err(AudiusError::SignerGroupGoverned.into());
+        }
+        if new_signer_group.eth_pop_required && valid_signer.key_type == SignerKeyType::SECP256K1 {
+            return Err(AudiusError::ProofOfPossessionMissing.into());
+        }
+        Self::check_not_revoked(
+            program_id,
+            revocation_registry_info,
+            new_signer_group_info.key,
+            valid_signer.eth_addresses(),
+        )?;
 
-        let valid_signer = Box::new(ValidSigner::try_from_slice(
-            &valid_signer_info.data.borrow(),
-        )?);
-
-        if !valid_signer.is_initialized() {
-            return Err(AudiusError::ValidSignerNotInitialized.into());
+        // unlink signer from the old group's signer list
+        if valid_signer.prev_signer == Pubkey::default() {
+            signer_group.first_signer = valid_signer.next_signer;
+        }
+        Self::update_list_signer(account_info_iter, &valid_signer.prev_signer, |prev| {
+            prev.next_signer = valid_signer.next_signer
+        })?;
+        if valid_signer.next_signer == Pubkey::default() {
+            signer_group.last_signer = valid_signer.prev_signer;
         }
+        Self::update_list_signer(account_info_iter, &valid_signer.next_signer, |next| {
+            next.prev_signer = valid_signer.prev_signer
+        })?;
+        signer_group.remove_valid_signer();
 
-        if valid_signer.signer_group != *signer_group_info.key {
-            return Err(AudiusError::WrongSignerGroup.into());
+        // and append it to the tail of the new group's one
+        Self::update_list_signer(account_info_iter, &new_signer_group.last_signer, |last| {
+            last.next_signer = *valid_signer_info.key
+        })?;
+        if new_signer_group.first_signer == Pubkey::default() {
+            new_signer_group.first_signer = *valid_signer_info.key;
         }
+        new_signer_group.add_valid_signer()?;
 
-        let mut instruction_data = vec![];
-        let data_start = 1 + SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
-        instruction_data.resize(
-            data_start
-                + SecpSignatureOffsets::ETH_ADDRESS_SIZE
-                + SecpSignatureOffsets::SECP_SIGNATURE_SIZE
-                + signature_data.message.len()
-                + 1,
-            0,
-        );
-        let eth_address_offset = data_start;
-        instruction_data
-            [eth_address_offset..eth_address_offset + SecpSignatureOffsets::ETH_ADDRESS_SIZE]
-            .copy_from_slice(&valid_signer.eth_address);
-
-        let signature_offset = data_start + SecpSignatureOffsets::ETH_ADDRESS_SIZE;
-        instruction_data
-            [signature_offset..signature_offset + SecpSignatureOffsets::SECP_SIGNATURE_SIZE]
-            .copy_from_slice(&signature_data.signature);
-
-        instruction_data[signature_offset + SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
-            signature_data.recovery_id;
-
-        let message_data_offset = signature_offset + SecpSignatureOffsets::SECP_SIGNATURE_SIZE + 1;
-        instruction_data[message_data_offset..].copy_from_slice(&signature_data.message);
-
-        let num_signatures = 1;
-        instruction_data[0] = num_signatures;
-        let offsets = SecpSignatureOffsets {
-            signature_offset: signature_offset as u16,
-            signature_instruction_index: 0,
-            eth_address_offset: eth_address_offset as u16,
-            eth_address_instruction_index: 0,
-            message_data_offset: message_data_offset as u16,
-            message_data_size: signature_data.message.len() as u16,
-            message_instruction_index: 0,
-        };
-        let packed_offsets = offsets.try_to_vec()?;
-        instruction_data[1..data_start].copy_from_slice(packed_offsets.as_slice());
+        ValidSigner::update_account_info(valid_signer_info, |valid_signer| {
+            valid_signer.signer_group = *new_signer_group_info.key;
+            // signer ids are unique within the group
+            valid_signer.signer_id = new_signer_group.assign_signer_id()?.into();
+            valid_signer.prev_signer = new_signer_group.last_signer;
+            valid_signer.next_signer = Pubkey::default();
+            Ok(())
+        })?;
+        new_signer_group.last_signer = *valid_signer_info.key;
 
-        if instruction_data != secp_instruction.data {
-            return Err(AudiusError::SignatureVerificationFailed.into());
-        }
+        signer_group.pack(&mut signer_group_info.data.borrow_mut())?;
+        new_signer_group.pack(&mut new_signer_group_info.data.borrow_mut())?;
 
+        log_event!(
+            "signer_migrated",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+                ("new_group", &new_signer_group_info.key.to_string()),
+            ]
+        );
         Ok(())
     }
 
-    /// Process an [Instruction]().
-    pub fn process(_program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
-        let instruction = AudiusInstruction::try_from_slice(input)?;
-
-        match instruction {
-            AudiusInstruction::InitSignerGroup => {
-                msg!("Instruction: InitSignerGroup");
-                Self::process_init_signer_group(accounts)
+    /// Process [ValidateSignature]().
+    pub fn process_validate_signature(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signature_data: SignatureData,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+        // valid signer's cosigner account, given only if the group requires one
+        let cosigner_info = Self::next_cosigner_info(signer_group_info, account_info_iter)?;
+
+        Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        // Instruction of Secp256 program call and its index in tx
+        let (secp_instruction_index, secp_instruction) = Self::load_secp_instruction(
+            &SysvarIntrospector::new(instruction_info)?,
+            &signature_data,
+        )?;
+
+        let valid_signer = Self::load_group_valid_signer(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            SignerKeyType::SECP256K1,
+        )?;
+        if let Some(cosigner_info) = cosigner_info {
+            valid_signer.check_cosigner(cosigner_info)?;
+        }
+
+        let eth_address = Self::verified_secp_eth_address(
+            secp_instruction_index,
+            &secp_instruction.data,
+            valid_signer.eth_addresses(),
+            &signature_data,
+        )?;
+        drop(valid_signer);
+
+        Self::count_signer_validation(valid_signer_info)?;
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "sig_validated",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })?;
+
+        // lets a program validating via CPI learn which signer made the signature
+        set_validation_result(
+            program_id,
+            &ValidationResult {
+                eth_address,
+                signer_group: *signer_group_info.key,
+            },
+        )
+    }
+
+    /// Process [ValidateSignatureDigest]().
+    pub fn process_validate_signature_digest(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signature_data: SignatureData,
+    ) -> ProgramResult {
+        if signature_data.message.len() != keccak::HASH_BYTES {
+            return Err(AudiusError::InvalidDigestLength.into());
+        }
+        // Sysvar Instruction account info
+        let instruction_info = accounts.get(2).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let (_, secp_instruction) = Self::load_secp_instruction(
+            &SysvarIntrospector::new(instruction_info)?,
+            &signature_data,
+        )?;
+        let offsets = Self::secp_signature_offsets(
+            &secp_instruction.data,
+            signature_data.secp_signature_index,
+        )?;
+        let message = Self::get_secp_slice(
+            &secp_instruction.data,
+            offsets.message_data_offset,
+            offsets.message_data_size as usize,
+        )?;
+        if keccak::hash(message).0[..] != signature_data.message[..] {
+            return Err(AudiusError::SignatureVerificationFailed.into());
+        }
+
+        // digest matches the signed message, the rest is the same as for the raw message
+        let signature_data = SignatureData {
+            message: message.to_vec(),
+            ..signature_data
+        };
+        Self::process_validate_signature(program_id, accounts, signature_data)
+    }
+
+    /// Process [ValidateAndAttest]().
+    pub fn process_validate_and_attest(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signature_data: SignatureData,
+        expires_at_slot: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+        // uninitialized attestation account
+        let attestation_info = next_account_info(account_info_iter)?;
+        // account paying for attestation account creation
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        let rent = Rent::from_account_info(rent_info)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        Self::check_cosigner_not_required(signer_group_info)?;
+        Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        // Instruction of Secp256 program call and its index in tx
+        let (secp_instruction_index, secp_instruction) = Self::load_secp_instruction(
+            &SysvarIntrospector::new(instruction_info)?,
+            &signature_data,
+        )?;
+
+        let valid_signer = Self::load_group_valid_signer(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            SignerKeyType::SECP256K1,
+        )?;
+        let eth_address = Self::verified_secp_eth_address(
+            secp_instruction_index,
+            &secp_instruction.data,
+            valid_signer.eth_addresses(),
+            &signature_data,
+        )?;
+        drop(valid_signer);
+
+        let message_hash = keccak::hash(&signature_data.message).0;
+        let (attestation_address, bump_seed) =
+            find_attestation_address(program_id, valid_signer_info.key, &message_hash);
+        if attestation_address != *attestation_info.key {
+            return Err(AudiusError::WrongAttestationAddress.into());
+        }
+        if !attestation_info.data_is_empty() {
+            return Err(AudiusError::AttestationAlreadyExists.into());
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                attestation_info.key,
+                rent.minimum_balance(Attestation::LEN),
+                Attestation::LEN as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                attestation_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[
+                ATTESTATION_SEED,
+                valid_signer_info.key.as_ref(),
+                message_hash.as_ref(),
+                &[bump_seed],
+            ]],
+        )?;
+
+        let attestation = Box::new(Attestation {
+            version: Self::ATTESTATION_VERSION,
+            signer_group: *signer_group_info.key,
+            valid_signer: *valid_signer_info.key,
+            eth_address,
+            message_hash,
+            slot: Clock::get()?.slot,
+            expires_at_slot,
+            payer: *payer_info.key,
+        });
+        attestation.pack(&mut attestation_info.data.borrow_mut())?;
+
+        Self::count_signer_validation(valid_signer_info)?;
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "attested",
+            &[
+                ("attestation", &attestation_info.key.to_string()),
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })
+    }
+
+    /// Process [CloseAttestation]().
+    pub fn process_close_attestation(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // expired attestation account
+        let attestation_info = next_account_info(account_info_iter)?;
+        // account which paid for the attestation
+        let payer_info = next_account_info(account_info_iter)?;
+
+        let attestation: Box<Attestation> =
+            Box::new(Self::load_account_checked(attestation_info, program_id)?);
+        if !attestation.is_expired(Clock::get()?.slot) {
+            return Err(AudiusError::AttestationNotExpired.into());
+        }
+        if *payer_info.key != attestation.payer {
+            return Err(AudiusError::WrongRentReceiver.into());
+        }
+
+        attestation_info.data.borrow_mut().fill(0);
+        Self::drain_lamports(attestation_info, payer_info)?;
+
+        log_event!(
+            "attestation_closed",
+            &[("attestation", &attestation_info.key.to_string())]
+        );
+        Ok(())
+    }
+
+    /// Process [CloseSignerGroup]().
+    pub fn process_close_signer_group(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // empty signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let owner_info = next_account_info(account_info_iter)?;
+        // account receiving the rent of the group
+        let rent_receiver_info = next_account_info(account_info_iter)?;
+        // registry of the owner's signer groups
+        let registry_info = next_account_info(account_info_iter)?;
+
+        let signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+        signer_group.check_owner(owner_info)?;
+        // signers left in the group would still validate against the closed group
+        if signer_group.first_signer != Pubkey::default() {
+            return Err(AudiusError::SignerGroupNotEmpty.into());
+        }
+        Self::unregister_owner_group(
+            program_id,
+            signer_group_info.key,
+            &signer_group.owner,
+            registry_info,
+        )?;
+
+        // tombstone goes in before the lamports are drained, the account refunded
+        // later in the transaction can't be initialized again
+        SignerGroup::write_tombstone(&mut signer_group_info.data.borrow_mut())?;
+        Self::drain_lamports(signer_group_info, rent_receiver_info)?;
+
+        log_event!(
+            "group_closed",
+            &[("group", &signer_group_info.key.to_string())]
+        );
+        Ok(())
+    }
+
+    /// Move all lamports of the closed account to the receiver. The runtime purges
+    /// the account with no lamports once the transaction ends.
+    fn drain_lamports(account_info: &AccountInfo, receiver_info: &AccountInfo) -> ProgramResult {
+        let receiver_lamports = receiver_info
+            .lamports()
+            .checked_add(account_info.lamports())
+            .ok_or(AudiusError::Overflow)?;
+        **receiver_info.lamports.borrow_mut() = receiver_lamports;
+        **account_info.lamports.borrow_mut() = 0;
+        Ok(())
+    }
+
+    /// Process [ValidateSignatures]().
+    pub fn process_validate_signatures(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        threshold: u8,
+        signatures: Vec<SignatureData>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer accounts, one per signature
+        let valid_signer_infos = Self::next_signer_infos(account_info_iter, signatures.len())?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+
+        Self::check_cosigner_not_required(signer_group_info)?;
+        for signature_data in &signatures {
+            Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        }
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        let matched_signers = Self::match_signatures(
+            program_id,
+            valid_signer_infos,
+            signer_group_info,
+            instruction_info,
+            &signatures,
+        )?;
+        if matched_signers.len() < threshold as usize {
+            return Err(AudiusError::ThresholdNotReached.into());
+        }
+
+        for valid_signer_info in &matched_signers {
+            Self::count_signer_validation(valid_signer_info)?;
+        }
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "sigs_validated",
+            &[
+                ("group", &signer_group_info.key.to_string()),
+                ("signers", &matched_signers.len().to_string()),
+                ("threshold", &threshold.to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })
+    }
+
+    /// Process [BatchValidateSignatures]().
+    pub fn process_batch_validate_signatures(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signatures: Vec<SignatureData>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer accounts, one per signature
+        let valid_signer_infos = Self::next_signer_infos(account_info_iter, signatures.len())?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+
+        Self::check_cosigner_not_required(signer_group_info)?;
+        for signature_data in &signatures {
+            Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        }
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        let introspector = SysvarIntrospector::new(instruction_info)?;
+        let mut batch_secp_instruction_index = None;
+        for (valid_signer_info, signature_data) in valid_signer_infos.iter().zip(&signatures) {
+            let (secp_instruction_index, secp_instruction) =
+                Self::load_secp_instruction(&introspector, signature_data)?;
+            if u16::from(secp_instruction_index) >= introspector.current_index()
+                || *batch_secp_instruction_index.get_or_insert(secp_instruction_index)
+                    != secp_instruction_index
+            {
+                return Err(AudiusError::BatchSecpInstructionMismatch.into());
+            }
+
+            let valid_signer = Self::load_group_valid_signer(
+                program_id,
+                valid_signer_info,
+                signer_group_info,
+                SignerKeyType::SECP256K1,
+            )?;
+            Self::validate_secp_instruction(
+                secp_instruction_index,
+                &secp_instruction.data,
+                valid_signer.eth_addresses(),
+                signature_data,
+            )?;
+        }
+
+        for valid_signer_info in &valid_signer_infos {
+            Self::count_signer_validation(valid_signer_info)?;
+        }
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "batch_validated",
+            &[
+                ("group", &signer_group_info.key.to_string()),
+                ("signatures", &signatures.len().to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })
+    }
+
+    /// Process [ValidateSignatureBatch]().
+    pub fn process_validate_signature_batch(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signatures: Vec<SignatureData>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+        // valid signer's cosigner account, given only if the group requires one
+        let cosigner_info = Self::next_cosigner_info(signer_group_info, account_info_iter)?;
+
+        for signature_data in &signatures {
+            Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        }
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        let valid_signer = Self::load_group_valid_signer(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            SignerKeyType::SECP256K1,
+        )?;
+        if let Some(cosigner_info) = cosigner_info {
+            valid_signer.check_cosigner(cosigner_info)?;
+        }
+
+        let introspector = SysvarIntrospector::new(instruction_info)?;
+        for (index, signature_data) in signatures.iter().enumerate() {
+            Self::load_secp_instruction(&introspector, signature_data)
+                .and_then(|(secp_instruction_index, secp_instruction)| {
+                    Self::validate_secp_instruction(
+                        secp_instruction_index,
+                        &secp_instruction.data,
+                        valid_signer.eth_addresses(),
+                        signature_data,
+                    )
+                })
+                .map_err(|err| {
+                    msg!("Signature {} of the batch failed validation", index);
+                    err
+                })?;
+        }
+        drop(valid_signer);
+
+        for _ in &signatures {
+            Self::count_signer_validation(valid_signer_info)?;
+        }
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "sig_batch_validated",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+                ("messages", &signatures.len().to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })
+    }
+
+    /// Process [ValidateSignatureWithMerkleProof]().
+    pub fn process_validate_signature_with_merkle_proof(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signature_data: SignatureData,
+        proof: Vec<MerkleNode>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+        // revocation registry of the group
+        let revocation_registry_info = next_account_info(account_info_iter)?;
+
+        let signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+        if signer_group.members_merkle_root == MerkleNode::default() {
+            return Err(AudiusError::MerkleRootNotSet.into());
+        }
+        // cosigners are kept in valid signer accounts, which aren't given here
+        Self::check_cosigner_not_required(signer_group_info)?;
+        Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        let (secp_instruction_index, secp_instruction) = Self::load_secp_instruction(
+            &SysvarIntrospector::new(instruction_info)?,
+            &signature_data,
+        )?;
+        let offsets = Self::secp_signature_offsets(
+            &secp_instruction.data,
+            signature_data.secp_signature_index,
+        )?;
+        let mut eth_address = [0u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        eth_address.copy_from_slice(Self::get_secp_slice(
+            &secp_instruction.data,
+            offsets.eth_address_offset,
+            SecpSignatureOffsets::ETH_ADDRESS_SIZE,
+        )?);
+        if !merkle::verify(
+            &signer_group.members_merkle_root,
+            &merkle::leaf(&eth_address),
+            &proof,
+        ) {
+            return Err(AudiusError::InvalidMerkleProof.into());
+        }
+        drop(signer_group);
+        // proof of membership doesn't outlive revocation of the address
+        Self::check_not_revoked(
+            program_id,
+            revocation_registry_info,
+            signer_group_info.key,
+            &[eth_address],
+        )?;
+
+        Self::validate_secp_instruction(
+            secp_instruction_index,
+            &secp_instruction.data,
+            &[eth_address],
+            &signature_data,
+        )?;
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "sig_validated_by_proof",
+            &[
+                ("group", &signer_group_info.key.to_string()),
+                ("eth_address", &format_hex(&eth_address)),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })
+    }
+
+    /// Process [GovernedInitValidSigner]().
+    pub fn process_governed_init_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        signatures: Vec<SignatureData>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // approving valid signer accounts, one per signature
+        let approver_infos = Self::next_signer_infos(account_info_iter, signatures.len())?;
+
+        Self::init_secp_valid_signer(
+            program_id,
+            account_info_iter.as_slice(),
+            InitValidSignerArgs {
+                eth_address,
+                label: [0; ValidSigner::LABEL_SIZE],
+                active_after_slot: 0,
+            },
+            None,
+            MembershipAuthority::Governance {
+                action: GovernanceAction::AddSigner,
+                eth_address,
+                approver_infos,
+                signatures: &signatures,
+            },
+        )
+    }
+
+    /// Process [GovernedClearValidSigner]().
+    pub fn process_governed_clear_valid_signer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        signatures: Vec<SignatureData>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // approving valid signer accounts, one per signature
+        let approver_infos = Self::next_signer_infos(account_info_iter, signatures.len())?;
+        let clear_accounts = account_info_iter.as_slice();
+        // valid signer to remove and its signer group
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        let signer_group_info = next_account_info(account_info_iter)?;
+
+        // approvals name the eth address, valid signer must be the account derived from it
+        let (valid_signer_address, _) =
+            find_valid_signer_address(program_id, signer_group_info.key, &eth_address);
+        if valid_signer_address != *valid_signer_info.key {
+            return Err(AudiusError::WrongValidSignerAddress.into());
+        }
+
+        Self::clear_valid_signer(
+            program_id,
+            clear_accounts,
+            MembershipAuthority::Governance {
+                action: GovernanceAction::RemoveSigner,
+                eth_address,
+                approver_infos,
+                signatures: &signatures,
+            },
+            false,
+        )
+    }
+
+    /// Process [AddSignerAddress]().
+    pub fn process_add_signer_address(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> ProgramResult {
+        let valid_signer = Self::update_signer_addresses(
+            program_id,
+            accounts,
+            |valid_signer, account_info_iter| {
+                // revocation registry of the group
+                let revocation_registry_info = next_account_info(account_info_iter)?;
+                Self::check_not_revoked(
+                    program_id,
+                    revocation_registry_info,
+                    &valid_signer.signer_group,
+                    &[eth_address],
+                )?;
+                valid_signer.add_eth_address(eth_address)
+            },
+        )?;
+        log_event!(
+            "signer_address_added",
+            &[
+                ("signer", &valid_signer.to_string()),
+                ("eth_address", &format_hex(&eth_address)),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [RemoveSignerAddress]().
+    pub fn process_remove_signer_address(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> ProgramResult {
+        let valid_signer =
+            Self::update_signer_addresses(program_id, accounts, |valid_signer, _| {
+                valid_signer.remove_eth_address(&eth_address)
+            })?;
+        log_event!(
+            "signer_address_removed",
+            &[
+                ("signer", &valid_signer.to_string()),
+                ("eth_address", &format_hex(&eth_address)),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetSignerLabel]().
+    pub fn process_set_signer_label(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        label: [u8; ValidSigner::LABEL_SIZE],
+    ) -> ProgramResult {
+        ValidSigner::unpack_label(&label)?;
+
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        ValidSigner::update_account_info(valid_signer_info, |valid_signer| {
+            valid_signer.label = label;
+            Ok(())
+        })?;
+        log_event!(
+            "signer_label_set",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("label", ValidSigner::unpack_label(&label)?),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetActivationSlot]().
+    pub fn process_set_activation_slot(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        active_after_slot: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        let slot = Clock::get()?.slot;
+        ValidSigner::update_account_info(valid_signer_info, |valid_signer| {
+            // activation can't be amended once signer's signatures are accepted
+            if valid_signer.is_active(slot) {
+                return Err(AudiusError::SignerAlreadyActive.into());
+            }
+            valid_signer.active_after_slot = active_after_slot.into();
+            Ok(())
+        })?;
+        log_event!(
+            "signer_activation_set",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("active_after_slot", &active_after_slot.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetSignerExpiry]().
+    pub fn process_set_signer_expiry(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        expires_at_slot: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        ValidSigner::update_account_info(valid_signer_info, |valid_signer| {
+            valid_signer.expires_at_slot = expires_at_slot.into();
+            Ok(())
+        })?;
+        log_event!(
+            "signer_expiry_set",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("expires_at_slot", &expires_at_slot.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetSignerCosigner]().
+    pub fn process_set_signer_cosigner(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        cosigner: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        ValidSigner::update_account_info(valid_signer_info, |valid_signer| {
+            valid_signer.cosigner = cosigner;
+            Ok(())
+        })?;
+        log_event!(
+            "signer_cosigner_set",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("cosigner", &cosigner.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [ProposeOwnership]().
+    pub fn process_propose_ownership(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_owner: Pubkey,
+    ) -> ProgramResult {
+        let mut owner = Pubkey::default();
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.pending_owner = new_owner;
+                owner = signer_group.owner;
+                Ok(())
+            })?;
+        log_event!(
+            "ownership_proposed",
+            &[
+                ("group", &signer_group.to_string()),
+                ("owner", &owner.to_string()),
+                ("pending_owner", &new_owner.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetDelegateOperator]().
+    pub fn process_set_delegate_operator(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        delegate_operator: Pubkey,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.delegate_operator = delegate_operator;
+                Ok(())
+            })?;
+        log_event!(
+            "delegate_operator_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("delegate_operator", &delegate_operator.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [UpdateMerkleRoot]().
+    pub fn process_update_merkle_root(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        members_merkle_root: MerkleNode,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.members_merkle_root = members_merkle_root;
+                Ok(())
+            })?;
+        log_event!(
+            "merkle_root_updated",
+            &[
+                ("group", &signer_group.to_string()),
+                ("root", &format_hex(&members_merkle_root)),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetGovernanceRealm]().
+    pub fn process_set_governance_realm(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        governance_realm: Pubkey,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.governance_realm = governance_realm;
+                Ok(())
+            })?;
+        log_event!(
+            "governance_realm_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("realm", &governance_realm.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [ExecuteGovernanceProposal]().
+    pub fn process_execute_governance_proposal(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction: AudiusInstruction,
+    ) -> ProgramResult {
+        match instruction {
+            AudiusInstruction::InitValidSigner(eth_address, None) => Self::init_secp_valid_signer(
+                program_id,
+                accounts,
+                InitValidSignerArgs {
+                    eth_address,
+                    label: [0; ValidSigner::LABEL_SIZE],
+                    active_after_slot: 0,
+                },
+                None,
+                MembershipAuthority::GovernanceRealm,
+            ),
+            AudiusInstruction::ClearValidSigner => Self::clear_valid_signer(
+                program_id,
+                accounts,
+                MembershipAuthority::GovernanceRealm,
+                false,
+            ),
+            _ => Err(AudiusError::UnsupportedProposalInstruction.into()),
+        }
+    }
+
+    /// Process [AcceptOwnership]().
+    pub fn process_accept_ownership(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let mut old_owner = Pubkey::default();
+        let mut new_owner = Pubkey::default();
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_pending_owner(authority_info)?;
+                old_owner = signer_group.owner;
+                signer_group.owner = signer_group.pending_owner;
+                signer_group.pending_owner = Pubkey::default();
+                // operator delegated by the previous owner isn't trusted by the new one
+                signer_group.delegate_operator = Pubkey::default();
+                new_owner = signer_group.owner;
+                Ok(())
+            })?;
+        Self::record_ownership_transfer(
+            program_id,
+            &signer_group,
+            &accounts[2..],
+            OwnershipTransfer {
+                previous_owner: old_owner,
+                new_owner,
+                transfer_slot: Clock::get()?.slot,
+            },
+        )?;
+
+        // ownership history and its funding accounts are followed by the registries
+        let account_info_iter = &mut accounts.iter().skip(3);
+        // account paying for the ownership history and the new owner's registry
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+        // registry of the previous owner's signer groups
+        let old_registry_info = next_account_info(account_info_iter)?;
+        // registry of the new owner's signer groups
+        let new_registry_info = next_account_info(account_info_iter)?;
+        Self::unregister_owner_group(program_id, &signer_group, &old_owner, old_registry_info)?;
+        Self::register_owner_group(
+            program_id,
+            &signer_group,
+            &new_owner,
+            new_registry_info,
+            payer_info,
+            rent_info,
+            system_program_info,
+        )?;
+
+        log_event!(
+            "owner_change",
+            &[
+                ("group", &signer_group.to_string()),
+                ("old", &old_owner.to_string()),
+                ("new", &new_owner.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Prepend the transfer to ownership history of the signer group, creating the history
+    /// account on the first transfer
+    fn record_ownership_transfer(
+        program_id: &Pubkey,
+        signer_group: &Pubkey,
+        accounts: &[AccountInfo],
+        transfer: OwnershipTransfer,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // ownership history of the group
+        let history_info = next_account_info(account_info_iter)?;
+        // account paying for the history
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let (history_address, bump_seed) = find_ownership_history_address(program_id, signer_group);
+        if history_address != *history_info.key {
+            return Err(AudiusError::WrongOwnershipHistoryAddress.into());
+        }
+
+        if history_info.data_is_empty() {
+            let rent = Rent::from_account_info(rent_info)?;
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    history_info.key,
+                    rent.minimum_balance(OwnershipHistory::LEN),
+                    OwnershipHistory::LEN as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    history_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[&[OWNERSHIP_HISTORY_SEED, signer_group.as_ref(), &[bump_seed]]],
+            )?;
+        } else {
+            Self::check_account_owner(history_info, program_id)?;
+        }
+
+        let mut history = OwnershipHistory::unpack(&history_info.data.borrow())?;
+        if !history.is_initialized() {
+            history.version = Self::OWNERSHIP_HISTORY_VERSION;
+            history.signer_group = *signer_group;
+        }
+        history.record(transfer);
+        history.pack(&mut history_info.data.borrow_mut())
+    }
+
+    /// Process [GetOwnershipHistory]().
+    pub fn process_get_ownership_history(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // ownership history of the group
+        let history_info = next_account_info(account_info_iter)?;
+
+        Self::load_account_checked::<SignerGroup>(signer_group_info, program_id)?;
+
+        let (history_address, _) =
+            find_ownership_history_address(program_id, signer_group_info.key);
+        if history_address != *history_info.key {
+            return Err(AudiusError::WrongOwnershipHistoryAddress.into());
+        }
+
+        // history account is created by the first ownership transfer
+        let history = if history_info.data_is_empty() {
+            OwnershipHistory::default()
+        } else {
+            Self::check_account_owner(history_info, program_id)?;
+            OwnershipHistory::unpack(&history_info.data.borrow())?
+        };
+
+        msg!(&history.to_json());
+        Ok(())
+    }
+
+    /// Process [CancelProposedOwnership]().
+    pub fn process_cancel_proposed_ownership(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                if signer_group.pending_owner == Pubkey::default() {
+                    return Err(AudiusError::OwnershipNotProposed.into());
+                }
+                signer_group.pending_owner = Pubkey::default();
+                Ok(())
+            })?;
+        log_event!(
+            "ownership_proposal_cancelled",
+            &[("group", &signer_group.to_string())]
+        );
+        Ok(())
+    }
+
+    /// Process [SetEthPoPRequired]().
+    pub fn process_set_eth_pop_required(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_pop_required: bool,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.eth_pop_required = eth_pop_required;
+                Ok(())
+            })?;
+        log_event!(
+            "eth_pop_required_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("eth_pop_required", &eth_pop_required.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetValidationFee]().
+    pub fn process_set_validation_fee(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        validation_fee: u64,
+        treasury: Pubkey,
+    ) -> ProgramResult {
+        if validation_fee != 0 && treasury == Pubkey::default() {
+            return Err(AudiusError::WrongTreasury.into());
+        }
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.validation_fee = validation_fee.into();
+                signer_group.treasury = treasury;
+                Ok(())
+            })?;
+        log_event!(
+            "validation_fee_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("validation_fee", &validation_fee.to_string()),
+                ("treasury", &treasury.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetValidationQuota]().
+    pub fn process_set_validation_quota(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        quota_per_window: u32,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.quota_per_window = quota_per_window.into();
+                Ok(())
+            })?;
+        log_event!(
+            "validation_quota_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("quota_per_window", &quota_per_window.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [UpdateMaxSigners]().
+    pub fn process_update_max_signers(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        max_valid_signers: u32,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.set_max_valid_signers(max_valid_signers)
+            })?;
+        log_event!(
+            "max_signers_updated",
+            &[
+                ("group", &signer_group.to_string()),
+                ("max_valid_signers", &max_valid_signers.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [AddMessagePrefix]().
+    pub fn process_add_message_prefix(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        prefix: [u8; SignerGroup::MESSAGE_PREFIX_SIZE],
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.add_message_prefix(prefix)
+            })?;
+        log_event!(
+            "message_prefix_added",
+            &[
+                ("group", &signer_group.to_string()),
+                ("prefix", &format_hex(&prefix)),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [RemoveMessagePrefix]().
+    pub fn process_remove_message_prefix(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        prefix: [u8; SignerGroup::MESSAGE_PREFIX_SIZE],
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.remove_message_prefix(&prefix)
+            })?;
+        log_event!(
+            "message_prefix_removed",
+            &[
+                ("group", &signer_group.to_string()),
+                ("prefix", &format_hex(&prefix)),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetCosignerRequired]().
+    pub fn process_set_cosigner_required(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        require_cosigner: bool,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.require_cosigner = require_cosigner;
+                Ok(())
+            })?;
+        log_event!(
+            "cosigner_required_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("require_cosigner", &require_cosigner.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [SetGovernance]().
+    pub fn process_set_governance(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        governed: bool,
+        threshold: u8,
+    ) -> ProgramResult {
+        let signer_group =
+            Self::update_signer_group(program_id, accounts, |signer_group, authority_info| {
+                signer_group.check_owner(authority_info)?;
+                signer_group.set_governance(governed, threshold)
+            })?;
+        log_event!(
+            "governance_set",
+            &[
+                ("group", &signer_group.to_string()),
+                ("governed", &governed.to_string()),
+                ("threshold", &threshold.to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Process [RevokeEthAddress]().
+    pub fn process_revoke_eth_address(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let group_owner_info = next_account_info(account_info_iter)?;
+        // revocation registry of the group
+        let registry_info = next_account_info(account_info_iter)?;
+        // account paying for the registry
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        let rent = Rent::from_account_info(rent_info)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+        signer_group.check_owner(group_owner_info)?;
+
+        let (registry_address, bump_seed) =
+            find_revocation_registry_address(program_id, signer_group_info.key);
+        if registry_address != *registry_info.key {
+            return Err(AudiusError::WrongRevocationRegistryAddress.into());
+        }
+
+        if registry_info.data_is_empty() {
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer_info.key,
+                    registry_info.key,
+                    rent.minimum_balance(RevocationRegistry::LEN),
+                    RevocationRegistry::LEN as u64,
+                    program_id,
+                ),
+                &[
+                    payer_info.clone(),
+                    registry_info.clone(),
+                    system_program_info.clone(),
+                ],
+                &[&[
+                    REVOCATION_SEED,
+                    signer_group_info.key.as_ref(),
+                    &[bump_seed],
+                ]],
+            )?;
+        }
+
+        let mut registry = RevocationRegistry::unpack(&registry_info.data.borrow())?;
+        if !registry.is_initialized() {
+            registry.version = Self::REVOCATION_REGISTRY_VERSION;
+            registry.signer_group = *signer_group_info.key;
+        }
+        registry.revoke(eth_address)?;
+        registry.pack(&mut registry_info.data.borrow_mut())?;
+
+        log_event!(
+            "eth_address_revoked",
+            &[
+                ("group", &signer_group_info.key.to_string()),
+                ("eth_address", &format_hex(&eth_address)),
+            ]
+        );
+        Ok(())
+    }
+
+    /// Update initialized signer group after the authority is checked by `update`,
+    /// returns the group address
+    fn update_signer_group<F: FnOnce(&mut SignerGroup, &AccountInfo) -> ProgramResult>(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        update: F,
+    ) -> Result<Pubkey, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner or pending owner
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let mut signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+
+        update(&mut signer_group, authority_info)?;
+
+        signer_group.pack(&mut signer_group_info.data.borrow_mut())?;
+        Ok(*signer_group_info.key)
+    }
+
+    /// Update eth addresses of Secp256k1 valid signer in place after checking owner's authority,
+    /// returns the valid signer address
+    fn update_signer_addresses<
+        F: FnOnce(&mut ValidSigner, &mut Iter<AccountInfo>) -> ProgramResult,
+    >(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        update: F,
+    ) -> Result<Pubkey, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        ValidSigner::update_account_info(valid_signer_info, |valid_signer| {
+            if valid_signer.key_type != SignerKeyType::SECP256K1 {
+                return Err(AudiusError::WrongSignerKeyType.into());
+            }
+            update(valid_signer, account_info_iter)
+        })?;
+        Ok(*valid_signer_info.key)
+    }
+
+    /// Process [ValidateEd25519Signature]().
+    pub fn process_validate_ed25519_signature(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signature_data: Ed25519SignatureData,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // Sysvar Instruction account info
+        let instruction_info = next_account_info(account_info_iter)?;
+
+        let introspector = SysvarIntrospector::new(instruction_info)?;
+        Self::check_cosigner_not_required(signer_group_info)?;
+        Self::check_message_prefix(signer_group_info, &signature_data.message)?;
+        Self::charge_validation_fee(signer_group_info, account_info_iter)?;
+
+        let ed25519_instruction = introspector
+            .instruction_at(signature_data.ed25519_instruction_index as usize)
+            .map_err(|_| AudiusError::Ed25519InstructionMissing)?;
+        if ed25519_instruction.program_id != ed25519_program::id() {
+            return Err(AudiusError::Ed25519InstructionMissing.into());
+        }
+
+        let valid_signer = Self::load_group_valid_signer(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            SignerKeyType::ED25519,
+        )?;
+
+        Self::validate_ed25519_instruction(
+            signature_data.ed25519_instruction_index,
+            &ed25519_instruction.data,
+            &valid_signer.ed25519_pubkey,
+            &signature_data,
+        )?;
+        drop(valid_signer);
+
+        Self::count_signer_validation(valid_signer_info)?;
+        Self::count_group_validation(signer_group_info)?;
+
+        log_event!(
+            "sig_validated",
+            &[
+                ("signer", &valid_signer_info.key.to_string()),
+                ("group", &signer_group_info.key.to_string()),
+            ]
+        );
+
+        Self::update_stats(program_id, account_info_iter, |stats| {
+            &mut stats.total_validations
+        })
+    }
+
+    /// Process [QuerySignerGroup]().
+    pub fn process_query_signer_group(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+
+        let signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+
+        msg!(&signer_group.to_json());
+        Ok(())
+    }
+
+    /// Process [InitValidSignerMetadata]().
+    pub fn process_init_valid_signer_metadata(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        metadata_args: MetadataArgs,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // uninitialized metadata account
+        let metadata_info = next_account_info(account_info_iter)?;
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+        // account paying for metadata account creation
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        let rent = Rent::from_account_info(rent_info)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let (metadata_address, bump_seed) =
+            find_valid_signer_metadata_address(program_id, valid_signer_info.key);
+        if metadata_address != *metadata_info.key {
+            return Err(AudiusError::WrongMetadataAccount.into());
+        }
+
+        if !metadata_info.data_is_empty() {
+            return Err(AudiusError::MetadataAlreadyInitialized.into());
+        }
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                metadata_info.key,
+                rent.minimum_balance(ValidSignerMetadata::LEN),
+                ValidSignerMetadata::LEN as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                metadata_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[METADATA_SEED, valid_signer_info.key.as_ref(), &[bump_seed]]],
+        )?;
+
+        let metadata = Box::new(ValidSignerMetadata {
+            version: Self::METADATA_VERSION,
+            valid_signer: *valid_signer_info.key,
+            endpoint_url: metadata_args.endpoint_url,
+            operator_name: metadata_args.operator_name,
+        });
+
+        log_event!(
+            "metadata_init",
+            &[("signer", &valid_signer_info.key.to_string())]
+        );
+
+        metadata.pack(&mut metadata_info.data.borrow_mut())
+    }
+
+    /// Process [UpdateValidSignerMetadata]().
+    pub fn process_update_valid_signer_metadata(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        metadata_args: MetadataArgs,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // initialized metadata account
+        let metadata_info = next_account_info(account_info_iter)?;
+        // initialized valid signer account
+        let valid_signer_info = next_account_info(account_info_iter)?;
+        // signer group account
+        let signer_group_info = next_account_info(account_info_iter)?;
+        // signer group's owner
+        let signer_groups_owner_info = next_account_info(account_info_iter)?;
+
+        let (metadata_address, _) =
+            find_valid_signer_metadata_address(program_id, valid_signer_info.key);
+        if metadata_address != *metadata_info.key {
+            return Err(AudiusError::WrongMetadataAccount.into());
+        }
+
+        let mut metadata: Box<ValidSignerMetadata> =
+            Box::new(Self::load_account_checked(metadata_info, program_id)?);
+
+        Self::check_valid_signer_authority(
+            program_id,
+            valid_signer_info,
+            signer_group_info,
+            signer_groups_owner_info,
+        )?;
+
+        metadata.endpoint_url = metadata_args.endpoint_url;
+        metadata.operator_name = metadata_args.operator_name;
+
+        log_event!(
+            "metadata_updated",
+            &[("signer", &valid_signer_info.key.to_string())]
+        );
+
+        metadata.pack(&mut metadata_info.data.borrow_mut())
+    }
+
+    /// Process [InitProgramStats]().
+    pub fn process_init_program_stats(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // uninitialized program stats account
+        let stats_info = next_account_info(account_info_iter)?;
+        // account paying for stats account creation
+        let payer_info = next_account_info(account_info_iter)?;
+        // rent sysvar account
+        let rent_info = next_account_info(account_info_iter)?;
+        let rent = Rent::from_account_info(rent_info)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let (stats_address, bump_seed) = find_program_stats_address(program_id);
+        if stats_address != *stats_info.key {
+            return Err(AudiusError::WrongStatsAccount.into());
+        }
+
+        if !stats_info.data_is_empty() {
+            return Err(AudiusError::StatsAlreadyInitialized.into());
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                stats_info.key,
+                rent.minimum_balance(ProgramStats::LEN),
+                ProgramStats::LEN as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                stats_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[STATS_SEED, &[bump_seed]]],
+        )?;
+
+        let stats = ProgramStats {
+            version: Self::STATS_VERSION,
+            ..ProgramStats::default()
+        };
+
+        log_event!("stats_init", &[("stats", &stats_info.key.to_string())]);
+
+        stats.pack(&mut stats_info.data.borrow_mut())
+    }
+
+    /// Process [GetProgramStats]().
+    pub fn process_get_program_stats(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        // program stats account
+        let stats_info = next_account_info(account_info_iter)?;
+
+        let (stats_address, _) = find_program_stats_address(program_id);
+        if stats_address != *stats_info.key {
+            return Err(AudiusError::WrongStatsAccount.into());
+        }
+
+        let stats: ProgramStats = Self::load_account_checked(stats_info, program_id)?;
+
+        msg!(
+            "groups: {}, signers: {}, validations: {}, clearances: {}",
+            stats.total_groups,
+            stats.total_signers,
+            stats.total_validations,
+            stats.total_clearances
+        );
+        Ok(())
+    }
+
+    /// Increment program stats counter selected by `counter` if stats account is passed
+    /// after the instruction's accounts, saturating at `u64::MAX`. Stats account which
+    /// isn't created yet is skipped.
+    #[cfg(feature = "track-stats")]
+    fn update_stats<F: FnOnce(&mut ProgramStats) -> &mut u64>(
+        program_id: &Pubkey,
+        account_info_iter: &mut Iter<AccountInfo>,
+        counter: F,
+    ) -> ProgramResult {
+        let stats_info = match account_info_iter.next() {
+            Some(stats_info) => stats_info,
+            None => return Ok(()),
+        };
+
+        let (stats_address, _) = find_program_stats_address(program_id);
+        if stats_address != *stats_info.key {
+            return Err(AudiusError::WrongStatsAccount.into());
+        }
+
+        if stats_info.data_is_empty() {
+            return Ok(());
+        }
+
+        let mut stats = ProgramStats::deserialize_any_version(&stats_info.data.borrow())?.0;
+        let counter = counter(&mut stats);
+        *counter = counter.saturating_add(1);
+        stats.pack(&mut stats_info.data.borrow_mut())
+    }
+
+    /// Stats tracking is disabled, stats account is ignored
+    #[cfg(not(feature = "track-stats"))]
+    fn update_stats<F: FnOnce(&mut ProgramStats) -> &mut u64>(
+        _program_id: &Pubkey,
+        _account_info_iter: &mut Iter<AccountInfo>,
+        _counter: F,
+    ) -> ProgramResult {
+        Ok(())
+    }
+
+    /// Check that the validated message starts with one of the group's prefixes, if it has any
+    fn check_message_prefix(signer_group_info: &AccountInfo, message: &[u8]) -> ProgramResult {
+        let signer_group =
+            Box::new(SignerGroup::deserialize_any_version(&signer_group_info.data.borrow())?.0);
+        signer_group.check_message_prefix(message)
+    }
+
+    /// Takes valid signer's cosigner account from the list of accounts if the group
+    /// requires one, no account is taken otherwise
+    fn next_cosigner_info<'a, 'b>(
+        signer_group_info: &AccountInfo,
+        account_info_iter: &mut Iter<'a, AccountInfo<'b>>,
+    ) -> Result<Option<&'a AccountInfo<'b>>, ProgramError> {
+        let signer_group =
+            Box::new(SignerGroup::deserialize_any_version(&signer_group_info.data.borrow())?.0);
+        if !signer_group.require_cosigner {
+            return Ok(None);
+        }
+        next_account_info(account_info_iter)
+            .map(Some)
+            .map_err(|_| AudiusError::MissingCosigner.into())
+    }
+
+    /// Check that the group doesn't require a cosigner, validations taking no cosigner
+    /// account would bypass it otherwise
+    fn check_cosigner_not_required(signer_group_info: &AccountInfo) -> ProgramResult {
+        let signer_group =
+            Box::new(SignerGroup::deserialize_any_version(&signer_group_info.data.borrow())?.0);
+        if signer_group.require_cosigner {
+            return Err(AudiusError::MissingCosigner.into());
+        }
+        Ok(())
+    }
+
+    /// Counts successful validation in the valid signer's total and against its rate limit.
+    /// Accounts of the older layout have no room for either, they are validated without
+    /// rate limit until migrated.
+    fn count_signer_validation(valid_signer_info: &AccountInfo) -> ProgramResult {
+        if valid_signer_info.data_len() < ValidSigner::LEN {
+            return Ok(());
+        }
+        let slot = Clock::get()?.slot;
+        let mut valid_signer = ValidSigner::from_account_info_mut(valid_signer_info)?;
+        valid_signer.consume_rate_limit(
+            slot,
+            Self::RATE_LIMIT_WINDOW_SLOTS,
+            Self::MAX_VALIDATIONS_PER_WINDOW,
+        )?;
+        valid_signer.count_validation();
+        Ok(())
+    }
+
+    /// Counts successful validation in the group's total and against its quota, if it has one.
+    /// Groups of the older layout have neither, their total is left as is until migrated.
+    fn count_group_validation(signer_group_info: &AccountInfo) -> ProgramResult {
+        if signer_group_info.data_len() < SignerGroup::LEN {
+            return Ok(());
+        }
+        let mut signer_group =
+            Box::new(SignerGroup::deserialize_any_version(&signer_group_info.data.borrow())?.0);
+        // Clock is read only for groups with validation quota
+        if u32::from(signer_group.quota_per_window) != 0 {
+            signer_group.consume_validation_quota(Clock::get()?.slot)?;
+        }
+        signer_group.count_validation();
+        signer_group.pack(&mut signer_group_info.data.borrow_mut())?;
+        Ok(())
+    }
+
+    /// Transfers group's validation fee to its treasury. Fee accounts are expected next
+    /// in the list of accounts only if the group charges a fee
+    fn charge_validation_fee(
+        signer_group_info: &AccountInfo,
+        account_info_iter: &mut Iter<AccountInfo>,
+    ) -> ProgramResult {
+        let signer_group =
+            Box::new(SignerGroup::deserialize_any_version(&signer_group_info.data.borrow())?.0);
+        let validation_fee = u64::from(signer_group.validation_fee);
+        if validation_fee == 0 {
+            return Ok(());
+        }
+
+        // fee payer account
+        let fee_payer_info = next_account_info(account_info_iter)
+            .map_err(|_| AudiusError::ValidationFeePayerMissing)?;
+        // group's treasury account
+        let treasury_info = next_account_info(account_info_iter)?;
+        // system program account
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !fee_payer_info.is_signer {
+            return Err(AudiusError::ValidationFeePayerMissing.into());
+        }
+        if *treasury_info.key != signer_group.treasury {
+            return Err(AudiusError::WrongTreasury.into());
+        }
+        if *system_program_info.key != system_program::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        invoke(
+            &system_instruction::transfer(fee_payer_info.key, treasury_info.key, validation_fee),
+            &[
+                fee_payer_info.clone(),
+                treasury_info.clone(),
+                system_program_info.clone(),
+            ],
+        )
+    }
+
+    /// Updates neighbour signer in the group's signer list, the account is expected
+    /// next in the list of accounts unless there is no such neighbour
+    fn update_list_signer<F: FnOnce(&mut ValidSigner)>(
+        account_info_iter: &mut Iter<AccountInfo>,
+        expected_signer: &Pubkey,
+        update: F,
+    ) -> ProgramResult {
+        if *expected_signer == Pubkey::default() {
+            return Ok(());
+        }
+        let list_signer_info = next_account_info(account_info_iter)?;
+        if list_signer_info.key != expected_signer {
+            return Err(AudiusError::WrongSignerListAccount.into());
+        }
+
+        ValidSigner::update_account_info(list_signer_info, |list_signer| {
+            update(list_signer);
+            Ok(())
+        })
+    }
+
+    /// Check that valid signer is initialized, belongs to the group
+    /// and group's owner signed the transaction
+    fn check_valid_signer_authority(
+        program_id: &Pubkey,
+        valid_signer_info: &AccountInfo,
+        signer_group_info: &AccountInfo,
+        signer_groups_owner_info: &AccountInfo,
+    ) -> ProgramResult {
+        let signer_group: Box<SignerGroup> =
+            Box::new(Self::load_account_checked(signer_group_info, program_id)?);
+
+        let valid_signer: Box<ValidSigner> =
+            Box::new(Self::load_account_checked(valid_signer_info, program_id)?);
+
+        if valid_signer.signer_group != *signer_group_info.key {
+            return Err(AudiusError::SignerGroupMismatch.into());
+        }
+
+        signer_group.check_owner(signer_groups_owner_info)
+    }
+
+    /// Check that proof of possession is the signature of valid signer's address
+    /// by one of its eth keys
+    fn check_eth_pop(
+        instruction_info: &AccountInfo,
+        valid_signer_key: &Pubkey,
+        eth_addresses: &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]],
+        eth_pop_signature: &SignatureData,
+    ) -> ProgramResult {
+        let introspector = SysvarIntrospector::new(instruction_info)?;
+        if eth_pop_signature.message != valid_signer_key.as_ref() {
+            return Err(AudiusError::InvalidProofOfPossession.into());
+        }
+
+        Self::verify_secp_signature(&introspector, eth_addresses, eth_pop_signature)
+    }
+
+    /// Check that Secp256 instruction referenced by signature data verifies the signature
+    /// for one of the eth addresses
+    pub fn verify_secp_signature<I: SecpIntrospector>(
+        introspector: &I,
+        eth_addresses: &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]],
+        signature_data: &SignatureData,
+    ) -> ProgramResult {
+        let (secp_instruction_index, secp_instruction) =
+            Self::load_secp_instruction(introspector, signature_data)?;
+        Self::validate_secp_instruction(
+            secp_instruction_index,
+            &secp_instruction.data,
+            eth_addresses,
+            signature_data,
+        )
+    }
+
+    /// Load Secp256 instruction referenced by signature data along with its index in tx
+    fn load_secp_instruction<I: SecpIntrospector>(
+        introspector: &I,
+        signature_data: &SignatureData,
+    ) -> Result<(u8, Instruction), ProgramError> {
+        let (index, secp_instruction) = if signature_data.preceding_secp_instruction {
+            // Index of current instruction in tx
+            let index = introspector.current_index();
+            if index == 0 {
+                return Err(AudiusError::Secp256InstructionLosing.into());
+            }
+            let secp_instruction = introspector
+                .instruction_at(index as usize - 1)
+                .map_err(|_| AudiusError::Secp256InstructionLosing)?;
+            (
+                u8::try_from(index - 1).map_err(|_| AudiusError::InvalidSecpOffsets)?,
+                secp_instruction,
+            )
+        } else {
+            let secp_instruction = introspector
+                .instruction_at(signature_data.secp_instruction_index as usize)
+                .map_err(|_| AudiusError::Secp256InstructionLosing)?;
+            (signature_data.secp_instruction_index, secp_instruction)
+        };
+
+        if secp_instruction.program_id != secp256k1_program::id() {
+            return Err(AudiusError::Secp256InstructionLosing.into());
+        }
+
+        Ok((index, secp_instruction))
+    }
+
+    /// Take the given number of leading valid signer accounts
+    fn next_signer_infos<'a, 'b>(
+        account_info_iter: &mut Iter<'a, AccountInfo<'b>>,
+        count: usize,
+    ) -> Result<Vec<&'a AccountInfo<'b>>, ProgramError> {
+        let valid_signer_infos = account_info_iter.by_ref().take(count).collect::<Vec<_>>();
+        if valid_signer_infos.len() != count {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(valid_signer_infos)
+    }
+
+    /// Match each signature against the Secp256k1 valid signer at its position, returns
+    /// distinct signers whose signatures verify
+    fn match_signatures<'a, 'b>(
+        program_id: &Pubkey,
+        valid_signer_infos: Vec<&'a AccountInfo<'b>>,
+        signer_group_info: &AccountInfo,
+        instruction_info: &AccountInfo,
+        signatures: &[SignatureData],
+    ) -> Result<Vec<&'a AccountInfo<'b>>, ProgramError> {
+        let introspector = SysvarIntrospector::new(instruction_info)?;
+        let mut matched_signers: Vec<&AccountInfo> = vec![];
+        for (valid_signer_info, signature_data) in valid_signer_infos.into_iter().zip(signatures) {
+            // signers are expected to belong to the group even if their signature doesn't match
+            let valid_signer = Self::load_group_valid_signer(
+                program_id,
+                valid_signer_info,
+                signer_group_info,
+                SignerKeyType::SECP256K1,
+            )?;
+            if matched_signers
+                .iter()
+                .any(|matched_info| matched_info.key == valid_signer_info.key)
+            {
+                continue;
+            }
+
+            let (secp_instruction_index, secp_instruction) =
+                Self::load_secp_instruction(&introspector, signature_data)?;
+            if Self::validate_secp_instruction(
+                secp_instruction_index,
+                &secp_instruction.data,
+                valid_signer.eth_addresses(),
+                signature_data,
+            )
+            .is_ok()
+            {
+                matched_signers.push(valid_signer_info);
+            }
+        }
+        Ok(matched_signers)
+    }
+
+    /// Check that the authority approves membership change of the group. Governance approvals
+    /// must be signed over the message of this very change, `authority_info` is
+    /// the Sysvar Instruction account then.
+    fn authorize_membership_change(
+        program_id: &Pubkey,
+        authority: MembershipAuthority,
+        signer_group: &mut SignerGroup,
+        signer_group_info: &AccountInfo,
+        authority_info: &AccountInfo,
+    ) -> ProgramResult {
+        match authority {
+            MembershipAuthority::Owner => signer_group.check_owner(authority_info),
+            MembershipAuthority::OwnerOrDelegate => {
+                signer_group.check_owner_or_delegate(authority_info)
+            }
+            MembershipAuthority::Governance {
+                action,
+                eth_address,
+                approver_infos,
+                signatures,
+            } => {
+                // approvals are read from the transaction's instructions
+                if *authority_info.key != sysvar::instructions::id() {
+                    return Err(ProgramError::UnsupportedSysvar);
+                }
+                let message = SignerGroup::governance_message(
+                    action,
+                    signer_group_info.key,
+                    &eth_address,
+                    signer_group.governance_nonce.into(),
+                );
+                if signatures
+                    .iter()
+                    .any(|signature_data| signature_data.message != message)
+                {
+                    return Err(AudiusError::WrongGovernanceMessage.into());
+                }
+                let approvers = Self::match_signatures(
+                    program_id,
+                    approver_infos,
+                    signer_group_info,
+                    authority_info,
+                    signatures,
+                )?;
+                signer_group.apply_governance(action, approvers.len())
+            }
+            MembershipAuthority::GovernanceRealm => {
+                Self::check_governance_signer(signer_group, authority_info)
+            }
+        }
+    }
+
+    /// Check that the governance account of the group's realm signs the transaction,
+    /// SPL Governance program signs with it when executing the realm's approved proposals
+    fn check_governance_signer(
+        signer_group: &SignerGroup,
+        governance_info: &AccountInfo,
+    ) -> ProgramResult {
+        // `GovernanceAccountType` tags of the governance accounts of v1 and v2 layouts,
+        // the realm follows the tag
+        const GOVERNANCE_ACCOUNT_TYPES: &[u8] = &[3, 4, 9, 10, 18, 19, 20, 21];
+        const REALM_OFFSET: usize = 1;
+
+        if signer_group.governance_realm == Pubkey::default() {
+            return Err(AudiusError::GovernanceRealmNotSet.into());
+        }
+        Self::check_account_owner(governance_info, &spl_governance::id())?;
+
+        let data = governance_info.data.borrow();
+        let header = data
+            .get(..REALM_OFFSET + 32)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        if !GOVERNANCE_ACCOUNT_TYPES.contains(&header[0]) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !ct_eq(
+            &header[REALM_OFFSET..],
+            signer_group.governance_realm.as_ref(),
+        ) {
+            return Err(AudiusError::WrongGovernanceRealm.into());
+        }
+        if !governance_info.is_signer {
+            return Err(AudiusError::SignatureMissing.into());
+        }
+        Ok(())
+    }
+
+    /// Check that the account is owned by the program, data of foreign accounts
+    /// can't be trusted whatever it holds
+    fn check_account_owner(account_info: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+        if account_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        Ok(())
+    }
+
+    /// Load initialized program account, checking it's owned by the program
+    pub fn load_account_checked<T: ProgramAccount>(
+        account_info: &AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<T, ProgramError> {
+        Self::check_account_owner(account_info, program_id)?;
+        let (account, _) = T::deserialize_any_version(&account_info.data.borrow())?;
+        if !account.is_initialized() {
+            return Err(T::UNINITIALIZED_ERROR.into());
+        }
+        Ok(account)
+    }
+
+    /// Load initialized valid signer of the initialized group checking its key type
+    fn load_group_valid_signer<'a>(
+        program_id: &Pubkey,
+        valid_signer_info: &'a AccountInfo,
+        signer_group_info: &AccountInfo,
+        key_type: SignerKeyType,
+    ) -> Result<ValidSignerRef<'a>, ProgramError> {
+        Self::load_account_checked::<SignerGroup>(signer_group_info, program_id)?;
+
+        // valid signer is read apart from the loader, so its owner is checked here
+        Self::check_account_owner(valid_signer_info, program_id)?;
+        let valid_signer = ValidSigner::from_account_info_any_version(valid_signer_info)?;
+
+        if !valid_signer.is_initialized() {
+            return Err(AudiusError::ValidSignerNotInitialized.into());
+        }
+
+        if valid_signer.signer_group != *signer_group_info.key {
+            return Err(AudiusError::SignerGroupMismatch.into());
+        }
+
+        if valid_signer.key_type != key_type {
+            return Err(AudiusError::WrongSignerKeyType.into());
+        }
+
+        // Clock is read only for signers with delayed activation or expiry
+        if valid_signer.active_after_slot != PodU64::default()
+            || valid_signer.expires_at_slot != PodU64::default()
+        {
+            let slot = Clock::get()?.slot;
+            if !valid_signer.is_active(slot) {
+                return Err(AudiusError::SignerNotYetActive.into());
+            }
+            if valid_signer.is_expired(slot) {
+                return Err(AudiusError::SignerExpired.into());
+            }
+        }
+
+        Ok(valid_signer)
+    }
+
+    /// Check that Ed25519 instruction verifies given signature and message for the public key.
+    /// Offsets must point to the Ed25519 instruction itself and stay within its data.
+    fn validate_ed25519_instruction(
+        ed25519_instruction_index: u8,
+        ed25519_instruction_data: &[u8],
+        pubkey: &Pubkey,
+        signature_data: &Ed25519SignatureData,
+    ) -> ProgramResult {
+        if signature_data.message.len() > MAX_MESSAGE_LEN {
+            return Err(AudiusError::MessageTooLarge.into());
+        }
+
+        let offsets_start = Ed25519SignatureOffsets::SIGNATURE_OFFSETS_START;
+        let offsets_end =
+            offsets_start + Ed25519SignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        if ed25519_instruction_data.len() < offsets_end || ed25519_instruction_data[0] != 1 {
+            return Err(AudiusError::InvalidSecpOffsets.into());
+        }
+
+        let offsets = Ed25519SignatureOffsets::try_from_slice(
+            &ed25519_instruction_data[offsets_start..offsets_end],
+        )?;
+
+        let is_own_index = |index: u16| {
+            index == Ed25519SignatureOffsets::CURRENT_INSTRUCTION
+                || index == ed25519_instruction_index as u16
+        };
+        if !is_own_index(offsets.signature_instruction_index)
+            || !is_own_index(offsets.public_key_instruction_index)
+            || !is_own_index(offsets.message_instruction_index)
+        {
+            return Err(AudiusError::InvalidSecpOffsets.into());
+        }
+
+        let instruction_pubkey = Self::get_secp_slice(
+            ed25519_instruction_data,
+            offsets.public_key_offset,
+            Ed25519SignatureOffsets::PUBKEY_SIZE,
+        )?;
+        let instruction_signature = Self::get_secp_slice(
+            ed25519_instruction_data,
+            offsets.signature_offset,
+            Ed25519SignatureOffsets::SIGNATURE_SIZE,
+        )?;
+        let instruction_message = Self::get_secp_slice(
+            ed25519_instruction_data,
+            offsets.message_data_offset,
+            offsets.message_data_size as usize,
+        )?;
+
+        if instruction_pubkey != pubkey.as_ref()
+            || instruction_signature != signature_data.signature
+            || instruction_message != signature_data.message.as_slice()
+        {
+            return Err(AudiusError::SignatureVerificationFailed.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that Secp256 instruction verifies given signature and message for one of the eth addresses.
+    /// Offsets inside of the Secp256 instruction are not trusted, all of them must point
+    /// to the Secp256 instruction itself and stay within its data.
+    fn validate_secp_instruction(
+        secp_instruction_index: u8,
+        secp_instruction_data: &[u8],
+        eth_addresses: &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]],
+        signature_data: &SignatureData,
+    ) -> ProgramResult {
+        Self::verified_secp_eth_address(
+            secp_instruction_index,
+            secp_instruction_data,
+            eth_addresses,
+            signature_data,
+        )
+        .map(|_| ())
+    }
+
+    /// Same as [validate_secp_instruction](#method.validate_secp_instruction), returns
+    /// the eth address which made the signature
+    fn verified_secp_eth_address(
+        secp_instruction_index: u8,
+        secp_instruction_data: &[u8],
+        eth_addresses: &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]],
+        signature_data: &SignatureData,
+    ) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], ProgramError> {
+        if signature_data.message.len() > MAX_MESSAGE_LEN {
+            return Err(AudiusError::MessageTooLarge.into());
+        }
+
+        if !SecpSignatureOffsets::is_low_s(&signature_data.signature) {
+            return Err(AudiusError::MalleableSignature.into());
+        }
+
+        let offsets = Self::secp_signature_offsets(
+            secp_instruction_data,
+            signature_data.secp_signature_index,
+        )?;
+
+        if offsets.signature_instruction_index != secp_instruction_index
+            || offsets.eth_address_instruction_index != secp_instruction_index
+            || offsets.message_instruction_index != secp_instruction_index
+        {
+            return Err(AudiusError::InvalidSecpOffsets.into());
+        }
+
+        let instruction_eth_address = Self::get_secp_slice(
+            secp_instruction_data,
+            offsets.eth_address_offset,
+            SecpSignatureOffsets::ETH_ADDRESS_SIZE,
+        )?;
+        // recovery ID is stored right after the signature
+        let instruction_signature = Self::get_secp_slice(
+            secp_instruction_data,
+            offsets.signature_offset,
+            SecpSignatureOffsets::SECP_SIGNATURE_SIZE + 1,
+        )?;
+        let instruction_message = Self::get_secp_slice(
+            secp_instruction_data,
+            offsets.message_data_offset,
+            offsets.message_data_size as usize,
+        )?;
+
+        if !eth_addresses
+            .iter()
+            .any(|eth_address| ct_eq(instruction_eth_address, eth_address))
+            || instruction_signature[..SecpSignatureOffsets::SECP_SIGNATURE_SIZE]
+                != signature_data.signature[..]
+            || instruction_signature[SecpSignatureOffsets::SECP_SIGNATURE_SIZE]
+                != signature_data.recovery_id
+            || instruction_message != signature_data.message.as_slice()
+        {
+            return Err(AudiusError::SignatureVerificationFailed.into());
+        }
+
+        let mut eth_address = [0; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        eth_address.copy_from_slice(instruction_eth_address);
+        Ok(eth_address)
+    }
+
+    /// Offsets of the signature at the given index among the ones verified by Secp256
+    /// instruction
+    fn secp_signature_offsets(
+        secp_instruction_data: &[u8],
+        secp_signature_index: u8,
+    ) -> Result<SecpSignatureOffsets, ProgramError> {
+        // Secp256 instruction may verify several signatures, pick the one for this validation
+        let num_signatures = *secp_instruction_data
+            .first()
+            .ok_or(AudiusError::InvalidSecpOffsets)?;
+        if secp_signature_index >= num_signatures {
+            return Err(AudiusError::InvalidSecpOffsets.into());
+        }
+
+        let offsets_start = 1 + secp_signature_index as usize
+            * SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let offsets_end = offsets_start + SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        Ok(SecpSignatureOffsets::try_from_slice(
+            secp_instruction_data
+                .get(offsets_start..offsets_end)
+                .ok_or(AudiusError::InvalidSecpOffsets)?,
+        )?)
+    }
+
+    /// Get slice of Secp256 or Ed25519 instruction data checking its bounds
+    fn get_secp_slice(data: &[u8], offset: u16, size: usize) -> Result<&[u8], ProgramError> {
+        let start = offset as usize;
+        let end = start
+            .checked_add(size)
+            .ok_or(AudiusError::InvalidSecpOffsets)?;
+        data.get(start..end)
+            .ok_or_else(|| AudiusError::InvalidSecpOffsets.into())
+    }
+
+    /// Process an [Instruction]().
+    ///
+    /// There is no re-entrancy guard. The runtime refuses to invoke a program which is
+    /// already on the invocation stack unless it invokes itself directly, and the handlers
+    /// only ever invoke the system program, so no handler is entered again while another
+    /// one runs. A guard flag would need writable static data, which BPF programs don't
+    /// have, or a write to the read-only program config in every instruction.
+    pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+        let instruction = AudiusInstruction::unpack(input)?;
+
+        // handlers ignore trailing accounts, so extra ones are rejected here
+        let (min_accounts, max_accounts) = instruction.account_count_bounds();
+        if accounts.len() < min_accounts {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        if accounts.len() > max_accounts {
+            return Err(AudiusError::TooManyAccounts.into());
+        }
+        let accounts = if instruction.takes_program_config() {
+            let (program_config_info, accounts) = accounts
+                .split_last()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            Self::check_not_locked(program_id, program_config_info)?;
+            accounts
+        } else {
+            accounts
+        };
+
+        match instruction {
+            AudiusInstruction::InitSignerGroup => {
+                msg!("Instruction: InitSignerGroup");
+                Self::process_init_signer_group(program_id, accounts)
+            }
+            AudiusInstruction::InitValidSigner(eth_pubkey, eth_pop_signature) => {
+                msg!("Instruction: InitValidSigner");
+                Self::process_init_valid_signer(program_id, accounts, eth_pubkey, eth_pop_signature)
+            }
+            AudiusInstruction::ClearValidSigner => {
+                msg!("Instruction: ClearValidSigner");
+                Self::process_clear_valid_signer(program_id, accounts)
+            }
+            AudiusInstruction::ValidateSignature(signature) => {
+                msg!("Instruction: ValidateSignature");
+                Self::process_validate_signature(program_id, accounts, signature)
+            }
+            AudiusInstruction::QuerySignerGroup => {
+                msg!("Instruction: QuerySignerGroup");
+                Self::process_query_signer_group(program_id, accounts)
+            }
+            AudiusInstruction::InitValidSignerMetadata(metadata) => {
+                msg!("Instruction: InitValidSignerMetadata");
+                Self::process_init_valid_signer_metadata(program_id, accounts, metadata)
+            }
+            AudiusInstruction::UpdateValidSignerMetadata(metadata) => {
+                msg!("Instruction: UpdateValidSignerMetadata");
+                Self::process_update_valid_signer_metadata(program_id, accounts, metadata)
+            }
+            AudiusInstruction::InitProgramStats => {
+                msg!("Instruction: InitProgramStats");
+                Self::process_init_program_stats(program_id, accounts)
+            }
+            AudiusInstruction::GetProgramStats => {
+                msg!("Instruction: GetProgramStats");
+                Self::process_get_program_stats(program_id, accounts)
+            }
+            AudiusInstruction::InitValidSignerEd25519(ed25519_pubkey) => {
+                msg!("Instruction: InitValidSignerEd25519");
+                Self::process_init_valid_signer_ed25519(program_id, accounts, ed25519_pubkey)
+            }
+            AudiusInstruction::ValidateEd25519Signature(signature) => {
+                msg!("Instruction: ValidateEd25519Signature");
+                Self::process_validate_ed25519_signature(program_id, accounts, signature)
+            }
+            AudiusInstruction::AddSignerAddress(eth_address) => {
+                msg!("Instruction: AddSignerAddress");
+                Self::process_add_signer_address(program_id, accounts, eth_address)
+            }
+            AudiusInstruction::RemoveSignerAddress(eth_address) => {
+                msg!("Instruction: RemoveSignerAddress");
+                Self::process_remove_signer_address(program_id, accounts, eth_address)
+            }
+            AudiusInstruction::InitValidSignerWithLabel(eth_address, label) => {
+                msg!("Instruction: InitValidSignerWithLabel");
+                Self::process_init_valid_signer_with_label(program_id, accounts, eth_address, label)
+            }
+            AudiusInstruction::SetSignerLabel(label) => {
+                msg!("Instruction: SetSignerLabel");
+                Self::process_set_signer_label(program_id, accounts, label)
             }
-            AudiusInstruction::InitValidSigner(eth_pubkey) => {
-                msg!("Instruction: InitValidSigner");
-                Self::process_init_valid_signer(accounts, eth_pubkey)
+            AudiusInstruction::InitValidSignerExtended(args) => {
+                msg!("Instruction: InitValidSignerExtended");
+                Self::process_init_valid_signer_extended(program_id, accounts, args)
             }
-            AudiusInstruction::ClearValidSigner => {
-                msg!("Instruction: ClearValidSigner");
-                Self::process_clear_valid_signer(accounts)
+            AudiusInstruction::SetActivationSlot(active_after_slot) => {
+                msg!("Instruction: SetActivationSlot");
+                Self::process_set_activation_slot(program_id, accounts, active_after_slot)
             }
-            AudiusInstruction::ValidateSignature(signature) => {
-                msg!("Instruction: ValidateSignature");
-                Self::process_validate_signature(accounts, signature)
+            AudiusInstruction::ProposeOwnership(new_owner) => {
+                msg!("Instruction: ProposeOwnership");
+                Self::process_propose_ownership(program_id, accounts, new_owner)
+            }
+            AudiusInstruction::AcceptOwnership => {
+                msg!("Instruction: AcceptOwnership");
+                Self::process_accept_ownership(program_id, accounts)
+            }
+            AudiusInstruction::CancelProposedOwnership => {
+                msg!("Instruction: CancelProposedOwnership");
+                Self::process_cancel_proposed_ownership(program_id, accounts)
+            }
+            AudiusInstruction::SetSignerExpiry(expires_at_slot) => {
+                msg!("Instruction: SetSignerExpiry");
+                Self::process_set_signer_expiry(program_id, accounts, expires_at_slot)
+            }
+            AudiusInstruction::SetEthPoPRequired(eth_pop_required) => {
+                msg!("Instruction: SetEthPoPRequired");
+                Self::process_set_eth_pop_required(program_id, accounts, eth_pop_required)
+            }
+            AudiusInstruction::SetValidationFee(validation_fee, treasury) => {
+                msg!("Instruction: SetValidationFee");
+                Self::process_set_validation_fee(program_id, accounts, validation_fee, treasury)
+            }
+            AudiusInstruction::SetValidationQuota(quota_per_window) => {
+                msg!("Instruction: SetValidationQuota");
+                Self::process_set_validation_quota(program_id, accounts, quota_per_window)
+            }
+            AudiusInstruction::ValidateSignatures {
+                threshold,
+                signatures,
+            } => {
+                msg!("Instruction: ValidateSignatures");
+                Self::process_validate_signatures(program_id, accounts, threshold, signatures)
+            }
+            AudiusInstruction::InitSignerGroupWithSigner(eth_address) => {
+                msg!("Instruction: InitSignerGroupWithSigner");
+                Self::process_init_signer_group_with_signer(program_id, accounts, eth_address)
+            }
+            AudiusInstruction::UpdateMaxSigners(max_valid_signers) => {
+                msg!("Instruction: UpdateMaxSigners");
+                Self::process_update_max_signers(program_id, accounts, max_valid_signers)
+            }
+            AudiusInstruction::AddMessagePrefix(prefix) => {
+                msg!("Instruction: AddMessagePrefix");
+                Self::process_add_message_prefix(program_id, accounts, prefix)
+            }
+            AudiusInstruction::RemoveMessagePrefix(prefix) => {
+                msg!("Instruction: RemoveMessagePrefix");
+                Self::process_remove_message_prefix(program_id, accounts, prefix)
+            }
+            AudiusInstruction::SetCosignerRequired(require_cosigner) => {
+                msg!("Instruction: SetCosignerRequired");
+                Self::process_set_cosigner_required(program_id, accounts, require_cosigner)
+            }
+            AudiusInstruction::SetSignerCosigner(cosigner) => {
+                msg!("Instruction: SetSignerCosigner");
+                Self::process_set_signer_cosigner(program_id, accounts, cosigner)
+            }
+            AudiusInstruction::SetGovernance(governed, threshold) => {
+                msg!("Instruction: SetGovernance");
+                Self::process_set_governance(program_id, accounts, governed, threshold)
+            }
+            AudiusInstruction::GovernedInitValidSigner {
+                eth_address,
+                signatures,
+            } => {
+                msg!("Instruction: GovernedInitValidSigner");
+                Self::process_governed_init_valid_signer(
+                    program_id,
+                    accounts,
+                    eth_address,
+                    signatures,
+                )
+            }
+            AudiusInstruction::GovernedClearValidSigner {
+                eth_address,
+                signatures,
+            } => {
+                msg!("Instruction: GovernedClearValidSigner");
+                Self::process_governed_clear_valid_signer(
+                    program_id,
+                    accounts,
+                    eth_address,
+                    signatures,
+                )
+            }
+            AudiusInstruction::BatchValidateSignatures { signatures, .. } => {
+                msg!("Instruction: BatchValidateSignatures");
+                Self::process_batch_validate_signatures(program_id, accounts, signatures)
+            }
+            AudiusInstruction::RevokeEthAddress { eth_address } => {
+                msg!("Instruction: RevokeEthAddress");
+                Self::process_revoke_eth_address(program_id, accounts, eth_address)
+            }
+            AudiusInstruction::ValidateAndAttest {
+                signature_data,
+                expires_at_slot,
+            } => {
+                msg!("Instruction: ValidateAndAttest");
+                Self::process_validate_and_attest(
+                    program_id,
+                    accounts,
+                    signature_data,
+                    expires_at_slot,
+                )
+            }
+            AudiusInstruction::CloseAttestation => {
+                msg!("Instruction: CloseAttestation");
+                Self::process_close_attestation(program_id, accounts)
+            }
+            AudiusInstruction::GetOwnershipHistory => {
+                msg!("Instruction: GetOwnershipHistory");
+                Self::process_get_ownership_history(program_id, accounts)
+            }
+            AudiusInstruction::InitValidSignerFromPubkey(pubkey) => {
+                msg!("Instruction: InitValidSignerFromPubkey");
+                Self::process_init_valid_signer_from_pubkey(program_id, accounts, pubkey)
+            }
+            AudiusInstruction::AddToCreationWhitelist(account) => {
+                msg!("Instruction: AddToCreationWhitelist");
+                Self::process_add_to_creation_whitelist(program_id, accounts, account)
+            }
+            AudiusInstruction::RemoveFromCreationWhitelist(account) => {
+                msg!("Instruction: RemoveFromCreationWhitelist");
+                Self::process_remove_from_creation_whitelist(program_id, accounts, account)
+            }
+            AudiusInstruction::SetCreationWhitelistBypass(bypass) => {
+                msg!("Instruction: SetCreationWhitelistBypass");
+                Self::process_set_creation_whitelist_bypass(program_id, accounts, bypass)
+            }
+            AudiusInstruction::ValidateSignatureDigest(signature_data) => {
+                msg!("Instruction: ValidateSignatureDigest");
+                Self::process_validate_signature_digest(program_id, accounts, signature_data)
+            }
+            AudiusInstruction::SetDelegateOperator(delegate_operator) => {
+                msg!("Instruction: SetDelegateOperator");
+                Self::process_set_delegate_operator(program_id, accounts, delegate_operator)
+            }
+            AudiusInstruction::ValidateSignatureBatch(signatures) => {
+                msg!("Instruction: ValidateSignatureBatch");
+                Self::process_validate_signature_batch(program_id, accounts, signatures)
+            }
+            AudiusInstruction::MigrateValidSigner { new_signer_group } => {
+                msg!("Instruction: MigrateValidSigner");
+                Self::process_migrate_valid_signer(program_id, accounts, new_signer_group)
+            }
+            AudiusInstruction::ValidateSignatureWithMerkleProof {
+                signature_data,
+                proof,
+            } => {
+                msg!("Instruction: ValidateSignatureWithMerkleProof");
+                Self::process_validate_signature_with_merkle_proof(
+                    program_id,
+                    accounts,
+                    signature_data,
+                    proof,
+                )
+            }
+            AudiusInstruction::UpdateMerkleRoot(members_merkle_root) => {
+                msg!("Instruction: UpdateMerkleRoot");
+                Self::process_update_merkle_root(program_id, accounts, members_merkle_root)
+            }
+            AudiusInstruction::SetGovernanceRealm(governance_realm) => {
+                msg!("Instruction: SetGovernanceRealm");
+                Self::process_set_governance_realm(program_id, accounts, governance_realm)
+            }
+            AudiusInstruction::ExecuteGovernanceProposal { instruction } => {
+                msg!("Instruction: ExecuteGovernanceProposal");
+                Self::process_execute_governance_proposal(program_id, accounts, *instruction.0)
+            }
+            AudiusInstruction::CloneSignerGroup { source_group, .. } => {
+                msg!("Instruction: CloneSignerGroup");
+                Self::process_clone_signer_group(program_id, accounts, source_group)
+            }
+            AudiusInstruction::SetLockdownMode(lockdown_mode) => {
+                msg!("Instruction: SetLockdownMode");
+                Self::process_set_lockdown_mode(program_id, accounts, lockdown_mode)
+            }
+            AudiusInstruction::CloseSignerGroup => {
+                msg!("Instruction: CloseSignerGroup");
+                Self::process_close_signer_group(program_id, accounts)
+            }
+            AudiusInstruction::CloseValidSigner => {
+                msg!("Instruction: CloseValidSigner");
+                Self::process_close_valid_signer(program_id, accounts)
             }
         }
     }
 }
 
+/// Extract eth address of the signer from Secp256 instruction preceding the current one.
+/// Only the first signature of Secp256 instruction is read, its eth address must be stored
+/// in the Secp256 instruction itself.
+pub fn extract_secp_signer(
+    instruction_info: &AccountInfo,
+) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], ProgramError> {
+    let introspector = SysvarIntrospector::new(instruction_info)?;
+    let secp_instruction_index = introspector
+        .current_index()
+        .checked_sub(1)
+        .ok_or(AudiusError::Secp256InstructionLosing)?;
+    let secp_instruction = introspector
+        .instruction_at(secp_instruction_index as usize)
+        .map_err(|_| AudiusError::Secp256InstructionLosing)?;
+    if secp_instruction.program_id != secp256k1_program::id() {
+        return Err(AudiusError::Secp256InstructionLosing.into());
+    }
+
+    let data = secp_instruction.data.as_slice();
+    if data.first().copied().unwrap_or_default() == 0 {
+        return Err(AudiusError::InvalidSecpOffsets.into());
+    }
+    let offsets = SecpSignatureOffsets::try_from_slice(
+        data.get(1..1 + SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .ok_or(AudiusError::InvalidSecpOffsets)?,
+    )?;
+    if offsets.eth_address_instruction_index as u16 != secp_instruction_index {
+        return Err(AudiusError::InvalidSecpOffsets.into());
+    }
+
+    let mut eth_address = [0u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    eth_address.copy_from_slice(Processor::get_secp_slice(
+        data,
+        offsets.eth_address_offset,
+        SecpSignatureOffsets::ETH_ADDRESS_SIZE,
+    )?);
+    Ok(eth_address)
+}
+
 impl PrintProgramError for AudiusError {
     fn print<E>(&self)
     where
         E: 'static + std::error::Error + DecodeError<E> + PrintProgramError + FromPrimitive,
     {
+        // no wildcard arm, every new error must get its message here
         match self {
             AudiusError::InvalidInstruction => msg!("Invalid instruction"),
             AudiusError::SignerGroupAlreadyInitialized => msg!("Signer group already initialized"),
             AudiusError::UninitializedSignerGroup => msg!("Uninitialized signer group"),
             AudiusError::SignerAlreadyInitialized => msg!("Signer is already initialized"),
             AudiusError::ValidSignerNotInitialized => msg!("Valid signer isn't initialized"),
-            AudiusError::WrongSignerGroup => msg!("Signer doesnt belong to this group"),
+            AudiusError::SignerGroupMismatch => msg!("Signer doesnt belong to this group"),
             AudiusError::WrongOwner => msg!("Wrong owner"),
             AudiusError::SignatureMissing => msg!("Signature missing"),
             AudiusError::SignatureVerificationFailed => msg!("Signature verification failed"),
             AudiusError::Secp256InstructionLosing => msg!("Secp256 instruction losing"),
+            AudiusError::InvalidSecpOffsets => msg!("Secp256 instruction offsets are invalid"),
+            AudiusError::WrongMetadataAccount => {
+                msg!("Metadata account address doesn't match valid signer")
+            }
+            AudiusError::MetadataAlreadyInitialized => {
+                msg!("Valid signer metadata already initialized")
+            }
+            AudiusError::UninitializedMetadata => msg!("Uninitialized valid signer metadata"),
+            AudiusError::MessageTooLarge => msg!("Message is too large"),
+            AudiusError::MalleableSignature => {
+                msg!("Signature is malleable, s must be in the lower half of curve order")
+            }
+            AudiusError::MalformedPubkey => msg!("Malformed base58 pubkey"),
+            AudiusError::WrongStatsAccount => {
+                msg!("Stats account address doesn't match program stats address")
+            }
+            AudiusError::StatsAlreadyInitialized => msg!("Program stats already initialized"),
+            AudiusError::UninitializedStats => msg!("Uninitialized program stats"),
+            AudiusError::WrongSignerListAccount => msg!("Signer list neighbour account mismatch"),
+            AudiusError::WrongSignerKeyType => msg!("Wrong valid signer key type"),
+            AudiusError::Ed25519InstructionMissing => msg!("Ed25519 instruction missing"),
+            AudiusError::EthAddressAlreadyRegistered => {
+                msg!("Eth address is already registered for valid signer")
+            }
+            AudiusError::EthAddressNotRegistered => {
+                msg!("Eth address is not registered for valid signer")
+            }
+            AudiusError::EthAddressLimitReached => msg!("Valid signer eth address limit reached"),
+            AudiusError::LastEthAddress => {
+                msg!("Last eth address of valid signer can't be removed")
+            }
+            AudiusError::SignerLabelTooLong => msg!("Signer label is too long"),
+            AudiusError::InvalidSignerLabel => msg!("Invalid signer label"),
+            AudiusError::SignerNotYetActive => msg!("Signer is not yet active"),
+            AudiusError::SignerAlreadyActive => msg!("Signer is already active"),
+            AudiusError::OwnershipNotProposed => msg!("Ownership is not proposed"),
+            AudiusError::SignerExpired => msg!("Signer is expired"),
+            AudiusError::InvalidAccountMeta => msg!("Invalid account meta"),
+            AudiusError::ProofOfPossessionMissing => msg!("Proof of possession is missing"),
+            AudiusError::InvalidProofOfPossession => msg!("Invalid proof of possession"),
+            AudiusError::ValidationFeePayerMissing => msg!("Validation fee payer is missing"),
+            AudiusError::WrongTreasury => msg!("Wrong treasury account"),
+            AudiusError::WrongValidSignerAddress => msg!("Wrong valid signer account address"),
+            AudiusError::QuotaExceeded => msg!("Validation quota exceeded"),
+            AudiusError::ThresholdNotReached => msg!("Signature threshold not reached"),
+            AudiusError::WrongOwnerRegistryAddress => {
+                msg!("Wrong owner registry account address")
+            }
+            AudiusError::OwnerRegistryFull => {
+                msg!("Owner registry is full, close or transfer one of the owner's signer groups")
+            }
+            AudiusError::SignerIdOverflow => msg!("Signer ids are exhausted"),
+            AudiusError::MaxSignersReached => msg!("Signer group reached its valid signer limit"),
+            AudiusError::MessagePrefixAlreadyAdded => msg!("Message prefix is already added"),
+            AudiusError::MessagePrefixLimitReached => msg!("Message prefix limit reached"),
+            AudiusError::MessagePrefixNotFound => msg!("Message prefix is not added"),
+            AudiusError::MessagePrefixNotAllowed => {
+                msg!("Message prefix is not allowed by the signer group")
+            }
+            AudiusError::TooManyAccounts => msg!("Too many accounts"),
+            AudiusError::MissingCosigner => msg!("Cosigner signature missing"),
+            AudiusError::InvalidOwner => msg!("Invalid owner"),
+            AudiusError::EthAddressRecoveryFailed => msg!("Eth address recovery failed"),
+            AudiusError::GovernanceDisabled => msg!("Signer group governance is disabled"),
+            AudiusError::InvalidGovernanceThreshold => msg!("Invalid governance threshold"),
+            AudiusError::WrongGovernanceMessage => msg!("Wrong governance approval message"),
+            AudiusError::RateLimitExceeded => msg!("Rate limit exceeded"),
+            AudiusError::AccountMigrationRequired => {
+                msg!("Account must be migrated to the current layout")
+            }
+            AudiusError::BatchSecpInstructionMismatch => {
+                msg!("Batch signatures must share one preceding Secp256 instruction")
+            }
+            AudiusError::Overflow => msg!("Arithmetic overflow"),
+            AudiusError::InvalidAccountDiscriminator => msg!("Account discriminator mismatch"),
+            AudiusError::EthAddressRevoked => msg!("Eth address is revoked"),
+            AudiusError::WrongRevocationRegistryAddress => {
+                msg!("Wrong revocation registry account address")
+            }
+            AudiusError::RevocationRegistryFull => msg!("Revocation registry is full"),
+            AudiusError::WrongAttestationAddress => msg!("Wrong attestation account address"),
+            AudiusError::AttestationAlreadyExists => msg!("Attestation already exists"),
+            AudiusError::UninitializedAttestation => msg!("Attestation is not initialized"),
+            AudiusError::AttestationNotExpired => msg!("Attestation is not expired"),
+            AudiusError::WrongRentReceiver => msg!("Wrong rent receiver"),
+            AudiusError::WrongOwnershipHistoryAddress => {
+                msg!("Wrong ownership history account address")
+            }
+            AudiusError::MalformedSecpPubkey => msg!("Malformed Secp256k1 public key"),
+            AudiusError::WrongCreationWhitelistAddress => {
+                msg!("Wrong creation whitelist account address")
+            }
+            AudiusError::UnauthorizedOperation => msg!("Unauthorized operation"),
+            AudiusError::CreationWhitelistFull => msg!("Creation whitelist is full"),
+            AudiusError::InvalidDigestLength => msg!("Message digest must be 32 bytes"),
+            AudiusError::SignerGroupNotProgramOwned => {
+                msg!("Signer group account must be created with the program as its owner")
+            }
+            AudiusError::MerkleRootNotSet => msg!("Members Merkle root is not set"),
+            AudiusError::InvalidMerkleProof => msg!("Invalid Merkle proof of membership"),
+            AudiusError::GovernanceRealmNotSet => msg!("Governance realm is not set"),
+            AudiusError::WrongGovernanceRealm => msg!("Wrong governance realm"),
+            AudiusError::UnsupportedProposalInstruction => {
+                msg!("Instruction can't be executed by governance proposal")
+            }
+            AudiusError::WrongProgramConfigAddress => {
+                msg!("Wrong program config account address")
+            }
+            AudiusError::ProgramLocked => msg!("Program is locked down"),
+            AudiusError::InvalidEthAddress => msg!("Invalid Ethereum address"),
+            AudiusError::DerivedSignerMigration => {
+                msg!("Valid signer with derived address can't migrate")
+            }
+            AudiusError::SignerGroupGoverned => msg!("Signer group is governed"),
+            AudiusError::AccountTombstoned => msg!("Account is closed"),
+            AudiusError::SignerGroupNotEmpty => msg!("Signer group still has valid signers"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::instruction::validate_signature_multi;
+    use crate::introspection::MockIntrospector;
+    use crate::logging::format_event;
+    use borsh::BorshSerialize;
+    use solana_program::message::Message;
+
+    const ETH_ADDRESS: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE] =
+        [3; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+
+    fn signature_data() -> SignatureData {
+        SignatureData {
+            signature: [5; SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+            recovery_id: 1,
+            message: vec![9; 30],
+            secp_instruction_index: 0,
+            preceding_secp_instruction: false,
+            secp_signature_index: 0,
+        }
+    }
+
+    fn secp_instruction_data(offsets: &SecpSignatureOffsets, signature: &SignatureData) -> Vec<u8> {
+        let mut data = vec![1u8];
+        data.extend_from_slice(&offsets.try_to_vec().unwrap());
+        data.extend_from_slice(&ETH_ADDRESS);
+        data.extend_from_slice(&signature.signature);
+        data.push(signature.recovery_id);
+        data.extend_from_slice(&signature.message);
+        data
+    }
+
+    fn valid_offsets(message_len: usize) -> SecpSignatureOffsets {
+        SecpSignatureOffsets::new(0, message_len).unwrap()
+    }
+
+    /// Instructions sysvar data of the constructed transaction
+    fn instructions_sysvar_data(instructions: &[Instruction], current_index: u16) -> Vec<u8> {
+        let mut data = Message::new(instructions, None).serialize_instructions();
+        sysvar::instructions::store_current_index(&mut data, current_index);
+        data
+    }
+
+    /// Run `extract_secp_signer` against instructions sysvar of the constructed transaction
+    fn extract_from_transaction(
+        instructions: &[Instruction],
+        current_index: u16,
+        sysvar_key: Pubkey,
+    ) -> Result<[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE], ProgramError> {
+        let mut data = instructions_sysvar_data(instructions, current_index);
+        let owner = sysvar::id();
+        let mut lamports = 0;
+        let info = AccountInfo::new(
+            &sysvar_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        extract_secp_signer(&info)
+    }
+
+    #[test]
+    fn test_extract_secp_signer() {
+        let signature = signature_data();
+        let secp_instruction = |offsets: &SecpSignatureOffsets| Instruction {
+            program_id: secp256k1_program::id(),
+            accounts: vec![],
+            data: secp_instruction_data(offsets, &signature),
+        };
+        let program_instruction = Instruction {
+            program_id: crate::id(),
+            accounts: vec![],
+            data: vec![],
+        };
+        let offsets = valid_offsets(signature.message.len());
+        let instructions = [secp_instruction(&offsets), program_instruction.clone()];
+
+        assert_eq!(
+            extract_from_transaction(&instructions, 1, sysvar::instructions::id()),
+            Ok(ETH_ADDRESS)
+        );
+        assert_eq!(
+            extract_from_transaction(&instructions, 1, Pubkey::new_unique()),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            extract_from_transaction(&instructions, 0, sysvar::instructions::id()),
+            Err(AudiusError::Secp256InstructionLosing.into())
+        );
+
+        // preceding instruction is not a Secp256 one
+        let instructions = [program_instruction.clone(), secp_instruction(&offsets)];
+        assert_eq!(
+            extract_from_transaction(&instructions, 1, sysvar::instructions::id()),
+            Err(AudiusError::Secp256InstructionLosing.into())
+        );
+
+        // eth address is stored in another instruction
+        let mut foreign_offsets = offsets;
+        foreign_offsets.eth_address_instruction_index = 1;
+        let instructions = [secp_instruction(&foreign_offsets), program_instruction];
+        assert_eq!(
+            extract_from_transaction(&instructions, 1, sysvar::instructions::id()),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_ed25519_signature_sysvar() {
+        let program_id = crate::id();
+        let signature_data = Ed25519SignatureData {
+            signature: [5; Ed25519SignatureOffsets::SIGNATURE_SIZE],
+            message: vec![9; 30],
+            ed25519_instruction_index: 0,
+        };
+        let mut data = instructions_sysvar_data(
+            &[Instruction {
+                program_id: ed25519_program::id(),
+                accounts: vec![],
+                data: vec![],
+            }],
+            1,
+        );
+        let (valid_signer_key, signer_group_key, fake_sysvar_key) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let owner = sysvar::id();
+        let (mut lamports, mut valid_signer_lamports, mut signer_group_lamports) = (0, 0, 0);
+        let (mut valid_signer_data, mut signer_group_data) = (vec![], vec![]);
+        let accounts = [
+            AccountInfo::new(
+                &valid_signer_key,
+                false,
+                true,
+                &mut valid_signer_lamports,
+                &mut valid_signer_data,
+                &program_id,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &signer_group_key,
+                false,
+                true,
+                &mut signer_group_lamports,
+                &mut signer_group_data,
+                &program_id,
+                false,
+                0,
+            ),
+            // forged instructions account listing an Ed25519 instruction
+            AccountInfo::new(
+                &fake_sysvar_key,
+                false,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            ),
+        ];
+        assert_eq!(
+            Processor::process_validate_ed25519_signature(&program_id, &accounts, signature_data),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_validate_signatures_mixed_prefixes() {
+        let program_id = crate::id();
+        let group_key = Pubkey::new_unique();
+        let mut signer_group = SignerGroup {
+            version: Processor::SIGNER_GROUP_VERSION,
+            ..SignerGroup::default()
+        };
+        signer_group.add_message_prefix(*b"track:v1").unwrap();
+        let mut data = vec![0; SignerGroup::LEN];
+        signer_group.pack(&mut data).unwrap();
+        let mut lamports = 0;
+        let group_info = AccountInfo::new(
+            &group_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+
+        // only the second message of the batch has a disallowed prefix
+        let signature = |message: &[u8]| SignatureData {
+            message: message.to_vec(),
+            ..signature_data()
+        };
+        let signatures = vec![signature(b"track:v1 42"), signature(b"delete:user 42")];
+        // signature matching isn't reached, so the group stands in for the other accounts
+        let accounts = [
+            group_info.clone(),
+            group_info.clone(),
+            group_info.clone(),
+            group_info,
+        ];
+        assert_eq!(
+            Processor::process_validate_signatures(&program_id, &accounts, 1, signatures),
+            Err(AudiusError::MessagePrefixNotAllowed.into())
+        );
+    }
+
+    #[test]
+    fn test_governance_approvals_sysvar() {
+        let program_id = crate::id();
+        let group_key = Pubkey::new_unique();
+        let mut signer_group = SignerGroup {
+            version: Processor::SIGNER_GROUP_VERSION,
+            governed: true,
+            governance_threshold: 1,
+            ..SignerGroup::default()
+        };
+        let mut data = vec![0; SignerGroup::LEN];
+        signer_group.pack(&mut data).unwrap();
+        let mut lamports = 0;
+        let group_info = AccountInfo::new(
+            &group_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        let eth_address = [7; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        let signatures = [SignatureData {
+            message: SignerGroup::governance_message(
+                GovernanceAction::AddSigner,
+                &group_key,
+                &eth_address,
+                0,
+            ),
+            ..signature_data()
+        }];
+
+        // forged instructions account standing in for the instructions sysvar
+        let forged_key = Pubkey::new_unique();
+        let owner = sysvar::id();
+        let mut forged_lamports = 0;
+        let mut forged_data = instructions_sysvar_data(&[], 0);
+        let forged_info = AccountInfo::new(
+            &forged_key,
+            false,
+            false,
+            &mut forged_lamports,
+            &mut forged_data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            Processor::authorize_membership_change(
+                &program_id,
+                MembershipAuthority::Governance {
+                    action: GovernanceAction::AddSigner,
+                    eth_address,
+                    approver_infos: vec![&group_info],
+                    signatures: &signatures,
+                },
+                &mut signer_group,
+                &group_info,
+                &forged_info,
+            ),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
+
+    #[test]
+    fn test_validate_secp_instruction() {
+        let signature = signature_data();
+        let data = secp_instruction_data(&valid_offsets(signature.message.len()), &signature);
+
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[ETH_ADDRESS], &signature),
+            Ok(())
+        );
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[[4; 20]], &signature),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[[4; 20], ETH_ADDRESS], &signature),
+            Ok(())
+        );
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[], &signature),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+
+        let mut other_message = signature_data();
+        other_message.message[0] = 0;
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[ETH_ADDRESS], &other_message),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+    }
+
+    /// Mocked transaction with Secp256 instruction verifying the signature followed by
+    /// the current instruction
+    fn mock_introspector(signature: &SignatureData) -> MockIntrospector {
+        MockIntrospector {
+            instructions: vec![Instruction {
+                program_id: secp256k1_program::id(),
+                accounts: vec![],
+                data: secp_instruction_data(&valid_offsets(signature.message.len()), signature),
+            }],
+            current_index: 1,
+        }
+    }
+
+    #[test]
+    fn test_verify_secp_signature_mock_match() {
+        let signature = signature_data();
+        let introspector = mock_introspector(&signature);
+        assert_eq!(
+            Processor::verify_secp_signature(&introspector, &[ETH_ADDRESS], &signature),
+            Ok(())
+        );
+        assert_eq!(
+            Processor::verify_secp_signature(&introspector, &[[4; 20], ETH_ADDRESS], &signature),
+            Ok(())
+        );
+
+        let mut preceding = signature_data();
+        preceding.preceding_secp_instruction = true;
+        preceding.secp_instruction_index = 7;
+        assert_eq!(
+            Processor::verify_secp_signature(&introspector, &[ETH_ADDRESS], &preceding),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_secp_signature_mock_mismatch() {
+        let signature = signature_data();
+        let introspector = mock_introspector(&signature);
+        assert_eq!(
+            Processor::verify_secp_signature(&introspector, &[[4; 20]], &signature),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+
+        let mut other_message = signature_data();
+        other_message.message[0] = 0;
+        assert_eq!(
+            Processor::verify_secp_signature(&introspector, &[ETH_ADDRESS], &other_message),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+
+        let mut missing = signature_data();
+        missing.secp_instruction_index = 1;
+        assert_eq!(
+            Processor::verify_secp_signature(&introspector, &[ETH_ADDRESS], &missing),
+            Err(AudiusError::Secp256InstructionLosing.into())
+        );
+
+        let mut preceding = signature_data();
+        preceding.preceding_secp_instruction = true;
+        let first_instruction = MockIntrospector {
+            current_index: 0,
+            ..introspector.clone()
+        };
+        assert_eq!(
+            Processor::verify_secp_signature(&first_instruction, &[ETH_ADDRESS], &preceding),
+            Err(AudiusError::Secp256InstructionLosing.into())
+        );
+
+        let mut other_program = introspector;
+        other_program.instructions[0].program_id = crate::id();
+        assert_eq!(
+            Processor::verify_secp_signature(&other_program, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::Secp256InstructionLosing.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_secp_instruction_foreign_index() {
+        let signature = signature_data();
+        let mut offsets = valid_offsets(signature.message.len());
+        offsets.eth_address_instruction_index = 1;
+        let data = secp_instruction_data(&offsets, &signature);
+
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+
+        let offsets = valid_offsets(signature.message.len());
+        let data = secp_instruction_data(&offsets, &signature);
+        assert_eq!(
+            Processor::validate_secp_instruction(1, &data, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_secp_instruction_out_of_bounds() {
+        let signature = signature_data();
+
+        let mut offsets = valid_offsets(signature.message.len());
+        offsets.message_data_offset = u16::MAX;
+        let data = secp_instruction_data(&offsets, &signature);
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+
+        let mut offsets = valid_offsets(signature.message.len());
+        offsets.message_data_size += 1;
+        let data = secp_instruction_data(&offsets, &signature);
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+
+        let mut offsets = valid_offsets(signature.message.len());
+        offsets.eth_address_offset = 0;
+        let data = secp_instruction_data(&offsets, &signature);
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+
+        assert_eq!(
+            Processor::validate_secp_instruction(0, &data[..5], &[ETH_ADDRESS], &signature),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_secp_instruction_multi() {
+        let signatures: Vec<_> = (0..3u8)
+            .map(|i| {
+                let mut signature = signature_data();
+                signature.message = vec![i; 10 + i as usize];
+                (signature, ETH_ADDRESS)
+            })
+            .collect();
+        let instructions = validate_signature_multi(
+            &Pubkey::new_from_array([1; 32]),
+            &Pubkey::new_from_array([2; 32]),
+            &Pubkey::new_from_array([3; 32]),
+            1,
+            signatures.clone(),
+        )
+        .unwrap();
+        assert_eq!(instructions.len(), 4);
+        let data = &instructions[0].data;
+
+        for (index, (signature, _)) in signatures.iter().enumerate() {
+            let mut signature = signature.clone();
+            signature.secp_signature_index = index as u8;
+            assert_eq!(
+                Processor::validate_secp_instruction(1, data, &[ETH_ADDRESS], &signature),
+                Ok(())
+            );
+
+            // signature data at another index doesn't match
+            signature.secp_signature_index = (index as u8 + 1) % 3;
+            assert_eq!(
+                Processor::validate_secp_instruction(1, data, &[ETH_ADDRESS], &signature),
+                Err(AudiusError::SignatureVerificationFailed.into())
+            );
+        }
+
+        let mut signature = signatures[0].0.clone();
+        signature.secp_signature_index = 3;
+        assert_eq!(
+            Processor::validate_secp_instruction(1, data, &[ETH_ADDRESS], &signature),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_ed25519_instruction() {
+        use crate::instruction::new_ed25519_instruction;
+        use solana_sdk::signature::{Keypair, Signer};
+
+        let keypair = Keypair::new();
+        let message = b"ed25519 message".to_vec();
+        let mut signature = [0u8; Ed25519SignatureOffsets::SIGNATURE_SIZE];
+        signature.copy_from_slice(keypair.sign_message(&message).as_ref());
+
+        let signature_data = Ed25519SignatureData {
+            signature,
+            message,
+            ed25519_instruction_index: 0,
+        };
+        let data = new_ed25519_instruction(&keypair.pubkey(), &signature, &signature_data.message)
+            .unwrap()
+            .data;
+
+        assert_eq!(
+            Processor::validate_ed25519_instruction(0, &data, &keypair.pubkey(), &signature_data),
+            Ok(())
+        );
+        assert_eq!(
+            Processor::validate_ed25519_instruction(
+                0,
+                &data,
+                &Keypair::new().pubkey(),
+                &signature_data
+            ),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+
+        let mut other_message = signature_data.clone();
+        other_message.message[0] = 0;
+        assert_eq!(
+            Processor::validate_ed25519_instruction(0, &data, &keypair.pubkey(), &other_message),
+            Err(AudiusError::SignatureVerificationFailed.into())
+        );
+
+        // offsets pointing to another instruction are rejected
+        let mut foreign_data = data.clone();
+        let mut offsets = Ed25519SignatureOffsets::new(signature_data.message.len()).unwrap();
+        offsets.public_key_instruction_index = 1;
+        foreign_data[2..16].copy_from_slice(&offsets.try_to_vec().unwrap());
+        assert_eq!(
+            Processor::validate_ed25519_instruction(
+                0,
+                &foreign_data,
+                &keypair.pubkey(),
+                &signature_data
+            ),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+
+        assert_eq!(
+            Processor::validate_ed25519_instruction(
+                0,
+                &data[..10],
+                &keypair.pubkey(),
+                &signature_data
+            ),
+            Err(AudiusError::InvalidSecpOffsets.into())
+        );
+    }
+
+    #[test]
+    fn test_load_account_checked() {
+        let program_id = crate::id();
+        let key = Pubkey::new_unique();
+        let signer_group = SignerGroup {
+            version: Processor::SIGNER_GROUP_VERSION,
+            ..SignerGroup::default()
+        };
+        let mut data = vec![0; SignerGroup::LEN];
+        signer_group.pack(&mut data).unwrap();
+        let mut lamports = 0;
+
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        assert_eq!(
+            Processor::load_account_checked::<SignerGroup>(&info, &program_id),
+            Ok(signer_group)
+        );
+
+        // same data in the account of another program isn't trusted
+        let foreign_owner = Pubkey::new_unique();
+        let foreign_info = AccountInfo {
+            owner: &foreign_owner,
+            ..info.clone()
+        };
+        assert_eq!(
+            Processor::load_account_checked::<SignerGroup>(&foreign_info, &program_id),
+            Err(ProgramError::IncorrectProgramId)
+        );
+
+        info.data.borrow_mut().fill(0);
+        assert_eq!(
+            Processor::load_account_checked::<SignerGroup>(&info, &program_id),
+            Err(AudiusError::UninitializedSignerGroup.into())
+        );
+    }
+
+    #[test]
+    fn test_check_not_locked() {
+        let program_id = crate::id();
+        let (config_key, _) = find_program_config_address(&program_id);
+        let mut data = vec![];
+        let mut lamports = 0;
+        let info = AccountInfo::new(
+            &config_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        // the program isn't locked until the config is created
+        assert_eq!(Processor::check_not_locked(&program_id, &info), Ok(()));
+
+        let wrong_key = Pubkey::new_unique();
+        let wrong_info = AccountInfo {
+            key: &wrong_key,
+            ..info.clone()
+        };
+        assert_eq!(
+            Processor::check_not_locked(&program_id, &wrong_info),
+            Err(AudiusError::WrongProgramConfigAddress.into())
+        );
+
+        let mut config = ProgramConfig {
+            version: Processor::PROGRAM_CONFIG_VERSION,
+            authority: Pubkey::new_unique(),
+            lockdown_mode: 1,
+        };
+        let mut data = vec![0; ProgramConfig::LEN];
+        config.pack(&mut data).unwrap();
+        let mut lamports = 0;
+        let info = AccountInfo::new(
+            &config_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        assert_eq!(
+            Processor::check_not_locked(&program_id, &info),
+            Err(AudiusError::ProgramLocked.into())
+        );
+
+        config.lockdown_mode = 0;
+        config.pack(&mut info.data.borrow_mut()).unwrap();
+        assert_eq!(Processor::check_not_locked(&program_id, &info), Ok(()));
+    }
+
+    #[test]
+    fn test_locked_program_rejects_mutations() {
+        use crate::instruction::*;
+
+        let program_id = crate::id();
+        let key = Pubkey::new_unique;
+        let eth_address = [7u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        let metadata = MetadataArgs {
+            endpoint_url: [1; ValidSignerMetadata::ENDPOINT_URL_SIZE],
+            operator_name: [2; ValidSignerMetadata::OPERATOR_NAME_SIZE],
+        };
+        let prefix = [3; SignerGroup::MESSAGE_PREFIX_SIZE];
+        let approvals = || vec![(key(), SignatureData::new([1; 64], 0, vec![2; 8]))];
+        let instructions = vec![
+            init_signer_group(&program_id, &key(), &key(), &key()).unwrap(),
+            init_signer_group_with_signer(&program_id, &key(), &key(), &key(), eth_address)
+                .unwrap(),
+            clone_signer_group(
+                &program_id,
+                &key(),
+                &key(),
+                &key(),
+                &key(),
+                &[(key(), key())],
+            )
+            .unwrap(),
+            init_valid_signer(&program_id, &key(), &key(), &key(), None, eth_address).unwrap(),
+            init_valid_signer_ed25519(&program_id, &key(), &key(), &key(), None, &key()).unwrap(),
+            clear_valid_signer(&program_id, &key(), &key(), &key(), None, None).unwrap(),
+            close_valid_signer(&program_id, &key(), &key(), &key(), &key(), None, None).unwrap(),
+            close_signer_group(&program_id, &key(), &key(), &key()).unwrap(),
+            migrate_valid_signer(
+                &program_id,
+                &key(),
+                &key(),
+                &key(),
+                &key(),
+                &key(),
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+            add_signer_address(&program_id, &key(), &key(), &key(), eth_address).unwrap(),
+            remove_signer_address(&program_id, &key(), &key(), &key(), eth_address).unwrap(),
+            set_signer_label(&program_id, &key(), &key(), &key(), "dn").unwrap(),
+            revoke_eth_address(&program_id, &key(), &key(), &key(), eth_address).unwrap(),
+            propose_ownership(&program_id, &key(), &key(), &key()).unwrap(),
+            accept_ownership(&program_id, &key(), &key(), &key(), &key()).unwrap(),
+            cancel_proposed_ownership(&program_id, &key(), &key()).unwrap(),
+            set_delegate_operator(&program_id, &key(), &key(), &key()).unwrap(),
+            set_governance(&program_id, &key(), &key(), true, 1).unwrap(),
+            set_governance_realm(&program_id, &key(), &key(), &key()).unwrap(),
+            governed_init_valid_signer(&program_id, &key(), &key(), None, eth_address, approvals())
+                .unwrap(),
+            governed_clear_valid_signer(&program_id, &key(), eth_address, None, None, approvals())
+                .unwrap(),
+            execute_governance_proposal(
+                clear_valid_signer(&program_id, &key(), &key(), &key(), None, None).unwrap(),
+            )
+            .unwrap(),
+            update_merkle_root(&program_id, &key(), &key(), [4; 32]).unwrap(),
+            init_valid_signer_metadata(
+                &program_id,
+                &key(),
+                &key(),
+                &key(),
+                &key(),
+                metadata.clone(),
+            )
+            .unwrap(),
+            update_valid_signer_metadata(&program_id, &key(), &key(), &key(), metadata).unwrap(),
+            add_message_prefix(&program_id, &key(), &key(), prefix).unwrap(),
+            remove_message_prefix(&program_id, &key(), &key(), prefix).unwrap(),
+        ];
+
+        let mut config = ProgramConfig {
+            version: Processor::PROGRAM_CONFIG_VERSION,
+            authority: Pubkey::new_unique(),
+            lockdown_mode: 1,
+        };
+        for lockdown_mode in [1, 0].iter() {
+            config.lockdown_mode = *lockdown_mode;
+            let mut config_data = vec![0; ProgramConfig::LEN];
+            config.pack(&mut config_data).unwrap();
+            for instruction in &instructions {
+                assert!(AudiusInstruction::unpack(&instruction.data)
+                    .unwrap()
+                    .takes_program_config());
+                let mut lamports = vec![0; instruction.accounts.len()];
+                let mut data = vec![vec![]; instruction.accounts.len()];
+                *data.last_mut().unwrap() = config_data.clone();
+                let infos: Vec<_> = instruction
+                    .accounts
+                    .iter()
+                    .zip(lamports.iter_mut())
+                    .zip(data.iter_mut())
+                    .map(|((meta, lamports), data)| {
+                        AccountInfo::new(
+                            &meta.pubkey,
+                            meta.is_signer,
+                            meta.is_writable,
+                            lamports,
+                            data,
+                            &program_id,
+                            false,
+                            0,
+                        )
+                    })
+                    .collect();
+                let result = Processor::process(&program_id, &infos, &instruction.data);
+                // unlocked, the instructions fail later on the blank accounts
+                assert_eq!(
+                    result == Err(AudiusError::ProgramLocked.into()),
+                    config.is_locked()
+                );
+            }
+        }
+    }
+
+    /// Run `f` collecting the lines program logs meanwhile. Syscall stubs are global,
+    /// so capturing tests run one at a time.
+    fn capture_logs<F: FnOnce()>(f: F) -> Vec<String> {
+        use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+        use std::sync::{Arc, Mutex};
+
+        struct CapturingStubs(Arc<Mutex<Vec<String>>>);
+        impl SyscallStubs for CapturingStubs {
+            fn sol_log(&self, message: &str) {
+                self.0.lock().unwrap().push(message.to_string());
+            }
+
+            fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+                unsafe {
+                    *(var_addr as *mut Clock) = Clock::default();
+                }
+                solana_program::entrypoint::SUCCESS
+            }
+        }
+
+        static CAPTURE_LOCK: Mutex<()> = Mutex::new(());
+        let _guard = CAPTURE_LOCK
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        let logs = Arc::new(Mutex::new(vec![]));
+        let previous_stubs = set_syscall_stubs(Box::new(CapturingStubs(logs.clone())));
+        f();
+        set_syscall_stubs(previous_stubs);
+        let logs = logs.lock().unwrap().clone();
+        logs
+    }
+
+    #[test]
+    fn test_print_every_error() {
+        let errors: Vec<AudiusError> = (0..)
+            .map(AudiusError::from_u32)
+            .take_while(Option::is_some)
+            .flatten()
+            .collect();
+        let mut silent_errors = vec![];
+        for error in &errors {
+            let logs = capture_logs(|| error.print::<AudiusError>());
+            if !logs.iter().any(|line| !line.is_empty()) {
+                silent_errors.push(error.clone());
+            }
+        }
+
+        assert_eq!(errors.first(), Some(&AudiusError::InvalidInstruction));
+        assert!(
+            silent_errors.is_empty(),
+            "{:?} print nothing",
+            silent_errors
+        );
+    }
+
+    #[test]
+    fn test_owner_change_logged() {
+        let program_id = crate::id();
+        let group_key = Pubkey::new_unique();
+        let old_owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let mut data = vec![0; SignerGroup::LEN];
+        SignerGroup {
+            version: Processor::SIGNER_GROUP_VERSION,
+            owner: old_owner,
+            pending_owner: new_owner,
+            ..SignerGroup::default()
+        }
+        .pack(&mut data)
+        .unwrap();
+        let mut group_lamports = 0;
+        let group_info = AccountInfo::new(
+            &group_key,
+            false,
+            true,
+            &mut group_lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut owner_lamports = 0;
+        let mut owner_data = vec![];
+        let system_program = Pubkey::default();
+        let new_owner_info = AccountInfo::new(
+            &new_owner,
+            true,
+            false,
+            &mut owner_lamports,
+            &mut owner_data,
+            &system_program,
+            false,
+            0,
+        );
+
+        let (history_key, _) = find_ownership_history_address(&program_id, &group_key);
+        let mut history_lamports = 0;
+        let mut history_data = vec![0; OwnershipHistory::LEN];
+        let history_info = AccountInfo::new(
+            &history_key,
+            false,
+            true,
+            &mut history_lamports,
+            &mut history_data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let registry_info = |key, lamports, data| {
+            AccountInfo::new(key, false, true, lamports, data, &program_id, false, 0)
+        };
+        let (old_registry_key, _) = find_owner_registry_address(&program_id, &old_owner);
+        let mut old_registry_lamports = 0;
+        let mut old_registry_data = vec![0; OwnerRegistry::LEN];
+        OwnerRegistry {
+            version: Processor::OWNER_REGISTRY_VERSION,
+            owner: old_owner,
+            groups: vec![Pubkey::new_unique(), group_key],
         }
+        .pack(&mut old_registry_data)
+        .unwrap();
+        let old_registry_info = registry_info(
+            &old_registry_key,
+            &mut old_registry_lamports,
+            &mut old_registry_data,
+        );
+        let (new_registry_key, _) = find_owner_registry_address(&program_id, &new_owner);
+        let mut new_registry_lamports = 0;
+        let mut new_registry_data = vec![0; OwnerRegistry::LEN];
+        let new_registry_info = registry_info(
+            &new_registry_key,
+            &mut new_registry_lamports,
+            &mut new_registry_data,
+        );
+
+        // history and registry accounts already exist, so payer, rent and system program
+        // aren't read
+        let accounts = [
+            group_info.clone(),
+            new_owner_info.clone(),
+            history_info.clone(),
+            new_owner_info.clone(),
+            new_owner_info.clone(),
+            new_owner_info,
+            old_registry_info.clone(),
+            new_registry_info.clone(),
+        ];
+        let logs =
+            capture_logs(|| Processor::process_accept_ownership(&program_id, &accounts).unwrap());
+        assert!(logs.contains(&format_event(
+            "owner_change",
+            &[
+                ("group", &group_key.to_string()),
+                ("old", &old_owner.to_string()),
+                ("new", &new_owner.to_string()),
+            ]
+        )));
+        assert_eq!(
+            SignerGroup::deserialize_any_version(&group_info.data.borrow())
+                .unwrap()
+                .0
+                .owner,
+            new_owner
+        );
+        let history = OwnershipHistory::unpack(&history_info.data.borrow()).unwrap();
+        assert_eq!(history.signer_group, group_key);
+        assert_eq!(
+            history.transfers,
+            vec![OwnershipTransfer {
+                previous_owner: old_owner,
+                new_owner,
+                transfer_slot: 0,
+            }]
+        );
+
+        // the group moves to the registry of the new owner
+        let old_registry = OwnerRegistry::unpack(&old_registry_info.data.borrow()).unwrap();
+        assert_eq!(old_registry.groups.len(), 1);
+        assert!(!old_registry.groups.contains(&group_key));
+        let new_registry = OwnerRegistry::unpack(&new_registry_info.data.borrow()).unwrap();
+        assert_eq!(new_registry.owner, new_owner);
+        assert_eq!(new_registry.groups, vec![group_key]);
     }
 }
@@ -0,0 +1,126 @@
+//! Typed results the program returns to its callers via return data
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+/// Max size of the return data the runtime keeps
+pub const MAX_RETURN_DATA: usize = 1024;
+
+/// Result of a successful signature validation, a program validating signatures via CPI
+/// reads it back with [get_validation_result]
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct ValidationResult {
+    /// Eth address of the valid signer which made the signature
+    pub eth_address: [u8; 20],
+    /// Signer group the valid signer belongs to
+    pub signer_group: Pubkey,
+}
+
+#[cfg(target_arch = "bpf")]
+mod syscalls {
+    // solana-program 1.6.4 doesn't wrap these yet, the runtime provides them since 1.8
+    extern "C" {
+        pub fn sol_set_return_data(data: *const u8, length: u64);
+        pub fn sol_get_return_data(data: *mut u8, length: u64, program_id: *mut u8) -> u64;
+    }
+}
+
+#[cfg(not(target_arch = "bpf"))]
+thread_local! {
+    // native builds run the callee of a CPI on the caller's thread, so keeping the data
+    // per thread emulates the runtime for program tests
+    static RETURN_DATA: std::cell::RefCell<Option<(Pubkey, Vec<u8>)>> =
+        std::cell::RefCell::new(None);
+}
+
+/// Set the return data of the current instruction, the runtime records the program id
+/// itself, native builds keep the given one
+pub fn set_return_data(program_id: &Pubkey, data: &[u8]) -> ProgramResult {
+    if data.len() > MAX_RETURN_DATA {
+        return Err(ProgramError::InvalidArgument);
+    }
+    store_return_data(program_id, data);
+    Ok(())
+}
+
+#[cfg(target_arch = "bpf")]
+fn store_return_data(_program_id: &Pubkey, data: &[u8]) {
+    unsafe { syscalls::sol_set_return_data(data.as_ptr(), data.len() as u64) };
+}
+
+#[cfg(not(target_arch = "bpf"))]
+fn store_return_data(program_id: &Pubkey, data: &[u8]) {
+    RETURN_DATA.with(|return_data| {
+        *return_data.borrow_mut() = Some((*program_id, data.to_vec()));
+    });
+}
+
+/// Return data set by the last invoked program along with the program id
+#[cfg(target_arch = "bpf")]
+pub fn get_return_data() -> Option<(Pubkey, Vec<u8>)> {
+    let mut data = vec![0u8; MAX_RETURN_DATA];
+    let mut program_id = Pubkey::default();
+    let size = unsafe {
+        syscalls::sol_get_return_data(
+            data.as_mut_ptr(),
+            data.len() as u64,
+            &mut program_id as *mut Pubkey as *mut u8,
+        )
+    } as usize;
+    if size == 0 {
+        return None;
+    }
+    data.truncate(size.min(MAX_RETURN_DATA));
+    Some((program_id, data))
+}
+
+/// Return data set by the last invoked program along with the program id
+#[cfg(not(target_arch = "bpf"))]
+pub fn get_return_data() -> Option<(Pubkey, Vec<u8>)> {
+    RETURN_DATA.with(|return_data| return_data.borrow().clone())
+}
+
+/// Return the validation result to the program which invoked the validation
+pub fn set_validation_result(program_id: &Pubkey, result: &ValidationResult) -> ProgramResult {
+    set_return_data(program_id, &result.try_to_vec()?)
+}
+
+/// Read the result of the signature validation just invoked, fails unless the Audius
+/// program returned it
+pub fn get_validation_result() -> Result<ValidationResult, ProgramError> {
+    match get_return_data() {
+        Some((program_id, data)) if program_id == crate::id() => {
+            ValidationResult::try_from_slice(&data).map_err(|_| ProgramError::InvalidAccountData)
+        }
+        _ => Err(ProgramError::InvalidArgument),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_result_round_trip() {
+        let result = ValidationResult {
+            eth_address: [7u8; 20],
+            signer_group: Pubkey::new_unique(),
+        };
+        set_validation_result(&crate::id(), &result).unwrap();
+        assert_eq!(get_validation_result().unwrap(), result);
+
+        // result of another program isn't taken for the validation one
+        set_validation_result(&Pubkey::new_unique(), &result).unwrap();
+        assert_eq!(get_validation_result(), Err(ProgramError::InvalidArgument));
+
+        set_return_data(&crate::id(), &[1, 2, 3]).unwrap();
+        assert_eq!(
+            get_validation_result(),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            set_return_data(&crate::id(), &[0; MAX_RETURN_DATA + 1]),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
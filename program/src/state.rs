@@ -1,9 +1,21 @@
 //! State transition types
 
 use crate::error::AudiusError;
+pub use audius_types::{PodU32, PodU64, SignerKeyType};
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use bytemuck::{Pod, Zeroable};
+use num_traits::{CheckedAdd, One};
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    program_pack::{IsInitialized, Pack, Sealed},
+    pubkey::Pubkey,
+};
+use std::cell::{Ref, RefMut};
 use std::mem::size_of;
+use std::ops::Deref;
+
+pub mod offsets;
 
 /// Signer group data
 #[repr(C)]
@@ -13,18 +25,244 @@ pub struct SignerGroup {
     pub version: u8,
     /// Pubkey of the account authorized to add/remove valid signers
     pub owner: Pubkey,
+    /// First ValidSigner in the group's signer list, zero if group is empty
+    pub first_signer: Pubkey,
+    /// Last ValidSigner in the group's signer list, zero if group is empty
+    pub last_signer: Pubkey,
+    /// Owner proposed to take over the group, zero if there is no proposal
+    pub pending_owner: Pubkey,
+    /// Require Ethereum key holder's proof of possession to register Secp256k1 valid signer
+    pub eth_pop_required: bool,
+    /// Lamports charged for each signature validation, zero if validations are free
+    pub validation_fee: PodU64,
+    /// Account validation fees are paid to
+    pub treasury: Pubkey,
+    /// Maximum number of signature validations per quota window, zero if unlimited
+    pub quota_per_window: PodU32,
+    /// Slot the current quota window started at
+    pub window_start_slot: PodU64,
+    /// Number of signature validations in the current quota window
+    pub used_in_window: PodU32,
+    /// Id of the next valid signer to join the group, ids start at 1 and are never reused
+    pub next_signer_id: PodU32,
+    /// Number of valid signers in the group
+    pub valid_signer_count: PodU32,
+    /// Maximum number of valid signers in the group, zero if unlimited
+    pub max_valid_signers: PodU32,
+    /// Prefixes of the messages group validates, only first `message_prefix_count` are set
+    pub message_prefixes:
+        [[u8; SignerGroup::MESSAGE_PREFIX_SIZE]; SignerGroup::MAX_MESSAGE_PREFIXES],
+    /// Number of registered message prefixes, zero if any message can be validated
+    pub message_prefix_count: u8,
+    /// Require transaction signature of the valid signer's cosigner to validate its signature
+    pub require_cosigner: bool,
+    /// Number of successful signature validations by all of the group's valid signers,
+    /// saturates at `u64::MAX`
+    pub total_validations_all: PodU64,
+    /// Allow the group's valid signers to add and remove signers without the owner
+    pub governed: bool,
+    /// Number of valid signers required to approve membership change of the governed group
+    pub governance_threshold: u8,
+    /// Number of membership changes applied by governance, included in the approval message
+    pub governance_nonce: PodU64,
+    /// Hot key allowed to add valid signers along with the owner, zero if there is none
+    pub delegate_operator: Pubkey,
+    /// Merkle root of the members' eth addresses set by the owner, see [merkle](../merkle/index.html),
+    /// zero if signatures can't be validated by Merkle proof
+    pub members_merkle_root: [u8; 32],
+    /// SPL Governance realm whose proposals may add and remove valid signers, zero if none
+    pub governance_realm: Pubkey,
+}
+
+/// Membership change of the governed signer group
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GovernanceAction {
+    /// Add valid signer with the eth address
+    AddSigner,
+    /// Remove valid signer derived from the eth address
+    RemoveSigner,
+}
+
+impl GovernanceAction {
+    /// Prefix of the message approving the action
+    pub fn message_prefix(self) -> &'static [u8] {
+        match self {
+            Self::AddSigner => b"audius:add-signer:",
+            Self::RemoveSigner => b"audius:remove-signer:",
+        }
+    }
 }
 
 /// Valid signer data
 #[repr(C)]
-#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
 pub struct ValidSigner {
     /// Signer version
     pub version: u8,
     /// SignerGroup this ValidSigner belongs to
     pub signer_group: Pubkey,
-    /// Ethereum address of signer
+    /// Ethereum addresses of signer, only first `eth_address_count` of them are registered
+    pub eth_addresses:
+        [[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]; ValidSigner::MAX_ETH_ADDRESSES],
+    /// Number of registered Ethereum addresses
+    pub eth_address_count: u8,
+    /// Next ValidSigner in the group's signer list, zero if this is the last one
+    pub next_signer: Pubkey,
+    /// Previous ValidSigner in the group's signer list, zero if this is the first one
+    pub prev_signer: Pubkey,
+    /// Type of the key signer signs with
+    pub key_type: SignerKeyType,
+    /// Ed25519 public key of signer, used instead of `eth_addresses` for Ed25519 signers
+    pub ed25519_pubkey: Pubkey,
+    /// Human readable label of signer, zero padded UTF-8
+    pub label: [u8; ValidSigner::LABEL_SIZE],
+    /// Slot signer's signatures become valid at, zero if signer is active immediately
+    pub active_after_slot: PodU64,
+    /// Last slot signer's signatures are valid at, zero if signer never expires
+    pub expires_at_slot: PodU64,
+    /// Bump seed of the account address derived from signer group and eth address,
+    /// zero for Ed25519 signers
+    pub bump_seed: u8,
+    /// Sequential id of signer within its group
+    pub signer_id: PodU32,
+    /// Solana key co-signing validations of signer's signatures, zero if there is none
+    pub cosigner: Pubkey,
+    /// Number of successful validations of signer's signatures, saturates at `u32::MAX`
+    pub total_validations: PodU32,
+    /// Slot of signer's last validation
+    pub last_validation_slot: PodU64,
+    /// Number of signer's validations in the current rate limit window
+    pub validations_this_window: PodU32,
+}
+
+/// Valid signer metadata, stored in a separate account derived from valid signer address
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ValidSignerMetadata {
+    /// Metadata version
+    pub version: u8,
+    /// ValidSigner this metadata belongs to
+    pub valid_signer: Pubkey,
+    /// URL of the signer's node endpoint, zero padded
+    pub endpoint_url: [u8; ValidSignerMetadata::ENDPOINT_URL_SIZE],
+    /// Name of the signer's operator, zero padded
+    pub operator_name: [u8; ValidSignerMetadata::OPERATOR_NAME_SIZE],
+}
+
+/// Signer groups owned by the owner, stored in an account derived from owner address.
+/// Groups are registered on creation and on ownership transfer, and leave the registry
+/// when transferred away or closed.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct OwnerRegistry {
+    /// Registry version
+    pub version: u8,
+    /// Owner of the registered signer groups
+    pub owner: Pubkey,
+    /// Registered signer groups in order of registration
+    pub groups: Vec<Pubkey>,
+}
+
+/// Eth addresses revoked by the signer group's owner, stored in an account derived
+/// from the group address
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct RevocationRegistry {
+    /// Registry version
+    pub version: u8,
+    /// Signer group the addresses are revoked in
+    pub signer_group: Pubkey,
+    /// Revoked eth addresses in order of revocation
+    pub eth_addresses: Vec<[u8; 20]>,
+}
+
+/// Ownership transfer of the signer group
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct OwnershipTransfer {
+    /// Owner which handed the group over
+    pub previous_owner: Pubkey,
+    /// Owner which accepted the group
+    pub new_owner: Pubkey,
+    /// Slot the ownership was accepted at
+    pub transfer_slot: u64,
+}
+
+/// Latest ownership transfers of the signer group, stored in an account derived
+/// from the group address
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct OwnershipHistory {
+    /// History version
+    pub version: u8,
+    /// Signer group the transfers belong to
+    pub signer_group: Pubkey,
+    /// Transfers from the most recent one, the oldest are dropped once the history is full
+    pub transfers: Vec<OwnershipTransfer>,
+}
+
+/// Accounts allowed to create signer groups, stored in a single account derived
+/// from program address. Anyone may create groups while the whitelist is empty.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct CreationWhitelist {
+    /// Whitelist version
+    pub version: u8,
+    /// Whitelist flags, see [CreationWhitelist::BYPASS_WHITELIST]
+    pub flags: u8,
+    /// Accounts allowed to sign signer group creation
+    pub accounts: Vec<Pubkey>,
+}
+
+/// Program-wide settings, stored in a single account derived from program address
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ProgramConfig {
+    /// Config version
+    pub version: u8,
+    /// Account allowed to change the config
+    pub authority: Pubkey,
+    /// Non-zero while the program is locked down, see [ProgramConfig::is_locked]
+    pub lockdown_mode: u8,
+}
+
+/// Record of a validated signature, stored in an account derived from valid signer address
+/// and message hash so other programs can check what was validated
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Attestation {
+    /// Attestation version
+    pub version: u8,
+    /// Signer group the valid signer belongs to
+    pub signer_group: Pubkey,
+    /// Valid signer which made the signature
+    pub valid_signer: Pubkey,
+    /// Eth address recovered from the signature
     pub eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    /// Keccak256 hash of the signed message
+    pub message_hash: [u8; 32],
+    /// Slot the signature was validated at
+    pub slot: u64,
+    /// Slot after which the attestation may be closed, 0 if it never expires
+    pub expires_at_slot: u64,
+    /// Account which paid for the attestation and gets the rent back on close
+    pub payer: Pubkey,
+}
+
+/// Program-wide statistics, stored in a single account derived from program address
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ProgramStats {
+    /// Stats version
+    pub version: u8,
+    /// Number of signer groups created
+    pub total_groups: u64,
+    /// Number of valid signers created
+    pub total_signers: u64,
+    /// Number of successful signature validations
+    pub total_validations: u64,
+    /// Number of valid signers cleared
+    pub total_clearances: u64,
 }
 
 /// Secp256k1 signature offsets data
@@ -46,18 +284,124 @@ pub struct SecpSignatureOffsets {
     pub message_instruction_index: u8,
 }
 
+/// Ed25519 signature offsets data
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Ed25519SignatureOffsets {
+    /// Offset to ed25519 signature of 64 bytes
+    pub signature_offset: u16,
+    /// Index of signature instruction in buffer, `u16::MAX` for Ed25519 instruction itself
+    pub signature_instruction_index: u16,
+    /// Offset to public key of 32 bytes
+    pub public_key_offset: u16,
+    /// Index of public key instruction in buffer, `u16::MAX` for Ed25519 instruction itself
+    pub public_key_instruction_index: u16,
+    /// Offset to start of message data
+    pub message_data_offset: u16,
+    /// Size of message data
+    pub message_data_size: u16,
+    /// Index of message instruction in buffer, `u16::MAX` for Ed25519 instruction itself
+    pub message_instruction_index: u16,
+}
+
+/// Deserialize account data of `len` bytes layout, data of the earlier shorter layouts
+/// is zero filled up to `len` so the fields added since then are read as zeroes
+fn unpack_zero_filled<T: BorshDeserialize>(data: &[u8], len: usize) -> Result<T, ProgramError> {
+    let mut buffer = vec![0; len];
+    let data_len = data.len().min(len);
+    buffer[..data_len].copy_from_slice(&data[..data_len]);
+    T::try_from_slice(&buffer).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Length of the Anchor discriminator SignerGroup and ValidSigner data starts with,
+/// accounts are stored without it unless the program is built with `anchor-compat` feature
+pub const ACCOUNT_DISCRIMINATOR_LEN: usize = if cfg!(feature = "anchor-compat") {
+    8
+} else {
+    0
+};
+
+/// Version of the closed SignerGroup and ValidSigner accounts. The tombstone is written
+/// before the lamports are drained, so the account refunded within the same transaction
+/// is neither initialized nor accepted by the init instructions.
+pub const TOMBSTONE_VERSION: u8 = u8::MAX;
+
+/// Account data following the discriminator it must start with. Zeroed data of
+/// the account which isn't initialized yet has no discriminator and is taken as is.
+fn strip_discriminator<'a>(data: &'a [u8], discriminator: &[u8]) -> Result<&'a [u8], ProgramError> {
+    if data.starts_with(discriminator) {
+        Ok(&data[discriminator.len()..])
+    } else if data.iter().all(|byte| *byte == 0) {
+        Ok(&data[data.len().min(discriminator.len())..])
+    } else {
+        Err(AudiusError::InvalidAccountDiscriminator.into())
+    }
+}
+
+// LEN is taken from size_of, any padding would make it longer than the Borsh encoding
+static_assertions::assert_eq_size!(
+    SignerGroup,
+    [u8; 1 + 32 * 4 + 1 + 8 + 32 + 4 + 8 + 4 + 4 + 4 + 4 + 8 * 4 + 1 + 1 + 8 + 1 + 1 + 8 + 32 * 3]
+);
+
+/// Add one to the counter, fails with [Overflow](../error/enum.AudiusError.html) instead of wrapping.
+/// Meant for counters that must stay exact such as ids and nonces, statistics saturate instead
+pub fn checked_increment<T: CheckedAdd + One>(counter: T) -> Result<T, ProgramError> {
+    counter
+        .checked_add(&T::one())
+        .ok_or_else(|| AudiusError::Overflow.into())
+}
+
+/// Format bytes as 0x prefixed hex for logs
+pub fn format_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::from("0x"), |hex, byte| {
+        hex + &format!("{:02x}", byte)
+    })
+}
+
+/// Compare the bytes in time independent of the position of the first difference,
+/// so authority checks don't leak how much of the expected key was matched
+pub fn ct_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .fold(0, |diff, (left, right)| diff | (left ^ right))
+            == 0
+}
+
 impl SignerGroup {
-    /// Length of SignerGroup when serialized
-    pub const LEN: usize = size_of::<SignerGroup>();
+    /// Length of SignerGroup account data, including the discriminator if it's stored
+    pub const LEN: usize = ACCOUNT_DISCRIMINATOR_LEN + size_of::<SignerGroup>();
+
+    /// Anchor account discriminator, `sha256("account:SignerGroup")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [88, 102, 32, 113, 207, 231, 125, 25];
+
+    /// Length of the validation quota window in slots, about a day of 400ms slots
+    pub const QUOTA_WINDOW_SLOTS: u64 = 216_000;
+
+    /// Size of message prefix in bytes
+    pub const MESSAGE_PREFIX_SIZE: usize = 8;
+
+    /// Maximum number of message prefixes one SignerGroup can hold
+    pub const MAX_MESSAGE_PREFIXES: usize = 4;
 
     /// Check if SignerGroup is initialized
     pub fn is_initialized(&self) -> bool {
-        self.version != 0
+        self.version != 0 && self.version != TOMBSTONE_VERSION
+    }
+
+    /// Check that the key can own the group being initialized, zero key can never sign
+    /// and the group can't sign for itself, either would leave the group without an owner
+    pub fn check_new_owner(signer_group: &Pubkey, owner: &Pubkey) -> Result<(), ProgramError> {
+        if *owner == Pubkey::default() || owner == signer_group {
+            return Err(AudiusError::InvalidOwner.into());
+        }
+        Ok(())
     }
 
     /// Check owner validity and signature
     pub fn check_owner(&self, owner_info: &AccountInfo) -> Result<(), ProgramError> {
-        if *owner_info.key != self.owner {
+        if !ct_eq(owner_info.key.as_ref(), self.owner.as_ref()) {
             return Err(AudiusError::WrongOwner.into());
         }
         if !owner_info.is_signer {
@@ -65,85 +409,2493 @@ impl SignerGroup {
         }
         Ok(())
     }
+
+    /// Check signature of the owner or the delegate operator, if the group has one
+    pub fn check_owner_or_delegate(
+        &self,
+        authority_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if self.delegate_operator != Pubkey::default()
+            && ct_eq(authority_info.key.as_ref(), self.delegate_operator.as_ref())
+        {
+            if !authority_info.is_signer {
+                return Err(AudiusError::SignatureMissing.into());
+            }
+            return Ok(());
+        }
+        self.check_owner(authority_info)
+    }
+
+    /// Check pending owner validity and signature
+    pub fn check_pending_owner(
+        &self,
+        pending_owner_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if self.pending_owner == Pubkey::default() {
+            return Err(AudiusError::OwnershipNotProposed.into());
+        }
+        if !ct_eq(pending_owner_info.key.as_ref(), self.pending_owner.as_ref()) {
+            return Err(AudiusError::WrongOwner.into());
+        }
+        if !pending_owner_info.is_signer {
+            return Err(AudiusError::SignatureMissing.into());
+        }
+        Ok(())
+    }
+
+    /// Count one more signature validation at the given slot against the group's quota,
+    /// the counter is reset once the slot is past the current window
+    pub fn consume_validation_quota(&mut self, slot: u64) -> Result<(), ProgramError> {
+        let quota_per_window = u32::from(self.quota_per_window);
+        if quota_per_window == 0 {
+            return Ok(());
+        }
+        let window_end_slot =
+            u64::from(self.window_start_slot).saturating_add(Self::QUOTA_WINDOW_SLOTS);
+        if slot >= window_end_slot {
+            self.window_start_slot = slot.into();
+            self.used_in_window = 0.into();
+        }
+        let used_in_window = u32::from(self.used_in_window);
+        if used_in_window >= quota_per_window {
+            return Err(AudiusError::QuotaExceeded.into());
+        }
+        self.used_in_window = checked_increment(used_in_window)?.into();
+        Ok(())
+    }
+
+    /// Count one more successful signature validation by the group's signers,
+    /// the statistic saturates at `u64::MAX` so that it never fails a validation
+    pub fn count_validation(&mut self) {
+        self.total_validations_all = u64::from(self.total_validations_all)
+            .saturating_add(1)
+            .into();
+    }
+
+    /// Take id for the valid signer joining the group
+    pub fn assign_signer_id(&mut self) -> Result<u32, ProgramError> {
+        let signer_id = u32::from(self.next_signer_id);
+        self.next_signer_id = signer_id
+            .checked_add(1)
+            .ok_or(AudiusError::SignerIdOverflow)?
+            .into();
+        Ok(signer_id)
+    }
+
+    /// Count valid signer joining the group, fails if the group is full
+    pub fn add_valid_signer(&mut self) -> Result<(), ProgramError> {
+        let valid_signer_count = u32::from(self.valid_signer_count);
+        let max_valid_signers = u32::from(self.max_valid_signers);
+        if max_valid_signers != 0 && valid_signer_count >= max_valid_signers {
+            return Err(AudiusError::MaxSignersReached.into());
+        }
+        self.valid_signer_count = checked_increment(valid_signer_count)?.into();
+        Ok(())
+    }
+
+    /// Count valid signer leaving the group, the count doesn't go below zero.
+    /// The members Merkle root is dropped, it would still prove the signer's membership.
+    pub fn remove_valid_signer(&mut self) {
+        self.valid_signer_count = u32::from(self.valid_signer_count).saturating_sub(1).into();
+        self.members_merkle_root = [0; 32];
+    }
+
+    /// Set maximum number of valid signers, zero removes the limit.
+    /// Limit below the number of signers already in the group is rejected.
+    pub fn set_max_valid_signers(&mut self, max_valid_signers: u32) -> Result<(), ProgramError> {
+        if max_valid_signers != 0 && max_valid_signers < u32::from(self.valid_signer_count) {
+            return Err(AudiusError::MaxSignersReached.into());
+        }
+        self.max_valid_signers = max_valid_signers.into();
+        Ok(())
+    }
+
+    /// Registered message prefixes of the group
+    pub fn message_prefixes(&self) -> &[[u8; Self::MESSAGE_PREFIX_SIZE]] {
+        let count = (self.message_prefix_count as usize).min(Self::MAX_MESSAGE_PREFIXES);
+        &self.message_prefixes[..count]
+    }
+
+    /// Register one more prefix of the messages group validates
+    pub fn add_message_prefix(
+        &mut self,
+        prefix: [u8; Self::MESSAGE_PREFIX_SIZE],
+    ) -> Result<(), ProgramError> {
+        if self.message_prefixes().contains(&prefix) {
+            return Err(AudiusError::MessagePrefixAlreadyAdded.into());
+        }
+        let count = self.message_prefixes().len();
+        if count == Self::MAX_MESSAGE_PREFIXES {
+            return Err(AudiusError::MessagePrefixLimitReached.into());
+        }
+        self.message_prefixes[count] = prefix;
+        self.message_prefix_count = count as u8 + 1;
+        Ok(())
+    }
+
+    /// Unregister message prefix, the remaining prefixes are shifted to keep them contiguous
+    pub fn remove_message_prefix(
+        &mut self,
+        prefix: &[u8; Self::MESSAGE_PREFIX_SIZE],
+    ) -> Result<(), ProgramError> {
+        let count = self.message_prefixes().len();
+        let index = self
+            .message_prefixes()
+            .iter()
+            .position(|registered| registered == prefix)
+            .ok_or(AudiusError::MessagePrefixNotFound)?;
+        self.message_prefixes.copy_within(index + 1..count, index);
+        self.message_prefixes[count - 1] = [0; Self::MESSAGE_PREFIX_SIZE];
+        self.message_prefix_count = count as u8 - 1;
+        Ok(())
+    }
+
+    /// Check that message starts with one of the registered prefixes,
+    /// any message is allowed if there are none
+    pub fn check_message_prefix(&self, message: &[u8]) -> Result<(), ProgramError> {
+        let prefixes = self.message_prefixes();
+        if prefixes.is_empty() || prefixes.iter().any(|prefix| message.starts_with(prefix)) {
+            Ok(())
+        } else {
+            Err(AudiusError::MessagePrefixNotAllowed.into())
+        }
+    }
+
+    /// Enable governance with the given threshold or disable it. Threshold of the governed
+    /// group must be reachable by the signers already in the group.
+    pub fn set_governance(&mut self, governed: bool, threshold: u8) -> Result<(), ProgramError> {
+        if governed && (threshold == 0 || u32::from(threshold) > u32::from(self.valid_signer_count))
+        {
+            return Err(AudiusError::InvalidGovernanceThreshold.into());
+        }
+        self.governed = governed;
+        self.governance_threshold = threshold;
+        Ok(())
+    }
+
+    /// Message the valid signers sign to approve the action, bound to the group and
+    /// the governance nonce, so the approval is only valid for a single change
+    pub fn governance_message(
+        action: GovernanceAction,
+        signer_group: &Pubkey,
+        eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        nonce: u64,
+    ) -> Vec<u8> {
+        [
+            action.message_prefix(),
+            signer_group.as_ref(),
+            &eth_address[..],
+            &nonce.to_le_bytes()[..],
+        ]
+        .concat()
+    }
+
+    /// Check that the governed group's approvals reach the threshold and consume the nonce.
+    /// Removal can't leave fewer signers than the threshold, governance would be stuck.
+    pub fn apply_governance(
+        &mut self,
+        action: GovernanceAction,
+        approvals: usize,
+    ) -> Result<(), ProgramError> {
+        if !self.governed {
+            return Err(AudiusError::GovernanceDisabled.into());
+        }
+        if approvals < self.governance_threshold as usize {
+            return Err(AudiusError::ThresholdNotReached.into());
+        }
+        if action == GovernanceAction::RemoveSigner
+            && u32::from(self.valid_signer_count) <= u32::from(self.governance_threshold)
+        {
+            return Err(AudiusError::InvalidGovernanceThreshold.into());
+        }
+        self.governance_nonce = checked_increment(u64::from(self.governance_nonce))?.into();
+        Ok(())
+    }
+
+    /// Format SignerGroup fields as JSON string, keys are the field names. Byte arrays
+    /// are 0x prefixed hex and only the registered message prefixes are listed
+    pub fn to_json(&self) -> String {
+        // destructured in full, so a field added to the group can't be left out
+        let SignerGroup {
+            version,
+            owner,
+            first_signer,
+            last_signer,
+            pending_owner,
+            eth_pop_required,
+            validation_fee,
+            treasury,
+            quota_per_window,
+            window_start_slot,
+            used_in_window,
+            next_signer_id,
+            valid_signer_count,
+            max_valid_signers,
+            message_prefixes: _,
+            message_prefix_count,
+            require_cosigner,
+            total_validations_all,
+            governed,
+            governance_threshold,
+            governance_nonce,
+            delegate_operator,
+            members_merkle_root,
+            governance_realm,
+        } = self;
+        let message_prefixes: Vec<String> = self
+            .message_prefixes()
+            .iter()
+            .map(|prefix| format!("\"{}\"", format_hex(prefix)))
+            .collect();
+        format!(
+            concat!(
+                "{{\"version\":{},\"owner\":\"{}\",\"first_signer\":\"{}\",",
+                "\"last_signer\":\"{}\",\"pending_owner\":\"{}\",\"eth_pop_required\":{},",
+                "\"validation_fee\":{},\"treasury\":\"{}\",\"quota_per_window\":{},",
+                "\"window_start_slot\":{},\"used_in_window\":{},\"next_signer_id\":{},",
+                "\"valid_signer_count\":{},\"max_valid_signers\":{},\"message_prefixes\":[{}],",
+                "\"message_prefix_count\":{},\"require_cosigner\":{},",
+                "\"total_validations_all\":{},\"governed\":{},\"governance_threshold\":{},",
+                "\"governance_nonce\":{},\"delegate_operator\":\"{}\",",
+                "\"members_merkle_root\":\"{}\",\"governance_realm\":\"{}\"}}"
+            ),
+            version,
+            owner,
+            first_signer,
+            last_signer,
+            pending_owner,
+            eth_pop_required,
+            u64::from(*validation_fee),
+            treasury,
+            u32::from(*quota_per_window),
+            u64::from(*window_start_slot),
+            u32::from(*used_in_window),
+            u32::from(*next_signer_id),
+            u32::from(*valid_signer_count),
+            u32::from(*max_valid_signers),
+            message_prefixes.join(","),
+            message_prefix_count,
+            require_cosigner,
+            u64::from(*total_validations_all),
+            governed,
+            governance_threshold,
+            u64::from(*governance_nonce),
+            delegate_operator,
+            format_hex(members_merkle_root),
+            governance_realm,
+        )
+    }
 }
 
+// SAFETY: ValidSigner is `repr(C)`, all of its fields are byte arrays or wrappers
+// of them with alignment of 1, so there is no padding and any bit pattern is valid
+unsafe impl Zeroable for ValidSigner {}
+unsafe impl Pod for ValidSigner {}
+
+// accounts are cast to ValidSigner in place, so it must be exactly as long as its Borsh encoding
+static_assertions::assert_eq_size!(
+    ValidSigner,
+    [u8; 1 + 32 + 20 * 3 + 1 + 32 + 32 + 1 + 32 + 32 + 8 + 8 + 1 + 4 + 32 + 4 + 8 + 4]
+);
+
 impl ValidSigner {
-    /// Length of ValidSigner when serialized
-    pub const LEN: usize = size_of::<ValidSigner>();
+    /// Length of ValidSigner account data, including the discriminator if it's stored
+    pub const LEN: usize = ACCOUNT_DISCRIMINATOR_LEN + size_of::<ValidSigner>();
+
+    /// Anchor account discriminator, `sha256("account:ValidSigner")[..8]`
+    pub const DISCRIMINATOR: [u8; 8] = [109, 170, 50, 9, 126, 18, 168, 10];
+
+    /// Maximum number of Ethereum addresses one ValidSigner can hold
+    pub const MAX_ETH_ADDRESSES: usize = 3;
+
+    /// Size of signer label in bytes
+    pub const LABEL_SIZE: usize = 32;
+
+    /// Offset of `eth_address_count` in account data
+    pub const ETH_ADDRESS_COUNT_OFFSET: usize = ACCOUNT_DISCRIMINATOR_LEN
+        + 1
+        + 32
+        + SecpSignatureOffsets::ETH_ADDRESS_SIZE * Self::MAX_ETH_ADDRESSES;
 
     /// Check if ValidSigner is initialized
     pub fn is_initialized(&self) -> bool {
-        self.version != 0
+        self.version != 0 && self.version != TOMBSTONE_VERSION
     }
-}
 
-impl SecpSignatureOffsets {
-    /// Max value can be hold in one byte
-    pub const MAX_VALUE_ONE_BYTE: u16 = 256;
+    /// Registered Ethereum addresses of signer
+    pub fn eth_addresses(&self) -> &[[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]] {
+        let count = (self.eth_address_count as usize).min(Self::MAX_ETH_ADDRESSES);
+        &self.eth_addresses[..count]
+    }
 
-    /// Size of serialized Secp256k1 signature
-    pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+    /// Check if Ethereum address is registered for signer
+    pub fn has_eth_address(
+        &self,
+        eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> bool {
+        self.eth_addresses()
+            .iter()
+            .any(|address| ct_eq(address, eth_address))
+    }
 
-    /// Size of Secp256k1 signature
-    pub const SECP_SIGNATURE_SIZE: usize = 64;
+    /// Register one more Ethereum address for signer
+    pub fn add_eth_address(
+        &mut self,
+        eth_address: [u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> Result<(), ProgramError> {
+        if eth_address == [0; SecpSignatureOffsets::ETH_ADDRESS_SIZE] {
+            return Err(AudiusError::InvalidEthAddress.into());
+        }
+        if self.has_eth_address(&eth_address) {
+            return Err(AudiusError::EthAddressAlreadyRegistered.into());
+        }
+        let count = self.eth_addresses().len();
+        if count == Self::MAX_ETH_ADDRESSES {
+            return Err(AudiusError::EthAddressLimitReached.into());
+        }
+        self.eth_addresses[count] = eth_address;
+        self.eth_address_count = count as u8 + 1;
+        Ok(())
+    }
 
-    /// Ethereum public key size
-    pub const ETH_ADDRESS_SIZE: usize = 20;
-}
+    /// Unregister Ethereum address, the remaining addresses are shifted to keep them contiguous
+    pub fn remove_eth_address(
+        &mut self,
+        eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    ) -> Result<(), ProgramError> {
+        let count = self.eth_addresses().len();
+        let index = self
+            .eth_addresses()
+            .iter()
+            .position(|address| ct_eq(address, eth_address))
+            .ok_or(AudiusError::EthAddressNotRegistered)?;
+        if count == 1 {
+            return Err(AudiusError::LastEthAddress.into());
+        }
+        self.eth_addresses.copy_within(index + 1..count, index);
+        self.eth_addresses[count - 1] = [0; SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+        self.eth_address_count = count as u8 - 1;
+        Ok(())
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Check if signer's signatures are valid at the slot
+    pub fn is_active(&self, slot: u64) -> bool {
+        slot >= u64::from(self.active_after_slot)
+    }
 
-    #[test]
-    fn test_signer_group() {
-        let signer_group = SignerGroup {
-            version: 0,
-            owner: Pubkey::new_from_array([1; 32]),
-        };
+    /// Check if signer's signatures are no longer valid at the slot
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expires_at_slot != PodU64::default() && slot > u64::from(self.expires_at_slot)
+    }
 
-        let packed = signer_group.try_to_vec().unwrap();
+    /// Count one more successful validation of signer's signature, saturating at `u32::MAX`
+    pub fn count_validation(&mut self) {
+        self.total_validations = u32::from(self.total_validations).saturating_add(1).into();
+    }
 
-        let unpacked = SignerGroup::try_from_slice(packed.as_slice()).unwrap();
+    /// Count validation at the slot against signer's rate limit. The window lasts while
+    /// validations follow each other closer than `window_slots`, the counter restarts after
+    pub fn consume_rate_limit(
+        &mut self,
+        slot: u64,
+        window_slots: u64,
+        max_per_window: u32,
+    ) -> Result<(), ProgramError> {
+        let mut validations_this_window = u32::from(self.validations_this_window);
+        if slot.saturating_sub(self.last_validation_slot.into()) >= window_slots {
+            validations_this_window = 0;
+        } else if validations_this_window >= max_per_window {
+            return Err(AudiusError::RateLimitExceeded.into());
+        }
+        self.last_validation_slot = slot.into();
+        self.validations_this_window = checked_increment(validations_this_window)?.into();
+        Ok(())
+    }
 
-        assert_eq!(signer_group, unpacked);
+    /// Check that the account is signer's registered cosigner and it signed the transaction
+    pub fn check_cosigner(&self, cosigner_info: &AccountInfo) -> Result<(), ProgramError> {
+        if self.cosigner == Pubkey::default()
+            || !ct_eq(cosigner_info.key.as_ref(), self.cosigner.as_ref())
+            || !cosigner_info.is_signer
+        {
+            return Err(AudiusError::MissingCosigner.into());
+        }
+        Ok(())
+    }
 
-        assert_eq!(signer_group.is_initialized(), false);
+    /// Signer label without padding
+    pub fn label(&self) -> Result<&str, ProgramError> {
+        Self::unpack_label(&self.label)
     }
 
-    #[test]
-    fn test_valid_signer() {
-        let valid_signer = ValidSigner {
-            version: 1,
-            signer_group: Pubkey::new_from_array([1; 32]),
-            eth_address: [7; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
-        };
+    /// Pack label into zero padded field, label is rejected instead of being truncated
+    pub fn pack_label(label: &str) -> Result<[u8; Self::LABEL_SIZE], ProgramError> {
+        if label.len() > Self::LABEL_SIZE {
+            return Err(AudiusError::SignerLabelTooLong.into());
+        }
+        // zero byte would be taken for the padding when label is read back
+        if label.contains('\0') {
+            return Err(AudiusError::InvalidSignerLabel.into());
+        }
+        let mut packed = [0u8; Self::LABEL_SIZE];
+        packed[..label.len()].copy_from_slice(label.as_bytes());
+        Ok(packed)
+    }
 
-        let packed = valid_signer.try_to_vec().unwrap();
+    /// Unpack zero padded label checking that padding has zeros only and label is valid UTF-8
+    pub fn unpack_label(label: &[u8; Self::LABEL_SIZE]) -> Result<&str, ProgramError> {
+        let len = label
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(Self::LABEL_SIZE);
+        if label[len..].iter().any(|byte| *byte != 0) {
+            return Err(AudiusError::InvalidSignerLabel.into());
+        }
+        std::str::from_utf8(&label[..len]).map_err(|_| AudiusError::InvalidSignerLabel.into())
+    }
 
-        let unpacked = ValidSigner::try_from_slice(packed.as_slice()).unwrap();
+    /// Read ValidSigner from account data of any layout. Accounts of the current layout are
+    /// borrowed without copying, older shorter ones are copied with the added fields zeroed
+    pub fn from_account_info_any_version<'a>(
+        info: &'a AccountInfo,
+    ) -> Result<ValidSignerRef<'a>, ProgramError> {
+        if info.data_len() >= Self::LEN {
+            return Self::from_account_info(info).map(ValidSignerRef::Borrowed);
+        }
+        let valid_signer = Self::deserialize_stored(&info.try_borrow_data()?)?;
+        Ok(ValidSignerRef::Copied(Box::new(valid_signer)))
+    }
 
-        assert_eq!(valid_signer, unpacked);
+    /// Update ValidSigner in account data of any layout. Accounts of the current layout are
+    /// changed in place, older shorter ones only while the change fits into their layout
+    pub fn update_account_info<R, F: FnOnce(&mut Self) -> Result<R, ProgramError>>(
+        info: &AccountInfo,
+        update: F,
+    ) -> Result<R, ProgramError> {
+        if info.data_len() >= Self::LEN {
+            return update(&mut *Self::from_account_info_mut(info)?);
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        let stored = Box::new(Self::deserialize_stored(&data)?);
+        let mut valid_signer = stored.clone();
+        let result = update(&mut valid_signer)?;
+        valid_signer.pack_stored(&stored, &mut data)?;
+        Ok(result)
+    }
 
-        assert_eq!(valid_signer.is_initialized(), true);
+    /// Deserialize ValidSigner from account data of any layout. Layouts
+    /// predating several addresses per signer stored the single one without a count.
+    pub fn deserialize_stored(data: &[u8]) -> Result<Self, ProgramError> {
+        let (mut valid_signer, _) = Self::deserialize_any_version(data)?;
+        if data.len() <= Self::ETH_ADDRESS_COUNT_OFFSET
+            && valid_signer.eth_address_count == 0
+            && valid_signer.eth_addresses[0] != [0; SecpSignatureOffsets::ETH_ADDRESS_SIZE]
+        {
+            valid_signer.eth_address_count = 1;
+        }
+        Ok(valid_signer)
     }
 
-    #[test]
-    fn test_offsets_pack_unpack() {
-        let offsets = SecpSignatureOffsets {
-            signature_offset: 345,
-            signature_instruction_index: 43,
-            eth_address_offset: 278,
-            eth_address_instruction_index: 7,
-            message_data_offset: 783,
-            message_data_size: 543,
-            message_instruction_index: 10,
-        };
+    /// Write ValidSigner read from account data of an older shorter layout back into it.
+    /// Only the stored fields may change, the account has to be migrated for the others.
+    fn pack_stored(&self, stored: &Self, data: &mut [u8]) -> Result<(), ProgramError> {
+        let mut packed = vec![0; Self::LEN];
+        self.pack(&mut packed)?;
+        let mut packed_stored = vec![0; Self::LEN];
+        stored.pack(&mut packed_stored)?;
+        let stored_len = data.len().min(Self::LEN);
+        if packed[stored_len..] != packed_stored[stored_len..] {
+            return Err(AudiusError::AccountMigrationRequired.into());
+        }
+        data[..stored_len].copy_from_slice(&packed[..stored_len]);
+        Ok(())
+    }
 
-        let packed = offsets.try_to_vec().unwrap();
+    /// Borrow ValidSigner from account data without copying it, account of the older
+    /// layout has to be migrated first
+    pub fn from_account_info<'a>(info: &'a AccountInfo) -> Result<Ref<'a, Self>, ProgramError> {
+        let data = info.try_borrow_data()?;
+        if data.len() < Self::LEN {
+            return Err(AudiusError::AccountMigrationRequired.into());
+        }
+        let fields = strip_discriminator(&data, Self::STORED_DISCRIMINATOR)?;
+        bytemuck::try_from_bytes::<Self>(fields).map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[ACCOUNT_DISCRIMINATOR_LEN..])
+        }))
+    }
 
-        let unpacked = SecpSignatureOffsets::try_from_slice(packed.as_slice()).unwrap();
+    /// Mutably borrow ValidSigner from account data, changes are written in place
+    pub fn from_account_info_mut<'a>(
+        info: &'a AccountInfo,
+    ) -> Result<RefMut<'a, Self>, ProgramError> {
+        let data = info.try_borrow_mut_data()?;
+        if data.len() < Self::LEN {
+            return Err(AudiusError::AccountMigrationRequired.into());
+        }
+        let fields = strip_discriminator(&data, Self::STORED_DISCRIMINATOR)?;
+        bytemuck::try_from_bytes::<Self>(fields).map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(RefMut::map(data, |data| {
+            bytemuck::from_bytes_mut(&mut data[ACCOUNT_DISCRIMINATOR_LEN..])
+        }))
+    }
+}
 
-        assert_eq!(offsets, unpacked);
+/// ValidSigner read by [ValidSigner::from_account_info_any_version]
+pub enum ValidSignerRef<'a> {
+    /// Borrowed from account data of the current layout
+    Borrowed(Ref<'a, ValidSigner>),
+    /// Copied from account data of an older layout
+    Copied(Box<ValidSigner>),
+}
+
+impl Deref for ValidSignerRef<'_> {
+    type Target = ValidSigner;
+
+    fn deref(&self) -> &ValidSigner {
+        match self {
+            Self::Borrowed(valid_signer) => valid_signer,
+            Self::Copied(valid_signer) => valid_signer,
+        }
+    }
+}
+
+impl Ed25519SignatureOffsets {
+    /// Size of serialized Ed25519 signature offsets
+    pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+
+    /// Offsets start after number of signatures and one byte of padding
+    pub const SIGNATURE_OFFSETS_START: usize = 2;
+
+    /// Size of Ed25519 signature
+    pub const SIGNATURE_SIZE: usize = 64;
+
+    /// Size of Ed25519 public key
+    pub const PUBKEY_SIZE: usize = 32;
+
+    /// Instruction index pointing to Ed25519 instruction itself
+    pub const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+    /// Offset of public key in a single signature Ed25519 instruction
+    pub const PUBKEY_OFFSET: usize =
+        Self::SIGNATURE_OFFSETS_START + Self::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    /// Offset of signature in a single signature Ed25519 instruction
+    pub const SIGNATURE_OFFSET: usize = Self::PUBKEY_OFFSET + Self::PUBKEY_SIZE;
+
+    /// Offset of message in a single signature Ed25519 instruction
+    pub const MESSAGE_DATA_OFFSET: usize = Self::SIGNATURE_OFFSET + Self::SIGNATURE_SIZE;
+
+    /// Create offsets of a single signature Ed25519 instruction
+    /// with public key, signature and message following each other
+    pub fn new(message_size: usize) -> Result<Self, ProgramError> {
+        if Self::MESSAGE_DATA_OFFSET.saturating_add(message_size) > u16::MAX as usize {
+            return Err(AudiusError::MessageTooLarge.into());
+        }
+        Ok(Self {
+            signature_offset: Self::SIGNATURE_OFFSET as u16,
+            signature_instruction_index: Self::CURRENT_INSTRUCTION,
+            public_key_offset: Self::PUBKEY_OFFSET as u16,
+            public_key_instruction_index: Self::CURRENT_INSTRUCTION,
+            message_data_offset: Self::MESSAGE_DATA_OFFSET as u16,
+            message_data_size: message_size as u16,
+            message_instruction_index: Self::CURRENT_INSTRUCTION,
+        })
+    }
+}
+
+impl ValidSignerMetadata {
+    /// Length of ValidSignerMetadata when serialized
+    pub const LEN: usize = size_of::<ValidSignerMetadata>();
+
+    /// Size of endpoint URL field
+    pub const ENDPOINT_URL_SIZE: usize = 128;
+
+    /// Size of operator name field
+    pub const OPERATOR_NAME_SIZE: usize = 64;
+
+    /// Check if ValidSignerMetadata is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+}
+
+impl OwnerRegistry {
+    /// Maximum number of signer groups one registry can hold
+    pub const MAX_GROUPS: usize = 32;
+
+    /// Length of the registry account, fits the maximum number of groups
+    pub const LEN: usize = 1 + 32 + 4 + 32 * Self::MAX_GROUPS;
+
+    /// Check if OwnerRegistry is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+
+    /// Deserialize registry from account data, unused tail of the account is ignored
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serialize registry into account data
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        self.serialize(&mut &mut data[..])
+            .map_err(|_| ProgramError::AccountDataTooSmall)
+    }
+
+    /// Register one more signer group of the owner
+    pub fn add_group(&mut self, signer_group: Pubkey) -> Result<(), ProgramError> {
+        if self.groups.len() >= Self::MAX_GROUPS {
+            return Err(AudiusError::OwnerRegistryFull.into());
+        }
+        self.groups.push(signer_group);
+        Ok(())
+    }
+
+    /// Remove signer group from the registry keeping the order of the others, returns
+    /// false if the group isn't registered. Groups created before the registration was
+    /// required aren't in the registry.
+    pub fn remove_group(&mut self, signer_group: &Pubkey) -> bool {
+        match self.groups.iter().position(|group| group == signer_group) {
+            Some(index) => {
+                self.groups.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl RevocationRegistry {
+    /// Maximum number of eth addresses one registry can hold
+    pub const MAX_ADDRESSES: usize = 64;
+
+    /// Length of the registry account, fits the maximum number of addresses
+    pub const LEN: usize = 1 + 32 + 4 + 20 * Self::MAX_ADDRESSES;
+
+    /// Check if RevocationRegistry is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+
+    /// Deserialize registry from account data, unused tail of the account is ignored
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serialize registry into account data
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        self.serialize(&mut &mut data[..])
+            .map_err(|_| ProgramError::AccountDataTooSmall)
+    }
+
+    /// Check if eth address is revoked
+    pub fn is_revoked(&self, eth_address: &[u8; 20]) -> bool {
+        self.eth_addresses
+            .iter()
+            .any(|address| ct_eq(address, eth_address))
+    }
+
+    /// Revoke one more eth address, revoking it again changes nothing
+    pub fn revoke(&mut self, eth_address: [u8; 20]) -> Result<(), ProgramError> {
+        if self.is_revoked(&eth_address) {
+            return Ok(());
+        }
+        if self.eth_addresses.len() >= Self::MAX_ADDRESSES {
+            return Err(AudiusError::RevocationRegistryFull.into());
+        }
+        self.eth_addresses.push(eth_address);
+        Ok(())
+    }
+}
+
+impl OwnershipHistory {
+    /// Maximum number of transfers one history keeps
+    pub const MAX_TRANSFERS: usize = 8;
+
+    /// Length of the history account, fits the maximum number of transfers
+    pub const LEN: usize = 1 + 32 + 4 + (32 + 32 + 8) * Self::MAX_TRANSFERS;
+
+    /// Check if OwnershipHistory is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+
+    /// Deserialize history from account data, unused tail of the account is ignored
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serialize history into account data
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        self.serialize(&mut &mut data[..])
+            .map_err(|_| ProgramError::AccountDataTooSmall)
+    }
+
+    /// Put the transfer in front of the history, dropping the oldest one if it's full
+    pub fn record(&mut self, transfer: OwnershipTransfer) {
+        self.transfers.insert(0, transfer);
+        self.transfers.truncate(Self::MAX_TRANSFERS);
+    }
+
+    /// Format transfers as JSON array string, from the most recent one
+    pub fn to_json(&self) -> String {
+        let transfers: Vec<String> = self
+            .transfers
+            .iter()
+            .map(|transfer| {
+                format!(
+                    "{{\"previous_owner\":\"{}\",\"new_owner\":\"{}\",\"transfer_slot\":{}}}",
+                    transfer.previous_owner, transfer.new_owner, transfer.transfer_slot
+                )
+            })
+            .collect();
+        format!("[{}]", transfers.join(","))
+    }
+}
+
+impl CreationWhitelist {
+    /// Maximum number of whitelisted accounts
+    pub const MAX_ACCOUNTS: usize = 32;
+
+    /// Flag letting anyone create signer groups without removing the whitelisted accounts
+    pub const BYPASS_WHITELIST: u8 = 1;
+
+    /// Length of the whitelist account, fits the maximum number of accounts
+    pub const LEN: usize = 1 + 1 + 4 + 32 * Self::MAX_ACCOUNTS;
+
+    /// Check if CreationWhitelist is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+
+    /// Deserialize whitelist from account data, unused tail of the account is ignored
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serialize whitelist into account data
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        self.serialize(&mut &mut data[..])
+            .map_err(|_| ProgramError::AccountDataTooSmall)
+    }
+
+    /// Check if group creation is restricted to the whitelisted accounts
+    pub fn is_enforced(&self) -> bool {
+        !self.accounts.is_empty() && self.flags & Self::BYPASS_WHITELIST == 0
+    }
+
+    /// Check if the account is whitelisted
+    pub fn contains(&self, account: &Pubkey) -> bool {
+        self.accounts
+            .iter()
+            .any(|whitelisted| ct_eq(whitelisted.as_ref(), account.as_ref()))
+    }
+
+    /// Add the account to the whitelist, adding it twice has no effect
+    pub fn add(&mut self, account: Pubkey) -> Result<(), ProgramError> {
+        if self.contains(&account) {
+            return Ok(());
+        }
+        if self.accounts.len() >= Self::MAX_ACCOUNTS {
+            return Err(AudiusError::CreationWhitelistFull.into());
+        }
+        self.accounts.push(account);
+        Ok(())
+    }
+
+    /// Remove the account from the whitelist, removing a missing account has no effect
+    pub fn remove(&mut self, account: &Pubkey) {
+        self.accounts.retain(|whitelisted| whitelisted != account);
+    }
+}
+
+impl ProgramConfig {
+    /// Length of ProgramConfig when serialized, struct itself is padded in memory
+    pub const LEN: usize = 1 + 32 + 1;
+
+    /// Check if ProgramConfig is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+
+    /// Deserialize config from account data
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::deserialize(&mut &data[..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Serialize config into account data
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+        self.serialize(&mut &mut data[..])
+            .map_err(|_| ProgramError::AccountDataTooSmall)
+    }
+
+    /// Check if the program is locked down, signer groups and valid signers can't be
+    /// created or removed while only signature validation keeps working
+    pub fn is_locked(&self) -> bool {
+        self.lockdown_mode != 0
+    }
+}
+
+impl Attestation {
+    /// Length of Attestation when serialized, struct itself is padded in memory
+    pub const LEN: usize = 1 + 32 + 32 + SecpSignatureOffsets::ETH_ADDRESS_SIZE + 32 + 8 + 8 + 32;
+
+    /// Check if the attestation may be closed at the given slot
+    pub fn is_expired(&self, slot: u64) -> bool {
+        self.expires_at_slot != 0 && slot > self.expires_at_slot
+    }
+}
+
+impl ProgramStats {
+    /// Length of ProgramStats when serialized, struct itself is padded in memory
+    pub const LEN: usize = 1 + 4 * 8;
+
+    /// Check if ProgramStats is initialized
+    pub fn is_initialized(&self) -> bool {
+        self.version != 0
+    }
+}
+
+/// Account owned by the program which handlers expect to be initialized,
+/// loaded with [Processor::load_account_checked](../processor/struct.Processor.html)
+pub trait ProgramAccount: BorshDeserialize + BorshSerialize + IsInitialized {
+    /// Error returned when the account isn't initialized yet
+    const UNINITIALIZED_ERROR: AudiusError;
+
+    /// Discriminator the account data starts with, empty if the account is stored without one
+    const STORED_DISCRIMINATOR: &'static [u8];
+
+    /// Deserialize account stored at any of its layouts along with the stored version.
+    /// Accounts created before fields were appended are shorter, the missing fields
+    /// are read as zeroes.
+    fn deserialize_any_version(data: &[u8]) -> Result<(Self, u8), ProgramError>;
+
+    /// Serialize account into its data. Accounts of the older layouts are too short to hold
+    /// the current one and have to be migrated first.
+    fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError>;
+
+    /// Check if the account was closed, see [TOMBSTONE_VERSION]()
+    fn is_tombstoned(&self) -> bool;
+
+    /// Replace account data of any layout with the tombstone of the closed account
+    fn write_tombstone(data: &mut [u8]) -> Result<(), ProgramError>;
+}
+
+macro_rules! impl_program_account {
+    ($account:ty, $uninitialized_error:expr) => {
+        impl_program_account!(@stored $account, $uninitialized_error, &[]);
+    };
+    ($account:ty, $uninitialized_error:expr, anchor: $discriminator:expr) => {
+        impl_program_account!(
+            @stored $account,
+            $uninitialized_error,
+            if cfg!(feature = "anchor-compat") {
+                &$discriminator
+            } else {
+                &[]
+            }
+        );
+    };
+    (@stored $account:ty, $uninitialized_error:expr, $stored_discriminator:expr) => {
+        impl IsInitialized for $account {
+            fn is_initialized(&self) -> bool {
+                self.version != 0 && self.version != TOMBSTONE_VERSION
+            }
+        }
+
+        impl ProgramAccount for $account {
+            const UNINITIALIZED_ERROR: AudiusError = $uninitialized_error;
+
+            const STORED_DISCRIMINATOR: &'static [u8] = $stored_discriminator;
+
+            fn deserialize_any_version(data: &[u8]) -> Result<(Self, u8), ProgramError> {
+                let data = strip_discriminator(data, Self::STORED_DISCRIMINATOR)?;
+                let len = <$account>::LEN - Self::STORED_DISCRIMINATOR.len();
+                let account: Self = unpack_zero_filled(data, len)?;
+                let version = account.version;
+                Ok((account, version))
+            }
+
+            fn pack(&self, data: &mut [u8]) -> Result<(), ProgramError> {
+                if data.len() < <$account>::LEN {
+                    return Err(AudiusError::AccountMigrationRequired.into());
+                }
+                let (discriminator, data) = data.split_at_mut(Self::STORED_DISCRIMINATOR.len());
+                discriminator.copy_from_slice(Self::STORED_DISCRIMINATOR);
+                self.serialize(&mut &mut data[..])
+                    .map_err(|_| ProgramError::AccountDataTooSmall)
+            }
+
+            fn is_tombstoned(&self) -> bool {
+                self.version == TOMBSTONE_VERSION
+            }
+
+            fn write_tombstone(data: &mut [u8]) -> Result<(), ProgramError> {
+                // version is the first field of every layout
+                if data.len() <= Self::STORED_DISCRIMINATOR.len() {
+                    return Err(ProgramError::AccountDataTooSmall);
+                }
+                data.fill(0);
+                let (discriminator, data) = data.split_at_mut(Self::STORED_DISCRIMINATOR.len());
+                discriminator.copy_from_slice(Self::STORED_DISCRIMINATOR);
+                data[0] = TOMBSTONE_VERSION;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_program_account!(
+    SignerGroup,
+    AudiusError::UninitializedSignerGroup,
+    anchor: SignerGroup::DISCRIMINATOR
+);
+impl_program_account!(
+    ValidSigner,
+    AudiusError::ValidSignerNotInitialized,
+    anchor: ValidSigner::DISCRIMINATOR
+);
+impl_program_account!(ValidSignerMetadata, AudiusError::UninitializedMetadata);
+impl_program_account!(ProgramStats, AudiusError::UninitializedStats);
+impl_program_account!(Attestation, AudiusError::UninitializedAttestation);
+
+/// `Pack` of the accounts downstream programs read with their generic helpers. It only
+/// takes data of exactly `LEN` bytes, accounts of the earlier shorter layouts are read
+/// with [ProgramAccount::deserialize_any_version]() instead.
+macro_rules! impl_pack {
+    ($account:ty) => {
+        impl Sealed for $account {}
+
+        impl Pack for $account {
+            const LEN: usize = <$account>::LEN;
+
+            fn pack_into_slice(&self, dst: &mut [u8]) {
+                // `Pack::pack` has already checked the data is `LEN` bytes long
+                ProgramAccount::pack(self, dst).unwrap()
+            }
+
+            fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+                Self::deserialize_any_version(src).map(|(account, _)| account)
+            }
+        }
+    };
+}
+
+impl_pack!(SignerGroup);
+impl_pack!(ValidSigner);
+
+impl SecpSignatureOffsets {
+    /// Max value can be hold in one byte
+    pub const MAX_VALUE_ONE_BYTE: u16 = 256;
+
+    /// Size of serialized Secp256k1 signature
+    pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+
+    /// Size of Secp256k1 signature
+    pub const SECP_SIGNATURE_SIZE: usize = 64;
+
+    /// Ethereum public key size
+    pub const ETH_ADDRESS_SIZE: usize = 20;
+
+    /// Half of secp256k1 curve order, signatures with greater `s` are malleable
+    pub const SECP256K1_HALF_ORDER: [u8; 32] = [
+        0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b,
+        0x20, 0xa0,
+    ];
+
+    /// Check that `s` component of the signature is in the lower half of curve order
+    pub fn is_low_s(signature: &[u8; Self::SECP_SIGNATURE_SIZE]) -> bool {
+        // signature is `r` followed by `s`, both are big endian
+        signature[32..] <= Self::SECP256K1_HALF_ORDER[..]
+    }
+
+    /// Offset of eth address in a single signature Secp256 instruction
+    pub const ETH_ADDRESS_OFFSET: usize = 1 + Self::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    /// Offset of signature in a single signature Secp256 instruction
+    pub const SIGNATURE_OFFSET: usize = Self::ETH_ADDRESS_OFFSET + Self::ETH_ADDRESS_SIZE;
+
+    /// Offset of message in a single signature Secp256 instruction,
+    /// signature is followed by one byte of recovery ID
+    pub const MESSAGE_DATA_OFFSET: usize = Self::SIGNATURE_OFFSET + Self::SECP_SIGNATURE_SIZE + 1;
+
+    /// Max message size keeping every byte of Secp256 instruction addressable by `u16` offsets
+    pub const MAX_MESSAGE_SIZE: usize = u16::MAX as usize - Self::MESSAGE_DATA_OFFSET;
+
+    /// Create offsets of a single signature Secp256 instruction
+    /// with eth address, signature and message following each other
+    pub fn new(instruction_index: u8, message_size: usize) -> Result<Self, ProgramError> {
+        Self::new_at(instruction_index, Self::ETH_ADDRESS_OFFSET, message_size)
+    }
+
+    /// Create offsets of eth address, signature and message following each other
+    /// starting from `data_offset` in the Secp256 instruction
+    pub fn new_at(
+        instruction_index: u8,
+        data_offset: usize,
+        message_size: usize,
+    ) -> Result<Self, ProgramError> {
+        let signature_offset = data_offset + Self::ETH_ADDRESS_SIZE;
+        let message_data_offset = signature_offset + Self::SECP_SIGNATURE_SIZE + 1;
+        if message_data_offset.saturating_add(message_size) > u16::MAX as usize {
+            return Err(AudiusError::MessageTooLarge.into());
+        }
+        Ok(Self {
+            signature_offset: signature_offset as u16,
+            signature_instruction_index: instruction_index,
+            eth_address_offset: data_offset as u16,
+            eth_address_instruction_index: instruction_index,
+            message_data_offset: message_data_offset as u16,
+            message_data_size: message_size as u16,
+            message_instruction_index: instruction_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fmt::Debug;
+
+    #[test]
+    fn test_signer_group() {
+        let signer_group = SignerGroup {
+            version: 0,
+            owner: Pubkey::new_from_array([1; 32]),
+            first_signer: Pubkey::new_from_array([2; 32]),
+            last_signer: Pubkey::new_from_array([3; 32]),
+            pending_owner: Pubkey::new_from_array([4; 32]),
+            eth_pop_required: true,
+            validation_fee: 5000.into(),
+            treasury: Pubkey::new_from_array([5; 32]),
+            quota_per_window: 100.into(),
+            window_start_slot: 1000.into(),
+            used_in_window: 10.into(),
+            next_signer_id: 3.into(),
+            valid_signer_count: 2.into(),
+            max_valid_signers: 10.into(),
+            message_prefixes: [*b"track:v1", *b"user:v1_", [0; 8], [0; 8]],
+            message_prefix_count: 2,
+            require_cosigner: true,
+            total_validations_all: 42.into(),
+            governed: true,
+            governance_threshold: 2,
+            governance_nonce: 6.into(),
+            delegate_operator: Pubkey::new_from_array([6; 32]),
+            members_merkle_root: [7; 32],
+            governance_realm: Pubkey::new_from_array([8; 32]),
+        };
+
+        let packed = signer_group.try_to_vec().unwrap();
+
+        assert_eq!(ACCOUNT_DISCRIMINATOR_LEN + packed.len(), SignerGroup::LEN);
+
+        let unpacked = SignerGroup::try_from_slice(packed.as_slice()).unwrap();
+
+        assert_eq!(signer_group, unpacked);
+
+        assert_eq!(signer_group.is_initialized(), false);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let inputs: [&[u8]; 6] = [&[], &[0; 20], &[1; 20], &[0; 32], &[1; 32], &[1; 33]];
+        for left in inputs.iter() {
+            for right in inputs.iter() {
+                assert_eq!(ct_eq(left, right), left == right);
+            }
+        }
+        let owner = Pubkey::new_from_array([1; 32]);
+        for index in [0, 15, 31].iter() {
+            let mut other = owner.to_bytes();
+            other[*index] ^= 0x80;
+            assert!(!ct_eq(owner.as_ref(), &other));
+        }
+        assert!(ct_eq(owner.as_ref(), &owner.to_bytes()));
+    }
+
+    #[test]
+    fn test_check_new_owner() {
+        let signer_group = Pubkey::new_from_array([1; 32]);
+        assert_eq!(
+            SignerGroup::check_new_owner(&signer_group, &Pubkey::new_from_array([2; 32])),
+            Ok(())
+        );
+        assert_eq!(
+            SignerGroup::check_new_owner(&signer_group, &Pubkey::default()),
+            Err(AudiusError::InvalidOwner.into())
+        );
+        assert_eq!(
+            SignerGroup::check_new_owner(&signer_group, &signer_group),
+            Err(AudiusError::InvalidOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_assign_signer_id() {
+        let mut signer_group = SignerGroup {
+            next_signer_id: 1.into(),
+            ..SignerGroup::default()
+        };
+        assert_eq!(signer_group.assign_signer_id(), Ok(1));
+        assert_eq!(signer_group.assign_signer_id(), Ok(2));
+        assert_eq!(u32::from(signer_group.next_signer_id), 3);
+
+        signer_group.next_signer_id = u32::MAX.into();
+        assert_eq!(
+            signer_group.assign_signer_id(),
+            Err(AudiusError::SignerIdOverflow.into())
+        );
+        assert_eq!(u32::from(signer_group.next_signer_id), u32::MAX);
+    }
+
+    #[test]
+    fn test_max_valid_signers() {
+        let mut signer_group = SignerGroup::default();
+        // zero limit doesn't cap the group
+        for _ in 0..3 {
+            signer_group.add_valid_signer().unwrap();
+        }
+
+        assert_eq!(
+            signer_group.set_max_valid_signers(2),
+            Err(AudiusError::MaxSignersReached.into())
+        );
+        signer_group.set_max_valid_signers(3).unwrap();
+        assert_eq!(
+            signer_group.add_valid_signer(),
+            Err(AudiusError::MaxSignersReached.into())
+        );
+        assert_eq!(u32::from(signer_group.valid_signer_count), 3);
+
+        signer_group.members_merkle_root = [1; 32];
+        signer_group.remove_valid_signer();
+        // the root would still prove the removed signer's membership
+        assert_eq!(signer_group.members_merkle_root, [0; 32]);
+        signer_group.add_valid_signer().unwrap();
+
+        signer_group.set_max_valid_signers(0).unwrap();
+        signer_group.add_valid_signer().unwrap();
+        assert_eq!(u32::from(signer_group.valid_signer_count), 4);
+    }
+
+    #[test]
+    fn test_valid_signer_count_bounds() {
+        // unlimited group account holding as many signers as the counter fits
+        let mut data = vec![0; SignerGroup::LEN];
+        SignerGroup {
+            version: 2,
+            owner: Pubkey::new_unique(),
+            valid_signer_count: u32::MAX.into(),
+            ..SignerGroup::default()
+        }
+        .pack(&mut data)
+        .unwrap();
+        let (mut signer_group, _) = SignerGroup::deserialize_any_version(&data).unwrap();
+
+        assert_eq!(
+            signer_group.add_valid_signer(),
+            Err(AudiusError::Overflow.into())
+        );
+        assert_eq!(u32::from(signer_group.valid_signer_count), u32::MAX);
+
+        signer_group.valid_signer_count = 0.into();
+        signer_group.remove_valid_signer();
+        assert_eq!(u32::from(signer_group.valid_signer_count), 0);
+    }
+
+    #[test]
+    fn test_message_prefixes() {
+        let mut signer_group = SignerGroup::default();
+        // no prefixes allow any message
+        signer_group.check_message_prefix(b"delete:user").unwrap();
+        signer_group.check_message_prefix(b"").unwrap();
+
+        signer_group.add_message_prefix(*b"track:v1").unwrap();
+        assert_eq!(
+            signer_group.add_message_prefix(*b"track:v1"),
+            Err(AudiusError::MessagePrefixAlreadyAdded.into())
+        );
+        signer_group.check_message_prefix(b"track:v1 42").unwrap();
+        assert_eq!(
+            signer_group.check_message_prefix(b"delete:user"),
+            Err(AudiusError::MessagePrefixNotAllowed.into())
+        );
+        assert_eq!(
+            signer_group.check_message_prefix(b"track"),
+            Err(AudiusError::MessagePrefixNotAllowed.into())
+        );
+
+        for prefix in &[*b"prefix:1", *b"prefix:2", *b"prefix:3"] {
+            signer_group.add_message_prefix(*prefix).unwrap();
+        }
+        assert_eq!(
+            signer_group.add_message_prefix(*b"prefix:4"),
+            Err(AudiusError::MessagePrefixLimitReached.into())
+        );
+
+        signer_group.remove_message_prefix(b"track:v1").unwrap();
+        assert_eq!(
+            signer_group.message_prefixes(),
+            &[*b"prefix:1", *b"prefix:2", *b"prefix:3"]
+        );
+        assert_eq!(
+            signer_group.remove_message_prefix(b"track:v1"),
+            Err(AudiusError::MessagePrefixNotFound.into())
+        );
+        for prefix in &[*b"prefix:1", *b"prefix:2", *b"prefix:3"] {
+            signer_group.remove_message_prefix(prefix).unwrap();
+        }
+        assert_eq!(signer_group.message_prefixes(), &[] as &[[u8; 8]]);
+        signer_group.check_message_prefix(b"delete:user").unwrap();
+    }
+
+    #[test]
+    fn test_consume_validation_quota() {
+        let mut signer_group = SignerGroup::default();
+        // zero quota doesn't limit validations
+        for slot in 0..3 {
+            signer_group.consume_validation_quota(slot).unwrap();
+        }
+        assert_eq!(u32::from(signer_group.used_in_window), 0);
+
+        signer_group.quota_per_window = 2.into();
+        signer_group.consume_validation_quota(10).unwrap();
+        signer_group.consume_validation_quota(20).unwrap();
+        assert_eq!(
+            signer_group.consume_validation_quota(30),
+            Err(AudiusError::QuotaExceeded.into())
+        );
+
+        // new window starts at the first validation after the current one ends
+        let slot = SignerGroup::QUOTA_WINDOW_SLOTS + 5;
+        signer_group.consume_validation_quota(slot).unwrap();
+        assert_eq!(u64::from(signer_group.window_start_slot), slot);
+        assert_eq!(u32::from(signer_group.used_in_window), 1);
+        signer_group.consume_validation_quota(slot + 1).unwrap();
+        assert_eq!(
+            signer_group.consume_validation_quota(slot + SignerGroup::QUOTA_WINDOW_SLOTS - 1),
+            Err(AudiusError::QuotaExceeded.into())
+        );
+        signer_group
+            .consume_validation_quota(slot + SignerGroup::QUOTA_WINDOW_SLOTS)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_count_validation() {
+        let mut signer_group = SignerGroup::default();
+        let mut valid_signer = ValidSigner::default();
+        signer_group.count_validation();
+        valid_signer.count_validation();
+        assert_eq!(u64::from(signer_group.total_validations_all), 1);
+        assert_eq!(u32::from(valid_signer.total_validations), 1);
+
+        // statistics saturate instead of wrapping or failing the validation
+        signer_group.total_validations_all = u64::MAX.into();
+        valid_signer.total_validations = u32::MAX.into();
+        signer_group.count_validation();
+        valid_signer.count_validation();
+        assert_eq!(u64::from(signer_group.total_validations_all), u64::MAX);
+        assert_eq!(u32::from(valid_signer.total_validations), u32::MAX);
+    }
+
+    #[test]
+    fn test_consume_rate_limit() {
+        let mut valid_signer = ValidSigner::default();
+        for slot in 10..13 {
+            valid_signer.consume_rate_limit(slot, 100, 3).unwrap();
+        }
+        assert_eq!(u32::from(valid_signer.validations_this_window), 3);
+        assert_eq!(u64::from(valid_signer.last_validation_slot), 12);
+        assert_eq!(
+            valid_signer.consume_rate_limit(111, 100, 3),
+            Err(AudiusError::RateLimitExceeded.into())
+        );
+        // rejected validation doesn't extend the window
+        assert_eq!(u64::from(valid_signer.last_validation_slot), 12);
+
+        // window expires once there were no validations for its length
+        valid_signer.consume_rate_limit(112, 100, 3).unwrap();
+        assert_eq!(u32::from(valid_signer.validations_this_window), 1);
+        assert_eq!(u64::from(valid_signer.last_validation_slot), 112);
+    }
+
+    #[test]
+    fn test_set_governance() {
+        let mut signer_group = SignerGroup {
+            valid_signer_count: 3.into(),
+            ..SignerGroup::default()
+        };
+        let invalid_threshold = Err(AudiusError::InvalidGovernanceThreshold.into());
+        assert_eq!(signer_group.set_governance(true, 0), invalid_threshold);
+        assert_eq!(signer_group.set_governance(true, 4), invalid_threshold);
+        assert!(!signer_group.governed);
+
+        assert_eq!(signer_group.set_governance(true, 3), Ok(()));
+        assert!(signer_group.governed);
+        assert_eq!(signer_group.governance_threshold, 3);
+
+        assert_eq!(signer_group.set_governance(false, 0), Ok(()));
+        assert!(!signer_group.governed);
+    }
+
+    #[test]
+    fn test_governance_message() {
+        let signer_group = Pubkey::new_from_array([1; 32]);
+        let message = SignerGroup::governance_message(
+            GovernanceAction::AddSigner,
+            &signer_group,
+            &[2; 20],
+            3,
+        );
+        assert_eq!(
+            message,
+            [
+                &b"audius:add-signer:"[..],
+                &[1; 32][..],
+                &[2; 20][..],
+                &[3, 0, 0, 0, 0, 0, 0, 0][..]
+            ]
+            .concat()
+        );
+
+        // message changes with every bound field
+        for other in &[
+            SignerGroup::governance_message(
+                GovernanceAction::RemoveSigner,
+                &signer_group,
+                &[2; 20],
+                3,
+            ),
+            SignerGroup::governance_message(
+                GovernanceAction::AddSigner,
+                &Pubkey::new_from_array([4; 32]),
+                &[2; 20],
+                3,
+            ),
+            SignerGroup::governance_message(
+                GovernanceAction::AddSigner,
+                &signer_group,
+                &[4; 20],
+                3,
+            ),
+            SignerGroup::governance_message(
+                GovernanceAction::AddSigner,
+                &signer_group,
+                &[2; 20],
+                4,
+            ),
+        ] {
+            assert_ne!(&message, other);
+        }
+    }
+
+    #[test]
+    fn test_apply_governance() {
+        let mut signer_group = SignerGroup {
+            valid_signer_count: 3.into(),
+            ..SignerGroup::default()
+        };
+        assert_eq!(
+            signer_group.apply_governance(GovernanceAction::AddSigner, 3),
+            Err(AudiusError::GovernanceDisabled.into())
+        );
+
+        signer_group.set_governance(true, 2).unwrap();
+        assert_eq!(
+            signer_group.apply_governance(GovernanceAction::AddSigner, 1),
+            Err(AudiusError::ThresholdNotReached.into())
+        );
+        assert_eq!(u64::from(signer_group.governance_nonce), 0);
+        assert_eq!(
+            signer_group.apply_governance(GovernanceAction::AddSigner, 2),
+            Ok(())
+        );
+        assert_eq!(u64::from(signer_group.governance_nonce), 1);
+        assert_eq!(
+            signer_group.apply_governance(GovernanceAction::RemoveSigner, 2),
+            Ok(())
+        );
+        assert_eq!(u64::from(signer_group.governance_nonce), 2);
+
+        // removal leaving fewer signers than the threshold is rejected
+        signer_group.valid_signer_count = 2.into();
+        assert_eq!(
+            signer_group.apply_governance(GovernanceAction::RemoveSigner, 2),
+            Err(AudiusError::InvalidGovernanceThreshold.into())
+        );
+    }
+
+    #[test]
+    fn test_signer_group_json() {
+        let owner = Pubkey::new_from_array([1; 32]);
+        let mut signer_group = SignerGroup {
+            version: 1,
+            owner,
+            validation_fee: 5000.into(),
+            governed: true,
+            members_merkle_root: [0xab; 32],
+            ..SignerGroup::default()
+        };
+        signer_group.add_message_prefix(*b"audius:v").unwrap();
+        let json = signer_group.to_json();
+
+        assert!(json.starts_with(&format!("{{\"version\":1,\"owner\":\"{}\",", owner)));
+        assert!(json.contains("\"validation_fee\":5000,"));
+        assert!(json.contains("\"message_prefixes\":[\"0x6175646975733a76\"],"));
+        assert!(json.contains("\"governed\":true,"));
+        assert!(json.contains(&format!(
+            "\"members_merkle_root\":\"0x{}\"",
+            "ab".repeat(32)
+        )));
+
+        // every field is formatted, the pattern stops compiling once a field is added
+        macro_rules! assert_fields_formatted {
+            ($($field:ident),*) => {
+                let SignerGroup { $($field: _),* } = signer_group;
+                $(assert!(
+                    json.contains(concat!("\"", stringify!($field), "\":")),
+                    "{} is missing", stringify!($field)
+                );)*
+            };
+        }
+        assert_fields_formatted!(
+            version,
+            owner,
+            first_signer,
+            last_signer,
+            pending_owner,
+            eth_pop_required,
+            validation_fee,
+            treasury,
+            quota_per_window,
+            window_start_slot,
+            used_in_window,
+            next_signer_id,
+            valid_signer_count,
+            max_valid_signers,
+            message_prefixes,
+            message_prefix_count,
+            require_cosigner,
+            total_validations_all,
+            governed,
+            governance_threshold,
+            governance_nonce,
+            delegate_operator,
+            members_merkle_root,
+            governance_realm
+        );
+    }
+
+    #[test]
+    fn test_valid_signer() {
+        let valid_signer = ValidSigner {
+            version: 1,
+            signer_group: Pubkey::new_from_array([1; 32]),
+            eth_addresses: [[7; 20], [8; 20], [0; 20]],
+            eth_address_count: 2,
+            next_signer: Pubkey::new_from_array([2; 32]),
+            prev_signer: Pubkey::new_from_array([3; 32]),
+            key_type: SignerKeyType::ED25519,
+            ed25519_pubkey: Pubkey::new_from_array([4; 32]),
+            label: ValidSigner::pack_label("node-1").unwrap(),
+            active_after_slot: 500.into(),
+            expires_at_slot: 1000.into(),
+            bump_seed: 255,
+            signer_id: 7.into(),
+            cosigner: Pubkey::new_from_array([5; 32]),
+            total_validations: 12.into(),
+            last_validation_slot: 900.into(),
+            validations_this_window: 3.into(),
+        };
+
+        let packed = valid_signer.try_to_vec().unwrap();
+
+        assert_eq!(ACCOUNT_DISCRIMINATOR_LEN + packed.len(), ValidSigner::LEN);
+
+        let unpacked = ValidSigner::try_from_slice(packed.as_slice()).unwrap();
+
+        assert_eq!(valid_signer, unpacked);
+
+        assert_eq!(valid_signer.is_initialized(), true);
+    }
+
+    /// Generic `Pack` round trip, the way downstream helpers use the accounts
+    fn check_pack<T: Pack + IsInitialized + Copy + Debug + Default + PartialEq>(account: T) {
+        let mut data = vec![0; T::LEN];
+        // zeroed account isn't initialized yet
+        assert_eq!(T::unpack(&data), Err(ProgramError::UninitializedAccount));
+        assert_eq!(T::unpack_unchecked(&data), Ok(T::default()));
+
+        T::pack(account, &mut data).unwrap();
+        assert_eq!(T::unpack(&data), Ok(account));
+        assert_eq!(T::unpack_unchecked(&data), Ok(account));
+
+        // only data of the current layout length is taken
+        assert_eq!(
+            T::unpack(&data[..T::LEN - 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            T::pack(account, &mut data[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_pack() {
+        assert_eq!(<SignerGroup as Pack>::LEN, SignerGroup::LEN);
+        check_pack(SignerGroup {
+            version: 1,
+            owner: Pubkey::new_from_array([1; 32]),
+            valid_signer_count: 2.into(),
+            members_merkle_root: [3; 32],
+            ..SignerGroup::default()
+        });
+
+        assert_eq!(<ValidSigner as Pack>::LEN, ValidSigner::LEN);
+        check_pack(ValidSigner {
+            version: 1,
+            signer_group: Pubkey::new_from_array([1; 32]),
+            eth_addresses: [[7; 20], [0; 20], [0; 20]],
+            eth_address_count: 1,
+            signer_id: 4.into(),
+            ..ValidSigner::default()
+        });
+    }
+
+    #[test]
+    fn test_valid_signer_from_account_info() {
+        let valid_signer = ValidSigner {
+            version: 1,
+            signer_group: Pubkey::new_from_array([1; 32]),
+            eth_addresses: [[7; 20], [0; 20], [0; 20]],
+            eth_address_count: 1,
+            ..ValidSigner::default()
+        };
+
+        let key = Pubkey::new_from_array([2; 32]);
+        let owner = Pubkey::new_from_array([3; 32]);
+        let mut lamports = 0;
+        let mut data = vec![0; ValidSigner::LEN];
+        valid_signer.pack(&mut data).unwrap();
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            *ValidSigner::from_account_info(&info).unwrap(),
+            valid_signer
+        );
+
+        ValidSigner::from_account_info_mut(&info).unwrap().version = 0;
+        let unpacked = ValidSigner::deserialize_any_version(&info.data.borrow())
+            .unwrap()
+            .0;
+        assert_eq!(unpacked.is_initialized(), false);
+        assert_eq!(unpacked.eth_addresses(), valid_signer.eth_addresses());
+
+        let mut short_data = vec![0; ValidSigner::LEN - 1];
+        let short_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut short_data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            ValidSigner::from_account_info(&short_info).err(),
+            Some(AudiusError::AccountMigrationRequired.into())
+        );
+    }
+
+    #[test]
+    fn test_valid_signer_eth_addresses() {
+        let mut valid_signer = ValidSigner::default();
+        assert!(valid_signer.eth_addresses().is_empty());
+
+        assert_eq!(
+            valid_signer.add_eth_address([0; 20]),
+            Err(AudiusError::InvalidEthAddress.into())
+        );
+        valid_signer.add_eth_address([1; 20]).unwrap();
+        valid_signer.add_eth_address([2; 20]).unwrap();
+        assert_eq!(
+            valid_signer.add_eth_address([1; 20]),
+            Err(AudiusError::EthAddressAlreadyRegistered.into())
+        );
+        valid_signer.add_eth_address([3; 20]).unwrap();
+        assert_eq!(
+            valid_signer.add_eth_address([4; 20]),
+            Err(AudiusError::EthAddressLimitReached.into())
+        );
+        assert_eq!(valid_signer.eth_addresses(), &[[1; 20], [2; 20], [3; 20]]);
+
+        valid_signer.remove_eth_address(&[1; 20]).unwrap();
+        assert_eq!(valid_signer.eth_addresses(), &[[2; 20], [3; 20]]);
+        assert_eq!(valid_signer.eth_addresses[2], [0; 20]);
+        assert!(!valid_signer.has_eth_address(&[1; 20]));
+        assert_eq!(
+            valid_signer.remove_eth_address(&[1; 20]),
+            Err(AudiusError::EthAddressNotRegistered.into())
+        );
+        let mut near_address = [2; 20];
+        near_address[19] ^= 1;
+        assert_eq!(
+            valid_signer.remove_eth_address(&near_address),
+            Err(AudiusError::EthAddressNotRegistered.into())
+        );
+
+        valid_signer.remove_eth_address(&[3; 20]).unwrap();
+        assert_eq!(
+            valid_signer.remove_eth_address(&[2; 20]),
+            Err(AudiusError::LastEthAddress.into())
+        );
+        assert_eq!(valid_signer.eth_addresses(), &[[2; 20]]);
+    }
+
+    #[test]
+    fn test_valid_signer_activation() {
+        let mut valid_signer = ValidSigner::default();
+        assert!(valid_signer.is_active(0));
+
+        valid_signer.active_after_slot = 100.into();
+        assert!(!valid_signer.is_active(99));
+        assert!(valid_signer.is_active(100));
+        assert!(valid_signer.is_active(101));
+
+        // stored the same way Borsh serializes u64
+        assert_eq!(
+            PodU64::from(u64::MAX - 1).try_to_vec().unwrap(),
+            (u64::MAX - 1).try_to_vec().unwrap()
+        );
+        assert_eq!(u64::from(PodU64::from(12345)), 12345);
+    }
+
+    #[test]
+    fn test_valid_signer_expiry() {
+        let mut valid_signer = ValidSigner::default();
+        assert!(!valid_signer.is_expired(u64::MAX));
+
+        valid_signer.expires_at_slot = 100.into();
+        assert!(!valid_signer.is_expired(99));
+        assert!(!valid_signer.is_expired(100));
+        assert!(valid_signer.is_expired(101));
+    }
+
+    #[test]
+    fn test_valid_signer_cosigner() {
+        let cosigner = Pubkey::new_from_array([1; 32]);
+        let other = Pubkey::new_from_array([2; 32]);
+        let owner = Pubkey::new_from_array([3; 32]);
+        let (mut lamports, mut other_lamports, mut unsigned_lamports) = (0, 0, 0);
+        let (mut data, mut other_data, mut unsigned_data) = (vec![], vec![], vec![]);
+        let cosigner_info = AccountInfo::new(
+            &cosigner,
+            true,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        let other_info = AccountInfo::new(
+            &other,
+            true,
+            false,
+            &mut other_lamports,
+            &mut other_data,
+            &owner,
+            false,
+            0,
+        );
+        let unsigned_info = AccountInfo::new(
+            &cosigner,
+            false,
+            false,
+            &mut unsigned_lamports,
+            &mut unsigned_data,
+            &owner,
+            false,
+            0,
+        );
+        let missing_cosigner = Err(AudiusError::MissingCosigner.into());
+
+        let mut valid_signer = ValidSigner::default();
+        assert_eq!(
+            valid_signer.check_cosigner(&cosigner_info),
+            missing_cosigner
+        );
+
+        valid_signer.cosigner = cosigner;
+        assert_eq!(valid_signer.check_cosigner(&cosigner_info), Ok(()));
+        assert_eq!(valid_signer.check_cosigner(&other_info), missing_cosigner);
+        assert_eq!(
+            valid_signer.check_cosigner(&unsigned_info),
+            missing_cosigner
+        );
+    }
+
+    #[test]
+    fn test_signer_group_delegate_operator() {
+        let owner = Pubkey::new_from_array([1; 32]);
+        let delegate = Pubkey::new_from_array([2; 32]);
+        let program = Pubkey::new_from_array([3; 32]);
+        let (mut owner_lamports, mut lamports, mut unsigned_lamports) = (0, 0, 0);
+        let (mut owner_data, mut data, mut unsigned_data) = (vec![], vec![], vec![]);
+        let owner_info = AccountInfo::new(
+            &owner,
+            true,
+            false,
+            &mut owner_lamports,
+            &mut owner_data,
+            &program,
+            false,
+            0,
+        );
+        let delegate_info = AccountInfo::new(
+            &delegate,
+            true,
+            false,
+            &mut lamports,
+            &mut data,
+            &program,
+            false,
+            0,
+        );
+        let unsigned_info = AccountInfo::new(
+            &delegate,
+            false,
+            false,
+            &mut unsigned_lamports,
+            &mut unsigned_data,
+            &program,
+            false,
+            0,
+        );
+
+        let mut signer_group = SignerGroup {
+            owner,
+            ..SignerGroup::default()
+        };
+        assert_eq!(signer_group.check_owner_or_delegate(&owner_info), Ok(()));
+        assert_eq!(
+            signer_group.check_owner_or_delegate(&delegate_info),
+            Err(AudiusError::WrongOwner.into())
+        );
+
+        signer_group.delegate_operator = delegate;
+        assert_eq!(signer_group.check_owner_or_delegate(&owner_info), Ok(()));
+        assert_eq!(signer_group.check_owner_or_delegate(&delegate_info), Ok(()));
+        assert_eq!(
+            signer_group.check_owner_or_delegate(&unsigned_info),
+            Err(AudiusError::SignatureMissing.into())
+        );
+        // delegation doesn't extend to the owner-only operations
+        assert_eq!(
+            signer_group.check_owner(&delegate_info),
+            Err(AudiusError::WrongOwner.into())
+        );
+
+        // keys differing in the last byte only are told apart
+        let mut near_delegate = delegate.to_bytes();
+        near_delegate[31] ^= 1;
+        let near_delegate = Pubkey::new_from_array(near_delegate);
+        let near_info = AccountInfo {
+            key: &near_delegate,
+            ..delegate_info.clone()
+        };
+        assert_eq!(
+            signer_group.check_owner_or_delegate(&near_info),
+            Err(AudiusError::WrongOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_valid_signer_label() {
+        let mut valid_signer = ValidSigner::default();
+        assert_eq!(valid_signer.label(), Ok(""));
+
+        valid_signer.label = ValidSigner::pack_label("discovery-1 ✓").unwrap();
+        assert_eq!(valid_signer.label(), Ok("discovery-1 ✓"));
+
+        // relabeling leaves no trace of the longer previous label
+        valid_signer.label = ValidSigner::pack_label("d2").unwrap();
+        assert_eq!(valid_signer.label(), Ok("d2"));
+        assert!(valid_signer.label[2..].iter().all(|byte| *byte == 0));
+
+        let full = "x".repeat(ValidSigner::LABEL_SIZE);
+        valid_signer.label = ValidSigner::pack_label(&full).unwrap();
+        assert_eq!(valid_signer.label(), Ok(full.as_str()));
+
+        // overlong label is rejected, not truncated
+        assert_eq!(
+            ValidSigner::pack_label(&"x".repeat(ValidSigner::LABEL_SIZE + 1)),
+            Err(AudiusError::SignerLabelTooLong.into())
+        );
+        // multibyte character crossing the boundary doesn't fit either
+        let boundary = format!("{}✓", "x".repeat(ValidSigner::LABEL_SIZE - 2));
+        assert_eq!(
+            ValidSigner::pack_label(&boundary),
+            Err(AudiusError::SignerLabelTooLong.into())
+        );
+        assert_eq!(
+            ValidSigner::pack_label("a\0b"),
+            Err(AudiusError::InvalidSignerLabel.into())
+        );
+
+        let mut truncated = [0u8; ValidSigner::LABEL_SIZE];
+        truncated[..2].copy_from_slice(&"✓".as_bytes()[..2]);
+        assert_eq!(
+            ValidSigner::unpack_label(&truncated),
+            Err(AudiusError::InvalidSignerLabel.into())
+        );
+        let mut gap = [0u8; ValidSigner::LABEL_SIZE];
+        gap[0] = b'a';
+        gap[2] = b'b';
+        assert_eq!(
+            ValidSigner::unpack_label(&gap),
+            Err(AudiusError::InvalidSignerLabel.into())
+        );
+    }
+
+    #[test]
+    fn test_valid_signer_metadata() {
+        let metadata = ValidSignerMetadata {
+            version: 1,
+            valid_signer: Pubkey::new_from_array([2; 32]),
+            endpoint_url: [3; ValidSignerMetadata::ENDPOINT_URL_SIZE],
+            operator_name: [4; ValidSignerMetadata::OPERATOR_NAME_SIZE],
+        };
+
+        let packed = metadata.try_to_vec().unwrap();
+
+        assert_eq!(packed.len(), ValidSignerMetadata::LEN);
+
+        let unpacked = ValidSignerMetadata::try_from_slice(packed.as_slice()).unwrap();
+
+        assert_eq!(metadata, unpacked);
+
+        assert_eq!(metadata.is_initialized(), true);
+    }
+
+    #[test]
+    fn test_offsets_pack_unpack() {
+        let offsets = SecpSignatureOffsets {
+            signature_offset: 345,
+            signature_instruction_index: 43,
+            eth_address_offset: 278,
+            eth_address_instruction_index: 7,
+            message_data_offset: 783,
+            message_data_size: 543,
+            message_instruction_index: 10,
+        };
+
+        let packed = offsets.try_to_vec().unwrap();
+
+        let unpacked = SecpSignatureOffsets::try_from_slice(packed.as_slice()).unwrap();
+
+        assert_eq!(offsets, unpacked);
+    }
+
+    #[test]
+    fn test_program_stats() {
+        let stats = ProgramStats {
+            version: 1,
+            total_groups: 2,
+            total_signers: 3,
+            total_validations: 4,
+            total_clearances: 5,
+        };
+
+        let packed = stats.try_to_vec().unwrap();
+
+        assert_eq!(packed.len(), ProgramStats::LEN);
+
+        let unpacked = ProgramStats::try_from_slice(packed.as_slice()).unwrap();
+
+        assert_eq!(stats, unpacked);
+    }
+
+    #[test]
+    fn test_ownership_history() {
+        let mut data = vec![0u8; OwnershipHistory::LEN];
+        let mut history = OwnershipHistory::unpack(&data).unwrap();
+        assert!(!history.is_initialized());
+        assert_eq!(history.to_json(), "[]");
+
+        history.version = 1;
+        history.signer_group = Pubkey::new_unique();
+        let transfer = |slot| OwnershipTransfer {
+            previous_owner: Pubkey::new_from_array([1; 32]),
+            new_owner: Pubkey::new_from_array([2; 32]),
+            transfer_slot: slot,
+        };
+        for slot in 0..OwnershipHistory::MAX_TRANSFERS as u64 + 2 {
+            history.record(transfer(slot));
+        }
+        // the most recent transfers are kept, newest first
+        assert_eq!(history.transfers.len(), OwnershipHistory::MAX_TRANSFERS);
+        assert_eq!(history.transfers[0].transfer_slot, 9);
+        assert_eq!(history.transfers[7].transfer_slot, 2);
+
+        // full history fits the account
+        history.pack(&mut data).unwrap();
+        assert_eq!(OwnershipHistory::unpack(&data).unwrap(), history);
+
+        history.transfers.truncate(1);
+        assert_eq!(
+            history.to_json(),
+            format!(
+                "[{{\"previous_owner\":\"{}\",\"new_owner\":\"{}\",\"transfer_slot\":9}}]",
+                Pubkey::new_from_array([1; 32]),
+                Pubkey::new_from_array([2; 32])
+            )
+        );
+    }
+
+    #[test]
+    fn test_creation_whitelist() {
+        let mut data = vec![0u8; CreationWhitelist::LEN];
+        let mut whitelist = CreationWhitelist::unpack(&data).unwrap();
+        assert!(!whitelist.is_initialized());
+        assert!(!whitelist.is_enforced());
+
+        whitelist.version = 1;
+        let creator = Pubkey::new_unique();
+        whitelist.add(creator).unwrap();
+        whitelist.add(creator).unwrap();
+        assert_eq!(whitelist.accounts, vec![creator]);
+        assert!(whitelist.is_enforced());
+
+        whitelist.flags |= CreationWhitelist::BYPASS_WHITELIST;
+        assert!(!whitelist.is_enforced());
+        assert!(whitelist.contains(&creator));
+
+        for _ in 1..CreationWhitelist::MAX_ACCOUNTS {
+            whitelist.add(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            whitelist.add(Pubkey::new_unique()),
+            Err(AudiusError::CreationWhitelistFull.into())
+        );
+
+        // full whitelist fits the account
+        whitelist.pack(&mut data).unwrap();
+        assert_eq!(CreationWhitelist::unpack(&data).unwrap(), whitelist);
+
+        whitelist.remove(&creator);
+        assert!(!whitelist.contains(&creator));
+        assert_eq!(
+            whitelist.accounts.len(),
+            CreationWhitelist::MAX_ACCOUNTS - 1
+        );
+    }
+
+    #[test]
+    fn test_program_config() {
+        let mut data = vec![0u8; ProgramConfig::LEN];
+        let mut config = ProgramConfig::unpack(&data).unwrap();
+        assert!(!config.is_initialized());
+        assert!(!config.is_locked());
+
+        config.version = 1;
+        config.authority = Pubkey::new_unique();
+        config.lockdown_mode = 1;
+        assert!(config.is_locked());
+
+        config.pack(&mut data).unwrap();
+        assert_eq!(ProgramConfig::unpack(&data).unwrap(), config);
+        assert_eq!(
+            config.pack(&mut data[1..]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_attestation() {
+        let mut attestation = Attestation {
+            version: 1,
+            signer_group: Pubkey::new_unique(),
+            valid_signer: Pubkey::new_unique(),
+            eth_address: [1; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            message_hash: [2; 32],
+            slot: 10,
+            expires_at_slot: 0,
+            payer: Pubkey::new_unique(),
+        };
+
+        let mut data = vec![0u8; Attestation::LEN];
+        attestation.pack(&mut data).unwrap();
+        assert_eq!(
+            Attestation::deserialize_any_version(&data).unwrap(),
+            (attestation.clone(), 1)
+        );
+
+        // attestation without expiry is never closed
+        assert!(!attestation.is_expired(u64::MAX));
+        attestation.expires_at_slot = 20;
+        assert!(!attestation.is_expired(20));
+        assert!(attestation.is_expired(21));
+    }
+
+    #[test]
+    fn test_tombstone() {
+        let signer_group = SignerGroup {
+            version: 2,
+            owner: Pubkey::new_unique(),
+            ..SignerGroup::default()
+        };
+        let mut data = vec![0u8; SignerGroup::LEN];
+        signer_group.pack(&mut data).unwrap();
+        SignerGroup::write_tombstone(&mut data).unwrap();
+        let (closed_group, version) = SignerGroup::deserialize_any_version(&data).unwrap();
+        assert_eq!(version, TOMBSTONE_VERSION);
+        assert!(closed_group.is_tombstoned());
+        assert!(!closed_group.is_initialized());
+        assert!(!IsInitialized::is_initialized(&closed_group));
+        assert_eq!(closed_group.owner, Pubkey::default());
+        assert!(!SignerGroup::default().is_tombstoned());
+
+        // signer of an older shorter layout is closed without migrating it
+        let mut data = vec![1u8; ValidSigner::ETH_ADDRESS_COUNT_OFFSET];
+        data[..ACCOUNT_DISCRIMINATOR_LEN].copy_from_slice(ValidSigner::STORED_DISCRIMINATOR);
+        ValidSigner::write_tombstone(&mut data).unwrap();
+        let closed_signer = ValidSigner::deserialize_stored(&data).unwrap();
+        assert!(closed_signer.is_tombstoned());
+        assert!(!closed_signer.is_initialized());
+        assert!(closed_signer.eth_addresses().is_empty());
+
+        assert_eq!(
+            ValidSigner::write_tombstone(&mut []),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_owner_registry() {
+        let mut data = vec![0u8; OwnerRegistry::LEN];
+        let mut registry = OwnerRegistry::unpack(&data).unwrap();
+        assert!(!registry.is_initialized());
+
+        registry.version = 1;
+        registry.owner = Pubkey::new_unique();
+        for _ in 0..OwnerRegistry::MAX_GROUPS {
+            registry.add_group(Pubkey::new_unique()).unwrap();
+        }
+        assert_eq!(
+            registry.add_group(Pubkey::new_unique()),
+            Err(AudiusError::OwnerRegistryFull.into())
+        );
+
+        // full registry fits the account
+        registry.pack(&mut data).unwrap();
+        assert_eq!(OwnerRegistry::unpack(&data).unwrap(), registry);
+
+        // removed group frees the room for another one
+        let removed = registry.groups[1];
+        let next = registry.groups[2];
+        assert!(registry.remove_group(&removed));
+        assert!(!registry.remove_group(&removed));
+        assert_eq!(registry.groups[1], next);
+        assert_eq!(registry.groups.len(), OwnerRegistry::MAX_GROUPS - 1);
+        registry.add_group(removed).unwrap();
+        assert_eq!(registry.groups.last(), Some(&removed));
+    }
+
+    #[test]
+    fn test_revocation_registry() {
+        let mut data = vec![0u8; RevocationRegistry::LEN];
+        let mut registry = RevocationRegistry::unpack(&data).unwrap();
+        assert!(!registry.is_initialized());
+        assert!(!registry.is_revoked(&[0; 20]));
+
+        registry.version = 1;
+        registry.signer_group = Pubkey::new_unique();
+        for i in 0..RevocationRegistry::MAX_ADDRESSES {
+            registry.revoke([i as u8 + 1; 20]).unwrap();
+        }
+        assert!(registry.is_revoked(&[1; 20]));
+        assert!(!registry.is_revoked(&[0; 20]));
+        // revoking again doesn't take room
+        registry.revoke([1; 20]).unwrap();
+        assert_eq!(
+            registry.revoke([0; 20]),
+            Err(AudiusError::RevocationRegistryFull.into())
+        );
+
+        // full registry fits the account
+        registry.pack(&mut data).unwrap();
+        assert_eq!(RevocationRegistry::unpack(&data).unwrap(), registry);
+    }
+
+    #[test]
+    fn test_low_s() {
+        let mut signature = [0u8; SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+        signature[32..].copy_from_slice(&SecpSignatureOffsets::SECP256K1_HALF_ORDER);
+
+        assert!(SecpSignatureOffsets::is_low_s(&signature));
+
+        signature[SecpSignatureOffsets::SECP_SIGNATURE_SIZE - 1] += 1;
+
+        assert!(!SecpSignatureOffsets::is_low_s(&signature));
+    }
+
+    #[test]
+    fn test_offsets_max_message_size() {
+        let offsets = SecpSignatureOffsets::new(0, SecpSignatureOffsets::MAX_MESSAGE_SIZE).unwrap();
+
+        assert_eq!(
+            offsets.message_data_offset as usize + offsets.message_data_size as usize,
+            u16::MAX as usize
+        );
+
+        assert_eq!(
+            SecpSignatureOffsets::new(0, SecpSignatureOffsets::MAX_MESSAGE_SIZE + 1),
+            Err(AudiusError::MessageTooLarge.into())
+        );
+    }
+
+    #[test]
+    fn test_offsets_new_at() {
+        let offsets = SecpSignatureOffsets::new_at(2, 100, 30).unwrap();
+
+        assert_eq!(offsets.eth_address_offset, 100);
+        assert_eq!(offsets.signature_offset, 120);
+        assert_eq!(offsets.message_data_offset, 185);
+        assert_eq!(offsets.message_data_size, 30);
+        assert_eq!(offsets.message_instruction_index, 2);
+
+        assert_eq!(
+            SecpSignatureOffsets::new_at(0, u16::MAX as usize - 85, 1),
+            Err(AudiusError::MessageTooLarge.into())
+        );
+    }
+
+    #[test]
+    fn test_ed25519_offsets() {
+        let offsets = Ed25519SignatureOffsets::new(30).unwrap();
+
+        let packed = offsets.try_to_vec().unwrap();
+        assert_eq!(
+            packed.len(),
+            Ed25519SignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE
+        );
+        assert_eq!(
+            Ed25519SignatureOffsets::try_from_slice(packed.as_slice()).unwrap(),
+            offsets
+        );
+
+        assert_eq!(offsets.public_key_offset, 16);
+        assert_eq!(offsets.signature_offset, 48);
+        assert_eq!(offsets.message_data_offset, 112);
+
+        assert_eq!(
+            Ed25519SignatureOffsets::new(u16::MAX as usize),
+            Err(AudiusError::MessageTooLarge.into())
+        );
+    }
+
+    #[test]
+    fn test_anchor_discriminators() {
+        use solana_program::hash::hash;
+        assert_eq!(
+            SignerGroup::DISCRIMINATOR[..],
+            hash(b"account:SignerGroup").to_bytes()[..8]
+        );
+        assert_eq!(
+            ValidSigner::DISCRIMINATOR[..],
+            hash(b"account:ValidSigner").to_bytes()[..8]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "anchor-compat"))]
+    fn test_default_layout_has_no_discriminator() {
+        assert_eq!(SignerGroup::LEN, size_of::<SignerGroup>());
+        assert_eq!(ValidSigner::LEN, size_of::<ValidSigner>());
+
+        let signer_group = SignerGroup {
+            version: 2,
+            ..SignerGroup::default()
+        };
+        let mut data = vec![0; SignerGroup::LEN];
+        signer_group.pack(&mut data).unwrap();
+        assert_eq!(data, signer_group.try_to_vec().unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "anchor-compat")]
+    fn test_anchor_compat_layout() {
+        assert_eq!(SignerGroup::LEN, 8 + size_of::<SignerGroup>());
+        assert_eq!(ValidSigner::LEN, 8 + size_of::<ValidSigner>());
+
+        let signer_group = SignerGroup {
+            version: 2,
+            owner: Pubkey::new_from_array([1; 32]),
+            ..SignerGroup::default()
+        };
+        let mut data = vec![0; SignerGroup::LEN];
+        signer_group.pack(&mut data).unwrap();
+        assert_eq!(data[..8], SignerGroup::DISCRIMINATOR);
+        assert_eq!(data[8..], signer_group.try_to_vec().unwrap()[..]);
+        assert_eq!(
+            SignerGroup::deserialize_any_version(&data),
+            Ok((signer_group, 2))
+        );
+
+        // zeroed account isn't initialized yet and has no discriminator
+        assert_eq!(
+            SignerGroup::deserialize_any_version(&[0; SignerGroup::LEN]),
+            Ok((SignerGroup::default(), 0))
+        );
+
+        // data of another account type is rejected
+        data[..8].copy_from_slice(&ValidSigner::DISCRIMINATOR);
+        assert_eq!(
+            SignerGroup::deserialize_any_version(&data),
+            Err(AudiusError::InvalidAccountDiscriminator.into())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anchor-compat")]
+    fn test_anchor_compat_valid_signer_from_account_info() {
+        let valid_signer = ValidSigner {
+            version: 8,
+            signer_group: Pubkey::new_from_array([1; 32]),
+            ..ValidSigner::default()
+        };
+        let key = Pubkey::new_from_array([2; 32]);
+        let owner = Pubkey::new_from_array([3; 32]);
+        let mut lamports = 0;
+        let mut data = vec![0; ValidSigner::LEN];
+        valid_signer.pack(&mut data).unwrap();
+        assert_eq!(data[..8], ValidSigner::DISCRIMINATOR);
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            *ValidSigner::from_account_info(&info).unwrap(),
+            valid_signer
+        );
+
+        info.data.borrow_mut()[..8].copy_from_slice(&SignerGroup::DISCRIMINATOR);
+        assert_eq!(
+            ValidSigner::from_account_info_mut(&info).err(),
+            Some(AudiusError::InvalidAccountDiscriminator.into())
+        );
+    }
+}
+
+/// Accounts stored in the earlier layouts must stay readable after state structs grow.
+/// Those layouts have no discriminator, `anchor-compat` builds start from the current one.
+#[cfg(all(test, not(feature = "anchor-compat")))]
+mod migration_compat {
+    use super::*;
+
+    /// Version 1 SignerGroup account: version and owner
+    const SIGNER_GROUP_V1: &str = "01\
+        0e2b5c4d3a1f6e8d7c9b0a1f2e3d4c5b6a798897a6b5c4d3e2f10f1e2d3c4b5a";
+
+    /// Version 1 ValidSigner account: version, signer group and eth address
+    const VALID_SIGNER_V1: &str = "01\
+        1f3a5c7e9b0d2f4a6c8e0b1d3f5a7c9e2b4d6f8a0c1e3b5d7f9a2c4e6b8d0f1a\
+        c0ffee254729296a45a3885639ac7e10f9d54979";
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_signer_group_v1() {
+        let data = from_hex(SIGNER_GROUP_V1);
+        assert_eq!(data.len(), 1 + 32);
+
+        let signer_group = SignerGroup::deserialize_any_version(&data).unwrap().0;
+        assert_eq!(signer_group.version, 1);
+        assert_eq!(
+            signer_group.owner,
+            Pubkey::new(&from_hex(
+                "0e2b5c4d3a1f6e8d7c9b0a1f2e3d4c5b6a798897a6b5c4d3e2f10f1e2d3c4b5a"
+            ))
+        );
+        assert_eq!(
+            signer_group,
+            SignerGroup {
+                version: signer_group.version,
+                owner: signer_group.owner,
+                ..SignerGroup::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_signer_v1() {
+        let data = from_hex(VALID_SIGNER_V1);
+        assert_eq!(data.len(), 1 + 32 + SecpSignatureOffsets::ETH_ADDRESS_SIZE);
+
+        let valid_signer = ValidSigner::deserialize_any_version(&data).unwrap().0;
+        assert_eq!(valid_signer.version, 1);
+        assert_eq!(
+            valid_signer.signer_group,
+            Pubkey::new(&from_hex(
+                "1f3a5c7e9b0d2f4a6c8e0b1d3f5a7c9e2b4d6f8a0c1e3b5d7f9a2c4e6b8d0f1a"
+            ))
+        );
+        // single eth address of v1 is the first slot of the address list
+        assert_eq!(
+            valid_signer.eth_addresses[0].to_vec(),
+            from_hex("c0ffee254729296a45a3885639ac7e10f9d54979")
+        );
+        let mut eth_addresses =
+            [[0; SecpSignatureOffsets::ETH_ADDRESS_SIZE]; ValidSigner::MAX_ETH_ADDRESSES];
+        eth_addresses[0] = valid_signer.eth_addresses[0];
+        assert_eq!(
+            valid_signer,
+            ValidSigner {
+                version: valid_signer.version,
+                signer_group: valid_signer.signer_group,
+                eth_addresses,
+                ..ValidSigner::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_valid_signer_account_info_v1() {
+        let key = Pubkey::new_from_array([2; 32]);
+        let owner = Pubkey::new_from_array([3; 32]);
+        let mut lamports = 0;
+        let mut data = from_hex(VALID_SIGNER_V1);
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // single eth address of v1 is registered for validations
+        let eth_address = ValidSigner::from_account_info_any_version(&info)
+            .unwrap()
+            .eth_addresses()
+            .to_vec();
+        assert_eq!(
+            eth_address,
+            vec![[
+                0xc0, 0xff, 0xee, 0x25, 0x47, 0x29, 0x29, 0x6a, 0x45, 0xa3, 0x88, 0x56, 0x39, 0xac,
+                0x7e, 0x10, 0xf9, 0xd5, 0x49, 0x79
+            ]]
+        );
+
+        // fields stored in v1 can be written
+        let signer_group = Pubkey::new_from_array([4; 32]);
+        ValidSigner::update_account_info(&info, |valid_signer| {
+            valid_signer.signer_group = signer_group;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            info.data_len(),
+            1 + 32 + SecpSignatureOffsets::ETH_ADDRESS_SIZE
+        );
+        assert_eq!(
+            ValidSigner::from_account_info_any_version(&info)
+                .unwrap()
+                .signer_group,
+            signer_group
+        );
+
+        // the added ones can't until the account is migrated
+        let stored = info.data.borrow().to_vec();
+        assert_eq!(
+            ValidSigner::update_account_info(&info, |valid_signer| {
+                valid_signer.label = [1; ValidSigner::LABEL_SIZE];
+                Ok(())
+            }),
+            Err(AudiusError::AccountMigrationRequired.into())
+        );
+        assert_eq!(
+            ValidSigner::update_account_info(&info, |valid_signer| {
+                valid_signer.add_eth_address([5; SecpSignatureOffsets::ETH_ADDRESS_SIZE])
+            }),
+            Err(AudiusError::AccountMigrationRequired.into())
+        );
+        assert_eq!(*info.data.borrow(), stored.as_slice());
+    }
+
+    #[test]
+    fn test_pack_v1() {
+        let mut data = from_hex(SIGNER_GROUP_V1);
+        let (signer_group, version) = SignerGroup::deserialize_any_version(&data).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(
+            signer_group.pack(&mut data),
+            Err(AudiusError::AccountMigrationRequired.into())
+        );
+
+        let mut data = from_hex(VALID_SIGNER_V1);
+        let (valid_signer, version) = ValidSigner::deserialize_any_version(&data).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(
+            valid_signer.pack(&mut data),
+            Err(AudiusError::AccountMigrationRequired.into())
+        );
+        // refused write leaves the account as it was
+        assert_eq!(data, from_hex(VALID_SIGNER_V1));
+    }
+
+    #[test]
+    fn test_current_layout() {
+        let signer_group = SignerGroup {
+            version: 2,
+            owner: Pubkey::new_from_array([1; 32]),
+            next_signer_id: 4.into(),
+            ..SignerGroup::default()
+        };
+        let packed = signer_group.try_to_vec().unwrap();
+        assert_eq!(
+            SignerGroup::deserialize_any_version(&packed),
+            Ok((signer_group, 2))
+        );
+
+        let valid_signer = ValidSigner {
+            version: 8,
+            signer_id: 3.into(),
+            ..ValidSigner::default()
+        };
+        let packed = valid_signer.try_to_vec().unwrap();
+        assert_eq!(
+            ValidSigner::deserialize_any_version(&packed),
+            Ok((valid_signer, 8))
+        );
     }
 }
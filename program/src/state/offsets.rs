@@ -0,0 +1,155 @@
+//! Byte offsets of the account fields, for `getProgramAccounts` memcmp filters.
+//! Offsets count from the start of the account data, so they include the discriminator
+//! when the program is built with `anchor-compat` feature.
+
+use super::{SecpSignatureOffsets, SignerKeyType, ValidSigner, ACCOUNT_DISCRIMINATOR_LEN};
+use audius_types::PodU64;
+use solana_program::pubkey::Pubkey;
+use std::mem::size_of;
+
+const VERSION_SIZE: usize = size_of::<u8>();
+const PUBKEY_SIZE: usize = size_of::<Pubkey>();
+
+/// Offset of the owner in the SignerGroup account data
+pub const SIGNER_GROUP_OWNER_OFFSET: usize = ACCOUNT_DISCRIMINATOR_LEN + VERSION_SIZE;
+
+/// Offset of the pending owner in the SignerGroup account data, after the owner
+/// and the first and last signers
+pub const SIGNER_GROUP_PENDING_OWNER_OFFSET: usize = SIGNER_GROUP_OWNER_OFFSET + PUBKEY_SIZE * 3;
+
+/// Offset of the treasury in the SignerGroup account data, after the pending owner,
+/// the proof of possession flag and the validation fee
+pub const SIGNER_GROUP_TREASURY_OFFSET: usize =
+    SIGNER_GROUP_PENDING_OWNER_OFFSET + PUBKEY_SIZE + size_of::<bool>() + size_of::<PodU64>();
+
+/// Offset of the signer group in the ValidSigner account data
+pub const VALID_SIGNER_GROUP_OFFSET: usize = ACCOUNT_DISCRIMINATOR_LEN + VERSION_SIZE;
+
+/// Offset of the first eth address slot in the ValidSigner account data
+pub const VALID_SIGNER_ETH_ADDRESS_OFFSET: usize = VALID_SIGNER_GROUP_OFFSET + PUBKEY_SIZE;
+
+/// Offset of the eth address count in the ValidSigner account data, after all the slots
+pub const VALID_SIGNER_ETH_ADDRESS_COUNT_OFFSET: usize = VALID_SIGNER_ETH_ADDRESS_OFFSET
+    + SecpSignatureOffsets::ETH_ADDRESS_SIZE * ValidSigner::MAX_ETH_ADDRESSES;
+
+/// Offset of the Ed25519 public key in the ValidSigner account data, after the eth address
+/// count, the next and previous signers and the key type
+pub const VALID_SIGNER_ED25519_PUBKEY_OFFSET: usize = VALID_SIGNER_ETH_ADDRESS_COUNT_OFFSET
+    + size_of::<u8>()
+    + PUBKEY_SIZE * 2
+    + size_of::<SignerKeyType>();
+
+/// Offset of the eth address slot in the ValidSigner account data, the address may be
+/// registered in any of `ValidSigner::MAX_ETH_ADDRESSES` slots
+pub const fn valid_signer_eth_address_offset(slot: usize) -> usize {
+    VALID_SIGNER_ETH_ADDRESS_OFFSET + slot * SecpSignatureOffsets::ETH_ADDRESS_SIZE
+}
+
+#[cfg(feature = "rpc-filters")]
+mod filters {
+    use super::*;
+    use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+
+    /// Filter of the accounts holding the bytes at the offset
+    fn memcmp(offset: usize, bytes: &[u8]) -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp {
+            offset,
+            bytes: MemcmpEncodedBytes::Binary(bs58::encode(bytes).into_string()),
+            encoding: None,
+        })
+    }
+
+    /// Filter of the signer groups owned by the key
+    pub fn signer_group_owner_filter(owner: &Pubkey) -> RpcFilterType {
+        memcmp(SIGNER_GROUP_OWNER_OFFSET, owner.as_ref())
+    }
+
+    /// Filter of the valid signers of the group
+    pub fn valid_signer_group_filter(signer_group: &Pubkey) -> RpcFilterType {
+        memcmp(VALID_SIGNER_GROUP_OFFSET, signer_group.as_ref())
+    }
+
+    /// Filter of the valid signers holding the eth address in the slot
+    pub fn valid_signer_eth_address_filter(
+        eth_address: &[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        slot: usize,
+    ) -> RpcFilterType {
+        memcmp(valid_signer_eth_address_offset(slot), eth_address)
+    }
+}
+
+#[cfg(feature = "rpc-filters")]
+pub use filters::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::{ProgramAccount, SignerGroup};
+
+    #[test]
+    fn test_signer_group_offsets() {
+        let signer_group = SignerGroup {
+            version: 1,
+            owner: Pubkey::new_from_array([1; 32]),
+            pending_owner: Pubkey::new_from_array([2; 32]),
+            treasury: Pubkey::new_from_array([3; 32]),
+            ..SignerGroup::default()
+        };
+        let mut data = vec![0; SignerGroup::LEN];
+        ProgramAccount::pack(&signer_group, &mut data).unwrap();
+
+        let field = |offset: usize| &data[offset..offset + PUBKEY_SIZE];
+        assert_eq!(
+            field(SIGNER_GROUP_OWNER_OFFSET),
+            signer_group.owner.as_ref()
+        );
+        assert_eq!(
+            field(SIGNER_GROUP_PENDING_OWNER_OFFSET),
+            signer_group.pending_owner.as_ref()
+        );
+        assert_eq!(
+            field(SIGNER_GROUP_TREASURY_OFFSET),
+            signer_group.treasury.as_ref()
+        );
+    }
+
+    #[test]
+    fn test_valid_signer_offsets() {
+        let valid_signer = ValidSigner {
+            version: 1,
+            signer_group: Pubkey::new_from_array([1; 32]),
+            eth_addresses: [[2; 20], [3; 20], [4; 20]],
+            eth_address_count: 3,
+            key_type: SignerKeyType::ED25519,
+            ed25519_pubkey: Pubkey::new_from_array([5; 32]),
+            ..ValidSigner::default()
+        };
+        let mut data = vec![0; ValidSigner::LEN];
+        ProgramAccount::pack(&valid_signer, &mut data).unwrap();
+
+        assert_eq!(
+            &data[VALID_SIGNER_GROUP_OFFSET..VALID_SIGNER_GROUP_OFFSET + PUBKEY_SIZE],
+            valid_signer.signer_group.as_ref()
+        );
+        assert_eq!(
+            valid_signer_eth_address_offset(0),
+            VALID_SIGNER_ETH_ADDRESS_OFFSET
+        );
+        for (slot, eth_address) in valid_signer.eth_addresses.iter().enumerate() {
+            let offset = valid_signer_eth_address_offset(slot);
+            assert_eq!(
+                &data[offset..offset + SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+                eth_address
+            );
+        }
+        assert_eq!(
+            data[VALID_SIGNER_ETH_ADDRESS_COUNT_OFFSET],
+            valid_signer.eth_address_count
+        );
+        assert_eq!(
+            &data[VALID_SIGNER_ED25519_PUBKEY_OFFSET
+                ..VALID_SIGNER_ED25519_PUBKEY_OFFSET + PUBKEY_SIZE],
+            valid_signer.ed25519_pubkey.as_ref()
+        );
+    }
+}
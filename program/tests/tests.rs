@@ -1,17 +1,23 @@
 #![cfg(feature = "test-bpf")]
 
 use audius::*;
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use rand::{thread_rng, Rng};
 use secp256k1::{PublicKey, SecretKey};
 use sha3::Digest;
-use solana_program::{hash::Hash, pubkey::Pubkey, system_instruction};
+use solana_program::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_instruction,
+};
 use solana_program_test::*;
 use solana_sdk::{
     account::Account,
     secp256k1_instruction,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
     transport::TransportError,
 };
 
@@ -85,7 +91,10 @@ async fn process_tx_init_signer_group(
     banks_client: &mut BanksClient,
 ) -> Result<(), TransportError> {
     let mut transaction = Transaction::new_with_payer(
-        &[instruction::init_signer_group(&id(), signer_group, group_owner).unwrap()],
+        &[
+            instruction::init_signer_group(&id(), signer_group, group_owner, &payer.pubkey())
+                .unwrap(),
+        ],
         Some(&payer.pubkey()),
     );
     transaction.sign(&[payer], recent_blockhash);
@@ -94,20 +103,25 @@ async fn process_tx_init_signer_group(
 }
 
 async fn process_tx_init_valid_signer(
-    valid_signer: &Pubkey,
     signer_group: &Pubkey,
     group_owner: &Keypair,
     payer: &Keypair,
     recent_blockhash: Hash,
     banks_client: &mut BanksClient,
     eth_address: [u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
-) -> Result<(), TransportError> {
+) -> Result<Pubkey, TransportError> {
+    let signer_group_account = get_account(banks_client, signer_group).await;
+    let signer_group_data =
+        state::SignerGroup::try_from_slice(&signer_group_account.data.as_slice()).unwrap();
+    let last_signer = Some(signer_group_data.last_signer).filter(|s| *s != Pubkey::default());
+
     let mut transaction = Transaction::new_with_payer(
         &[instruction::init_valid_signer(
             &id(),
-            valid_signer,
             signer_group,
             &group_owner.pubkey(),
+            &payer.pubkey(),
+            last_signer.as_ref(),
             eth_address,
         )
         .unwrap()],
@@ -115,7 +129,7 @@ async fn process_tx_init_valid_signer(
     );
     transaction.sign(&[payer, group_owner], recent_blockhash);
     banks_client.process_transaction(transaction).await?;
-    Ok(())
+    Ok(find_valid_signer_address(&id(), signer_group, &eth_address).0)
 }
 
 fn construct_eth_address(
@@ -127,6 +141,41 @@ fn construct_eth_address(
     addr
 }
 
+fn sign_message(
+    priv_key: &SecretKey,
+    message: &[u8],
+    secp_instruction_index: u8,
+) -> (Instruction, instruction::SignatureData) {
+    let mut secp256_program_instruction =
+        secp256k1_instruction::new_secp256k1_instruction(priv_key, message);
+
+    let start = 1;
+    let end = start + state::SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    let mut offsets =
+        state::SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])
+            .unwrap();
+
+    let sig_start = offsets.signature_offset as usize;
+    let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+
+    let mut signature = [0u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+    signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
+
+    let recovery_id = secp256_program_instruction.data[sig_end];
+
+    offsets.signature_instruction_index = secp_instruction_index;
+    offsets.eth_address_instruction_index = secp_instruction_index;
+    offsets.message_instruction_index = secp_instruction_index;
+    secp256_program_instruction.data[start..end].copy_from_slice(&offsets.try_to_vec().unwrap());
+
+    let mut signature_data =
+        instruction::SignatureData::new(signature, recovery_id, message.to_vec());
+    signature_data.secp_instruction_index = secp_instruction_index;
+
+    (secp256_program_instruction, signature_data)
+}
+
 #[tokio::test]
 async fn init_signer_group() {
     let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
@@ -154,34 +203,277 @@ async fn init_signer_group() {
 }
 
 #[tokio::test]
-async fn init_valid_signer() {
-    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+async fn init_signer_group_with_invalid_owner() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, _) = setup().await;
 
-    process_tx_init_signer_group(
+    for owner in &[Pubkey::default(), signer_group.pubkey()] {
+        // Builder rejects such owners, send the instruction as a client bypassing it would
+        let instruction = Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(signer_group.pubkey(), false),
+                AccountMeta::new_readonly(*owner, false),
+                AccountMeta::new_readonly(find_creation_whitelist_address(&id()).0, false),
+                AccountMeta::new_readonly(find_program_config_address(&id()).0, false),
+            ],
+            data: instruction::AudiusInstruction::InitSignerGroup
+                .try_to_vec()
+                .unwrap(),
+        };
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+        transaction.sign(&[&payer], recent_blockhash);
+
+        assert_audius_error(
+            banks_client.process_transaction(transaction).await,
+            0,
+            error::AudiusError::InvalidOwner,
+        );
+    }
+
+    let signer_group_account = get_account(&mut banks_client, &signer_group.pubkey()).await;
+    let signer_group_data =
+        state::SignerGroup::try_from_slice(&signer_group_account.data.as_slice()).unwrap();
+    assert!(!signer_group_data.is_initialized());
+}
+
+#[tokio::test]
+async fn init_signer_group_not_program_owned() {
+    let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+
+    // account is created, but left owned by the system program
+    let rent = banks_client.get_rent().await.unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[system_instruction::create_account(
+            &payer.pubkey(),
+            &signer_group.pubkey(),
+            rent.minimum_balance(state::SignerGroup::LEN),
+            state::SignerGroup::LEN as u64,
+            &solana_program::system_program::id(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &signer_group], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    assert_audius_error(
+        process_tx_init_signer_group(
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+        )
+        .await,
+        0,
+        error::AudiusError::SignerGroupNotProgramOwned,
+    );
+
+    let signer_group_account = get_account(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(
+        signer_group_account.owner,
+        solana_program::system_program::id()
+    );
+    assert!(signer_group_account.data.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn init_signer_group_with_registry() {
+    let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+    let group_owner = Keypair::new();
+
+    let mut signer_groups = vec![];
+    for _ in 0..3 {
+        let signer_group = Keypair::new();
+        create_account(
+            &mut banks_client,
+            &payer,
+            &recent_blockhash,
+            &signer_group,
+            state::SignerGroup::LEN,
+        )
+        .await
+        .unwrap();
+        process_tx_init_signer_group(
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+        )
+        .await
+        .unwrap();
+        signer_groups.push(signer_group.pubkey());
+    }
+
+    let (owner_registry, _) = find_owner_registry_address(&id(), &group_owner.pubkey());
+    let owner_registry_account = get_account(&mut banks_client, &owner_registry).await;
+    assert_eq!(owner_registry_account.data.len(), state::OwnerRegistry::LEN);
+    assert_eq!(owner_registry_account.owner, id());
+    let owner_registry_data = state::OwnerRegistry::unpack(&owner_registry_account.data).unwrap();
+    assert!(owner_registry_data.is_initialized());
+    assert_eq!(owner_registry_data.owner, group_owner.pubkey());
+    assert_eq!(owner_registry_data.groups, signer_groups);
+
+    // group of another owner can't be added to the registry
+    let signer_group = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let mut instruction = instruction::init_signer_group(
+        &id(),
         &signer_group.pubkey(),
         &group_owner.pubkey(),
+        &payer.pubkey(),
+    )
+    .unwrap();
+    instruction.accounts[1].pubkey = Pubkey::new_unique();
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        0,
+        error::AudiusError::WrongOwnerRegistryAddress,
+    );
+
+    // closed group leaves the registry
+    process_tx_signed_by_owner(
+        &mut banks_client,
         &payer,
         recent_blockhash,
-        &mut banks_client,
+        instruction::close_signer_group(
+            &id(),
+            &signer_groups[1],
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+        )
+        .unwrap(),
+        &group_owner,
     )
     .await
     .unwrap();
+    let owner_registry_data =
+        state::OwnerRegistry::unpack(&get_account(&mut banks_client, &owner_registry).await.data)
+            .unwrap();
+    assert_eq!(
+        owner_registry_data.groups,
+        vec![signer_groups[0], signer_groups[2]]
+    );
+}
 
-    let valid_signer = Keypair::new();
+#[tokio::test]
+async fn init_signer_group_in_full_registry() {
+    let (mut banks_client, payer, recent_blockhash) = program_test().start().await;
+    let group_owner = Keypair::new();
 
-    create_account(
+    for index in 0..=state::OwnerRegistry::MAX_GROUPS {
+        let signer_group = Keypair::new();
+        create_account(
+            &mut banks_client,
+            &payer,
+            &recent_blockhash,
+            &signer_group,
+            state::SignerGroup::LEN,
+        )
+        .await
+        .unwrap();
+        let result = process_tx_init_signer_group(
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+        )
+        .await;
+        if index < state::OwnerRegistry::MAX_GROUPS {
+            result.unwrap();
+        } else {
+            assert_audius_error(result, 0, error::AudiusError::OwnerRegistryFull);
+        }
+    }
+}
+
+#[tokio::test]
+async fn init_signer_group_with_signer() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let eth_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let instruction = instruction::init_signer_group_with_signer(
+        &id(),
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer.pubkey(),
+        eth_address,
+    )
+    .unwrap();
+
+    // failure of the signer half leaves the group uninitialized
+    let mut wrong_signer_instruction = instruction.clone();
+    wrong_signer_instruction.accounts[4].pubkey = Pubkey::new_unique();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            wrong_signer_instruction,
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongValidSignerAddress,
+    );
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert!(!signer_group_data.is_initialized());
+
+    process_tx_signed_by_owner(
         &mut banks_client,
         &payer,
-        &recent_blockhash,
-        &valid_signer,
-        state::ValidSigner::LEN,
+        recent_blockhash,
+        instruction,
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    let (valid_signer, _) = find_valid_signer_address(&id(), &signer_group.pubkey(), &eth_address);
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert!(signer_group_data.is_initialized());
+    assert_eq!(signer_group_data.owner, group_owner.pubkey());
+    assert_eq!(signer_group_data.first_signer, valid_signer);
+    assert_eq!(signer_group_data.last_signer, valid_signer);
+
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    assert_eq!(valid_signer_account.owner, id());
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
+    assert!(valid_signer_data.is_initialized());
+    assert_eq!(valid_signer_data.eth_addresses(), &[eth_address]);
+    assert_eq!(valid_signer_data.signer_group, signer_group.pubkey());
+    assert_eq!(u32::from(valid_signer_data.signer_id), 1);
+}
+
+#[tokio::test]
+async fn init_valid_signer() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
     )
     .await
     .unwrap();
 
     let eth_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
-    process_tx_init_valid_signer(
-        &valid_signer.pubkey(),
+    let valid_signer = process_tx_init_valid_signer(
         &signer_group.pubkey(),
         &group_owner,
         &payer,
@@ -192,7 +484,7 @@ async fn init_valid_signer() {
     .await
     .unwrap();
 
-    let valid_signer_account = get_account(&mut banks_client, &valid_signer.pubkey()).await;
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
 
     assert_eq!(valid_signer_account.data.len(), state::ValidSigner::LEN);
     assert_eq!(valid_signer_account.owner, id());
@@ -201,12 +493,12 @@ async fn init_valid_signer() {
         state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
 
     assert!(valid_signer_data.is_initialized());
-    assert_eq!(valid_signer_data.eth_address, eth_address);
+    assert_eq!(valid_signer_data.eth_addresses(), &[eth_address]);
     assert_eq!(valid_signer_data.signer_group, signer_group.pubkey());
 }
 
 #[tokio::test]
-async fn clear_valid_signer() {
+async fn init_valid_signer_with_zero_eth_address() {
     let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
 
     process_tx_init_signer_group(
@@ -219,37 +511,45 @@ async fn clear_valid_signer() {
     .await
     .unwrap();
 
-    let valid_signer = Keypair::new();
-
-    create_account(
-        &mut banks_client,
+    let result = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
         &payer,
-        &recent_blockhash,
-        &valid_signer,
-        state::ValidSigner::LEN,
+        recent_blockhash,
+        &mut banks_client,
+        [0u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
     )
     .await
-    .unwrap();
+    .map(|_| ());
+    assert_audius_error(result, 0, error::AudiusError::InvalidEthAddress);
+}
 
-    let eth_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
-    process_tx_init_valid_signer(
-        &valid_signer.pubkey(),
+#[tokio::test]
+async fn init_valid_signer_from_pubkey() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
         &signer_group.pubkey(),
-        &group_owner,
+        &group_owner.pubkey(),
         &payer,
         recent_blockhash,
         &mut banks_client,
-        eth_address,
     )
     .await
     .unwrap();
 
+    let priv_key = SecretKey::parse(&thread_rng().gen()).unwrap();
+    let secp_pubkey = PublicKey::from_secret_key(&priv_key);
+    let eth_address = construct_eth_address(&secp_pubkey);
+
     let mut transaction = Transaction::new_with_payer(
-        &[instruction::clear_valid_signer(
+        &[instruction::init_valid_signer_from_pubkey(
             &id(),
-            &valid_signer.pubkey(),
             &signer_group.pubkey(),
             &group_owner.pubkey(),
+            &payer.pubkey(),
+            None,
+            &secp_pubkey.serialize_compressed(),
         )
         .unwrap()],
         Some(&payer.pubkey()),
@@ -257,49 +557,17 @@ async fn clear_valid_signer() {
     transaction.sign(&[&payer, &group_owner], recent_blockhash);
     banks_client.process_transaction(transaction).await.unwrap();
 
-    let valid_signer_account = get_account(&mut banks_client, &valid_signer.pubkey()).await;
-
+    // signer is registered under the eth address derived on-chain
+    let (valid_signer, _) = find_valid_signer_address(&id(), &signer_group.pubkey(), &eth_address);
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
     let valid_signer_data =
         state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
-
-    assert_eq!(valid_signer_data.is_initialized(), false);
+    assert!(valid_signer_data.is_initialized());
+    assert_eq!(valid_signer_data.eth_addresses(), &[eth_address]);
 }
 
 #[tokio::test]
-async fn validate_signature() {
-    let mut rng = thread_rng();
-    let key: [u8; 32] = rng.gen();
-    let priv_key = SecretKey::parse(&key).unwrap();
-    let secp_pubkey = PublicKey::from_secret_key(&priv_key);
-    let eth_address = construct_eth_address(&secp_pubkey);
-
-    let message = [8u8; 30];
-
-    let secp256_program_instruction =
-        secp256k1_instruction::new_secp256k1_instruction(&priv_key, &message);
-
-    let start = 1;
-    let end = start + state::SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
-
-    let offsets =
-        state::SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])
-            .unwrap();
-
-    let sig_start = offsets.signature_offset as usize;
-    let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
-
-    let mut signature: [u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
-        [0u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
-    signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
-
-    let recovery_id = secp256_program_instruction.data[sig_end];
-
-    let signature_data = instruction::SignatureData {
-        signature,
-        recovery_id,
-        message: message.to_vec(),
-    };
-
+async fn clear_valid_signer() {
     let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
 
     process_tx_init_signer_group(
@@ -312,20 +580,8 @@ async fn validate_signature() {
     .await
     .unwrap();
 
-    let valid_signer = Keypair::new();
-
-    create_account(
-        &mut banks_client,
-        &payer,
-        &recent_blockhash,
-        &valid_signer,
-        state::ValidSigner::LEN,
-    )
-    .await
-    .unwrap();
-
-    process_tx_init_valid_signer(
-        &valid_signer.pubkey(),
+    let eth_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let valid_signer = process_tx_init_valid_signer(
         &signer_group.pubkey(),
         &group_owner,
         &payer,
@@ -337,31 +593,419 @@ async fn validate_signature() {
     .unwrap();
 
     let mut transaction = Transaction::new_with_payer(
-        &[
-            secp256_program_instruction,
-            instruction::validate_signature(
-                &id(),
-                &valid_signer.pubkey(),
-                &signer_group.pubkey(),
-                signature_data,
-            )
-            .unwrap(),
-        ],
+        &[instruction::clear_valid_signer(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            None,
+        )
+        .unwrap()],
         Some(&payer.pubkey()),
     );
-    transaction.sign(&[&payer], recent_blockhash);
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
     banks_client.process_transaction(transaction).await.unwrap();
-}
 
-#[tokio::test]
-async fn validate_signature_with_wrong_data() {
-    let mut rng = thread_rng();
-    let key: [u8; 32] = rng.gen();
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
+
+    assert_eq!(valid_signer_data.is_initialized(), false);
+    assert_eq!(valid_signer_account.data.len(), state::ValidSigner::LEN);
+    assert!(valid_signer_account.data.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn cleared_valid_signer_is_reinitialized_fresh() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let mut valid_signers = vec![];
+    for eth_address in &[[1u8; 20], [2u8; 20]] {
+        valid_signers.push(
+            process_tx_init_valid_signer(
+                &signer_group.pubkey(),
+                &group_owner,
+                &payer,
+                recent_blockhash,
+                &mut banks_client,
+                *eth_address,
+            )
+            .await
+            .unwrap(),
+        );
+    }
+
+    for instruction in &[
+        instruction::set_signer_label(
+            &id(),
+            &valid_signers[0],
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            "stale",
+        )
+        .unwrap(),
+        instruction::set_signer_cosigner(
+            &id(),
+            &valid_signers[0],
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &Pubkey::new_unique(),
+        )
+        .unwrap(),
+        instruction::clear_valid_signer(
+            &id(),
+            &valid_signers[0],
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            Some(&valid_signers[1]),
+        )
+        .unwrap(),
+    ] {
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction.clone(),
+            &group_owner,
+        )
+        .await
+        .unwrap();
+    }
+
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [1u8; 20],
+    )
+    .await
+    .unwrap();
+    assert_eq!(valid_signer, valid_signers[0]);
+
+    let valid_signer_data = state::ValidSigner::try_from_slice(
+        &get_account(&mut banks_client, &valid_signer).await.data,
+    )
+    .unwrap();
+    assert!(valid_signer_data.is_initialized());
+    assert_eq!(valid_signer_data.signer_group, signer_group.pubkey());
+    assert_eq!(valid_signer_data.eth_addresses(), &[[1u8; 20]]);
+    assert_eq!(valid_signer_data.label, [0; state::ValidSigner::LABEL_SIZE]);
+    assert_eq!(valid_signer_data.cosigner, Pubkey::default());
+    assert_eq!(u32::from(valid_signer_data.total_validations), 0);
+    assert_eq!(u32::from(valid_signer_data.signer_id), 3);
+    assert_eq!(valid_signer_data.prev_signer, valid_signers[1]);
+    assert_eq!(valid_signer_data.next_signer, Pubkey::default());
+}
+
+#[tokio::test]
+async fn close_signer_group_and_valid_signer() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [1u8; 20],
+    )
+    .await
+    .unwrap();
+    let rent_receiver = Pubkey::new_unique();
+
+    let close_group = |rent_receiver: &Pubkey| {
+        instruction::close_signer_group(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            rent_receiver,
+        )
+        .unwrap()
+    };
+    let result = process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        close_group(&Pubkey::new_unique()),
+        &group_owner,
+    )
+    .await;
+    assert_audius_error(result, 0, error::AudiusError::SignerGroupNotEmpty);
+
+    let signer_lamports = get_account(&mut banks_client, &valid_signer).await.lamports;
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::close_valid_signer(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &rent_receiver,
+            None,
+            None,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert!(banks_client
+        .get_account(valid_signer)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        banks_client.get_balance(rent_receiver).await.unwrap(),
+        signer_lamports
+    );
+
+    let group_lamports = get_account(&mut banks_client, &signer_group.pubkey())
+        .await
+        .lamports;
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        close_group(&rent_receiver),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert!(banks_client
+        .get_account(signer_group.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        banks_client.get_balance(rent_receiver).await.unwrap(),
+        signer_lamports + group_lamports
+    );
+}
+
+#[tokio::test]
+async fn closed_accounts_refunded_in_transaction_are_not_reinitialized() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+    let eth_address = [1u8; 20];
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        eth_address,
+    )
+    .await
+    .unwrap();
+    let rent = banks_client.get_rent().await.unwrap();
+
+    // the refund keeps the account alive past the transaction, its tombstone
+    // stops the signer from coming back with the history of the closed one
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::close_valid_signer(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                &payer.pubkey(),
+                None,
+                None,
+            )
+            .unwrap(),
+            system_instruction::transfer(
+                &payer.pubkey(),
+                &valid_signer,
+                rent.minimum_balance(state::ValidSigner::LEN),
+            ),
+            instruction::init_valid_signer(
+                &id(),
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                &payer.pubkey(),
+                None,
+                eth_address,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    let result = banks_client.process_transaction(transaction).await;
+    assert_audius_error(result, 2, error::AudiusError::AccountTombstoned);
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::close_valid_signer(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            None,
+            None,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::close_signer_group(
+                &id(),
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                &payer.pubkey(),
+            )
+            .unwrap(),
+            system_instruction::transfer(
+                &payer.pubkey(),
+                &signer_group.pubkey(),
+                rent.minimum_balance(state::SignerGroup::LEN),
+            ),
+            instruction::init_signer_group(
+                &id(),
+                &signer_group.pubkey(),
+                &Pubkey::new_unique(),
+                &payer.pubkey(),
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    let result = banks_client.process_transaction(transaction).await;
+    assert_audius_error(result, 2, error::AudiusError::AccountTombstoned);
+}
+
+async fn get_signer_id(banks_client: &mut BanksClient, valid_signer: &Pubkey) -> u32 {
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&get_account(banks_client, valid_signer).await.data)
+            .unwrap();
+    valid_signer_data.signer_id.into()
+}
+
+#[tokio::test]
+async fn signer_ids_are_not_reused() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let mut valid_signers = vec![];
+    for eth_address in &[[1u8; 20], [2u8; 20]] {
+        valid_signers.push(
+            process_tx_init_valid_signer(
+                &signer_group.pubkey(),
+                &group_owner,
+                &payer,
+                recent_blockhash,
+                &mut banks_client,
+                *eth_address,
+            )
+            .await
+            .unwrap(),
+        );
+    }
+    assert_eq!(get_signer_id(&mut banks_client, &valid_signers[0]).await, 1);
+    assert_eq!(get_signer_id(&mut banks_client, &valid_signers[1]).await, 2);
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::clear_valid_signer(
+            &id(),
+            &valid_signers[1],
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            Some(&valid_signers[0]),
+            None,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    // cleared signer's id is skipped, also when its account is reused
+    for (eth_address, signer_id) in &[([3u8; 20], 3), ([2u8; 20], 4)] {
+        let valid_signer = process_tx_init_valid_signer(
+            &signer_group.pubkey(),
+            &group_owner,
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+            *eth_address,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_signer_id(&mut banks_client, &valid_signer).await,
+            *signer_id
+        );
+    }
+    assert_eq!(
+        u32::from(
+            get_signer_group(&mut banks_client, &signer_group.pubkey())
+                .await
+                .next_signer_id
+        ),
+        5
+    );
+}
+
+#[tokio::test]
+async fn validate_signature() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
     let priv_key = SecretKey::parse(&key).unwrap();
     let secp_pubkey = PublicKey::from_secret_key(&priv_key);
     let eth_address = construct_eth_address(&secp_pubkey);
 
-    let message = [1u8; 29];
+    let message = [8u8; 30];
 
     let secp256_program_instruction =
         secp256k1_instruction::new_secp256k1_instruction(&priv_key, &message);
@@ -376,16 +1020,13 @@ async fn validate_signature_with_wrong_data() {
     let sig_start = offsets.signature_offset as usize;
     let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
 
-    let signature: [u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
-        [8u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+    let mut signature: [u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
+        [0u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+    signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
 
     let recovery_id = secp256_program_instruction.data[sig_end];
 
-    let signature_data = instruction::SignatureData {
-        signature,
-        recovery_id,
-        message: message.to_vec(),
-    };
+    let signature_data = instruction::SignatureData::new(signature, recovery_id, message.to_vec());
 
     let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
 
@@ -399,20 +1040,7 @@ async fn validate_signature_with_wrong_data() {
     .await
     .unwrap();
 
-    let valid_signer = Keypair::new();
-
-    create_account(
-        &mut banks_client,
-        &payer,
-        &recent_blockhash,
-        &valid_signer,
-        state::ValidSigner::LEN,
-    )
-    .await
-    .unwrap();
-
-    process_tx_init_valid_signer(
-        &valid_signer.pubkey(),
+    let valid_signer = process_tx_init_valid_signer(
         &signer_group.pubkey(),
         &group_owner,
         &payer,
@@ -426,9 +1054,9 @@ async fn validate_signature_with_wrong_data() {
     let mut transaction = Transaction::new_with_payer(
         &[
             secp256_program_instruction,
-            instruction::validate_signature(
+            instruction::validate_signature_raw(
                 &id(),
-                &valid_signer.pubkey(),
+                &valid_signer,
                 &signer_group.pubkey(),
                 signature_data,
             )
@@ -437,7 +1065,5238 @@ async fn validate_signature_with_wrong_data() {
         Some(&payer.pubkey()),
     );
     transaction.sign(&[&payer], recent_blockhash);
-    let transaction_error = banks_client.process_transaction(transaction).await;
+    banks_client.process_transaction(transaction).await.unwrap();
+}
 
-    assert!(transaction_error.is_err());
+#[tokio::test]
+async fn validate_signature_raw_and_digest() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+    let message = b"raw message of arbitrary length";
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, message, 0);
+    let mut digest_data = signature_data.clone();
+    digest_data.message = solana_program::keccak::hash(message).0.to_vec();
+
+    // both forms verify the same Secp256 instruction
+    for validate_instruction in vec![
+        instruction::validate_signature_raw(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            signature_data,
+        )
+        .unwrap(),
+        instruction::validate_signature_digest(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            digest_data.clone(),
+        )
+        .unwrap(),
+    ] {
+        let mut transaction = Transaction::new_with_payer(
+            &[secp256_program_instruction.clone(), validate_instruction],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer], recent_blockhash);
+        banks_client.process_transaction(transaction).await.unwrap();
+    }
+
+    // digest of another message doesn't match
+    let mut wrong_digest_data = digest_data.clone();
+    wrong_digest_data.message = solana_program::keccak::hash(b"another message").0.to_vec();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction.clone(),
+            instruction::validate_signature_digest(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                wrong_digest_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::SignatureVerificationFailed,
+    );
+
+    // builder rejects digests of other lengths, send the instruction as a client bypassing it would
+    let mut short_digest_data = digest_data;
+    short_digest_data.message.pop();
+    assert_eq!(
+        instruction::validate_signature_digest(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            short_digest_data.clone(),
+        )
+        .err(),
+        Some(error::AudiusError::InvalidDigestLength.into())
+    );
+    let mut validate_instruction = instruction::validate_signature_raw(
+        &id(),
+        &valid_signer,
+        &signer_group.pubkey(),
+        short_digest_data.clone(),
+    )
+    .unwrap();
+    validate_instruction.data =
+        instruction::AudiusInstruction::ValidateSignatureDigest(short_digest_data)
+            .pack()
+            .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[secp256_program_instruction, validate_instruction],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::InvalidDigestLength,
+    );
+}
+
+#[tokio::test]
+async fn validate_signature_with_wrong_data() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let secp_pubkey = PublicKey::from_secret_key(&priv_key);
+    let eth_address = construct_eth_address(&secp_pubkey);
+
+    let message = [1u8; 29];
+
+    let secp256_program_instruction =
+        secp256k1_instruction::new_secp256k1_instruction(&priv_key, &message);
+
+    let start = 1;
+    let end = start + state::SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    let offsets =
+        state::SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])
+            .unwrap();
+
+    let sig_start = offsets.signature_offset as usize;
+    let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+
+    let signature: [u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
+        [8u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+
+    let recovery_id = secp256_program_instruction.data[sig_end];
+
+    let signature_data = instruction::SignatureData::new(signature, recovery_id, message.to_vec());
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        eth_address,
+    )
+    .await
+    .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    let transaction_error = banks_client.process_transaction(transaction).await;
+
+    assert!(transaction_error.is_err());
+}
+
+#[tokio::test]
+async fn validate_signature_with_foreign_offsets() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let secp_pubkey = PublicKey::from_secret_key(&priv_key);
+    let eth_address = construct_eth_address(&secp_pubkey);
+
+    let message = [8u8; 30];
+
+    let mut secp256_program_instruction =
+        secp256k1_instruction::new_secp256k1_instruction(&priv_key, &message);
+
+    let start = 1;
+    let end = start + state::SecpSignatureOffsets::SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    let mut offsets =
+        state::SecpSignatureOffsets::try_from_slice(&secp256_program_instruction.data[start..end])
+            .unwrap();
+
+    let sig_start = offsets.signature_offset as usize;
+    let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+
+    let mut signature: [u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE] =
+        [0u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE];
+    signature.copy_from_slice(&secp256_program_instruction.data[sig_start..sig_end]);
+
+    let recovery_id = secp256_program_instruction.data[sig_end];
+
+    // eth address is now read from the audius instruction
+    offsets.eth_address_instruction_index = 1;
+    secp256_program_instruction.data[start..end].copy_from_slice(&offsets.try_to_vec().unwrap());
+
+    let signature_data = instruction::SignatureData::new(signature, recovery_id, message.to_vec());
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        eth_address,
+    )
+    .await
+    .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    let transaction_error = banks_client.process_transaction(transaction).await;
+
+    assert!(transaction_error.is_err());
+}
+
+#[tokio::test]
+async fn query_signer_group() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::query_signer_group(&id(), &signer_group.pubkey()).unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let signer_group_account = get_account(&mut banks_client, &signer_group.pubkey()).await;
+    let signer_group_data =
+        state::SignerGroup::try_from_slice(&signer_group_account.data.as_slice()).unwrap();
+
+    assert!(signer_group_data.to_json().starts_with(&format!(
+        "{{\"version\":{},\"owner\":\"{}\",",
+        processor::Processor::SIGNER_GROUP_VERSION,
+        group_owner.pubkey()
+    )));
+}
+
+#[tokio::test]
+async fn account_count_is_checked() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+    let instruction =
+        instruction::set_validation_quota(&id(), &signer_group.pubkey(), &group_owner.pubkey(), 5)
+            .unwrap();
+
+    let mut too_few = instruction.clone();
+    too_few.accounts.pop();
+    let mut transaction = Transaction::new_with_payer(&[too_few], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_eq!(
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    let mut too_many = instruction.clone();
+    too_many
+        .accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            too_many,
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::TooManyAccounts,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction,
+        &group_owner,
+    )
+    .await
+    .unwrap();
+}
+
+async fn setup_valid_signer(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    signer_group: &Keypair,
+    group_owner: &Keypair,
+    eth_address: [u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+) -> Pubkey {
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        payer,
+        recent_blockhash,
+        banks_client,
+    )
+    .await
+    .unwrap();
+
+    process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        group_owner,
+        payer,
+        recent_blockhash,
+        banks_client,
+        eth_address,
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn validate_signature_with_secp_index() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, &[8u8; 30], 2);
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::query_signer_group(&id(), &signer_group.pubkey()).unwrap(),
+            instruction::query_signer_group(&id(), &signer_group.pubkey()).unwrap(),
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+}
+
+#[tokio::test]
+async fn validate_signature_with_wrong_secp_index() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (secp256_program_instruction, mut signature_data) = sign_message(&priv_key, &[8u8; 30], 1);
+    // declared index points at the non-secp instruction
+    signature_data.secp_instruction_index = 0;
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::query_signer_group(&id(), &signer_group.pubkey()).unwrap(),
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    let transaction_error = banks_client.process_transaction(transaction).await;
+
+    assert!(transaction_error.is_err());
+}
+
+#[tokio::test]
+async fn clear_valid_signer_with_foreign_group() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    let foreign_group = Keypair::new();
+    let foreign_owner = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &foreign_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    process_tx_init_signer_group(
+        &foreign_group.pubkey(),
+        &foreign_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::clear_valid_signer(
+            &id(),
+            &valid_signer,
+            &foreign_group.pubkey(),
+            &foreign_owner.pubkey(),
+            None,
+            None,
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &foreign_owner], recent_blockhash);
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
+
+    assert!(valid_signer_data.is_initialized());
+}
+
+#[tokio::test]
+async fn validate_signature_with_foreign_group() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, &[8u8; 30], 0);
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let foreign_group = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &foreign_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    process_tx_init_signer_group(
+        &foreign_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &foreign_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    let transaction_error = banks_client.process_transaction(transaction).await;
+
+    assert!(transaction_error.is_err());
+}
+
+#[tokio::test]
+async fn validate_signature_preceding() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, &[8u8; 30], 1);
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::query_signer_group(&id(), &signer_group.pubkey()).unwrap(),
+            secp256_program_instruction,
+            instruction::validate_signature_preceding(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+}
+
+#[tokio::test]
+async fn validate_signature_preceding_with_interleaved_instruction() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, &[8u8; 30], 0);
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::query_signer_group(&id(), &signer_group.pubkey()).unwrap(),
+            instruction::validate_signature_preceding(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    let transaction_error = banks_client.process_transaction(transaction).await;
+
+    assert!(transaction_error.is_err());
+}
+
+#[tokio::test]
+async fn init_and_update_valid_signer_metadata() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::init_valid_signer_metadata(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            instruction::MetadataArgs {
+                endpoint_url: [1u8; state::ValidSignerMetadata::ENDPOINT_URL_SIZE],
+                operator_name: [2u8; state::ValidSignerMetadata::OPERATOR_NAME_SIZE],
+            },
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let (metadata_address, _) = find_valid_signer_metadata_address(&id(), &valid_signer);
+    let metadata_account = get_account(&mut banks_client, &metadata_address).await;
+
+    assert_eq!(metadata_account.data.len(), state::ValidSignerMetadata::LEN);
+    assert_eq!(metadata_account.owner, id());
+
+    let metadata =
+        state::ValidSignerMetadata::try_from_slice(&metadata_account.data.as_slice()).unwrap();
+
+    assert!(metadata.is_initialized());
+    assert_eq!(metadata.valid_signer, valid_signer);
+    assert_eq!(
+        metadata.operator_name,
+        [2u8; state::ValidSignerMetadata::OPERATOR_NAME_SIZE]
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::update_valid_signer_metadata(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            instruction::MetadataArgs {
+                endpoint_url: [3u8; state::ValidSignerMetadata::ENDPOINT_URL_SIZE],
+                operator_name: [4u8; state::ValidSignerMetadata::OPERATOR_NAME_SIZE],
+            },
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let metadata_account = get_account(&mut banks_client, &metadata_address).await;
+    let metadata =
+        state::ValidSignerMetadata::try_from_slice(&metadata_account.data.as_slice()).unwrap();
+
+    assert_eq!(
+        metadata.endpoint_url[..],
+        [3u8; state::ValidSignerMetadata::ENDPOINT_URL_SIZE][..]
+    );
+    assert_eq!(
+        metadata.operator_name,
+        [4u8; state::ValidSignerMetadata::OPERATOR_NAME_SIZE]
+    );
+}
+
+fn high_s_twin(
+    signature: &[u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE],
+) -> [u8; state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE] {
+    // secp256k1 curve order
+    let order: [u8; 32] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36,
+        0x41, 0x41,
+    ];
+    let mut twin = *signature;
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let mut diff = order[i] as i16 - signature[32 + i] as i16 - borrow;
+        borrow = if diff < 0 { 1 } else { 0 };
+        if diff < 0 {
+            diff += 256;
+        }
+        twin[32 + i] = diff as u8;
+    }
+    twin
+}
+
+#[tokio::test]
+async fn validate_signature_with_high_s() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let message = [8u8; 30];
+    let (mut secp256_program_instruction, mut signature_data) =
+        sign_message(&priv_key, &message, 0);
+
+    assert!(state::SecpSignatureOffsets::is_low_s(
+        &signature_data.signature
+    ));
+
+    // high-s twin recovers to the same address with flipped recovery ID
+    signature_data.signature = high_s_twin(&signature_data.signature);
+    signature_data.recovery_id ^= 1;
+
+    let sig_start = state::SecpSignatureOffsets::SIGNATURE_OFFSET;
+    let sig_end = sig_start + state::SecpSignatureOffsets::SECP_SIGNATURE_SIZE;
+    secp256_program_instruction.data[sig_start..sig_end].copy_from_slice(&signature_data.signature);
+    secp256_program_instruction.data[sig_end] = signature_data.recovery_id;
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    let transaction_error = banks_client.process_transaction(transaction).await;
+
+    assert!(transaction_error.is_err());
+}
+
+#[tokio::test]
+async fn validate_signature_with_max_message_len() {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let message = [8u8; MAX_MESSAGE_LEN];
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, &message, 0);
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+}
+
+async fn process_tx_validate_signature_multi(
+    num_signatures: usize,
+    signed_count: Option<u8>,
+) -> Result<(), TransportError> {
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let signatures = (0..num_signatures)
+        .map(|i| {
+            let message = vec![i as u8; 20 + i];
+            let (_, signature_data) = sign_message(&priv_key, &message, 0);
+            (signature_data, eth_address)
+        })
+        .collect();
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let mut instructions = instruction::validate_signature_multi(
+        &id(),
+        &valid_signer,
+        &signer_group.pubkey(),
+        0,
+        signatures,
+    )
+    .unwrap();
+    assert_eq!(instructions.len(), num_signatures + 1);
+    if let Some(signed_count) = signed_count {
+        // Secp256 program verifies only the first signatures
+        instructions[0].data[0] = signed_count;
+    }
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn validate_signature_multi_two() {
+    process_tx_validate_signature_multi(2, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn validate_signature_multi_three() {
+    process_tx_validate_signature_multi(3, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn validate_signature_multi_count_mismatch() {
+    assert!(process_tx_validate_signature_multi(3, Some(2))
+        .await
+        .is_err());
+}
+
+async fn process_tx_validate_signatures(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    signer_group: &Pubkey,
+    threshold: u8,
+    signatures: &[(Pubkey, &SecretKey)],
+    message: &[u8],
+) -> Result<(), TransportError> {
+    let mut instructions = vec![];
+    let mut signers = vec![];
+    for (secp_instruction_index, (valid_signer, priv_key)) in signatures.iter().enumerate() {
+        let (secp256_program_instruction, signature_data) =
+            sign_message(priv_key, message, secp_instruction_index as u8);
+        instructions.push(secp256_program_instruction);
+        signers.push((*valid_signer, signature_data));
+    }
+    instructions
+        .push(instruction::validate_signatures(&id(), signer_group, threshold, signers).unwrap());
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn validate_signatures_threshold() {
+    let mut rng = thread_rng();
+    let priv_keys: Vec<SecretKey> = (0..3)
+        .map(|_| SecretKey::parse(&rng.gen()).unwrap())
+        .collect();
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let mut valid_signers = vec![];
+    for priv_key in &priv_keys {
+        let eth_address = construct_eth_address(&PublicKey::from_secret_key(priv_key));
+        valid_signers.push(
+            setup_valid_signer(
+                &mut banks_client,
+                &payer,
+                recent_blockhash,
+                &signer_group,
+                &group_owner,
+                eth_address,
+            )
+            .await,
+        );
+    }
+
+    process_tx_validate_signatures(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group.pubkey(),
+        3,
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[1], &priv_keys[1]),
+            (valid_signers[2], &priv_keys[2]),
+        ],
+        &[1u8; 30],
+    )
+    .await
+    .unwrap();
+
+    // signature by the key of another signer isn't counted
+    let signatures = [
+        (valid_signers[0], &priv_keys[0]),
+        (valid_signers[1], &priv_keys[1]),
+        (valid_signers[2], &priv_keys[0]),
+    ];
+    process_tx_validate_signatures(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group.pubkey(),
+        2,
+        &signatures,
+        &[2u8; 30],
+    )
+    .await
+    .unwrap();
+    assert_audius_error(
+        process_tx_validate_signatures(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &signer_group.pubkey(),
+            3,
+            &signatures,
+            &[2u8; 30],
+        )
+        .await,
+        3,
+        error::AudiusError::ThresholdNotReached,
+    );
+}
+
+#[tokio::test]
+async fn validate_signatures_duplicate_signer() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    // the same signer is counted once however many times it signs
+    let signatures = [(valid_signer, &priv_key), (valid_signer, &priv_key)];
+    assert_audius_error(
+        process_tx_validate_signatures(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &signer_group.pubkey(),
+            2,
+            &signatures,
+            &[1u8; 30],
+        )
+        .await,
+        2,
+        error::AudiusError::ThresholdNotReached,
+    );
+    process_tx_validate_signatures(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group.pubkey(),
+        1,
+        &signatures,
+        &[1u8; 30],
+    )
+    .await
+    .unwrap();
+}
+
+async fn traverse_signer_group(
+    banks_client: &mut BanksClient,
+    signer_group: &Pubkey,
+) -> Vec<Pubkey> {
+    let signer_group_account = get_account(banks_client, signer_group).await;
+    let signer_group_data =
+        state::SignerGroup::try_from_slice(&signer_group_account.data.as_slice()).unwrap();
+
+    let mut signers = vec![];
+    let mut prev_signer = Pubkey::default();
+    let mut next_signer = signer_group_data.first_signer;
+    while next_signer != Pubkey::default() {
+        let valid_signer_account = get_account(banks_client, &next_signer).await;
+        let valid_signer_data =
+            state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
+        assert_eq!(valid_signer_data.prev_signer, prev_signer);
+        signers.push(next_signer);
+        prev_signer = next_signer;
+        next_signer = valid_signer_data.next_signer;
+    }
+    assert_eq!(signer_group_data.last_signer, prev_signer);
+
+    signers
+}
+
+#[tokio::test]
+async fn init_valid_signer_ed25519() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let valid_signer = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &valid_signer,
+        state::ValidSigner::LEN,
+    )
+    .await
+    .unwrap();
+
+    let ed25519_key = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::init_valid_signer_ed25519(
+            &id(),
+            &valid_signer.pubkey(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            &ed25519_key.pubkey(),
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer.pubkey()).await;
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
+
+    assert!(valid_signer_data.is_initialized());
+    assert_eq!(valid_signer_data.key_type, state::SignerKeyType::ED25519);
+    assert_eq!(valid_signer_data.ed25519_pubkey, ed25519_key.pubkey());
+
+    // Secp256k1 signature can't be validated for Ed25519 signer
+    let mut rng = thread_rng();
+    let key: [u8; 32] = rng.gen();
+    let priv_key = SecretKey::parse(&key).unwrap();
+    let (secp256_program_instruction, signature_data) = sign_message(&priv_key, &[8u8; 30], 0);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(
+                &id(),
+                &valid_signer.pubkey(),
+                &signer_group.pubkey(),
+                signature_data,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn enumerate_signer_group() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    let first_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    let mut signers = vec![first_signer];
+    for eth_address in 2..4u8 {
+        let valid_signer = process_tx_init_valid_signer(
+            &signer_group.pubkey(),
+            &group_owner,
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+            [eth_address; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        )
+        .await
+        .unwrap();
+        signers.push(valid_signer);
+    }
+
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &signer_group.pubkey()).await,
+        signers
+    );
+
+    // remove signer from the middle of the list
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::clear_valid_signer(
+            &id(),
+            &signers[1],
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            Some(&signers[0]),
+            Some(&signers[2]),
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+    signers.remove(1);
+
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &signer_group.pubkey()).await,
+        signers
+    );
+
+    // remove head of the list
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::clear_valid_signer(
+            &id(),
+            &signers[0],
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            Some(&signers[1]),
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+    signers.remove(0);
+
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &signer_group.pubkey()).await,
+        signers
+    );
+}
+
+#[tokio::test]
+async fn clear_valid_signer_with_wrong_neighbour() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    let first_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    let second_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [2u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .unwrap();
+
+    // pass an account which is not the next signer in the list
+    let foreign_signer = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::clear_valid_signer(
+            &id(),
+            &first_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            Some(&foreign_signer.pubkey()),
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &signer_group.pubkey()).await,
+        vec![first_signer, second_signer]
+    );
+}
+
+async fn process_tx_signed_by_owner(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    group_owner: &Keypair,
+) -> Result<(), TransportError> {
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[payer, group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await
+}
+
+async fn process_tx_validate_signature(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    valid_signer: &Pubkey,
+    signer_group: &Pubkey,
+    priv_key: &SecretKey,
+    message: &[u8],
+) -> Result<(), TransportError> {
+    let (secp256_program_instruction, signature_data) = sign_message(priv_key, message, 0);
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_raw(&id(), valid_signer, signer_group, signature_data)
+                .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn rotate_signer_eth_address() {
+    let mut rng = thread_rng();
+    let hot_key = SecretKey::parse(&rng.gen()).unwrap();
+    let cold_key = SecretKey::parse(&rng.gen()).unwrap();
+    let hot_address = construct_eth_address(&PublicKey::from_secret_key(&hot_key));
+    let cold_address = construct_eth_address(&PublicKey::from_secret_key(&cold_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        hot_address,
+    )
+    .await;
+
+    // cold key isn't registered yet
+    assert!(process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &cold_key,
+        &[1u8; 30],
+    )
+    .await
+    .is_err());
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::add_signer_address(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            cold_address,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    // both keys are valid during rotation
+    for (priv_key, message) in &[(&hot_key, [2u8; 30]), (&cold_key, [3u8; 30])] {
+        process_tx_validate_signature(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            priv_key,
+            message,
+        )
+        .await
+        .unwrap();
+    }
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::remove_signer_address(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            hot_address,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    let valid_signer_data = state::ValidSigner::try_from_slice(
+        &get_account(&mut banks_client, &valid_signer).await.data,
+    )
+    .unwrap();
+    assert_eq!(valid_signer_data.eth_addresses(), &[cold_address]);
+
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &cold_key,
+        &[4u8; 30],
+    )
+    .await
+    .unwrap();
+    assert!(process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &hot_key,
+        &[5u8; 30],
+    )
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+async fn add_signer_address_rejects_duplicate_and_foreign_owner() {
+    let eth_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let add_address = |eth_address, owner: &Keypair| {
+        instruction::add_signer_address(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &owner.pubkey(),
+            eth_address,
+        )
+        .unwrap()
+    };
+
+    assert!(process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        add_address(eth_address, &group_owner),
+        &group_owner,
+    )
+    .await
+    .is_err());
+
+    let foreign_owner = Keypair::new();
+    assert!(process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        add_address([2u8; 20], &foreign_owner),
+        &foreign_owner,
+    )
+    .await
+    .is_err());
+
+    // the only address can't be removed
+    assert!(process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::remove_signer_address(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            eth_address,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .is_err());
+
+    let valid_signer_data = state::ValidSigner::try_from_slice(
+        &get_account(&mut banks_client, &valid_signer).await.data,
+    )
+    .unwrap();
+    assert_eq!(valid_signer_data.eth_addresses(), &[eth_address]);
+}
+
+async fn get_valid_signer_label(banks_client: &mut BanksClient, valid_signer: &Pubkey) -> String {
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&get_account(banks_client, valid_signer).await.data)
+            .unwrap();
+    valid_signer_data.label().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn init_valid_signer_with_label_and_relabel() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let (valid_signer, _) = find_valid_signer_address(
+        &id(),
+        &signer_group.pubkey(),
+        &[1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::init_valid_signer_with_label(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            None,
+            [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            "discovery-node-1",
+        )
+        .unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+    assert_eq!(
+        get_valid_signer_label(&mut banks_client, &valid_signer).await,
+        "discovery-node-1"
+    );
+
+    let set_label = |label: &str| {
+        instruction::set_signer_label(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            label,
+        )
+        .unwrap()
+    };
+
+    // shorter label replaces the whole field, empty label clears it
+    for label in &["dn-1", ""] {
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            set_label(label),
+            &group_owner,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_valid_signer_label(&mut banks_client, &valid_signer).await,
+            *label
+        );
+    }
+
+    // invalid UTF-8 is rejected by the program
+    let mut invalid_label = set_label("dn-2");
+    invalid_label.data[1] = 0xff;
+    assert!(process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        invalid_label,
+        &group_owner,
+    )
+    .await
+    .is_err());
+
+    // only the group's owner can relabel
+    let foreign_owner = Keypair::new();
+    assert!(process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_signer_label(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &foreign_owner.pubkey(),
+            "dn-3",
+        )
+        .unwrap(),
+        &foreign_owner,
+    )
+    .await
+    .is_err());
+    assert_eq!(
+        get_valid_signer_label(&mut banks_client, &valid_signer).await,
+        ""
+    );
+}
+
+fn assert_audius_error(
+    result: Result<(), TransportError>,
+    instruction_index: u8,
+    error: error::AudiusError,
+) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            instruction_index,
+            InstructionError::Custom(error as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn validate_signature_with_activation_slot() {
+    const ACTIVE_AFTER_SLOT: u64 = 100;
+    const AMENDED_ACTIVE_AFTER_SLOT: u64 = 120;
+
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let mut context = program_test().start_with_context().await;
+    let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let recent_blockhash = context.last_blockhash;
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+    let (valid_signer, _) = find_valid_signer_address(&id(), &signer_group.pubkey(), &eth_address);
+
+    create_account(
+        &mut context.banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut context.banks_client,
+    )
+    .await
+    .unwrap();
+    process_tx_signed_by_owner(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::init_valid_signer_extended(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            None,
+            instruction::InitValidSignerArgs::new(eth_address, "", ACTIVE_AFTER_SLOT).unwrap(),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    let set_activation_slot = |active_after_slot| {
+        instruction::set_activation_slot(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            active_after_slot,
+        )
+        .unwrap()
+    };
+
+    assert_audius_error(
+        process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            &[1u8; 30],
+        )
+        .await,
+        1,
+        error::AudiusError::SignerNotYetActive,
+    );
+
+    // activation is postponed while the signer is not active yet
+    process_tx_signed_by_owner(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        set_activation_slot(AMENDED_ACTIVE_AFTER_SLOT),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    // the slot before the boundary, then the boundary itself, then after it
+    for (slot, message, active) in &[
+        (AMENDED_ACTIVE_AFTER_SLOT - 1, [2u8; 30], false),
+        (AMENDED_ACTIVE_AFTER_SLOT, [3u8; 30], true),
+        (AMENDED_ACTIVE_AFTER_SLOT + 10, [4u8; 30], true),
+    ] {
+        context.warp_to_slot(*slot).unwrap();
+        let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+        let result = process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            message,
+        )
+        .await;
+        if *active {
+            result.unwrap();
+        } else {
+            assert_audius_error(result, 1, error::AudiusError::SignerNotYetActive);
+        }
+    }
+
+    // activation slot has passed and can't be amended anymore
+    let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            set_activation_slot(AMENDED_ACTIVE_AFTER_SLOT + 100),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::SignerAlreadyActive,
+    );
+}
+
+#[tokio::test]
+async fn validate_signature_with_expiry_slot() {
+    const EXPIRES_AT_SLOT: u64 = 100;
+
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let mut context = program_test().start_with_context().await;
+    let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let recent_blockhash = context.last_blockhash;
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+
+    create_account(
+        &mut context.banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let valid_signer = setup_valid_signer(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    // only the group owner can set the expiry
+    let not_owner = Keypair::new();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::set_signer_expiry(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &not_owner.pubkey(),
+                EXPIRES_AT_SLOT,
+            )
+            .unwrap(),
+            &not_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+    process_tx_signed_by_owner(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_signer_expiry(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            EXPIRES_AT_SLOT,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    // the slot before the boundary, then the boundary itself, then after it
+    for (slot, message, expired) in &[
+        (EXPIRES_AT_SLOT - 1, [2u8; 30], false),
+        (EXPIRES_AT_SLOT, [3u8; 30], false),
+        (EXPIRES_AT_SLOT + 1, [4u8; 30], true),
+    ] {
+        context.warp_to_slot(*slot).unwrap();
+        let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+        let result = process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            message,
+        )
+        .await;
+        if *expired {
+            assert_audius_error(result, 1, error::AudiusError::SignerExpired);
+        } else {
+            result.unwrap();
+        }
+    }
+
+    // expired signer is still cleared from the group
+    let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+    process_tx_signed_by_owner(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::clear_valid_signer(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            None,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        traverse_signer_group(&mut context.banks_client, &signer_group.pubkey()).await,
+        Vec::<Pubkey>::new()
+    );
+}
+
+#[tokio::test]
+async fn init_valid_signer_with_eth_pop() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let other_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_eth_pop_required(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            true,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert!(
+        get_signer_group(&mut banks_client, &signer_group.pubkey())
+            .await
+            .eth_pop_required
+    );
+
+    let (valid_signer, _) = find_valid_signer_address(&id(), &signer_group.pubkey(), &eth_address);
+
+    // group owner alone can't register eth address anymore
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::init_valid_signer(
+                &id(),
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                &payer.pubkey(),
+                None,
+                eth_address,
+            )
+            .unwrap(),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::ProofOfPossessionMissing,
+    );
+
+    let init_with_pop_transaction = |priv_key: &SecretKey, message: &[u8]| {
+        let (secp256_program_instruction, eth_pop_signature) = sign_message(priv_key, message, 0);
+        let mut transaction = Transaction::new_with_payer(
+            &[
+                secp256_program_instruction,
+                instruction::init_valid_signer_with_pop(
+                    &id(),
+                    &signer_group.pubkey(),
+                    &group_owner.pubkey(),
+                    &payer.pubkey(),
+                    None,
+                    eth_address,
+                    eth_pop_signature,
+                )
+                .unwrap(),
+            ],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, &group_owner], recent_blockhash);
+        transaction
+    };
+
+    // signature of some other account doesn't prove consent to this registration
+    assert_audius_error(
+        banks_client
+            .process_transaction(init_with_pop_transaction(
+                &priv_key,
+                Pubkey::new_unique().as_ref(),
+            ))
+            .await,
+        1,
+        error::AudiusError::InvalidProofOfPossession,
+    );
+    // neither does the signature by someone else's key
+    assert_audius_error(
+        banks_client
+            .process_transaction(init_with_pop_transaction(&other_key, valid_signer.as_ref()))
+            .await,
+        1,
+        error::AudiusError::SignatureVerificationFailed,
+    );
+
+    banks_client
+        .process_transaction(init_with_pop_transaction(&priv_key, valid_signer.as_ref()))
+        .await
+        .unwrap();
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &signer_group.pubkey()).await,
+        vec![valid_signer]
+    );
+}
+
+#[tokio::test]
+async fn validate_signature_with_fee() {
+    const VALIDATION_FEE: u64 = 1_000_000;
+
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+    let treasury = Pubkey::new_unique();
+    let fee_payer = Keypair::new();
+    let poor_fee_payer = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &fee_payer.pubkey(), 10 * VALIDATION_FEE),
+            system_instruction::transfer(
+                &payer.pubkey(),
+                &poor_fee_payer.pubkey(),
+                VALIDATION_FEE / 2,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let validate_with_fee = |fee_payer: &Keypair, treasury: &Pubkey, message: &[u8]| {
+        let (secp256_program_instruction, signature_data) = sign_message(&priv_key, message, 0);
+        let mut transaction = Transaction::new_with_payer(
+            &[
+                secp256_program_instruction,
+                instruction::validate_signature_with_fee(
+                    &id(),
+                    &valid_signer,
+                    &signer_group.pubkey(),
+                    &fee_payer.pubkey(),
+                    treasury,
+                    signature_data,
+                )
+                .unwrap(),
+            ],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, fee_payer], recent_blockhash);
+        transaction
+    };
+
+    // fee disabled, fee accounts are taken only from groups charging a fee
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        &[1u8; 30],
+    )
+    .await
+    .unwrap();
+    assert_eq!(banks_client.get_balance(treasury).await.unwrap(), 0);
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_validation_fee(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            VALIDATION_FEE,
+            &treasury,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    assert_audius_error(
+        process_tx_validate_signature(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            &[2u8; 30],
+        )
+        .await,
+        1,
+        error::AudiusError::ValidationFeePayerMissing,
+    );
+    assert_audius_error(
+        banks_client
+            .process_transaction(validate_with_fee(
+                &fee_payer,
+                &Pubkey::new_unique(),
+                &[3u8; 30],
+            ))
+            .await,
+        1,
+        error::AudiusError::WrongTreasury,
+    );
+    // system program fails the transfer with ResultWithNegativeLamports
+    assert_eq!(
+        banks_client
+            .process_transaction(validate_with_fee(&poor_fee_payer, &treasury, &[4u8; 30]))
+            .await
+            .unwrap_err()
+            .unwrap(),
+        TransactionError::InstructionError(1, InstructionError::Custom(1))
+    );
+
+    let fee_payer_balance = banks_client.get_balance(fee_payer.pubkey()).await.unwrap();
+    banks_client
+        .process_transaction(validate_with_fee(&fee_payer, &treasury, &[5u8; 30]))
+        .await
+        .unwrap();
+    assert_eq!(
+        banks_client.get_balance(treasury).await.unwrap(),
+        VALIDATION_FEE
+    );
+    assert_eq!(
+        banks_client.get_balance(fee_payer.pubkey()).await.unwrap(),
+        fee_payer_balance - VALIDATION_FEE
+    );
+}
+
+#[tokio::test]
+async fn init_valid_signer_with_wrong_address() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let eth_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let init_instruction = instruction::init_valid_signer(
+        &id(),
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer.pubkey(),
+        None,
+        eth_address,
+    )
+    .unwrap();
+
+    // account derived for another eth address is rejected
+    let mut instruction = init_instruction.clone();
+    instruction.accounts[0].pubkey =
+        find_valid_signer_address(&id(), &signer_group.pubkey(), &[2u8; 20]).0;
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction,
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongValidSignerAddress,
+    );
+
+    // as well as a pre-created account
+    let keypair_signer = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &keypair_signer,
+        state::ValidSigner::LEN,
+    )
+    .await
+    .unwrap();
+    let mut instruction = init_instruction.clone();
+    instruction.accounts[0].pubkey = keypair_signer.pubkey();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction,
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongValidSignerAddress,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        init_instruction,
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let (valid_signer, bump_seed) =
+        find_valid_signer_address(&id(), &signer_group.pubkey(), &eth_address);
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    assert_eq!(valid_signer_account.owner, id());
+    let valid_signer_data =
+        state::ValidSigner::try_from_slice(&valid_signer_account.data.as_slice()).unwrap();
+    assert_eq!(valid_signer_data.bump_seed, bump_seed);
+
+    // account of the cleared signer is reused when the address is registered again
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::clear_valid_signer(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            None,
+            None,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::init_valid_signer_with_label(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            None,
+            eth_address,
+            "reused",
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_valid_signer_label(&mut banks_client, &valid_signer).await,
+        "reused"
+    );
+}
+
+#[tokio::test]
+async fn validate_signature_with_quota() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let mut context = program_test().start_with_context().await;
+    let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let recent_blockhash = context.last_blockhash;
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+
+    create_account(
+        &mut context.banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let valid_signer = setup_valid_signer(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    // only the group owner can set the quota
+    let not_owner = Keypair::new();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::set_validation_quota(
+                &id(),
+                &signer_group.pubkey(),
+                &not_owner.pubkey(),
+                2,
+            )
+            .unwrap(),
+            &not_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+    process_tx_signed_by_owner(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_validation_quota(&id(), &signer_group.pubkey(), &group_owner.pubkey(), 2)
+            .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    for message in &[[2u8; 30], [3u8; 30]] {
+        process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            message,
+        )
+        .await
+        .unwrap();
+    }
+    assert_audius_error(
+        process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            &[4u8; 30],
+        )
+        .await,
+        1,
+        error::AudiusError::QuotaExceeded,
+    );
+
+    // quota is restored in the next window
+    context
+        .warp_to_slot(state::SignerGroup::QUOTA_WINDOW_SLOTS + 1)
+        .unwrap();
+    let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+    process_tx_validate_signature(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        &[4u8; 30],
+    )
+    .await
+    .unwrap();
+    let signer_group_data =
+        get_signer_group(&mut context.banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.quota_per_window, 2.into());
+    assert_eq!(signer_group_data.used_in_window, 1.into());
+}
+
+#[tokio::test]
+async fn validate_signature_with_rate_limit() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let mut context = program_test().start_with_context().await;
+    let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let recent_blockhash = context.last_blockhash;
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+
+    create_account(
+        &mut context.banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let valid_signer = setup_valid_signer(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    // validations under the limit pass
+    for message in 0..processor::Processor::MAX_VALIDATIONS_PER_WINDOW as u8 {
+        process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            &[message; 30],
+        )
+        .await
+        .unwrap();
+    }
+    assert_audius_error(
+        process_tx_validate_signature(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            &[u8::MAX; 30],
+        )
+        .await,
+        1,
+        error::AudiusError::RateLimitExceeded,
+    );
+
+    // counter restarts once the window expires
+    context
+        .warp_to_slot(processor::Processor::RATE_LIMIT_WINDOW_SLOTS * 2)
+        .unwrap();
+    let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+    process_tx_validate_signature(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        &[u8::MAX; 30],
+    )
+    .await
+    .unwrap();
+    let valid_signer_data = state::ValidSigner::try_from_slice(
+        &get_account(&mut context.banks_client, &valid_signer)
+            .await
+            .data,
+    )
+    .unwrap();
+    assert_eq!(u32::from(valid_signer_data.validations_this_window), 1);
+    assert_eq!(
+        u32::from(valid_signer_data.total_validations),
+        processor::Processor::MAX_VALIDATIONS_PER_WINDOW + 1
+    );
+}
+
+async fn get_signer_group(
+    banks_client: &mut BanksClient,
+    signer_group: &Pubkey,
+) -> state::SignerGroup {
+    let signer_group_account = get_account(banks_client, signer_group).await;
+    state::SignerGroup::try_from_slice(&signer_group_account.data.as_slice()).unwrap()
+}
+
+#[tokio::test]
+async fn update_max_signers() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+    process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [2u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .unwrap();
+
+    let update = |max_valid_signers| {
+        instruction::update_max_signers(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            max_valid_signers,
+        )
+        .unwrap()
+    };
+
+    // raise the limit
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        update(5),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u32::from(signer_group_data.valid_signer_count), 2);
+    assert_eq!(u32::from(signer_group_data.max_valid_signers), 5);
+
+    // the limit can't go below the number of registered signers
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            update(1),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::MaxSignersReached,
+    );
+
+    // lower the limit down to the number of registered signers
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        update(2),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u32::from(signer_group_data.max_valid_signers), 2);
+
+    // full group accepts no more signers
+    let result = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [3u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .map(|_| ());
+    assert_audius_error(result, 0, error::AudiusError::MaxSignersReached);
+}
+
+#[tokio::test]
+async fn validate_signature_with_message_prefixes() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    // group without prefixes validates any message
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        b"user:delete 1",
+    )
+    .await
+    .unwrap();
+
+    // only the group owner manages prefixes
+    let not_owner = Keypair::new();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::add_message_prefix(
+                &id(),
+                &signer_group.pubkey(),
+                &not_owner.pubkey(),
+                *b"track:v1",
+            )
+            .unwrap(),
+            &not_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::add_message_prefix(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            *b"track:v1",
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.message_prefixes(), &[*b"track:v1"]);
+
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        b"track:v1 attestation",
+    )
+    .await
+    .unwrap();
+    assert_audius_error(
+        process_tx_validate_signature(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            b"user:delete 2",
+        )
+        .await,
+        1,
+        error::AudiusError::MessagePrefixNotAllowed,
+    );
+
+    // removing the last prefix allows any message again
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::remove_message_prefix(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            *b"track:v1",
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        b"user:delete 3",
+    )
+    .await
+    .unwrap();
+}
+
+fn cosigned_validation_instructions(
+    valid_signer: &Pubkey,
+    signer_group: &Pubkey,
+    priv_key: &SecretKey,
+    message: &[u8],
+    cosigner: &Pubkey,
+) -> Vec<Instruction> {
+    let (secp256_program_instruction, signature_data) = sign_message(priv_key, message, 0);
+    vec![
+        secp256_program_instruction,
+        instruction::validate_signature_with_cosigner(
+            &id(),
+            valid_signer,
+            signer_group,
+            cosigner,
+            signature_data,
+        )
+        .unwrap(),
+    ]
+}
+
+#[tokio::test]
+async fn validate_signature_with_cosigner() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let cosigner = Keypair::new();
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_signer_cosigner(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &cosigner.pubkey(),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    assert_eq!(
+        state::ValidSigner::unpack(&valid_signer_account.data)
+            .unwrap()
+            .cosigner,
+        cosigner.pubkey()
+    );
+
+    // cosigner isn't needed until the group requires it
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        b"cosigner disabled",
+    )
+    .await
+    .unwrap();
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_cosigner_required(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            true,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert!(
+        get_signer_group(&mut banks_client, &signer_group.pubkey())
+            .await
+            .require_cosigner
+    );
+
+    assert_audius_error(
+        process_tx_validate_signature(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            b"cosigner omitted",
+        )
+        .await,
+        1,
+        error::AudiusError::MissingCosigner,
+    );
+    // cosigner account is given but the transaction isn't signed by it
+    let mut instructions = cosigned_validation_instructions(
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        b"cosigner didn't sign",
+        &cosigner.pubkey(),
+    );
+    instructions[1].accounts[3].is_signer = false;
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::MissingCosigner,
+    );
+
+    let wrong_cosigner = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &cosigned_validation_instructions(
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            b"wrong cosigner",
+            &wrong_cosigner.pubkey(),
+        ),
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &wrong_cosigner], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::MissingCosigner,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &cosigned_validation_instructions(
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            b"cosigned",
+            &cosigner.pubkey(),
+        ),
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &cosigner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    // threshold validation takes no cosigner accounts, so it can't bypass the requirement
+    assert_audius_error(
+        process_tx_validate_signatures(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &signer_group.pubkey(),
+            1,
+            &[(valid_signer, &priv_key)],
+            b"threshold",
+        )
+        .await,
+        1,
+        error::AudiusError::MissingCosigner,
+    );
+}
+
+#[tokio::test]
+async fn validate_signature_counts_validations() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    for message in &[&b"first"[..], b"second", b"third"] {
+        process_tx_validate_signature(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            &priv_key,
+            message,
+        )
+        .await
+        .unwrap();
+    }
+    // failed validations aren't counted
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &Pubkey::new_unique(),
+        &priv_key,
+        b"fourth",
+    )
+    .await
+    .unwrap_err();
+
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    let valid_signer_data = state::ValidSigner::unpack(&valid_signer_account.data).unwrap();
+    assert_eq!(u32::from(valid_signer_data.total_validations), 3);
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u64::from(signer_group_data.total_validations_all), 3);
+}
+
+#[tokio::test]
+async fn transfer_ownership_with_mistyped_owner() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+    let new_owner = Keypair::new();
+    // nobody holds the key of the mistyped owner
+    let mistyped_owner = Pubkey::new_unique();
+
+    let propose = |owner: &Keypair, proposed_owner: &Pubkey| {
+        instruction::propose_ownership(
+            &id(),
+            &signer_group.pubkey(),
+            &owner.pubkey(),
+            proposed_owner,
+        )
+        .unwrap()
+    };
+    let set_label = |owner: &Keypair, label: &str| {
+        instruction::set_signer_label(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &owner.pubkey(),
+            label,
+        )
+        .unwrap()
+    };
+
+    // only the current owner may propose
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            propose(&new_owner, &new_owner.pubkey()),
+            &new_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        propose(&group_owner, &mistyped_owner),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.owner, group_owner.pubkey());
+    assert_eq!(signer_group_data.pending_owner, mistyped_owner);
+
+    // intended owner can't accept ownership proposed to someone else
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::accept_ownership(
+                &id(),
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                &new_owner.pubkey(),
+                &payer.pubkey(),
+            )
+            .unwrap(),
+            &new_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    // original owner still manages the group and withdraws the proposal
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        set_label(&group_owner, "dn-1"),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let cancel = |owner: &Keypair| {
+        instruction::cancel_proposed_ownership(&id(), &signer_group.pubkey(), &owner.pubkey())
+            .unwrap()
+    };
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        cancel(&group_owner),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_signer_group(&mut banks_client, &signer_group.pubkey())
+            .await
+            .pending_owner,
+        Pubkey::default()
+    );
+
+    // proposal to the intended owner completes once it's accepted
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        propose(&group_owner, &new_owner.pubkey()),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::accept_ownership(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &new_owner.pubkey(),
+            &payer.pubkey(),
+        )
+        .unwrap(),
+        &new_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.owner, new_owner.pubkey());
+    assert_eq!(signer_group_data.pending_owner, Pubkey::default());
+
+    // accepted transfer is recorded in the group's ownership history
+    let (history, _) = find_ownership_history_address(&id(), &signer_group.pubkey());
+    let history_account = get_account(&mut banks_client, &history).await;
+    assert_eq!(history_account.owner, id());
+    let history_data = state::OwnershipHistory::unpack(&history_account.data).unwrap();
+    assert_eq!(history_data.signer_group, signer_group.pubkey());
+    assert_eq!(history_data.transfers.len(), 1);
+    assert_eq!(
+        history_data.transfers[0].previous_owner,
+        group_owner.pubkey()
+    );
+    assert_eq!(history_data.transfers[0].new_owner, new_owner.pubkey());
+
+    // the group moves between the owners' registries
+    for (owner, groups) in &[
+        (group_owner.pubkey(), vec![]),
+        (new_owner.pubkey(), vec![signer_group.pubkey()]),
+    ] {
+        let (registry, _) = find_owner_registry_address(&id(), owner);
+        let registry_account = get_account(&mut banks_client, &registry).await;
+        assert_eq!(
+            &state::OwnerRegistry::unpack(&registry_account.data)
+                .unwrap()
+                .groups,
+            groups
+        );
+    }
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::get_ownership_history(&id(), &signer_group.pubkey()).unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            set_label(&group_owner, "dn-2"),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        set_label(&new_owner, "dn-2"),
+        &new_owner,
+    )
+    .await
+    .unwrap();
+
+    // accepted proposal can't be cancelled
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            cancel(&new_owner),
+            &new_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::OwnershipNotProposed,
+    );
+}
+
+#[cfg(feature = "track-stats")]
+#[tokio::test]
+async fn program_stats() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::init_program_stats(&id(), &payer.pubkey()).unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::clear_valid_signer(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                None,
+                None,
+            )
+            .unwrap(),
+            instruction::get_program_stats(&id()).unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let (stats_address, _) = find_program_stats_address(&id());
+    let stats_account = get_account(&mut banks_client, &stats_address).await;
+
+    assert_eq!(stats_account.data.len(), state::ProgramStats::LEN);
+
+    let stats = state::ProgramStats::try_from_slice(&stats_account.data.as_slice()).unwrap();
+
+    assert!(stats.is_initialized());
+    assert_eq!(stats.total_groups, 1);
+    assert_eq!(stats.total_signers, 1);
+    assert_eq!(stats.total_clearances, 1);
+    assert_eq!(stats.total_validations, 0);
+}
+
+/// Secp256 instructions signing the governance message of the current nonce by the approvers,
+/// returned along with the approvals
+async fn governance_approvals(
+    banks_client: &mut BanksClient,
+    signer_group: &Pubkey,
+    action: state::GovernanceAction,
+    eth_address: &[u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    approvers: &[(Pubkey, &SecretKey)],
+) -> (Vec<Instruction>, Vec<(Pubkey, instruction::SignatureData)>) {
+    let nonce = get_signer_group(banks_client, signer_group)
+        .await
+        .governance_nonce;
+    let message =
+        state::SignerGroup::governance_message(action, signer_group, eth_address, nonce.into());
+    approvers
+        .iter()
+        .enumerate()
+        .map(|(secp_instruction_index, (valid_signer, priv_key))| {
+            let (secp256_program_instruction, signature_data) =
+                sign_message(priv_key, &message, secp_instruction_index as u8);
+            (secp256_program_instruction, (*valid_signer, signature_data))
+        })
+        .unzip()
+}
+
+/// Group of three valid signers governed with threshold of two, returns their keys and accounts
+async fn setup_governed_group(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    signer_group: &Keypair,
+    group_owner: &Keypair,
+) -> (Vec<SecretKey>, Vec<Pubkey>) {
+    let mut rng = thread_rng();
+    let priv_keys: Vec<SecretKey> = (0..3)
+        .map(|_| SecretKey::parse(&rng.gen()).unwrap())
+        .collect();
+    let mut valid_signers = vec![];
+    for priv_key in &priv_keys {
+        let eth_address = construct_eth_address(&PublicKey::from_secret_key(priv_key));
+        valid_signers.push(
+            setup_valid_signer(
+                banks_client,
+                payer,
+                recent_blockhash,
+                signer_group,
+                group_owner,
+                eth_address,
+            )
+            .await,
+        );
+    }
+
+    process_tx_signed_by_owner(
+        banks_client,
+        payer,
+        recent_blockhash,
+        instruction::set_governance(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            true,
+            2,
+        )
+        .unwrap(),
+        group_owner,
+    )
+    .await
+    .unwrap();
+    (priv_keys, valid_signers)
+}
+
+#[tokio::test]
+async fn governed_init_valid_signer() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let (priv_keys, valid_signers) = setup_governed_group(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+    )
+    .await;
+
+    let new_eth_address = [9u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let (mut instructions, approvals) = governance_approvals(
+        &mut banks_client,
+        &signer_group.pubkey(),
+        state::GovernanceAction::AddSigner,
+        &new_eth_address,
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[2], &priv_keys[2]),
+        ],
+    )
+    .await;
+    instructions.push(
+        instruction::governed_init_valid_signer(
+            &id(),
+            &signer_group.pubkey(),
+            &payer.pubkey(),
+            Some(&valid_signers[2]),
+            new_eth_address,
+            approvals,
+        )
+        .unwrap(),
+    );
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    // owner doesn't sign the governed change
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let (new_valid_signer, _) =
+        find_valid_signer_address(&id(), &signer_group.pubkey(), &new_eth_address);
+    let valid_signer_data = state::ValidSigner::try_from_slice(
+        &get_account(&mut banks_client, &new_valid_signer).await.data,
+    )
+    .unwrap();
+    assert!(valid_signer_data.is_initialized());
+    assert_eq!(valid_signer_data.eth_addresses(), &[new_eth_address]);
+
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u32::from(signer_group_data.valid_signer_count), 4);
+    assert_eq!(u64::from(signer_group_data.governance_nonce), 1);
+    assert_eq!(signer_group_data.last_signer, new_valid_signer);
+}
+
+#[tokio::test]
+async fn governed_init_valid_signer_with_insufficient_approvals() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let (priv_keys, valid_signers) = setup_governed_group(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+    )
+    .await;
+
+    // approval signed by the key of another signer isn't counted
+    let new_eth_address = [9u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let (mut instructions, approvals) = governance_approvals(
+        &mut banks_client,
+        &signer_group.pubkey(),
+        state::GovernanceAction::AddSigner,
+        &new_eth_address,
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[1], &priv_keys[0]),
+        ],
+    )
+    .await;
+    instructions.push(
+        instruction::governed_init_valid_signer(
+            &id(),
+            &signer_group.pubkey(),
+            &payer.pubkey(),
+            Some(&valid_signers[2]),
+            new_eth_address,
+            approvals,
+        )
+        .unwrap(),
+    );
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        2,
+        error::AudiusError::ThresholdNotReached,
+    );
+
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u32::from(signer_group_data.valid_signer_count), 3);
+    assert_eq!(u64::from(signer_group_data.governance_nonce), 0);
+}
+
+#[tokio::test]
+async fn governed_approval_replay() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let (priv_keys, valid_signers) = setup_governed_group(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+    )
+    .await;
+
+    let new_eth_address = [9u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let (new_valid_signer, _) =
+        find_valid_signer_address(&id(), &signer_group.pubkey(), &new_eth_address);
+    let (add_instructions, add_approvals) = governance_approvals(
+        &mut banks_client,
+        &signer_group.pubkey(),
+        state::GovernanceAction::AddSigner,
+        &new_eth_address,
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[1], &priv_keys[1]),
+        ],
+    )
+    .await;
+    // second quorum approves the same addition at the same nonce, kept to be replayed later
+    let (stale_instructions, stale_approvals) = governance_approvals(
+        &mut banks_client,
+        &signer_group.pubkey(),
+        state::GovernanceAction::AddSigner,
+        &new_eth_address,
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[2], &priv_keys[2]),
+        ],
+    )
+    .await;
+    let mut instructions = add_instructions;
+    instructions.push(
+        instruction::governed_init_valid_signer(
+            &id(),
+            &signer_group.pubkey(),
+            &payer.pubkey(),
+            Some(&valid_signers[2]),
+            new_eth_address,
+            add_approvals,
+        )
+        .unwrap(),
+    );
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let (mut instructions, approvals) = governance_approvals(
+        &mut banks_client,
+        &signer_group.pubkey(),
+        state::GovernanceAction::RemoveSigner,
+        &new_eth_address,
+        &[
+            (valid_signers[1], &priv_keys[1]),
+            (valid_signers[2], &priv_keys[2]),
+        ],
+    )
+    .await;
+    instructions.push(
+        instruction::governed_clear_valid_signer(
+            &id(),
+            &signer_group.pubkey(),
+            new_eth_address,
+            Some(&valid_signers[2]),
+            None,
+            approvals,
+        )
+        .unwrap(),
+    );
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+    // governed removal wipes the whole record like the owner's one
+    let cleared_data = get_account(&mut banks_client, &new_valid_signer).await.data;
+    assert_eq!(cleared_data.len(), state::ValidSigner::LEN);
+    assert!(cleared_data.iter().all(|byte| *byte == 0));
+
+    // approvals signed before the addition are bound to the consumed nonce
+    let mut instructions = stale_instructions;
+    instructions.push(
+        instruction::governed_init_valid_signer(
+            &id(),
+            &signer_group.pubkey(),
+            &payer.pubkey(),
+            Some(&valid_signers[2]),
+            new_eth_address,
+            stale_approvals,
+        )
+        .unwrap(),
+    );
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        2,
+        error::AudiusError::WrongGovernanceMessage,
+    );
+}
+
+/// Account data written by the first program version: version byte followed by the fields
+/// that layout had
+fn v1_account(fields: &[&[u8]]) -> Account {
+    let mut data = vec![1u8];
+    for field in fields {
+        data.extend_from_slice(field);
+    }
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn outdated_layout_accounts() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let signer_group = Pubkey::new_unique();
+    let valid_signer = Pubkey::new_unique();
+    let group_owner = Keypair::new();
+
+    let mut program_test = program_test();
+    program_test.add_account(signer_group, v1_account(&[group_owner.pubkey().as_ref()]));
+    program_test.add_account(
+        valid_signer,
+        v1_account(&[signer_group.as_ref(), eth_address.as_ref()]),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    // reading an outdated group doesn't need a migration
+    let mut transaction = Transaction::new_with_payer(
+        &[instruction::query_signer_group(&id(), &signer_group).unwrap()],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let owner = group_owner.pubkey();
+    let owner_instructions = vec![
+        instruction::set_eth_pop_required(&id(), &signer_group, &owner, true).unwrap(),
+        instruction::set_validation_fee(&id(), &signer_group, &owner, 1, &payer.pubkey()).unwrap(),
+        instruction::set_validation_quota(&id(), &signer_group, &owner, 1).unwrap(),
+        instruction::update_max_signers(&id(), &signer_group, &owner, 1).unwrap(),
+        instruction::add_message_prefix(
+            &id(),
+            &signer_group,
+            &owner,
+            [1; state::SignerGroup::MESSAGE_PREFIX_SIZE],
+        )
+        .unwrap(),
+        instruction::set_cosigner_required(&id(), &signer_group, &owner, true).unwrap(),
+        instruction::set_governance(&id(), &signer_group, &owner, false, 0).unwrap(),
+        instruction::propose_ownership(&id(), &signer_group, &owner, &payer.pubkey()).unwrap(),
+        instruction::set_signer_label(&id(), &valid_signer, &signer_group, &owner, "label")
+            .unwrap(),
+        instruction::set_signer_expiry(&id(), &valid_signer, &signer_group, &owner, 1).unwrap(),
+        instruction::set_signer_cosigner(
+            &id(),
+            &valid_signer,
+            &signer_group,
+            &owner,
+            &payer.pubkey(),
+        )
+        .unwrap(),
+        instruction::add_signer_address(&id(), &valid_signer, &signer_group, &owner, [2; 20])
+            .unwrap(),
+        instruction::init_valid_signer(
+            &id(),
+            &signer_group,
+            &owner,
+            &payer.pubkey(),
+            None,
+            [3; 20],
+        )
+        .unwrap(),
+        instruction::clear_valid_signer(&id(), &valid_signer, &signer_group, &owner, None, None)
+            .unwrap(),
+    ];
+    for instruction in owner_instructions {
+        assert_audius_error(
+            process_tx_signed_by_owner(
+                &mut banks_client,
+                &payer,
+                recent_blockhash,
+                instruction,
+                &group_owner,
+            )
+            .await,
+            0,
+            error::AudiusError::AccountMigrationRequired,
+        );
+    }
+
+    // writes that fit into the outdated signer fail on their own checks
+    let owner_instructions = vec![
+        (
+            instruction::set_activation_slot(&id(), &valid_signer, &signer_group, &owner, 1)
+                .unwrap(),
+            error::AudiusError::SignerAlreadyActive,
+        ),
+        (
+            instruction::remove_signer_address(
+                &id(),
+                &valid_signer,
+                &signer_group,
+                &owner,
+                eth_address,
+            )
+            .unwrap(),
+            error::AudiusError::LastEthAddress,
+        ),
+    ];
+    for (instruction, error) in owner_instructions {
+        assert_audius_error(
+            process_tx_signed_by_owner(
+                &mut banks_client,
+                &payer,
+                recent_blockhash,
+                instruction,
+                &group_owner,
+            )
+            .await,
+            0,
+            error,
+        );
+    }
+
+    // outdated signer is validated by its single eth address, without counters
+    // the outdated accounts have no room for
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group,
+        &priv_key,
+        b"message",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_account(&mut banks_client, &valid_signer).await.data,
+        v1_account(&[signer_group.as_ref(), eth_address.as_ref()]).data
+    );
+}
+
+async fn process_tx_batch_validate_signatures(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    signer_group: &Pubkey,
+    signatures: &[(Pubkey, &SecretKey)],
+    message: &[u8],
+) -> Result<(), TransportError> {
+    let signatures = signatures
+        .iter()
+        .map(|(valid_signer, priv_key)| {
+            let (_, signature_data) = sign_message(priv_key, message, 0);
+            let eth_address = construct_eth_address(&PublicKey::from_secret_key(priv_key));
+            (*valid_signer, signature_data, eth_address)
+        })
+        .collect();
+    let instructions =
+        instruction::batch_validate_signatures(&id(), signer_group, 0, signatures).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn batch_validate_signatures() {
+    let mut rng = thread_rng();
+    let priv_keys: Vec<SecretKey> = (0..3)
+        .map(|_| SecretKey::parse(&rng.gen()).unwrap())
+        .collect();
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let mut valid_signers = vec![];
+    for priv_key in &priv_keys {
+        let eth_address = construct_eth_address(&PublicKey::from_secret_key(priv_key));
+        valid_signers.push(
+            setup_valid_signer(
+                &mut banks_client,
+                &payer,
+                recent_blockhash,
+                &signer_group,
+                &group_owner,
+                eth_address,
+            )
+            .await,
+        );
+    }
+
+    process_tx_batch_validate_signatures(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group.pubkey(),
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[1], &priv_keys[1]),
+            (valid_signers[2], &priv_keys[2]),
+        ],
+        &[1u8; 30],
+    )
+    .await
+    .unwrap();
+    for valid_signer in &valid_signers {
+        let valid_signer = state::ValidSigner::try_from_slice(
+            &get_account(&mut banks_client, valid_signer).await.data,
+        )
+        .unwrap();
+        assert_eq!(u32::from(valid_signer.total_validations), 1);
+    }
+
+    // the same signer may sign more than once
+    process_tx_batch_validate_signatures(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group.pubkey(),
+        &[
+            (valid_signers[0], &priv_keys[0]),
+            (valid_signers[0], &priv_keys[0]),
+        ],
+        &[2u8; 30],
+    )
+    .await
+    .unwrap();
+
+    // a single signature which doesn't verify fails the batch
+    assert_audius_error(
+        process_tx_batch_validate_signatures(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            &signer_group.pubkey(),
+            &[
+                (valid_signers[0], &priv_keys[0]),
+                (valid_signers[1], &priv_keys[1]),
+                (valid_signers[2], &priv_keys[0]),
+            ],
+            &[3u8; 30],
+        )
+        .await,
+        1,
+        error::AudiusError::SignatureVerificationFailed,
+    );
+}
+
+#[tokio::test]
+async fn revoke_eth_address() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let revoked_address = [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE];
+    let revoke_instruction = instruction::revoke_eth_address(
+        &id(),
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer.pubkey(),
+        revoked_address,
+    )
+    .unwrap();
+
+    // only the owner revokes addresses
+    let stranger = Keypair::new();
+    let mut stranger_instruction = revoke_instruction.clone();
+    stranger_instruction.accounts[1].pubkey = stranger.pubkey();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            stranger_instruction,
+            &stranger,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        revoke_instruction,
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    let (registry, _) = find_revocation_registry_address(&id(), &signer_group.pubkey());
+    let registry_account = get_account(&mut banks_client, &registry).await;
+    assert_eq!(registry_account.data.len(), state::RevocationRegistry::LEN);
+    assert_eq!(registry_account.owner, id());
+    let registry_data = state::RevocationRegistry::unpack(&registry_account.data).unwrap();
+    assert_eq!(registry_data.signer_group, signer_group.pubkey());
+    assert_eq!(registry_data.eth_addresses, vec![revoked_address]);
+
+    let result = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        revoked_address,
+    )
+    .await;
+    assert_audius_error(result.map(|_| ()), 0, error::AudiusError::EthAddressRevoked);
+
+    // addresses which aren't revoked are still accepted
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [2u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .unwrap();
+
+    // revoked address can't be added to an existing signer either
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::add_signer_address(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                revoked_address,
+            )
+            .unwrap(),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::EthAddressRevoked,
+    );
+}
+
+async fn process_tx_validate_and_attest(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    valid_signer: &Pubkey,
+    signer_group: &Pubkey,
+    (secp256_program_instruction, signature_data): (Instruction, instruction::SignatureData),
+    expires_at_slot: u64,
+) -> Result<(), TransportError> {
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_and_attest(
+                &id(),
+                valid_signer,
+                signer_group,
+                &payer.pubkey(),
+                None,
+                signature_data,
+                expires_at_slot,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await
+}
+
+#[tokio::test]
+async fn validate_and_attest() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let mut context = program_test().start_with_context().await;
+    let payer = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let recent_blockhash = context.last_blockhash;
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+
+    create_account(
+        &mut context.banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let valid_signer = setup_valid_signer(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let message = [1u8; 30];
+    let expires_at_slot = 100;
+    process_tx_validate_and_attest(
+        &mut context.banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        sign_message(&priv_key, &message, 0),
+        expires_at_slot,
+    )
+    .await
+    .unwrap();
+
+    let message_hash = solana_program::keccak::hash(&message).0;
+    let (attestation, _) = find_attestation_address(&id(), &valid_signer, &message_hash);
+    let attestation_account = get_account(&mut context.banks_client, &attestation).await;
+    assert_eq!(attestation_account.owner, id());
+    assert_eq!(attestation_account.data.len(), state::Attestation::LEN);
+    let attestation_data =
+        state::Attestation::try_from_slice(&attestation_account.data.as_slice()).unwrap();
+    assert_eq!(attestation_data.signer_group, signer_group.pubkey());
+    assert_eq!(attestation_data.valid_signer, valid_signer);
+    assert_eq!(attestation_data.eth_address, eth_address);
+    assert_eq!(attestation_data.message_hash, message_hash);
+    assert_eq!(attestation_data.expires_at_slot, expires_at_slot);
+    assert_eq!(attestation_data.payer, payer.pubkey());
+
+    // the same message is attested only once
+    assert_audius_error(
+        process_tx_validate_and_attest(
+            &mut context.banks_client,
+            &payer,
+            recent_blockhash,
+            &valid_signer,
+            &signer_group.pubkey(),
+            sign_message(&priv_key, &message, 0),
+            expires_at_slot + 1,
+        )
+        .await,
+        1,
+        error::AudiusError::AttestationAlreadyExists,
+    );
+
+    let close = |receiver: &Pubkey, recent_blockhash: Hash| {
+        let mut transaction = Transaction::new_with_payer(
+            &[instruction::close_attestation(&id(), &attestation, receiver).unwrap()],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer], recent_blockhash);
+        transaction
+    };
+
+    // attestation isn't closed before it expires
+    assert_audius_error(
+        context
+            .banks_client
+            .process_transaction(close(&payer.pubkey(), recent_blockhash))
+            .await,
+        0,
+        error::AudiusError::AttestationNotExpired,
+    );
+
+    context.warp_to_slot(expires_at_slot + 1).unwrap();
+    let recent_blockhash = context.banks_client.get_recent_blockhash().await.unwrap();
+
+    // rent goes back only to the account which paid it
+    assert_audius_error(
+        context
+            .banks_client
+            .process_transaction(close(&Pubkey::new_unique(), recent_blockhash))
+            .await,
+        0,
+        error::AudiusError::WrongRentReceiver,
+    );
+
+    context
+        .banks_client
+        .process_transaction(close(&payer.pubkey(), recent_blockhash))
+        .await
+        .unwrap();
+    assert!(context
+        .banks_client
+        .get_account(attestation)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+/// Program data account of the upgradeable loader keeping the upgrade authority
+fn program_data_account(upgrade_authority: &Pubkey) -> Account {
+    let mut data = vec![3, 0, 0, 0];
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: solana_program::bpf_loader_upgradeable::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn creation_whitelist() {
+    let upgrade_authority = Keypair::new();
+    let creator = Keypair::new();
+    let group_owner = Pubkey::new_unique();
+
+    let mut program_test = program_test();
+    program_test.add_account(
+        find_program_data_address(&id()).0,
+        program_data_account(&upgrade_authority.pubkey()),
+    );
+    // creation authority pays for the owner registry
+    program_test.add_account(
+        creator.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            ..Account::default()
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let mut signer_groups = vec![];
+    for _ in 0..3 {
+        let signer_group = Keypair::new();
+        create_account(
+            &mut banks_client,
+            &payer,
+            &recent_blockhash,
+            &signer_group,
+            state::SignerGroup::LEN,
+        )
+        .await
+        .unwrap();
+        signer_groups.push(signer_group.pubkey());
+    }
+
+    // only the upgrade authority manages the whitelist
+    let impostor = Keypair::new();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::add_to_creation_whitelist(
+                &id(),
+                &impostor.pubkey(),
+                &payer.pubkey(),
+                &creator.pubkey(),
+            )
+            .unwrap(),
+            &impostor,
+        )
+        .await,
+        0,
+        error::AudiusError::UnauthorizedOperation,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::add_to_creation_whitelist(
+            &id(),
+            &upgrade_authority.pubkey(),
+            &payer.pubkey(),
+            &creator.pubkey(),
+        )
+        .unwrap(),
+        &upgrade_authority,
+    )
+    .await
+    .unwrap();
+
+    let (creation_whitelist, _) = find_creation_whitelist_address(&id());
+    let whitelist_account = get_account(&mut banks_client, &creation_whitelist).await;
+    assert_eq!(whitelist_account.owner, id());
+    let whitelist = state::CreationWhitelist::unpack(&whitelist_account.data).unwrap();
+    assert!(whitelist.is_initialized());
+    assert_eq!(whitelist.accounts, vec![creator.pubkey()]);
+
+    // creation signed by the payer outside of the whitelist is rejected while it's enforced
+    let signer_group = signer_groups[0];
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::init_signer_group(&id(), &signer_group, &group_owner, &payer.pubkey())
+                .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        0,
+        error::AudiusError::UnauthorizedOperation,
+    );
+
+    // so is creation signed by any other account outside of it
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::init_signer_group(&id(), &signer_group, &group_owner, &impostor.pubkey())
+                .unwrap(),
+            &impostor,
+        )
+        .await,
+        0,
+        error::AudiusError::UnauthorizedOperation,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::init_signer_group(&id(), &signer_group, &group_owner, &creator.pubkey())
+            .unwrap(),
+        &creator,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group).await;
+    assert!(signer_group_data.is_initialized());
+    assert_eq!(signer_group_data.owner, group_owner);
+
+    // owner creating the group along with its signer must be whitelisted too
+    let signer_group = signer_groups[1];
+    let owner = Keypair::new();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::init_signer_group_with_signer(
+                &id(),
+                &signer_group,
+                &owner.pubkey(),
+                &payer.pubkey(),
+                [1; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+            )
+            .unwrap(),
+            &owner,
+        )
+        .await,
+        0,
+        error::AudiusError::UnauthorizedOperation,
+    );
+
+    // bypass flag lets anyone create groups again
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_creation_whitelist_bypass(
+            &id(),
+            &upgrade_authority.pubkey(),
+            &payer.pubkey(),
+            true,
+        )
+        .unwrap(),
+        &upgrade_authority,
+    )
+    .await
+    .unwrap();
+    process_tx_init_signer_group(
+        &signer_group,
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    // as does emptying the whitelist once the flag is cleared
+    for instruction in vec![
+        instruction::set_creation_whitelist_bypass(
+            &id(),
+            &upgrade_authority.pubkey(),
+            &payer.pubkey(),
+            false,
+        )
+        .unwrap(),
+        instruction::remove_from_creation_whitelist(
+            &id(),
+            &upgrade_authority.pubkey(),
+            &payer.pubkey(),
+            &creator.pubkey(),
+        )
+        .unwrap(),
+    ] {
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction,
+            &upgrade_authority,
+        )
+        .await
+        .unwrap();
+    }
+    let whitelist_account = get_account(&mut banks_client, &creation_whitelist).await;
+    let whitelist = state::CreationWhitelist::unpack(&whitelist_account.data).unwrap();
+    assert_eq!(whitelist.flags, 0);
+    assert!(whitelist.accounts.is_empty());
+
+    process_tx_init_signer_group(
+        &signer_groups[2],
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn lockdown_mode() {
+    let upgrade_authority = Keypair::new();
+    let mut program_test = program_test();
+    program_test.add_account(
+        find_program_data_address(&id()).0,
+        program_data_account(&upgrade_authority.pubkey()),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let priv_key = SecretKey::parse(&[7u8; 32]).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    // only the upgrade authority creates the config
+    let impostor = Keypair::new();
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::set_lockdown_mode(&id(), &impostor.pubkey(), &payer.pubkey(), 1).unwrap(),
+            &impostor,
+        )
+        .await,
+        0,
+        error::AudiusError::UnauthorizedOperation,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_lockdown_mode(&id(), &upgrade_authority.pubkey(), &payer.pubkey(), 1)
+            .unwrap(),
+        &upgrade_authority,
+    )
+    .await
+    .unwrap();
+    let (program_config, _) = find_program_config_address(&id());
+    let config_account = get_account(&mut banks_client, &program_config).await;
+    assert_eq!(config_account.owner, id());
+    let config = state::ProgramConfig::unpack(&config_account.data).unwrap();
+    assert_eq!(config.authority, upgrade_authority.pubkey());
+    assert!(config.is_locked());
+
+    // groups and signers can be neither created nor removed
+    let new_group = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &new_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    assert_audius_error(
+        process_tx_init_signer_group(
+            &new_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+        )
+        .await,
+        0,
+        error::AudiusError::ProgramLocked,
+    );
+    assert_audius_error(
+        process_tx_init_valid_signer(
+            &signer_group.pubkey(),
+            &group_owner,
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+            [2u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        )
+        .await
+        .map(|_| ()),
+        0,
+        error::AudiusError::ProgramLocked,
+    );
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::clear_valid_signer(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &group_owner.pubkey(),
+                None,
+                None,
+            )
+            .unwrap(),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::ProgramLocked,
+    );
+
+    // nor changed by their owner
+    let owner_instructions = vec![
+        instruction::set_validation_quota(&id(), &signer_group.pubkey(), &group_owner.pubkey(), 5)
+            .unwrap(),
+        instruction::propose_ownership(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &Pubkey::new_unique(),
+        )
+        .unwrap(),
+        instruction::set_signer_label(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            "locked",
+        )
+        .unwrap(),
+        instruction::add_signer_address(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            [4u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        )
+        .unwrap(),
+        instruction::revoke_eth_address(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            [5u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+        )
+        .unwrap(),
+    ];
+    for owner_instruction in owner_instructions {
+        assert_audius_error(
+            process_tx_signed_by_owner(
+                &mut banks_client,
+                &payer,
+                recent_blockhash,
+                owner_instruction,
+                &group_owner,
+            )
+            .await,
+            0,
+            error::AudiusError::ProgramLocked,
+        );
+    }
+
+    // while signatures are still validated
+    process_tx_validate_signature(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &valid_signer,
+        &signer_group.pubkey(),
+        &priv_key,
+        b"validated in lockdown",
+    )
+    .await
+    .unwrap();
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_lockdown_mode(&id(), &upgrade_authority.pubkey(), &payer.pubkey(), 0)
+            .unwrap(),
+        &upgrade_authority,
+    )
+    .await
+    .unwrap();
+    process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &group_owner,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [3u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn delegate_operator() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let first_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+    let delegate = Keypair::new();
+
+    // only the owner may set the delegate operator
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::set_delegate_operator(
+                &id(),
+                &signer_group.pubkey(),
+                &delegate.pubkey(),
+                &delegate.pubkey(),
+            )
+            .unwrap(),
+            &delegate,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_delegate_operator(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &delegate.pubkey(),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.delegate_operator, delegate.pubkey());
+
+    // delegate adds valid signers
+    let valid_signer = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &delegate,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [2u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u32::from(signer_group_data.valid_signer_count), 2);
+    assert_eq!(signer_group_data.last_signer, valid_signer);
+
+    // but can't remove them
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::clear_valid_signer(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &delegate.pubkey(),
+                Some(&first_signer),
+                None,
+            )
+            .unwrap(),
+            &delegate,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    // nor hand the delegation over
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::clear_delegate_operator(&id(), &signer_group.pubkey(), &delegate.pubkey())
+                .unwrap(),
+            &delegate,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::clear_delegate_operator(&id(), &signer_group.pubkey(), &group_owner.pubkey())
+            .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let result = process_tx_init_valid_signer(
+        &signer_group.pubkey(),
+        &delegate,
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+        [3u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await
+    .map(|_| ());
+    assert_audius_error(result, 0, error::AudiusError::WrongOwner);
+}
+
+#[tokio::test]
+async fn validate_signature_batch() {
+    let mut rng = thread_rng();
+    let priv_key = SecretKey::parse(&rng.gen()).unwrap();
+    let eth_address = construct_eth_address(&PublicKey::from_secret_key(&priv_key));
+
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let valid_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        eth_address,
+    )
+    .await;
+
+    let batch = |messages: &[&[u8]]| {
+        let signatures = messages
+            .iter()
+            .map(|message| (sign_message(&priv_key, message, 0).1, eth_address))
+            .collect();
+        instruction::validate_signature_batch(
+            &id(),
+            &valid_signer,
+            &signer_group.pubkey(),
+            0,
+            signatures,
+        )
+        .unwrap()
+    };
+
+    let mut transaction = Transaction::new_with_payer(
+        &batch(&[b"track-1", b"track-2", b"track-3"]),
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    let valid_signer_data = state::ValidSigner::unpack(&valid_signer_account.data).unwrap();
+    assert_eq!(u32::from(valid_signer_data.total_validations), 3);
+
+    // one tampered message fails the whole batch
+    let mut instructions = batch(&[b"track-4", b"track-5", b"track-6"]);
+    let mut signatures = match instruction::AudiusInstruction::unpack(&instructions[1].data) {
+        Ok(instruction::AudiusInstruction::ValidateSignatureBatch(signatures)) => signatures,
+        _ => panic!("wrong instruction unpacked"),
+    };
+    signatures[1].message = b"track-7".to_vec();
+    instructions[1].data = instruction::AudiusInstruction::ValidateSignatureBatch(signatures)
+        .pack()
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::SignatureVerificationFailed,
+    );
+
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    let valid_signer_data = state::ValidSigner::unpack(&valid_signer_account.data).unwrap();
+    assert_eq!(u32::from(valid_signer_data.total_validations), 3);
+}
+
+#[tokio::test]
+async fn migrate_valid_signer() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+    let prev_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &signer_group,
+        &group_owner,
+        [1u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    // Ed25519 signer's account address isn't derived from its group
+    let valid_signer = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &valid_signer,
+        state::ValidSigner::LEN,
+    )
+    .await
+    .unwrap();
+    let ed25519_key = Keypair::new();
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::init_valid_signer_ed25519(
+            &id(),
+            &valid_signer.pubkey(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            Some(&prev_signer),
+            &ed25519_key.pubkey(),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let valid_signer = valid_signer.pubkey();
+
+    let new_signer_group = Keypair::new();
+    let new_owner = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &new_signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    let new_last_signer = setup_valid_signer(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        &new_signer_group,
+        &new_owner,
+        [3u8; state::SecpSignatureOffsets::ETH_ADDRESS_SIZE],
+    )
+    .await;
+
+    let migrate = |valid_signer: &Pubkey,
+                   new_owner: &Pubkey,
+                   prev_signer: Option<&Pubkey>,
+                   next_signer: Option<&Pubkey>| {
+        instruction::migrate_valid_signer(
+            &id(),
+            valid_signer,
+            &signer_group.pubkey(),
+            &new_signer_group.pubkey(),
+            &group_owner.pubkey(),
+            new_owner,
+            prev_signer,
+            next_signer,
+            Some(&new_last_signer),
+        )
+        .unwrap()
+    };
+
+    // both owners have to sign
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            migrate(
+                &valid_signer,
+                &group_owner.pubkey(),
+                Some(&prev_signer),
+                None,
+            ),
+            &group_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongOwner,
+    );
+
+    // signer at the address derived from its group and eth address stays in the group
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate(
+            &prev_signer,
+            &new_owner.pubkey(),
+            None,
+            Some(&valid_signer),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner, &new_owner], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        0,
+        error::AudiusError::DerivedSignerMigration,
+    );
+
+    // governed group is only joined with approvals of its signers
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            instruction::set_governance(
+                &id(),
+                &new_signer_group.pubkey(),
+                &new_owner.pubkey(),
+                true,
+                1,
+            )
+            .unwrap(),
+            migrate(&valid_signer, &new_owner.pubkey(), Some(&prev_signer), None),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner, &new_owner], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::SignerGroupGoverned,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate(
+            &valid_signer,
+            &new_owner.pubkey(),
+            Some(&prev_signer),
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &group_owner, &new_owner], recent_blockhash);
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let get_valid_signer = |account: Account| state::ValidSigner::unpack(&account.data).unwrap();
+    let valid_signer_data = get_valid_signer(get_account(&mut banks_client, &valid_signer).await);
+    assert_eq!(valid_signer_data.signer_group, new_signer_group.pubkey());
+    assert_eq!(valid_signer_data.ed25519_pubkey, ed25519_key.pubkey());
+    assert_eq!(valid_signer_data.prev_signer, new_last_signer);
+    assert_eq!(valid_signer_data.next_signer, Pubkey::default());
+
+    // the signer left the old group's list
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(u32::from(signer_group_data.valid_signer_count), 1);
+    assert_eq!(signer_group_data.last_signer, prev_signer);
+    let prev_signer_data = get_valid_signer(get_account(&mut banks_client, &prev_signer).await);
+    assert_eq!(prev_signer_data.next_signer, Pubkey::default());
+
+    // and joined the tail of the new group's one
+    let new_signer_group_data =
+        get_signer_group(&mut banks_client, &new_signer_group.pubkey()).await;
+    assert_eq!(u32::from(new_signer_group_data.valid_signer_count), 2);
+    assert_eq!(new_signer_group_data.last_signer, valid_signer);
+    let new_last_signer_data =
+        get_valid_signer(get_account(&mut banks_client, &new_last_signer).await);
+    assert_eq!(new_last_signer_data.next_signer, valid_signer);
+}
+
+#[tokio::test]
+async fn validate_signature_with_merkle_proof() {
+    let (mut banks_client, payer, recent_blockhash, signer_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let priv_keys: Vec<SecretKey> = (0..3)
+        .map(|_| SecretKey::parse(&thread_rng().gen()).unwrap())
+        .collect();
+    let leaves: Vec<merkle::MerkleNode> = priv_keys
+        .iter()
+        .map(|priv_key| {
+            merkle::leaf(&construct_eth_address(&PublicKey::from_secret_key(
+                priv_key,
+            )))
+        })
+        .collect();
+
+    let validate_transaction = |priv_key: &SecretKey, proof: Vec<merkle::MerkleNode>| {
+        let (secp256_program_instruction, signature_data) =
+            sign_message(priv_key, b"proven member", 0);
+        let mut transaction = Transaction::new_with_payer(
+            &[
+                secp256_program_instruction,
+                instruction::validate_signature_with_merkle_proof(
+                    &id(),
+                    &signer_group.pubkey(),
+                    None,
+                    signature_data,
+                    proof,
+                )
+                .unwrap(),
+            ],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer], recent_blockhash);
+        transaction
+    };
+
+    // nothing is proven before the owner sets the root
+    assert_audius_error(
+        banks_client
+            .process_transaction(validate_transaction(
+                &priv_keys[0],
+                merkle::proof(&leaves, 0).unwrap(),
+            ))
+            .await,
+        1,
+        error::AudiusError::MerkleRootNotSet,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::update_merkle_root(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            merkle::root(&leaves),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.members_merkle_root, merkle::root(&leaves));
+
+    for (index, priv_key) in priv_keys.iter().enumerate() {
+        banks_client
+            .process_transaction(validate_transaction(
+                priv_key,
+                merkle::proof(&leaves, index).unwrap(),
+            ))
+            .await
+            .unwrap();
+    }
+
+    // proof of another member doesn't prove the signer
+    assert_audius_error(
+        banks_client
+            .process_transaction(validate_transaction(
+                &priv_keys[0],
+                merkle::proof(&leaves, 2).unwrap(),
+            ))
+            .await,
+        1,
+        error::AudiusError::InvalidMerkleProof,
+    );
+    let outsider = SecretKey::parse(&thread_rng().gen()).unwrap();
+    assert_audius_error(
+        banks_client
+            .process_transaction(validate_transaction(
+                &outsider,
+                merkle::proof(&leaves, 0).unwrap(),
+            ))
+            .await,
+        1,
+        error::AudiusError::InvalidMerkleProof,
+    );
+
+    // revoked member's proof is no longer accepted
+    let revoked_key = &priv_keys[1];
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::revoke_eth_address(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &payer.pubkey(),
+            construct_eth_address(&PublicKey::from_secret_key(revoked_key)),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let (secp256_program_instruction, signature_data) =
+        sign_message(revoked_key, b"revoked member", 0);
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            secp256_program_instruction,
+            instruction::validate_signature_with_merkle_proof(
+                &id(),
+                &signer_group.pubkey(),
+                None,
+                signature_data,
+                merkle::proof(&leaves, 1).unwrap(),
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert_audius_error(
+        banks_client.process_transaction(transaction).await,
+        1,
+        error::AudiusError::EthAddressRevoked,
+    );
+}
+
+/// SPL Governance v2 governance account of the realm, the account type tag followed by
+/// the realm and the governed account
+fn governance_account(realm: &Pubkey) -> Account {
+    let mut data = vec![18];
+    data.extend_from_slice(realm.as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: spl_governance::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn execute_governance_proposal() {
+    let realm = Pubkey::new_unique();
+    let governance = Keypair::new();
+    let foreign_governance = Keypair::new();
+    let signer_group = Keypair::new();
+    let group_owner = Keypair::new();
+
+    let mut program_test = program_test();
+    program_test.add_account(governance.pubkey(), governance_account(&realm));
+    program_test.add_account(
+        foreign_governance.pubkey(),
+        governance_account(&Pubkey::new_unique()),
+    );
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &signer_group,
+        state::SignerGroup::LEN,
+    )
+    .await
+    .unwrap();
+    process_tx_init_signer_group(
+        &signer_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+
+    let init_proposal = |governance: &Pubkey, eth_address| {
+        instruction::execute_governance_proposal(
+            instruction::init_valid_signer(
+                &id(),
+                &signer_group.pubkey(),
+                governance,
+                &payer.pubkey(),
+                None,
+                eth_address,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    // proposals aren't executed before the owner sets the realm
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            init_proposal(&governance.pubkey(), [2; 20]),
+            &governance,
+        )
+        .await,
+        0,
+        error::AudiusError::GovernanceRealmNotSet,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_governance_realm(
+            &id(),
+            &signer_group.pubkey(),
+            &group_owner.pubkey(),
+            &realm,
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.governance_realm, realm);
+
+    // governance of another realm can't execute the proposal
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            init_proposal(&foreign_governance.pubkey(), [1; 20]),
+            &foreign_governance,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongGovernanceRealm,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        init_proposal(&governance.pubkey(), [1; 20]),
+        &governance,
+    )
+    .await
+    .unwrap();
+    let valid_signer = find_valid_signer_address(&id(), &signer_group.pubkey(), &[1; 20]).0;
+    let valid_signer_data = state::ValidSigner::try_from_slice(
+        &get_account(&mut banks_client, &valid_signer).await.data,
+    )
+    .unwrap();
+    assert!(valid_signer_data.is_initialized());
+    assert_eq!(valid_signer_data.signer_group, signer_group.pubkey());
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::execute_governance_proposal(
+            instruction::clear_valid_signer(
+                &id(),
+                &valid_signer,
+                &signer_group.pubkey(),
+                &governance.pubkey(),
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+        .unwrap(),
+        &governance,
+    )
+    .await
+    .unwrap();
+    let valid_signer_account = get_account(&mut banks_client, &valid_signer).await;
+    assert!(valid_signer_account.data.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn clone_signer_group() {
+    let (mut banks_client, payer, recent_blockhash, source_group, group_owner) = setup().await;
+
+    process_tx_init_signer_group(
+        &source_group.pubkey(),
+        &group_owner.pubkey(),
+        &payer,
+        recent_blockhash,
+        &mut banks_client,
+    )
+    .await
+    .unwrap();
+    for eth_address in &[[1; 20], [2; 20]] {
+        process_tx_init_valid_signer(
+            &source_group.pubkey(),
+            &group_owner,
+            &payer,
+            recent_blockhash,
+            &mut banks_client,
+            *eth_address,
+        )
+        .await
+        .unwrap();
+    }
+    let first_signer = find_valid_signer_address(&id(), &source_group.pubkey(), &[1; 20]).0;
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::set_signer_label(
+            &id(),
+            &first_signer,
+            &source_group.pubkey(),
+            &group_owner.pubkey(),
+            "node-1",
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+
+    let ed25519_signer = Keypair::new();
+    create_account(
+        &mut banks_client,
+        &payer,
+        &recent_blockhash,
+        &ed25519_signer,
+        state::ValidSigner::LEN,
+    )
+    .await
+    .unwrap();
+    let last_signer = get_signer_group(&mut banks_client, &source_group.pubkey())
+        .await
+        .last_signer;
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::init_valid_signer_ed25519(
+            &id(),
+            &ed25519_signer.pubkey(),
+            &source_group.pubkey(),
+            &group_owner.pubkey(),
+            Some(&last_signer),
+            &Pubkey::new_unique(),
+        )
+        .unwrap(),
+        &group_owner,
+    )
+    .await
+    .unwrap();
+    let source_signers = traverse_signer_group(&mut banks_client, &source_group.pubkey()).await;
+
+    // copies go to the group of another owner
+    let signer_group = Keypair::new();
+    let new_owner = Keypair::new();
+    let ed25519_copy = Keypair::new();
+    for (account, size) in &[
+        (&signer_group, state::SignerGroup::LEN),
+        (&ed25519_copy, state::ValidSigner::LEN),
+    ] {
+        create_account(&mut banks_client, &payer, &recent_blockhash, account, *size)
+            .await
+            .unwrap();
+    }
+    let copies = vec![
+        find_valid_signer_address(&id(), &signer_group.pubkey(), &[1; 20]).0,
+        find_valid_signer_address(&id(), &signer_group.pubkey(), &[2; 20]).0,
+        ed25519_copy.pubkey(),
+    ];
+    let signers: Vec<(Pubkey, Pubkey)> = source_signers
+        .iter()
+        .copied()
+        .zip(copies.iter().copied())
+        .collect();
+
+    // every signer of the source group is copied
+    assert_audius_error(
+        process_tx_signed_by_owner(
+            &mut banks_client,
+            &payer,
+            recent_blockhash,
+            instruction::clone_signer_group(
+                &id(),
+                &source_group.pubkey(),
+                &signer_group.pubkey(),
+                &new_owner.pubkey(),
+                &payer.pubkey(),
+                &signers[..2],
+            )
+            .unwrap(),
+            &new_owner,
+        )
+        .await,
+        0,
+        error::AudiusError::WrongSignerListAccount,
+    );
+
+    process_tx_signed_by_owner(
+        &mut banks_client,
+        &payer,
+        recent_blockhash,
+        instruction::clone_signer_group(
+            &id(),
+            &source_group.pubkey(),
+            &signer_group.pubkey(),
+            &new_owner.pubkey(),
+            &payer.pubkey(),
+            &signers,
+        )
+        .unwrap(),
+        &new_owner,
+    )
+    .await
+    .unwrap();
+
+    let signer_group_data = get_signer_group(&mut banks_client, &signer_group.pubkey()).await;
+    assert_eq!(signer_group_data.owner, new_owner.pubkey());
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &signer_group.pubkey()).await,
+        copies
+    );
+    assert_eq!(
+        traverse_signer_group(&mut banks_client, &source_group.pubkey()).await,
+        source_signers
+    );
+    for (source_signer, copy) in signers {
+        let source_signer = state::ValidSigner::try_from_slice(
+            &get_account(&mut banks_client, &source_signer).await.data,
+        )
+        .unwrap();
+        let copy =
+            state::ValidSigner::try_from_slice(&get_account(&mut banks_client, &copy).await.data)
+                .unwrap();
+        assert_eq!(copy.signer_group, signer_group.pubkey());
+        assert_eq!(copy.key_type, source_signer.key_type);
+        assert_eq!(copy.eth_addresses(), source_signer.eth_addresses());
+        assert_eq!(copy.ed25519_pubkey, source_signer.ed25519_pubkey);
+        assert_eq!(copy.label, source_signer.label);
+    }
+    assert_eq!(
+        get_valid_signer_label(&mut banks_client, &copies[0]).await,
+        "node-1"
+    );
 }
@@ -0,0 +1,274 @@
+//! TypeScript declarations of the Audius program instruction and state types
+
+use quote::ToTokens;
+use syn::{
+    Attribute, Fields, GenericArgument, Item, ItemEnum, ItemStruct, Lit, Meta, PathArguments, Type,
+    Visibility,
+};
+
+/// Program sources the declarations are generated from, relative to the repository root
+pub const SOURCES: &[&str] = &[
+    "types/src/lib.rs",
+    "program/src/state.rs",
+    "program/src/instruction.rs",
+];
+
+/// Header of the generated file
+const HEADER: &str = "// Generated by tools/gen_ts from types/src/lib.rs, program/src/state.rs and
+// program/src/instruction.rs, run `cargo run` in tools/gen_ts after changing them. Do not edit.
+";
+
+/// Generate declarations of the public structs and enums of the given Rust sources
+pub fn generate(sources: &[&str]) -> Result<String, syn::Error> {
+    let mut declarations = vec![HEADER.to_string()];
+    for source in sources {
+        for item in syn::parse_file(source)?.items {
+            match item {
+                Item::Struct(item) if is_public(&item.vis) => {
+                    declarations.push(struct_declaration(&item))
+                }
+                Item::Enum(item) if is_public(&item.vis) => {
+                    declarations.push(enum_declaration(&item))
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(declarations.join("\n"))
+}
+
+fn is_public(vis: &Visibility) -> bool {
+    matches!(vis, Visibility::Public(_))
+}
+
+/// Convert type of a Rust field to TypeScript type
+pub fn ts_type(ty: &Type) -> String {
+    match ty {
+        Type::Array(array) if is_u8(&array.elem) => "Uint8Array".to_string(),
+        Type::Array(array) => array_type(&array.elem),
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last().expect("empty type path");
+            match segment.ident.to_string().as_str() {
+                "u8" | "u16" | "u32" | "i8" | "i16" | "i32" => "number".to_string(),
+                "u64" | "u128" | "i64" | "i128" => "bigint".to_string(),
+                "bool" => "boolean".to_string(),
+                "String" | "Pubkey" => "string".to_string(),
+                "Vec" if is_u8(generic_argument(&segment.arguments)) => "Uint8Array".to_string(),
+                "Vec" => array_type(generic_argument(&segment.arguments)),
+                "Option" => format!("{} | null", ts_type(generic_argument(&segment.arguments))),
+                "Box" => ts_type(generic_argument(&segment.arguments)),
+                name => name.to_string(),
+            }
+        }
+        _ => panic!("Unsupported type {}", ty.to_token_stream()),
+    }
+}
+
+fn array_type(elem: &Type) -> String {
+    let elem = ts_type(elem);
+    if elem.contains(' ') {
+        format!("({})[]", elem)
+    } else {
+        format!("{}[]", elem)
+    }
+}
+
+fn is_u8(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("u8"))
+}
+
+fn generic_argument(arguments: &PathArguments) -> &Type {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(ty)) => ty,
+            _ => panic!(
+                "Unsupported generic argument {}",
+                arguments.to_token_stream()
+            ),
+        },
+        _ => panic!("Missing generic argument"),
+    }
+}
+
+/// JSDoc comment built from `///` doc comments
+fn doc_comment(attrs: &[Attribute], indent: &str) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(meta)) if meta.path.is_ident("doc") => match meta.lit {
+                Lit::Str(doc) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut comment = format!("{}/**\n", indent);
+    for line in lines {
+        if line.is_empty() {
+            comment.push_str(&format!("{} *\n", indent));
+        } else {
+            comment.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    comment.push_str(&format!("{} */\n", indent));
+    comment
+}
+
+/// Named fields as TypeScript object members
+fn fields_members(fields: &Fields, indent: &str) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}{}{}: {};\n",
+                doc_comment(&field.attrs, indent),
+                indent,
+                field.ident.as_ref().expect("unnamed field"),
+                ts_type(&field.ty)
+            )
+        })
+        .collect()
+}
+
+/// Tuple fields as TypeScript tuple type
+fn tuple_type(fields: &Fields) -> String {
+    let types: Vec<String> = fields.iter().map(|field| ts_type(&field.ty)).collect();
+    format!("[{}]", types.join(", "))
+}
+
+/// Structs with named fields become interfaces, newtypes become type aliases
+fn struct_declaration(item: &ItemStruct) -> String {
+    let docs = doc_comment(&item.attrs, "");
+    match &item.fields {
+        Fields::Named(_) => format!(
+            "{}export interface {} {{\n{}}}\n",
+            docs,
+            item.ident,
+            fields_members(&item.fields, "  ")
+        ),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => format!(
+            "{}export type {} = {};\n",
+            docs,
+            item.ident,
+            ts_type(&fields.unnamed[0].ty)
+        ),
+        Fields::Unnamed(_) => format!(
+            "{}export type {} = {};\n",
+            docs,
+            item.ident,
+            tuple_type(&item.fields)
+        ),
+        Fields::Unit => format!("{}export type {} = {{}};\n", docs, item.ident),
+    }
+}
+
+/// Enums become a union of variants told by `kind`, along with the enum
+/// of Borsh tags variants are serialized with
+fn enum_declaration(item: &ItemEnum) -> String {
+    let docs = doc_comment(&item.attrs, "");
+
+    let mut tags = format!("{}export enum {}Tag {{\n", docs, item.ident);
+    for (tag, variant) in item.variants.iter().enumerate() {
+        tags.push_str(&format!("  {} = {},\n", variant.ident, tag));
+    }
+    tags.push_str("}\n");
+
+    let mut union = format!("{}export type {} =\n", docs, item.ident);
+    for variant in &item.variants {
+        let members = match &variant.fields {
+            Fields::Named(_) => format!("\n{}  ", fields_members(&variant.fields, "      ")),
+            Fields::Unnamed(_) => format!(" fields: {}; ", tuple_type(&variant.fields)),
+            Fields::Unit => " ".to_string(),
+        };
+        union.push_str(&format!(
+            "{}  | {{ kind: \"{}\";{}}}\n",
+            doc_comment(&variant.attrs, "  "),
+            variant.ident,
+            members
+        ));
+    }
+    union.pop();
+    union.push_str(";\n");
+
+    format!("{}\n{}", tags, union)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{fs, path::Path};
+
+    fn ts(ty: &str) -> String {
+        ts_type(&syn::parse_str(ty).unwrap())
+    }
+
+    #[test]
+    fn test_ts_type() {
+        assert_eq!(ts("u8"), "number");
+        assert_eq!(ts("u16"), "number");
+        assert_eq!(ts("u64"), "bigint");
+        assert_eq!(ts("bool"), "boolean");
+        assert_eq!(ts("Pubkey"), "string");
+        assert_eq!(
+            ts("[u8; SecpSignatureOffsets::ETH_ADDRESS_SIZE]"),
+            "Uint8Array"
+        );
+        assert_eq!(ts("[[u8; 20]; 3]"), "Uint8Array[]");
+        assert_eq!(ts("Vec<u8>"), "Uint8Array");
+        assert_eq!(ts("Vec<SignatureData>"), "SignatureData[]");
+        assert_eq!(ts("Option<SignatureData>"), "SignatureData | null");
+        assert_eq!(ts("Vec<Option<u8>>"), "(number | null)[]");
+        assert_eq!(ts("Box<AudiusInstruction>"), "AudiusInstruction");
+        assert_eq!(ts("PodU64"), "PodU64");
+    }
+
+    #[test]
+    fn test_generate() {
+        let source = r#"
+            /// Args
+            pub struct Args {
+                /// Amount
+                pub amount: u64,
+                pub key: Pubkey,
+            }
+            pub struct Pod(pub [u8; 8]);
+            struct Private(u8);
+            pub enum Instruction {
+                /// Init
+                Init,
+                Set(u8, Option<Args>),
+                Batch { threshold: u8 },
+            }
+        "#;
+        let generated = generate(&[source]).unwrap();
+        assert!(generated.contains(
+            "/**\n * Args\n */\nexport interface Args {\n  /**\n   * Amount\n   */\n  amount: bigint;\n  key: string;\n}\n"
+        ));
+        assert!(generated.contains("export type Pod = Uint8Array;\n"));
+        assert!(!generated.contains("Private"));
+        assert!(generated
+            .contains("export enum InstructionTag {\n  Init = 0,\n  Set = 1,\n  Batch = 2,\n}\n"));
+        assert!(generated.contains("  | { kind: \"Set\"; fields: [number, Args | null]; }\n"));
+        assert!(generated.contains("  | { kind: \"Batch\";\n      threshold: number;\n  };\n"));
+    }
+
+    #[test]
+    fn test_declarations_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let sources: Vec<String> = SOURCES
+            .iter()
+            .map(|source| fs::read_to_string(root.join(source)).unwrap())
+            .collect();
+        let sources: Vec<&str> = sources.iter().map(String::as_str).collect();
+        let declarations =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../../js_client/generated/audius.d.ts");
+        assert_eq!(
+            generate(&sources).unwrap(),
+            fs::read_to_string(declarations).unwrap(),
+            "js_client/generated/audius.d.ts is stale, run `cargo run` in tools/gen_ts"
+        );
+    }
+}
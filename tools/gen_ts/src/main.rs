@@ -0,0 +1,31 @@
+//! Writes TypeScript declarations of the Audius program types to the JS client
+
+use gen_ts::{generate, SOURCES};
+use std::{fs, path::Path, process::exit};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let sources: Vec<String> = SOURCES
+        .iter()
+        .map(|source| fs::read_to_string(root.join(source)))
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|err| {
+            eprintln!("error: can't read program sources: {}", err);
+            exit(1);
+        });
+    let sources: Vec<&str> = sources.iter().map(String::as_str).collect();
+
+    let declarations = generate(&sources).unwrap_or_else(|err| {
+        eprintln!("error: can't parse program sources: {}", err);
+        exit(1);
+    });
+
+    let output_dir = root.join("js_client/generated");
+    let output = output_dir.join("audius.d.ts");
+    if let Err(err) = fs::create_dir_all(&output_dir).and_then(|_| fs::write(&output, declarations))
+    {
+        eprintln!("error: can't write {}: {}", output.display(), err);
+        exit(1);
+    }
+    println!("Generated {}", output.display());
+}
@@ -0,0 +1,83 @@
+//! Plain data types of the Audius program state, usable without `std` and the Solana SDK
+
+#![no_std]
+
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Type of the valid signer key, stored as a plain byte to keep ValidSigner `Pod`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+pub struct SignerKeyType(pub u8);
+
+impl SignerKeyType {
+    /// Secp256k1 key identified by Ethereum address
+    pub const SECP256K1: SignerKeyType = SignerKeyType(0);
+    /// Ed25519 public key
+    pub const ED25519: SignerKeyType = SignerKeyType(1);
+}
+
+/// Little-endian u64 stored as plain bytes to keep ValidSigner `Pod`, serialized as u64 by Borsh
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+pub struct PodU64(pub [u8; 8]);
+
+impl From<u64> for PodU64 {
+    fn from(value: u64) -> Self {
+        PodU64(value.to_le_bytes())
+    }
+}
+
+impl From<PodU64> for u64 {
+    fn from(value: PodU64) -> Self {
+        u64::from_le_bytes(value.0)
+    }
+}
+
+/// Little-endian u32 stored as plain bytes to keep state unaligned, serialized as u32 by Borsh
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+pub struct PodU32(pub [u8; 4]);
+
+impl From<u32> for PodU32 {
+    fn from(value: u32) -> Self {
+        PodU32(value.to_le_bytes())
+    }
+}
+
+impl From<PodU32> for u32 {
+    fn from(value: PodU32) -> Self {
+        u32::from_le_bytes(value.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pod_conversions() {
+        assert_eq!(u64::from(PodU64::from(12345)), 12345);
+        assert_eq!(u32::from(PodU32::from(u32::MAX - 1)), u32::MAX - 1);
+        assert_eq!(PodU64::from(1).0, [1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_pod_borsh() {
+        use borsh::BorshSerialize;
+
+        // stored the same way Borsh serializes integers
+        assert_eq!(
+            PodU64::from(u64::MAX - 1).try_to_vec().unwrap(),
+            (u64::MAX - 1).try_to_vec().unwrap()
+        );
+        assert_eq!(
+            PodU32::from(7).try_to_vec().unwrap(),
+            7u32.try_to_vec().unwrap()
+        );
+    }
+}